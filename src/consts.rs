@@ -15,47 +15,81 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::units::exact::ExactFactor;
+
 /// Length
 ///
 /// The conversion numeric for a foot to a meter
 pub(crate) const LENGTH_FT_TO_METER: f64 = 0.3048;
+/// The exact conversion ratio for a foot to a meter
+pub(crate) const LENGTH_FT_TO_METER_EXACT: ExactFactor = ExactFactor::ratio(381, 1250);
 /// The conversion numeric for an inch to a meter
 pub(crate) const LENGTH_IN_TO_METER: f64 = 0.0254;
+/// The exact conversion ratio for an inch to a meter
+pub(crate) const LENGTH_IN_TO_METER_EXACT: ExactFactor = ExactFactor::ratio(127, 5000);
 /// The conversion numeric for a yard to a meter
 pub(crate) const LENGTH_YD_TO_METER: f64 = 0.9144;
+/// The exact conversion ratio for a yard to a meter
+pub(crate) const LENGTH_YD_TO_METER_EXACT: ExactFactor = ExactFactor::ratio(1143, 1250);
 /// The conversion numeric for a mile to a meter
 pub(crate) const LENGTH_MILE_TO_METER: f64 = 1609.344;
+/// The exact conversion ratio for a mile to a meter
+pub(crate) const LENGTH_MILE_TO_METER_EXACT: ExactFactor = ExactFactor::ratio(201_168, 125);
 /// The conversion numeric for a astronomical unit to a meter
 pub(crate) const LENGTH_AU_TO_METER: f64 = 149_597_870_700.0;
+/// The exact conversion ratio for a astronomical unit to a meter
+pub(crate) const LENGTH_AU_TO_METER_EXACT: ExactFactor = ExactFactor::from_int(149_597_870_700);
 /// The conversion numeric for a lightyear to a meter
 pub(crate) const LENGTH_LYR_TO_METER: f64 = 9_460_730_472_580_800.0;
+/// The exact conversion ratio for a lightyear to a meter
+pub(crate) const LENGTH_LYR_TO_METER_EXACT: ExactFactor =
+    ExactFactor::from_int(9_460_730_472_580_800);
 /// The conversion numeric for an Ångström to a meter
 pub(crate) const LENGTH_A_TO_METER: f64 = 0.000_000_000_1;
+/// The exact conversion ratio for an Ångström to a meter
+pub(crate) const LENGTH_A_TO_METER_EXACT: ExactFactor = ExactFactor::ratio(1, 10_000_000_000);
 /// The conversion numeric for a parsec to a meter
 pub(crate) const LENGTH_PC_TO_METER: f64 = (648_000.0 / std::f64::consts::PI) * LENGTH_AU_TO_METER;
+/// The exact conversion ratio for a parsec to a meter
+pub(crate) const LENGTH_PC_TO_METER_EXACT: ExactFactor =
+    ExactFactor::from_int(96_939_420_213_600_000).with_pi(-1);
 
 /// Mass
 ///
 /// The conversion numeric for a pound to a gram
 pub(crate) const MASS_LB_TO_G: f64 = 453.592_37;
+/// The exact conversion ratio for a pound to a gram
+pub(crate) const MASS_LB_TO_G_EXACT: ExactFactor = ExactFactor::ratio(45_359_237, 100_000);
 /// The conversion numeric for a grain to a gram
 pub(crate) const MASS_GR_TO_G: f64 = MASS_LB_TO_G / 7000.0;
+/// The exact conversion ratio for a grain to a gram
+pub(crate) const MASS_GR_TO_G_EXACT: ExactFactor = ExactFactor::ratio(6_479_891, 100_000_000);
 /// The conversion numeric for an ounce to a gram
 pub(crate) const MASS_OZ_TO_G: f64 = MASS_LB_TO_G / 16.0;
+/// The exact conversion ratio for an ounce to a gram
+pub(crate) const MASS_OZ_TO_G_EXACT: ExactFactor = ExactFactor::ratio(45_359_237, 1_600_000);
 
 /// Angle
 ///
 /// The conversion numeric for a degree to a radian
 pub(crate) const ANGLE_DEG_TO_RAD: f64 = std::f64::consts::PI / 180.0;
+/// The exact conversion ratio for a degree to a radian
+pub(crate) const ANGLE_DEG_TO_RAD_EXACT: ExactFactor = ExactFactor::ratio(1, 180).with_pi(1);
 /// The conversion numeric for a minute of angle to a radian
 pub(crate) const ANGLE_MOA_TO_RAD: f64 = std::f64::consts::PI / 10800.0;
+/// The exact conversion ratio for a minute of angle to a radian
+pub(crate) const ANGLE_MOA_TO_RAD_EXACT: ExactFactor = ExactFactor::ratio(1, 10800).with_pi(1);
 /// The conversion numeric for an arcsecond to a radian
 pub(crate) const ANGLE_ASEC_TO_RAD: f64 = ANGLE_MOA_TO_RAD / 60.0;
+/// The exact conversion ratio for an arcsecond to a radian
+pub(crate) const ANGLE_ASEC_TO_RAD_EXACT: ExactFactor = ExactFactor::ratio(1, 648_000).with_pi(1);
 
 /// Absorbed dose of ionizing radiation
 ///
 /// The conversion numeric for a rad to a Gray
 pub(crate) const AB_RAD_TO_GY: f64 = 0.01;
+/// The exact conversion ratio for a rad to a Gray
+pub(crate) const AB_RAD_TO_GY_EXACT: ExactFactor = ExactFactor::ratio(1, 100);
 /// The conversion numeric for a Röntgen to a Gray
 pub(crate) const AB_ROE_TO_GY: f64 = 1.0 / 114.025;
 
@@ -63,34 +97,56 @@ pub(crate) const AB_ROE_TO_GY: f64 = 1.0 / 114.025;
 ///
 /// The conversion numeric for a calorie to a joule
 pub(crate) const EN_CAL_TO_J: f64 = 4.184;
+/// The exact conversion ratio for a calorie to a joule
+pub(crate) const EN_CAL_TO_J_EXACT: ExactFactor = ExactFactor::ratio(523, 125);
 /// The conversion numeric for a footpound to a joule
 pub(crate) const EN_FTLB_TO_J: f64 = 1.355818;
 /// The conversion numeric for an electron volt to a joule
 pub(crate) const EN_EV_TO_J: f64 = 1.602176634e-19;
+/// The exact conversion ratio for an electron volt to a joule
+pub(crate) const EN_EV_TO_J_EXACT: ExactFactor =
+    ExactFactor::ratio(801_088_317, 5_000_000_000_000_000_000_000_000_000);
+/// The conversion numeric for a British thermal unit to a joule
+pub(crate) const EN_BTU_TO_J: f64 = 1055.05585262;
+/// The exact conversion ratio for a British thermal unit to a joule
+pub(crate) const EN_BTU_TO_J_EXACT: ExactFactor = ExactFactor::ratio(52_752_792_631, 50_000_000);
 
 /// Force
 ///
 /// The conversion numeric for poundforce to a newton
 pub(crate) const FC_LBF_TO_N: f64 = 4.448_221_615_260_5;
+/// The exact conversion ratio for poundforce to a newton
+pub(crate) const FC_LBF_TO_N_EXACT: ExactFactor =
+    ExactFactor::ratio(8_896_443_230_521, 2_000_000_000_000);
 
 /// Pressure
 ///
 /// The conversion numeric for atmospheres to pascals
 pub(crate) const PR_ATM_TO_P: f64 = 101325.0;
+/// The exact conversion ratio for atmospheres to pascals
+pub(crate) const PR_ATM_TO_P_EXACT: ExactFactor = ExactFactor::from_int(101_325);
 /// The conversion numeric for a bar to pascals
 pub(crate) const PR_BAR_TO_P: f64 = 100000.0;
+/// The exact conversion ratio for a bar to pascals
+pub(crate) const PR_BAR_TO_P_EXACT: ExactFactor = ExactFactor::from_int(100_000);
 /// The conversion numeric for inHg to pascals
 pub(crate) const PR_IN_TO_P: f64 = 3_386.388_666_6;
 /// The conversion numeric for mmHg to pascals
 /// This is a different value to what Torr is converted from
 pub(crate) const PR_MM_TO_P: f64 = 133.322_387_415;
+/// The exact conversion ratio for mmHg to pascals
+pub(crate) const PR_MM_TO_P_EXACT: ExactFactor = ExactFactor::ratio(26_664_477_483, 200_000_000);
 /// The conversion numeric for cmHg to pascals
 pub(crate) const PR_CM_TO_P: f64 = 1_333.223_874_15;
+/// The exact conversion ratio for cmHg to pascals
+pub(crate) const PR_CM_TO_P_EXACT: ExactFactor = ExactFactor::ratio(26_664_477_483, 20_000_000);
 /// The conversion numeric for pounds per square inch to pascals
 pub(crate) const PR_PSI_TO_P: f64 = 6894.757;
 /// The conversion numeric for Torr to pascals
 /// This is a different value to what mmHg is converted from
 pub(crate) const PR_TORR_TO_P: f64 = 101325.0 / 760.0;
+/// The exact conversion ratio for Torr to pascals
+pub(crate) const PR_TORR_TO_P_EXACT: ExactFactor = ExactFactor::ratio(20265, 152);
 
 /// Power
 ///
@@ -101,11 +157,20 @@ pub(crate) const PW_HPWR_TO_W: f64 = 745.699872;
 ///
 /// The conversion numeric for curies to becquerels
 pub(crate) const RADIO_C_TO_BQ: f64 = 37_000_000_000.0;
+/// The exact conversion ratio for curies to becquerels
+pub(crate) const RADIO_C_TO_BQ_EXACT: ExactFactor = ExactFactor::from_int(37_000_000_000);
 
 /// Equivalent dose of ionizing radiation
 ///
 /// The conversion numeric for REM to sievert
 pub(crate) const RADEX_REM_TO_SV: f64 = 0.01;
+/// The exact conversion ratio for REM to sievert
+pub(crate) const RADEX_REM_TO_SV_EXACT: ExactFactor = ExactFactor::ratio(1, 100);
+
+/// Sound
+///
+/// The conversion numeric for nepers to bels
+pub(crate) const SOUND_NP_TO_B: f64 = 2.0 / std::f64::consts::LN_10;
 
 /// Temperature
 ///
@@ -292,3 +357,5 @@ pub(crate) const SOUND_MAP: usize = 1 << SOUND_INDEX;
 pub(crate) const INFORMATION_MAP: usize = 1 << INFORMATION_INDEX;
 /// Solid angle bitmap
 pub(crate) const SOLID_ANGLE_MAP: usize = 1 << SOLID_ANGLE_INDEX;
+/// Bitmap of the first user-defined dimension, the others follow it
+pub(crate) const CUSTOM_MAP: usize = 1 << (SOLID_ANGLE_INDEX + 1);
@@ -32,6 +32,10 @@ pub enum V3Error {
     ParsingError(String),
     /// Declared when there is an error when reducing a `Value`
     UnitReductionError(String),
+    /// Declared when an operation is given `Value`s with incompatible units
+    IncompatibleUnits(String),
+    /// Declared when the unit exponents of a `Value` do not allow an operation
+    InvalidUnitExponent(String),
     /// For any other error case
     UnknownError(&'static str),
 }
@@ -45,6 +49,8 @@ impl fmt::Display for V3Error {
             V3Error::UnsupportedMetric(ref s) => write!(f, "Unsupported metric: {s}"),
             V3Error::ParsingError(ref s) => write!(f, "Parsing error: {s}"),
             V3Error::UnitReductionError(ref s) => write!(f, "Unit Reduction error: {s}"),
+            V3Error::IncompatibleUnits(ref s) => write!(f, "Incompatible units: {s}"),
+            V3Error::InvalidUnitExponent(ref s) => write!(f, "Invalid unit exponent: {s}"),
             V3Error::UnknownError(s) => write!(f, "Unknown Error: {s}"),
         }
     }
@@ -92,6 +98,14 @@ mod value_errors_testing {
             format!("{}", V3Error::UnitReductionError(String::from("reduce"))),
             "Unit Reduction error: reduce"
         );
+        assert_eq!(
+            format!("{}", V3Error::IncompatibleUnits(String::from("m and s"))),
+            "Incompatible units: m and s"
+        );
+        assert_eq!(
+            format!("{}", V3Error::InvalidUnitExponent(String::from("m^3"))),
+            "Invalid unit exponent: m^3"
+        );
     }
 
     #[test]
@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitAngle};
+use super::{
+    BaseUnit, Convert, Metric, UnitAngle,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitAngle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -47,7 +50,7 @@ impl From<UnitAngle> for String {
 impl Convert<UnitAngle> for UnitAngle {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitAngle) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -71,6 +74,15 @@ impl BaseUnit for UnitAngle {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Radian(_) => Some(ExactFactor::ONE),
+            Self::Degree => Some(consts::ANGLE_DEG_TO_RAD_EXACT),
+            Self::Moa => Some(consts::ANGLE_MOA_TO_RAD_EXACT),
+            Self::ArcSec(_) => Some(consts::ANGLE_ASEC_TO_RAD_EXACT),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
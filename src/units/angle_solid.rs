@@ -17,7 +17,10 @@
 
 use std::fmt::Display;
 
-use super::{BaseUnit, Convert, Metric, UnitSolidAngle};
+use super::{
+    BaseUnit, Convert, Metric, UnitSolidAngle,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitSolidAngle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -34,7 +37,7 @@ impl From<UnitSolidAngle> for String {
 impl Convert<UnitSolidAngle> for UnitSolidAngle {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitSolidAngle) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -53,6 +56,12 @@ impl BaseUnit for UnitSolidAngle {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Steradian(_) => Some(ExactFactor::ONE),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
@@ -0,0 +1,308 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    fmt::Display,
+    sync::{RwLock, RwLockReadGuard},
+};
+
+use crate::{errors::V3Error, value::Value};
+
+use super::{BaseUnit, Convert, Metric, UnitCustom, exact::convert_base};
+
+/// The largest number of user-defined dimensions that can be registered
+///
+/// Each dimension takes a bit of the [`Value`] unit map after the built-in unit types.
+/// The map has room for 8 on 64-bit targets, but only 1 on narrower targets.
+pub const MAX_CUSTOM_DIMENSIONS: usize = if usize::BITS >= 64 { 8 } else { 1 };
+
+/// The largest number of units a user-defined dimension can hold
+///
+/// Units are indexed by a `u8` within their dimension.
+pub const MAX_CUSTOM_UNITS: usize = u8::MAX as usize + 1;
+
+// dimensions are indexed by a `u8` as well
+const _: () = assert!(MAX_CUSTOM_DIMENSIONS <= u8::MAX as usize + 1);
+
+/// A registered dimension and its units with their factors to the first unit
+struct Dimension {
+    /// The name of the dimension
+    name: String,
+    /// The unit symbols and their factors to the first unit
+    units: Vec<(String, f64)>,
+}
+
+/// Every dimension registered so far, indexed by [`UnitCustom`]
+static DIMENSIONS: RwLock<Vec<Dimension>> = RwLock::new(Vec::new());
+
+/// Returns the registry, ignoring poisoning as it is never left half written
+fn _dimensions() -> RwLockReadGuard<'static, Vec<Dimension>> {
+    DIMENSIONS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A user-defined dimension that can be declared as a constant
+///
+/// The dimension is registered the first time it is used, after which its units take part
+/// in parsing, arithmetic, conversion and display like the built-in units.
+///
+/// # Example
+/// ```rust
+/// use bxvl::units::custom::CustomDimension;
+/// use bxvl::value::Value;
+///
+/// const REQUESTS:CustomDimension = CustomDimension::new("requests", &[("req", 1.0), ("kreq", 1000.0)]);
+///
+/// REQUESTS.register().unwrap();
+/// let r:Value = Value::new(1200.0, "req/min").unwrap();
+/// assert_eq!((r >> "req/s").unwrap().to_string(), "20 req/s");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomDimension {
+    /// The name of the dimension
+    name: &'static str,
+    /// The unit symbols and their factors to the first unit
+    units: &'static [(&'static str, f64)],
+}
+
+impl CustomDimension {
+    /// Declares a dimension with its units
+    ///
+    /// The first unit is the base unit of the dimension and must have a factor of `1.0`,
+    /// the factors of the other units are how many base units they hold.
+    pub const fn new(name: &'static str, units: &'static [(&'static str, f64)]) -> CustomDimension {
+        CustomDimension { name, units }
+    }
+
+    /// Registers the dimension and returns its base unit
+    ///
+    /// Registering the same dimension again returns the same unit.
+    pub fn register(&self) -> Result<UnitCustom, V3Error> {
+        register_dimension(self.name, self.units)
+    }
+
+    /// Registers the dimension and returns one of its units
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::units::custom::CustomDimension;
+    /// use bxvl::value::Value;
+    ///
+    /// const PIXELS:CustomDimension = CustomDimension::new("pixels", &[("px", 1.0)]);
+    ///
+    /// let d:Value = 300.0 * PIXELS.unit("px").unwrap() / Value::new(1.0, "in").unwrap();
+    /// assert_eq!(d.to_string(), "300 px/in");
+    /// ```
+    pub fn unit(&self, symbol: &str) -> Result<UnitCustom, V3Error> {
+        let base: UnitCustom = self.register()?;
+        match UnitCustom::find(symbol) {
+            Some(u) if u.dim == base.dim => Ok(u),
+            _ => Err(V3Error::UnsupportedUnit(format!(
+                "[CustomDimension] {symbol} is not a unit of {}",
+                self.name
+            ))),
+        }
+    }
+}
+
+/// Registers a user-defined dimension at run time and returns its base unit
+///
+/// The first unit is the base unit of the dimension and must have a factor of `1.0`,
+/// the factors of the other units are how many base units they hold.
+/// Unit symbols may only contain letters and `_`, and cannot shadow any other unit.
+/// Registering a dimension with the same name and units again returns the same unit.
+///
+/// # Example
+/// ```rust
+/// use bxvl::units::custom::register_dimension;
+/// use bxvl::value::Value;
+///
+/// register_dimension("items", &[("item", 1.0), ("dozen", 12.0)]).unwrap();
+/// let v:Value = Value::new(6.0, "dozen/hr").unwrap();
+/// assert_eq!((v >> "item/hr").unwrap().to_string(), "72 item/hr");
+///
+/// assert!(register_dimension("distance", &[("m", 1.0)]).is_err());
+/// ```
+pub fn register_dimension(name: &str, units: &[(&str, f64)]) -> Result<UnitCustom, V3Error> {
+    if let Some(u) = _existing(&_dimensions(), name, units)? {
+        return Ok(u);
+    }
+    if name.is_empty() || units.is_empty() || units[0].1 != 1.0 {
+        return Err(V3Error::UnsupportedUnit(format!(
+            "[register_dimension] {name} needs a base unit with a factor of 1"
+        )));
+    } else if units.len() > MAX_CUSTOM_UNITS {
+        return Err(V3Error::UnsupportedUnit(format!(
+            "[register_dimension] Too many units to register {name}"
+        )));
+    }
+    for (i, (symbol, factor)) in units.iter().enumerate() {
+        if symbol.is_empty() || !symbol.chars().all(|c| c.is_alphabetic() || c == '_') {
+            return Err(V3Error::UnsupportedUnit(format!(
+                "[register_dimension] Invalid unit symbol: {symbol}"
+            )));
+        } else if !factor.is_finite() || *factor <= 0.0 {
+            return Err(V3Error::UnsupportedUnit(format!(
+                "[register_dimension] Invalid factor for {symbol}"
+            )));
+        } else if units[..i].iter().any(|(s, _)| s == symbol)
+            || UnitCustom::find(symbol).is_some()
+            || Value::new(1.0, symbol).is_ok()
+        {
+            return Err(V3Error::UnsupportedUnit(format!(
+                "[register_dimension] {symbol} is already defined"
+            )));
+        }
+    }
+
+    let mut dims = DIMENSIONS.write().unwrap_or_else(|e| e.into_inner());
+    // another thread may have registered the dimension in the meantime
+    if let Some(u) = _existing(&dims, name, units)? {
+        return Ok(u);
+    } else if dims.iter().any(|d| {
+        d.units
+            .iter()
+            .any(|(s, _)| units.iter().any(|(t, _)| s == t))
+    }) {
+        return Err(V3Error::UnsupportedUnit(format!(
+            "[register_dimension] {name} has units that are already defined"
+        )));
+    } else if dims.len() >= MAX_CUSTOM_DIMENSIONS {
+        return Err(V3Error::UnsupportedUnit(format!(
+            "[register_dimension] Too many dimensions to register {name}"
+        )));
+    }
+    let dim: u8 = _dim_index(dims.len())?;
+    dims.push(Dimension {
+        name: name.into(),
+        units: units.iter().map(|(s, f)| (String::from(*s), *f)).collect(),
+    });
+    Ok(UnitCustom { dim, unit: 0 })
+}
+
+/// Returns the base unit of a dimension that is already registered with the same units
+fn _existing(
+    dims: &[Dimension],
+    name: &str,
+    units: &[(&str, f64)],
+) -> Result<Option<UnitCustom>, V3Error> {
+    let Some(dim) = dims.iter().position(|d| d.name == name) else {
+        return Ok(None);
+    };
+    let same: bool = dims[dim].units.len() == units.len()
+        && dims[dim]
+            .units
+            .iter()
+            .zip(units)
+            .all(|((s, f), (t, g))| s == t && f == g);
+    if !same {
+        return Err(V3Error::UnsupportedUnit(format!(
+            "[register_dimension] {name} is already registered with other units"
+        )));
+    }
+    Ok(Some(UnitCustom {
+        dim: _dim_index(dim)?,
+        unit: 0,
+    }))
+}
+
+/// Returns the index of a dimension as stored in [`UnitCustom`]
+fn _dim_index(dim: usize) -> Result<u8, V3Error> {
+    u8::try_from(dim).map_err(|_| {
+        V3Error::UnsupportedUnit("[register_dimension] Too many dimensions registered".into())
+    })
+}
+
+impl UnitCustom {
+    /// Returns the registered unit with the given symbol
+    pub fn find(symbol: &str) -> Option<UnitCustom> {
+        for (dim, d) in _dimensions().iter().enumerate() {
+            if let Some(unit) = d.units.iter().position(|(s, _)| s == symbol) {
+                return Some(UnitCustom {
+                    dim: u8::try_from(dim).ok()?,
+                    unit: u8::try_from(unit).ok()?,
+                });
+            }
+        }
+        None
+    }
+
+    /// Returns the name of the dimension of the unit
+    pub fn dimension(&self) -> String {
+        _dimensions()[self.dim as usize].name.clone()
+    }
+
+    /// Returns the base unit of the dimension of the unit
+    pub fn base_unit(&self) -> UnitCustom {
+        UnitCustom {
+            dim: self.dim,
+            unit: 0,
+        }
+    }
+
+    /// Returns the index of the dimension within a [`Value`]
+    pub(crate) fn index(&self) -> usize {
+        self.dim as usize
+    }
+}
+
+impl Display for UnitCustom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            _dimensions()[self.dim as usize].units[self.unit as usize].0
+        )
+    }
+}
+
+impl From<UnitCustom> for String {
+    fn from(val: UnitCustom) -> Self {
+        val.to_string()
+    }
+}
+
+impl TryFrom<String> for UnitCustom {
+    type Error = V3Error;
+    fn try_from(symbol: String) -> Result<Self, Self::Error> {
+        match UnitCustom::find(&symbol) {
+            Some(u) => Ok(u),
+            None => Err(V3Error::UnsupportedUnit(format!(
+                "[UnitCustom] {symbol} is not registered"
+            ))),
+        }
+    }
+}
+
+impl Convert<UnitCustom> for UnitCustom {
+    fn convert(&self, other: &UnitCustom) -> f64 {
+        convert_base(self, other)
+    }
+}
+
+impl BaseUnit for UnitCustom {
+    fn scale(&self) -> f64 {
+        1.0
+    }
+
+    fn base(&self) -> f64 {
+        _dimensions()[self.dim as usize].units[self.unit as usize].1
+    }
+
+    fn get_metric(&self) -> Metric {
+        Metric::None
+    }
+}
@@ -17,7 +17,10 @@
 
 use std::fmt::Display;
 
-use super::{BaseUnit, Convert, Metric, UnitElectricConductance};
+use super::{
+    BaseUnit, Convert, Metric, UnitElectricConductance,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitElectricConductance {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -34,7 +37,7 @@ impl From<UnitElectricConductance> for String {
 impl Convert<UnitElectricConductance> for UnitElectricConductance {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitElectricConductance) -> f64 {
-        self.scale() / other.scale()
+        convert_base(self, other)
     }
 }
 
@@ -56,6 +59,10 @@ impl BaseUnit for UnitElectricConductance {
     fn base(&self) -> f64 {
         1.0
     }
+
+    fn exact_base(&self) -> Option<ExactFactor> {
+        Some(ExactFactor::ONE)
+    }
 }
 
 #[cfg(test)]
@@ -17,7 +17,10 @@
 
 use std::fmt::Display;
 
-use super::{BaseUnit, Convert, Metric, UnitElectricPotential};
+use super::{
+    BaseUnit, Convert, Metric, UnitElectricPotential,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitElectricPotential {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -34,7 +37,7 @@ impl From<UnitElectricPotential> for String {
 impl Convert<UnitElectricPotential> for UnitElectricPotential {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitElectricPotential) -> f64 {
-        self.scale() / other.scale()
+        convert_base(self, other)
     }
 }
 
@@ -56,6 +59,10 @@ impl BaseUnit for UnitElectricPotential {
     fn base(&self) -> f64 {
         1.0
     }
+
+    fn exact_base(&self) -> Option<ExactFactor> {
+        Some(ExactFactor::ONE)
+    }
 }
 
 #[cfg(test)]
@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitEnergy};
+use super::{
+    BaseUnit, Convert, Metric, UnitEnergy,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitEnergy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -32,6 +35,7 @@ impl Display for UnitEnergy {
                 Self::GramCalorie(m) => format!("{}cal", m.as_str()),
                 Self::FootPound => "ftlb".into(),
                 Self::ElectronVolt(m) => format!("{}eV", m.as_str()),
+                Self::BritishThermalUnit => "BTU".into(),
             }
         )
     }
@@ -46,7 +50,7 @@ impl From<UnitEnergy> for String {
 impl Convert<UnitEnergy> for UnitEnergy {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitEnergy) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -68,6 +72,17 @@ impl BaseUnit for UnitEnergy {
             Self::GramCalorie(_) => consts::EN_CAL_TO_J,
             Self::FootPound => consts::EN_FTLB_TO_J,
             Self::ElectronVolt(_) => consts::EN_EV_TO_J,
+            Self::BritishThermalUnit => consts::EN_BTU_TO_J,
+        }
+    }
+
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Joule(_) => Some(ExactFactor::ONE),
+            Self::GramCalorie(_) => Some(consts::EN_CAL_TO_J_EXACT),
+            Self::FootPound => None,
+            Self::ElectronVolt(_) => Some(consts::EN_EV_TO_J_EXACT),
+            Self::BritishThermalUnit => Some(consts::EN_BTU_TO_J_EXACT),
         }
     }
 
@@ -97,6 +112,8 @@ mod energy_testing {
         // Electron Volts
         assert!(UnitEnergy::ElectronVolt(Metric::None).base() >= 1.602_176_634e-19);
         assert!(UnitEnergy::ElectronVolt(Metric::None).base() < 1.602_176_635e-19);
+        // British thermal units
+        assert!(UnitEnergy::BritishThermalUnit.base() == 1055.05585262);
     }
 
     #[test]
@@ -128,6 +145,7 @@ mod energy_testing {
             (UnitEnergy::Joule(Metric::Zepto), "zJ"),
             (UnitEnergy::Joule(Metric::Zetta), "ZJ"),
             (UnitEnergy::FootPound, "ftlb"),
+            (UnitEnergy::BritishThermalUnit, "BTU"),
             (UnitEnergy::GramCalorie(Metric::Ronto), "rcal"),
             (UnitEnergy::GramCalorie(Metric::Ronna), "Rcal"),
             (UnitEnergy::GramCalorie(Metric::Quetta), "Qcal"),
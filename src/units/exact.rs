@@ -0,0 +1,373 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{f64::consts::PI, fmt::Display};
+
+use crate::value::{exponent::Exponent, numeric::Numeric};
+
+use super::BaseUnit;
+
+/// An exact conversion factor of the form `num/den * π^pi`
+///
+/// Factors are kept in lowest terms and are only rounded to an `f64` when they are applied,
+/// so conversions composed from several exact factors are rounded once.
+/// Every operation returns `None` instead of overflowing.
+///
+/// # Example
+/// ```rust
+/// use bxvl::units::exact::ExactFactor;
+/// use bxvl::units::{BaseUnit, UnitLength};
+///
+/// let ft:ExactFactor = UnitLength::Foot.exact_base().unwrap();
+/// let inch:ExactFactor = UnitLength::Inch.exact_base().unwrap();
+/// let f:ExactFactor = ft.checked_div(inch).unwrap();
+/// assert_eq!(f, ExactFactor::from_int(12));
+/// assert_eq!(f.checked_pow(3).unwrap().to_f64(), 1728.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExactFactor {
+    /// The numerator of the rational part
+    num: u128,
+    /// The denominator of the rational part, never zero
+    den: u128,
+    /// The power of π
+    pi: i32,
+}
+
+/// The largest integer below which every integer is an exact `f64`
+const MAX_EXACT_INT: u128 = 1 << 53;
+
+/// π as the unevaluated sum of two `f64`s
+const PI_PAIR: (f64, f64) = (PI, 1.2246467991473532e-16);
+
+/// Splits an integer into the unevaluated sum of its nearest `f64` and the remainder
+fn _split(n: u128) -> (f64, f64) {
+    let hi: f64 = n as f64;
+    (hi, (n.wrapping_sub(hi as u128) as i128) as f64)
+}
+
+/// Multiplies two unevaluated sums of `f64`s
+fn _mul_pair(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let p: f64 = a.0 * b.0;
+    let e: f64 = a.0.mul_add(b.0, -p) + (a.0 * b.1 + a.1 * b.0);
+    let s: f64 = p + e;
+    (s, e - (s - p))
+}
+
+/// Divides two unevaluated sums of `f64`s, rounding the quotient to the nearest `f64`
+fn _div_pair(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let q: f64 = a.0 / b.0;
+    if !q.is_finite() || q == 0.0 {
+        return q;
+    }
+    let p: f64 = q * b.0;
+    let r: f64 = (a.0 - p) - q.mul_add(b.0, -p) + a.1 - q * b.1;
+    q + r / b.0
+}
+
+/// Greatest common divisor used to keep an [`ExactFactor`] in lowest terms
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl ExactFactor {
+    /// The identity factor
+    pub const ONE: ExactFactor = ExactFactor::from_int(1);
+
+    /// Creates a whole number [`ExactFactor`]
+    pub const fn from_int(num: u128) -> ExactFactor {
+        ExactFactor { num, den: 1, pi: 0 }
+    }
+
+    /// Creates an [`ExactFactor`] of `num/den` that is already in lowest terms
+    pub(crate) const fn ratio(num: u128, den: u128) -> ExactFactor {
+        ExactFactor { num, den, pi: 0 }
+    }
+
+    /// Creates a new [`ExactFactor`] of `num/den` in lowest terms
+    ///
+    /// Returns `None` if either part is zero.
+    pub fn new(num: u128, den: u128) -> Option<ExactFactor> {
+        if num == 0 || den == 0 {
+            return None;
+        }
+        let g: u128 = gcd(num, den);
+        Some(ExactFactor::ratio(num / g, den / g))
+    }
+
+    /// Returns the factor multiplied by `π^p`
+    pub const fn with_pi(self, p: i32) -> ExactFactor {
+        ExactFactor {
+            pi: self.pi + p,
+            ..self
+        }
+    }
+
+    /// Returns `10^p` as an [`ExactFactor`]
+    pub fn pow10(p: i32) -> Option<ExactFactor> {
+        let n: u128 = 10_u128.checked_pow(p.unsigned_abs())?;
+        if p < 0 {
+            Some(ExactFactor::ratio(1, n))
+        } else {
+            Some(ExactFactor::from_int(n))
+        }
+    }
+
+    /// Returns the numerator of the rational part
+    pub fn numer(&self) -> u128 {
+        self.num
+    }
+
+    /// Returns the denominator of the rational part
+    pub fn denom(&self) -> u128 {
+        self.den
+    }
+
+    /// Returns the power of π
+    pub fn pi_power(&self) -> i32 {
+        self.pi
+    }
+
+    /// Multiplies two factors
+    pub fn checked_mul(self, other: ExactFactor) -> Option<ExactFactor> {
+        let g1: u128 = gcd(self.num, other.den);
+        let g2: u128 = gcd(other.num, self.den);
+        Some(ExactFactor {
+            num: (self.num / g1).checked_mul(other.num / g2)?,
+            den: (self.den / g2).checked_mul(other.den / g1)?,
+            pi: self.pi.checked_add(other.pi)?,
+        })
+    }
+
+    /// Returns the reciprocal of the factor
+    pub fn recip(self) -> ExactFactor {
+        ExactFactor {
+            num: self.den,
+            den: self.num,
+            pi: -self.pi,
+        }
+    }
+
+    /// Divides two factors
+    pub fn checked_div(self, other: ExactFactor) -> Option<ExactFactor> {
+        self.checked_mul(other.recip())
+    }
+
+    /// Raises the factor to an integer power
+    pub fn checked_pow(self, p: i32) -> Option<ExactFactor> {
+        let base: ExactFactor = if p < 0 { self.recip() } else { self };
+        let e: u32 = p.unsigned_abs();
+        Some(ExactFactor {
+            num: base.num.checked_pow(e)?,
+            den: base.den.checked_pow(e)?,
+            pi: base.pi.checked_mul(e as i32)?,
+        })
+    }
+
+    /// Raises the factor to a unit [`Exponent`]
+    ///
+    /// Returns `None` for fractional exponents, which have no exact rational result.
+    pub fn checked_powr(self, e: Exponent) -> Option<ExactFactor> {
+        if !e.is_integer() {
+            return None;
+        }
+        self.checked_pow(e.numer())
+    }
+
+    /// Rounds the factor to the nearest `f64`
+    pub fn to_f64(&self) -> f64 {
+        // the parts are carried with twice the precision of an `f64` and rounded once
+        let mut n: (f64, f64) = _split(self.num);
+        let mut d: (f64, f64) = _split(self.den);
+        for _ in 0..self.pi.max(0) {
+            n = _mul_pair(n, PI_PAIR);
+        }
+        for _ in 0..self.pi.min(0).unsigned_abs() {
+            d = _mul_pair(d, PI_PAIR);
+        }
+        _div_pair(n, d)
+    }
+
+    /// Multiplies a numeric by the factor
+    ///
+    /// Rational factors whose parts are exact `f64`s are applied with a single rounding.
+    pub fn apply(&self, val: f64) -> f64 {
+        self._apply_to(val).unwrap()
+    }
+
+    /// Multiplies any [`Numeric`] by the factor, `None` if the result cannot be represented
+    pub(crate) fn _apply_to<T: Numeric>(&self, val: T) -> Option<T> {
+        if self.pi != 0 || self.num > MAX_EXACT_INT || self.den > MAX_EXACT_INT {
+            return val.scale(self.to_f64());
+        }
+        val.scale_ratio(self.num as f64, self.den as f64)
+    }
+}
+
+/// Multiplies an `f64` by the ratio of two exact `f64`s with a single rounding
+pub(crate) fn _apply_ratio(val: f64, n: f64, d: f64) -> f64 {
+    let p: f64 = val * n;
+    if d == 1.0 || !p.is_finite() {
+        return p / d;
+    }
+    // carry the rounding errors of the product and quotient into the result
+    let e: f64 = val.mul_add(n, -p);
+    let q: f64 = p / d;
+    let r: f64 = (-q).mul_add(d, p) + e;
+    q + r / d
+}
+
+impl Display for ExactFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.num)?;
+        if self.den != 1 {
+            write!(f, "/{}", self.den)?;
+        }
+        match self.pi {
+            0 => Ok(()),
+            1 => write!(f, "*π"),
+            p => write!(f, "*π^{p}"),
+        }
+    }
+}
+
+/// Returns the exact conversion factor between two units of the same kind
+///
+/// Returns `None` if either unit has no exact definition.
+pub fn exact_convert<U: BaseUnit>(from: &U, to: &U) -> Option<ExactFactor> {
+    from.exact_scale()?
+        .checked_mul(from.exact_base()?)?
+        .checked_div(to.exact_scale()?.checked_mul(to.exact_base()?)?)
+}
+
+/// Returns the conversion factor between two units of the same kind
+///
+/// The factor is exact when both units have exact definitions.
+pub(crate) fn convert_base<U: BaseUnit>(from: &U, to: &U) -> f64 {
+    match exact_convert(from, to) {
+        Some(f) => f.to_f64(),
+        None => (from.scale() / to.scale()) * (from.base() / to.base()),
+    }
+}
+
+#[cfg(test)]
+mod exact_testing {
+    use crate::units::{
+        BaseUnit, Convert, Metric, UnitAngle, UnitInformation, UnitLength, UnitMass, UnitSound,
+        UnitTime,
+        exact::{ExactFactor, exact_convert},
+    };
+    use crate::value::exponent::Exponent;
+
+    #[test]
+    fn factor_arithmetic() {
+        let a: ExactFactor = ExactFactor::new(6, 4).unwrap();
+        assert_eq!(a, ExactFactor::new(3, 2).unwrap());
+        assert_eq!(a.to_string(), "3/2");
+        assert_eq!(a.checked_mul(a.recip()).unwrap(), ExactFactor::ONE);
+        assert_eq!(a.checked_pow(-2).unwrap().to_string(), "4/9");
+        assert_eq!(a.with_pi(-1).to_string(), "3/2*π^-1");
+        assert_eq!(
+            a.checked_powr(Exponent::from(2)).unwrap(),
+            ExactFactor::new(9, 4).unwrap()
+        );
+        assert!(a.checked_powr(Exponent::new(1, 2).unwrap()).is_none());
+        assert!(ExactFactor::new(0, 1).is_none());
+        assert!(ExactFactor::from_int(u128::MAX).checked_pow(2).is_none());
+        assert_eq!(
+            ExactFactor::pow10(-3).unwrap(),
+            ExactFactor::new(1, 1000).unwrap()
+        );
+        assert!(ExactFactor::pow10(39).is_none());
+    }
+
+    #[test]
+    fn factor_f64() {
+        assert_eq!(ExactFactor::new(1, 3).unwrap().apply(3.0), 1.0);
+        assert_eq!(ExactFactor::from_int(12).apply(0.1), 0.1 * 12.0);
+        assert_eq!(
+            ExactFactor::new(1, 180).unwrap().with_pi(1).to_f64(),
+            std::f64::consts::PI / 180.0
+        );
+        // factors with π and parts beyond 2^53 are still rounded once
+        let pc: ExactFactor = UnitLength::Parsec(Metric::None).exact_base().unwrap();
+        assert_eq!(pc.to_f64(), 3.085677581491367e16);
+        assert_eq!(
+            pc.checked_mul(ExactFactor::from_int(100))
+                .unwrap()
+                .recip()
+                .to_f64(),
+            3.240779289444365e-19
+        );
+        assert_eq!(
+            Metric::Milli.exact_scale(),
+            ExactFactor::new(1, 1000).unwrap()
+        );
+    }
+
+    #[test]
+    fn unit_factors() {
+        assert_eq!(
+            exact_convert(&UnitLength::Mile, &UnitLength::Foot).unwrap(),
+            ExactFactor::from_int(5280)
+        );
+        assert_eq!(
+            exact_convert(&UnitLength::Foot, &UnitLength::Meter(Metric::Centi)).unwrap(),
+            ExactFactor::new(3048, 100).unwrap()
+        );
+        assert_eq!(
+            exact_convert(&UnitMass::Pound, &UnitMass::Ounce).unwrap(),
+            ExactFactor::from_int(16)
+        );
+        assert_eq!(
+            exact_convert(&UnitTime::Day, &UnitTime::Second(Metric::Milli)).unwrap(),
+            ExactFactor::from_int(86_400_000)
+        );
+        assert_eq!(
+            exact_convert(&UnitAngle::Degree, &UnitAngle::Moa).unwrap(),
+            ExactFactor::from_int(60)
+        );
+        assert_eq!(
+            exact_convert(&UnitAngle::Degree, &UnitAngle::Radian(Metric::None))
+                .unwrap()
+                .pi_power(),
+            1
+        );
+        assert_eq!(
+            exact_convert(
+                &UnitInformation::Byte(Metric::Kilo),
+                &UnitInformation::Bit(Metric::None)
+            )
+            .unwrap(),
+            ExactFactor::from_int(8192)
+        );
+        assert!(
+            exact_convert(
+                &UnitLength::Parsec(Metric::None),
+                &UnitLength::Meter(Metric::None)
+            )
+            .is_some()
+        );
+        assert!(UnitSound::Neper.exact_base().is_none());
+
+        assert_eq!(UnitLength::Foot.convert(&UnitLength::Inch), 12.0);
+        assert_eq!(UnitLength::Yard.convert(&UnitLength::Foot), 3.0);
+    }
+}
@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitForce};
+use super::{
+    BaseUnit, Convert, Metric, UnitForce,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitForce {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -43,7 +46,7 @@ impl From<UnitForce> for String {
 impl Convert<UnitForce> for UnitForce {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitForce) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -64,6 +67,13 @@ impl BaseUnit for UnitForce {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Newton(_) => Some(ExactFactor::ONE),
+            Self::PoundForce => Some(consts::FC_LBF_TO_N_EXACT),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
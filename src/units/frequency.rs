@@ -17,7 +17,10 @@
 
 use std::fmt::Display;
 
-use super::{BaseUnit, Convert, Metric, UnitFrequency, UnitTime};
+use super::{
+    BaseUnit, Convert, Metric, UnitFrequency, UnitTime,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitFrequency {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -34,14 +37,14 @@ impl From<UnitFrequency> for String {
 impl Convert<UnitFrequency> for UnitFrequency {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitFrequency) -> f64 {
-        self.scale() / other.scale()
+        convert_base(self, other)
     }
 }
 
 impl Convert<UnitTime> for UnitFrequency {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitTime) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        (self.scale() * self.base()) * (other.scale() * other.base())
     }
 }
 
@@ -63,6 +66,10 @@ impl BaseUnit for UnitFrequency {
     fn base(&self) -> f64 {
         1.0
     }
+
+    fn exact_base(&self) -> Option<ExactFactor> {
+        Some(ExactFactor::ONE)
+    }
 }
 
 #[cfg(test)]
@@ -17,7 +17,10 @@
 
 use std::fmt::Display;
 
-use super::{BaseUnit, Convert, Metric, UnitInformation};
+use super::{
+    BaseUnit, Convert, Metric, UnitInformation,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitInformation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -41,7 +44,7 @@ impl From<UnitInformation> for String {
 impl Convert<UnitInformation> for UnitInformation {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitInformation) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -74,6 +77,23 @@ impl BaseUnit for UnitInformation {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Byte(_) => Some(ExactFactor::ONE),
+            Self::Bit(_) => Some(ExactFactor::ratio(1, 8)),
+        }
+    }
+
+    fn exact_scale(&self) -> Option<ExactFactor> {
+        // metric prefixes of information are powers of 1024
+        let p: i32 = self.get_metric().power();
+        if p > 0 && p % 3 == 0 {
+            Some(ExactFactor::from_int(1 << (10 * p / 3)))
+        } else {
+            Some(ExactFactor::ONE)
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitLength, UnitVolume};
+use super::{
+    BaseUnit, Convert, Metric, UnitLength, UnitVolume,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitLength {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -53,7 +56,7 @@ impl From<UnitLength> for String {
 
 impl Convert<UnitLength> for UnitLength {
     fn convert(&self, other: &UnitLength) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -90,6 +93,20 @@ impl BaseUnit for UnitLength {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Meter(_) => Some(ExactFactor::ONE),
+            Self::Inch => Some(consts::LENGTH_IN_TO_METER_EXACT),
+            Self::Foot => Some(consts::LENGTH_FT_TO_METER_EXACT),
+            Self::Yard => Some(consts::LENGTH_YD_TO_METER_EXACT),
+            Self::Mile => Some(consts::LENGTH_MILE_TO_METER_EXACT),
+            Self::AstronomicalUnit => Some(consts::LENGTH_AU_TO_METER_EXACT),
+            Self::Parsec(_) => Some(consts::LENGTH_PC_TO_METER_EXACT),
+            Self::LightYear(_) => Some(consts::LENGTH_LYR_TO_METER_EXACT),
+            Self::Angstrom => Some(consts::LENGTH_A_TO_METER_EXACT),
+        }
+    }
+
     fn get_metric(&self) -> Metric {
         match self {
             Self::Meter(m) => *m,
@@ -17,7 +17,10 @@
 
 use std::fmt::Display;
 
-use super::{BaseUnit, Convert, Metric, UnitLuminousFlux};
+use super::{
+    BaseUnit, Convert, Metric, UnitLuminousFlux,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitLuminousFlux {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -34,7 +37,7 @@ impl From<UnitLuminousFlux> for String {
 impl Convert<UnitLuminousFlux> for UnitLuminousFlux {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitLuminousFlux) -> f64 {
-        self.scale() / other.scale()
+        convert_base(self, other)
     }
 }
 
@@ -56,6 +59,10 @@ impl BaseUnit for UnitLuminousFlux {
     fn base(&self) -> f64 {
         1.0
     }
+
+    fn exact_base(&self) -> Option<ExactFactor> {
+        Some(ExactFactor::ONE)
+    }
 }
 
 #[cfg(test)]
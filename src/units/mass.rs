@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitMass};
+use super::{
+    BaseUnit, Convert, Metric, UnitMass,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitMass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -45,7 +48,7 @@ impl From<UnitMass> for String {
 impl Convert<UnitMass> for UnitMass {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitMass) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -68,6 +71,15 @@ impl BaseUnit for UnitMass {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Gram(_) => Some(ExactFactor::ONE),
+            Self::Grain => Some(consts::MASS_GR_TO_G_EXACT),
+            Self::Ounce => Some(consts::MASS_OZ_TO_G_EXACT),
+            Self::Pound => Some(consts::MASS_LB_TO_G_EXACT),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
@@ -17,7 +17,7 @@
 
 use crate::errors::V3Error;
 
-use super::Metric;
+use super::{Metric, exact::ExactFactor};
 
 impl Metric {
     /// Returns the numeric scaling of a given metric prefix
@@ -51,6 +51,42 @@ impl Metric {
         }
     }
 
+    /// Returns the power of ten of the metric prefix
+    pub fn power(&self) -> i32 {
+        match self {
+            Metric::Quetta => 30,
+            Metric::Ronna => 27,
+            Metric::Yotta => 24,
+            Metric::Zetta => 21,
+            Metric::Exa => 18,
+            Metric::Peta => 15,
+            Metric::Tera => 12,
+            Metric::Giga => 9,
+            Metric::Mega => 6,
+            Metric::Kilo => 3,
+            Metric::Hecto => 2,
+            Metric::Deca => 1,
+            Metric::None => 0,
+            Metric::Deci => -1,
+            Metric::Centi => -2,
+            Metric::Milli => -3,
+            Metric::Micro => -6,
+            Metric::Nano => -9,
+            Metric::Pico => -12,
+            Metric::Femto => -15,
+            Metric::Atto => -18,
+            Metric::Zepto => -21,
+            Metric::Yocto => -24,
+            Metric::Ronto => -27,
+            Metric::Quecto => -30,
+        }
+    }
+
+    /// Returns the exact scale of the metric prefix
+    pub fn exact_scale(&self) -> ExactFactor {
+        ExactFactor::pow10(self.power()).unwrap()
+    }
+
     /// Returns the string representation of the metric prefix
     pub fn as_str(&self) -> &str {
         match self {
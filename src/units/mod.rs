@@ -24,6 +24,9 @@ pub mod angle_solid;
 /// Unit module for [`UnitCatalyticActivity`].
 pub mod catalytic_activity;
 
+/// Unit module for [`UnitCustom`] and the registry of user-defined dimensions
+pub mod custom;
+
 /// Unit module for [`UnitElectricCapacitance`].
 pub mod electrical_capacitance;
 
@@ -48,6 +51,9 @@ pub mod electrical_resistance;
 /// Unit module for [`UnitEnergy`].
 pub mod energy;
 
+/// Unit module for exact conversion factors between units
+pub mod exact;
+
 /// Unit module for [`UnitForce`].
 pub mod force;
 
@@ -116,6 +122,8 @@ pub mod volume;
 
 use serde::{Deserialize, Serialize};
 
+use exact::ExactFactor;
+
 /// The Metric scale names
 #[derive(Debug, PartialOrd, Eq, PartialEq, Copy, Clone, Default, Serialize, Deserialize)]
 pub enum Metric {
@@ -199,6 +207,19 @@ pub enum UnitCatalyticActivity {
     Katal(Metric),
 }
 
+/// A unit of a user-defined dimension
+///
+/// Units are created by registering a dimension with [`custom::register_dimension`] or
+/// [`custom::CustomDimension`], and are stored as symbols when serialized.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct UnitCustom {
+    /// The index of the dimension within the registry
+    dim: u8,
+    /// The index of the unit within its dimension
+    unit: u8,
+}
+
 /// The unit types for electric capacitance
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub enum UnitElectricCapacitance {
@@ -259,6 +280,8 @@ pub enum UnitEnergy {
     FootPound,
     /// SI integrated
     ElectronVolt(Metric),
+    /// Imperial
+    BritishThermalUnit,
 }
 
 /// The unit types of force
@@ -421,6 +444,18 @@ pub enum UnitRadioactivity {
 pub enum UnitSound {
     /// SI unit
     Bel(Metric),
+    /// Natural logarithmic unit
+    Neper,
+    /// Power level referenced to 1 mW
+    DecibelMilliwatt,
+    /// Power level referenced to 1 W
+    DecibelWatt,
+    /// Voltage level referenced to 1 V
+    DecibelVolt,
+    /// Voltage level referenced to 1 μV
+    DecibelMicrovolt,
+    /// Sound pressure level referenced to 20 μPa
+    DecibelSoundPressure,
 }
 
 /// The unit types for substance
@@ -439,6 +474,12 @@ pub enum UnitTemperature {
     Fahrenheit,
     /// SI unit
     Kelvin(Metric),
+    /// SI Unit temperature interval
+    DeltaCelsius(Metric),
+    /// Imperial temperature interval
+    DeltaFahrenheit,
+    /// SI unit temperature interval
+    DeltaKelvin(Metric),
 }
 
 /// The unit types for time
@@ -460,6 +501,18 @@ pub enum UnitNone {
     /// To describe a `Value` representing a percentage
     Percentage,
 
+    /// To describe a `Value` representing a per-mille ratio
+    PerMille,
+
+    /// To describe a `Value` representing parts per million
+    PartsPerMillion,
+
+    /// To describe a `Value` representing parts per billion
+    PartsPerBillion,
+
+    /// To describe a `Value` representing parts per trillion
+    PartsPerTrillion,
+
     /// Literally just a number
     None,
 }
@@ -487,6 +540,18 @@ pub trait BaseUnit {
 
     /// Returns the [`Metric`] prefix for the unit
     fn get_metric(&self) -> Metric;
+
+    /// Returns the exact [`Metric`] scaler of an SI unit
+    fn exact_scale(&self) -> Option<ExactFactor> {
+        Some(self.get_metric().exact_scale())
+    }
+
+    /// Returns the exact base unit conversion in relation to the standard SI unit
+    ///
+    /// Returns `None` if the unit is not defined exactly in terms of the SI unit.
+    fn exact_base(&self) -> Option<ExactFactor> {
+        None
+    }
 }
 
 #[cfg(test)]
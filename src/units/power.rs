@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts::PW_HPWR_TO_W;
 
-use super::{BaseUnit, Convert, Metric, UnitPower};
+use super::{
+    BaseUnit, Convert, Metric, UnitPower,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitPower {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -43,7 +46,7 @@ impl From<UnitPower> for String {
 impl Convert<UnitPower> for UnitPower {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitPower) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -70,6 +73,13 @@ impl BaseUnit for UnitPower {
             Self::Horsepower => PW_HPWR_TO_W,
         }
     }
+
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Watt(_) => Some(ExactFactor::ONE),
+            Self::Horsepower => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitPressure};
+use super::{
+    BaseUnit, Convert, Metric, UnitPressure,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitPressure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -49,7 +52,7 @@ impl From<UnitPressure> for String {
 impl Convert<UnitPressure> for UnitPressure {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitPressure) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -77,6 +80,19 @@ impl BaseUnit for UnitPressure {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Pascal(_) => Some(ExactFactor::ONE),
+            Self::Bar(_) => Some(consts::PR_BAR_TO_P_EXACT),
+            Self::Torr => Some(consts::PR_TORR_TO_P_EXACT),
+            Self::Hgmm => Some(consts::PR_MM_TO_P_EXACT),
+            Self::Hgcm => Some(consts::PR_CM_TO_P_EXACT),
+            Self::Hgin => None,
+            Self::Atm => Some(consts::PR_ATM_TO_P_EXACT),
+            Self::Psi => None,
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
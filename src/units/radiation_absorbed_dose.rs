@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitAbsorbedDose};
+use super::{
+    BaseUnit, Convert, Metric, UnitAbsorbedDose,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitAbsorbedDose {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -44,7 +47,7 @@ impl From<UnitAbsorbedDose> for String {
 impl Convert<UnitAbsorbedDose> for UnitAbsorbedDose {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitAbsorbedDose) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -66,6 +69,14 @@ impl BaseUnit for UnitAbsorbedDose {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Gray(_) => Some(ExactFactor::ONE),
+            Self::Roentgen => None,
+            Self::Rad => Some(consts::AB_RAD_TO_GY_EXACT),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
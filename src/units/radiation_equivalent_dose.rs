@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitRadioactivityExposure};
+use super::{
+    BaseUnit, Convert, Metric, UnitRadioactivityExposure,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitRadioactivityExposure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -43,7 +46,7 @@ impl From<UnitRadioactivityExposure> for String {
 impl Convert<UnitRadioactivityExposure> for UnitRadioactivityExposure {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitRadioactivityExposure) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -64,6 +67,13 @@ impl BaseUnit for UnitRadioactivityExposure {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Sievert(_) => Some(ExactFactor::ONE),
+            Self::Rem => Some(consts::RADEX_REM_TO_SV_EXACT),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
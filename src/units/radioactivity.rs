@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitRadioactivity};
+use super::{
+    BaseUnit, Convert, Metric, UnitRadioactivity,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitRadioactivity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -43,7 +46,7 @@ impl From<UnitRadioactivity> for String {
 impl Convert<UnitRadioactivity> for UnitRadioactivity {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitRadioactivity) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
@@ -64,6 +67,13 @@ impl BaseUnit for UnitRadioactivity {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Becquerel(_) => Some(ExactFactor::ONE),
+            Self::Curie => Some(consts::RADIO_C_TO_BQ_EXACT),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
@@ -17,11 +17,21 @@
 
 use std::fmt::Display;
 
+use crate::consts::SOUND_NP_TO_B;
+
 use super::{BaseUnit, Convert, Metric, UnitSound};
 
 impl Display for UnitSound {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}B", self.get_metric().as_str())
+        match self {
+            Self::Bel(m) => write!(f, "{}B", m.as_str()),
+            Self::Neper => write!(f, "Np"),
+            Self::DecibelMilliwatt => write!(f, "dBm"),
+            Self::DecibelWatt => write!(f, "dBW"),
+            Self::DecibelVolt => write!(f, "dBV"),
+            Self::DecibelMicrovolt => write!(f, "dBμV"),
+            Self::DecibelSoundPressure => write!(f, "dBSPL"),
+        }
     }
 }
 
@@ -33,8 +43,72 @@ impl From<UnitSound> for String {
 
 impl Convert<UnitSound> for UnitSound {
     /// Returns the `f64` multiplier to convert a `Value`
+    ///
+    /// This does not account for the offset between referenced levels,
+    /// see [`UnitSound::convert_level`]
     fn convert(&self, other: &UnitSound) -> f64 {
-        self.scale() / other.scale()
+        (self.scale() * self.base()) / (other.scale() * other.base())
+    }
+}
+
+impl UnitSound {
+    /// Converts a level from one unit to another
+    ///
+    /// Levels referenced to the same kind of quantity are offset from one another,
+    /// such as `0 dBW` being equal to `30 dBm`. The units must be compatible,
+    /// see [`UnitSound::is_compatible`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::units::{Metric, UnitSound};
+    /// let dbm = UnitSound::DecibelMilliwatt;
+    /// assert_eq!(dbm.convert_level(&UnitSound::DecibelWatt, 30.0), 0.0);
+    /// assert_eq!(UnitSound::Bel(Metric::None).convert_level(&UnitSound::Bel(Metric::Deci), 6.0), 60.0);
+    /// ```
+    pub fn convert_level(&self, other: &UnitSound, val: f64) -> f64 {
+        ((val * self.scale() * self.base() * 10.0) + self.offset() - other.offset())
+            / (other.scale() * other.base() * 10.0)
+    }
+
+    /// Returns `true` if the unit is a level referenced to a physical quantity
+    pub fn is_referenced(&self) -> bool {
+        !matches!(self, Self::Bel(_) | Self::Neper)
+    }
+
+    /// Returns `true` if levels of the two units can be converted between each other
+    pub fn is_compatible(&self, other: &UnitSound) -> bool {
+        self.reference_kind() == other.reference_kind()
+    }
+
+    /// Returns the multiplier applied to the logarithm of a ratio of linear quantities
+    ///
+    /// Power levels use `10.0` and field levels (voltage, pressure) use `20.0`.
+    /// Returns `None` for levels that are not referenced to a quantity.
+    pub fn level_multiplier(&self) -> Option<f64> {
+        match self {
+            Self::Bel(_) | Self::Neper => None,
+            Self::DecibelMilliwatt | Self::DecibelWatt => Some(10.0),
+            Self::DecibelVolt | Self::DecibelMicrovolt | Self::DecibelSoundPressure => Some(20.0),
+        }
+    }
+
+    /// Returns the kind of quantity the unit is referenced to
+    fn reference_kind(&self) -> usize {
+        match self {
+            Self::Bel(_) | Self::Neper => 0,
+            Self::DecibelMilliwatt | Self::DecibelWatt => 1,
+            Self::DecibelVolt | Self::DecibelMicrovolt => 2,
+            Self::DecibelSoundPressure => 3,
+        }
+    }
+
+    /// Returns the offset in decibels from the level of the same kind with a unit reference
+    fn offset(&self) -> f64 {
+        match self {
+            Self::DecibelMilliwatt => -30.0,
+            Self::DecibelMicrovolt => -120.0,
+            _ => 0.0,
+        }
     }
 }
 
@@ -43,6 +117,7 @@ impl BaseUnit for UnitSound {
     fn scale(&self) -> f64 {
         match self {
             Self::Bel(m) => m.scale(),
+            _ => 1.0,
         }
     }
 
@@ -50,21 +125,66 @@ impl BaseUnit for UnitSound {
     fn get_metric(&self) -> Metric {
         match self {
             Self::Bel(m) => *m,
+            _ => Metric::None,
         }
     }
 
+    /// Returns the size of the unit in bels
     fn base(&self) -> f64 {
-        1.0
+        match self {
+            Self::Bel(_) => 1.0,
+            Self::Neper => SOUND_NP_TO_B,
+            _ => 0.1,
+        }
     }
 }
 
 #[cfg(test)]
 mod sound_testing {
-    use crate::units::{BaseUnit, Metric, UnitSound};
+    use crate::units::{BaseUnit, Convert, Metric, UnitSound};
 
     #[test]
     fn unit_angle_base_comparison() {
         assert!(UnitSound::Bel(Metric::None).base() == 1.0);
+        assert!(UnitSound::DecibelMilliwatt.base() == 0.1);
+        assert!((UnitSound::Neper.base() - 0.868_588_963_8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unit_sound_levels() {
+        for i in [
+            (UnitSound::Neper, "Np"),
+            (UnitSound::DecibelMilliwatt, "dBm"),
+            (UnitSound::DecibelWatt, "dBW"),
+            (UnitSound::DecibelVolt, "dBV"),
+            (UnitSound::DecibelMicrovolt, "dBμV"),
+            (UnitSound::DecibelSoundPressure, "dBSPL"),
+        ] {
+            assert_eq!(&i.0.to_string(), i.1);
+            assert_eq!(i.0.get_metric(), Metric::None);
+            assert_eq!(i.0.scale(), 1.0);
+            assert_eq!(i.0.is_referenced(), i.0 != UnitSound::Neper);
+        }
+
+        let db = UnitSound::Bel(Metric::Deci);
+        assert!((UnitSound::Neper.convert(&db) - 8.685_889_638).abs() < 1e-9);
+        assert_eq!(
+            UnitSound::DecibelWatt.convert_level(&UnitSound::DecibelMilliwatt, -10.0),
+            20.0
+        );
+        assert_eq!(
+            UnitSound::DecibelVolt.convert_level(&UnitSound::DecibelMicrovolt, 0.0),
+            120.0
+        );
+        assert!(db.is_compatible(&UnitSound::Neper));
+        assert!(!db.is_compatible(&UnitSound::DecibelMilliwatt));
+        assert!(!UnitSound::DecibelVolt.is_compatible(&UnitSound::DecibelSoundPressure));
+        assert_eq!(db.level_multiplier(), None);
+        assert_eq!(UnitSound::DecibelWatt.level_multiplier(), Some(10.0));
+        assert_eq!(
+            UnitSound::DecibelSoundPressure.level_multiplier(),
+            Some(20.0)
+        );
     }
 
     #[test]
@@ -30,6 +30,9 @@ impl Display for UnitTemperature {
                 Self::Celsius(m) => format!("°{}c", m.as_str()),
                 Self::Kelvin(m) => format!("{}K", m.as_str()),
                 Self::Fahrenheit => "°f".into(),
+                Self::DeltaCelsius(m) => format!("Δ°{}c", m.as_str()),
+                Self::DeltaKelvin(m) => format!("Δ{}K", m.as_str()),
+                Self::DeltaFahrenheit => "Δ°f".into(),
             }
         )
     }
@@ -43,7 +46,13 @@ impl From<UnitTemperature> for String {
 
 impl UnitTemperature {
     /// Returns a `f64` to assign to a `Value`
+    ///
+    /// Offsets are only applied when both units are absolute temperatures,
+    /// temperature intervals are always converted linearly.
     pub fn convert(&self, other: &UnitTemperature, val: f64) -> f64 {
+        if self.is_interval() || other.is_interval() {
+            return val * self.interval_factor(other);
+        }
         match self {
             Self::Celsius(old_m) => match other {
                 Self::Celsius(new_m) => val * (old_m.scale() / new_m.scale()),
@@ -51,6 +60,7 @@ impl UnitTemperature {
                 Self::Kelvin(m) => {
                     f64::max((val * old_m.scale()) + KELVIN_TO_CELSIUS, 0.0) / m.scale()
                 }
+                _ => unreachable!("[convert] Unreachable"),
             },
             Self::Fahrenheit => match other {
                 Self::Celsius(m) => ((val - 32.0) / 1.8) / m.scale(),
@@ -58,12 +68,85 @@ impl UnitTemperature {
                 Self::Kelvin(m) => {
                     f64::max(((val - 32.0) / 1.8) + KELVIN_TO_CELSIUS, 0.0) / m.scale()
                 }
+                _ => unreachable!("[convert] Unreachable"),
             },
             Self::Kelvin(old_m) => match other {
                 Self::Celsius(m) => ((val * old_m.scale()) - KELVIN_TO_CELSIUS) / m.scale(),
                 Self::Fahrenheit => ((val - KELVIN_TO_CELSIUS) * 1.8) + 32.0,
                 Self::Kelvin(new_m) => val * (old_m.scale() / new_m.scale()),
+                _ => unreachable!("[convert] Unreachable"),
             },
+            _ => unreachable!("[convert] Unreachable"),
+        }
+    }
+
+    /// Returns the linear conversion factor between two temperature units
+    ///
+    /// This ignores the offsets between temperature scales and is used for
+    /// temperature intervals and temperatures within compound units.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::units::{Metric, UnitTemperature};
+    /// let k = UnitTemperature::Kelvin(Metric::None);
+    /// assert_eq!(UnitTemperature::DeltaCelsius(Metric::Kilo).interval_factor(&k), 1000.0);
+    /// ```
+    pub fn interval_factor(&self, other: &UnitTemperature) -> f64 {
+        self.degree_size() / other.degree_size()
+    }
+
+    /// Returns the size of a single degree in Kelvin
+    fn degree_size(&self) -> f64 {
+        match self {
+            Self::Celsius(m) | Self::Kelvin(m) | Self::DeltaCelsius(m) | Self::DeltaKelvin(m) => {
+                m.scale()
+            }
+            Self::Fahrenheit | Self::DeltaFahrenheit => 1.0 / 1.8,
+        }
+    }
+
+    /// Returns `true` if the unit is a temperature interval
+    pub fn is_interval(&self) -> bool {
+        matches!(
+            self,
+            Self::DeltaCelsius(_) | Self::DeltaFahrenheit | Self::DeltaKelvin(_)
+        )
+    }
+
+    /// Returns the temperature interval counterpart of the unit
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::units::{Metric, UnitTemperature};
+    /// let c = UnitTemperature::Celsius(Metric::None);
+    /// assert_eq!(c.interval(), UnitTemperature::DeltaCelsius(Metric::None));
+    /// assert_eq!(c.interval().absolute(), c);
+    /// ```
+    pub fn interval(&self) -> UnitTemperature {
+        match self {
+            Self::Celsius(m) => Self::DeltaCelsius(*m),
+            Self::Fahrenheit => Self::DeltaFahrenheit,
+            Self::Kelvin(m) => Self::DeltaKelvin(*m),
+            _ => *self,
+        }
+    }
+
+    /// Returns the absolute temperature counterpart of the unit
+    pub fn absolute(&self) -> UnitTemperature {
+        match self {
+            Self::DeltaCelsius(m) => Self::Celsius(*m),
+            Self::DeltaFahrenheit => Self::Fahrenheit,
+            Self::DeltaKelvin(m) => Self::Kelvin(*m),
+            _ => *self,
+        }
+    }
+
+    /// Returns the counterpart of the unit with the same kind as `other`
+    pub(crate) fn _as_kind_of(&self, other: &UnitTemperature) -> UnitTemperature {
+        if other.is_interval() {
+            self.interval()
+        } else {
+            self.absolute()
         }
     }
 }
@@ -74,6 +157,8 @@ impl BaseUnit for UnitTemperature {
         match self {
             UnitTemperature::Kelvin(m) => *m,
             UnitTemperature::Celsius(m) => *m,
+            UnitTemperature::DeltaKelvin(m) => *m,
+            UnitTemperature::DeltaCelsius(m) => *m,
             _ => Metric::None,
         }
     }
@@ -82,6 +167,8 @@ impl BaseUnit for UnitTemperature {
         match self {
             UnitTemperature::Kelvin(m) => m.scale(),
             UnitTemperature::Celsius(m) => m.scale(),
+            UnitTemperature::DeltaKelvin(m) => m.scale(),
+            UnitTemperature::DeltaCelsius(m) => m.scale(),
             _ => 1.0,
         }
     }
@@ -132,6 +219,9 @@ mod temperature_testing {
             (UnitTemperature::Kelvin(Metric::Zetta), "ZK"),
             (UnitTemperature::Fahrenheit, "°f"),
             (UnitTemperature::Celsius(Metric::None), "°c"),
+            (UnitTemperature::DeltaKelvin(Metric::Milli), "ΔmK"),
+            (UnitTemperature::DeltaFahrenheit, "Δ°f"),
+            (UnitTemperature::DeltaCelsius(Metric::None), "Δ°c"),
         ] {
             assert_eq!(&i.0.to_string(), i.1);
             let t: String = i.0.into();
@@ -275,4 +365,25 @@ mod temperature_testing {
             2.0
         );
     }
+
+    #[test]
+    fn unit_temperature_intervals() {
+        let c = UnitTemperature::Celsius(Metric::None);
+        let f = UnitTemperature::Fahrenheit;
+        let k = UnitTemperature::Kelvin(Metric::Milli);
+        for t in [c, f, k] {
+            assert!(!t.is_interval());
+            assert!(t.interval().is_interval());
+            assert_eq!(t.interval().absolute(), t);
+            assert_eq!(t.absolute(), t);
+            assert_eq!(t.interval().interval(), t.interval());
+            assert_eq!(t.interval().get_metric(), t.get_metric());
+            assert_eq!(t.interval().scale(), t.scale());
+        }
+
+        assert_eq!(c.interval().convert(&f.interval(), 10.0), 18.0);
+        assert_eq!(c.convert(&f.interval(), 10.0), 18.0);
+        assert_eq!(c.interval_factor(&k), 1000.0);
+        assert_eq!(f.interval_factor(&c), 1.0 / 1.8);
+    }
 }
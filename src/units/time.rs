@@ -17,7 +17,10 @@
 
 use std::fmt::Display;
 
-use super::{BaseUnit, Convert, Metric, UnitFrequency, UnitTime};
+use super::{
+    BaseUnit, Convert, Metric, UnitFrequency, UnitTime,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -42,13 +45,13 @@ impl From<UnitTime> for String {
 
 impl Convert<UnitTime> for UnitTime {
     fn convert(&self, other: &UnitTime) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        convert_base(self, other)
     }
 }
 
 impl Convert<UnitFrequency> for UnitTime {
     fn convert(&self, other: &UnitFrequency) -> f64 {
-        (self.scale() / other.scale()) * (self.base() / other.base())
+        1.0 / ((self.scale() * self.base()) * (other.scale() * other.base()))
     }
 }
 
@@ -71,6 +74,15 @@ impl BaseUnit for UnitTime {
         }
     }
 
+    fn exact_base(&self) -> Option<ExactFactor> {
+        match self {
+            Self::Second(_) => Some(ExactFactor::ONE),
+            Self::Minute => Some(ExactFactor::from_int(60)),
+            Self::Hour => Some(ExactFactor::from_int(3600)),
+            Self::Day => Some(ExactFactor::from_int(86400)),
+        }
+    }
+
     /// Returns the `Metric` prefix for the unit
     fn get_metric(&self) -> Metric {
         match self {
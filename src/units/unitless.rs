@@ -27,6 +27,10 @@ impl Display for UnitNone {
             match self {
                 Self::None => "",
                 Self::Percentage => "%",
+                Self::PerMille => "‰",
+                Self::PartsPerMillion => "ppm",
+                Self::PartsPerBillion => "ppb",
+                Self::PartsPerTrillion => "ppt",
             }
         )
     }
@@ -53,7 +57,14 @@ impl BaseUnit for UnitNone {
     }
 
     fn base(&self) -> f64 {
-        1.0
+        match self {
+            Self::None => 1.0,
+            Self::Percentage => 1e-2,
+            Self::PerMille => 1e-3,
+            Self::PartsPerMillion => 1e-6,
+            Self::PartsPerBillion => 1e-9,
+            Self::PartsPerTrillion => 1e-12,
+        }
     }
 
     fn get_metric(&self) -> Metric {
@@ -74,12 +85,23 @@ mod unitless_testing {
     #[test]
     fn unit_time_base_comparison() {
         assert_eq!(UnitNone::None.base(), 1.0);
-        assert_eq!(UnitNone::Percentage.base(), 1.0);
+        assert_eq!(UnitNone::Percentage.base(), 0.01);
+        assert_eq!(UnitNone::PerMille.base(), 0.001);
+        assert_eq!(UnitNone::PartsPerMillion.base(), 1e-6);
+        assert_eq!(UnitNone::PartsPerBillion.base(), 1e-9);
+        assert_eq!(UnitNone::PartsPerTrillion.base(), 1e-12);
     }
 
     #[test]
     fn unit_angle_to_string() {
-        for i in [(UnitNone::None, ""), (UnitNone::Percentage, "%")] {
+        for i in [
+            (UnitNone::None, ""),
+            (UnitNone::Percentage, "%"),
+            (UnitNone::PerMille, "‰"),
+            (UnitNone::PartsPerMillion, "ppm"),
+            (UnitNone::PartsPerBillion, "ppb"),
+            (UnitNone::PartsPerTrillion, "ppt"),
+        ] {
             assert_eq!(&i.0.to_string(), i.1);
             let t: String = i.0.into();
             assert_eq!(t, i.1.to_string());
@@ -19,7 +19,10 @@ use std::fmt::Display;
 
 use crate::consts;
 
-use super::{BaseUnit, Convert, Metric, UnitLength, UnitVolume};
+use super::{
+    BaseUnit, Convert, Metric, UnitLength, UnitVolume,
+    exact::{ExactFactor, convert_base},
+};
 
 impl Display for UnitVolume {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -36,7 +39,7 @@ impl From<UnitVolume> for String {
 impl Convert<UnitVolume> for UnitVolume {
     /// Returns the `f64` multiplier to convert a `Value`
     fn convert(&self, other: &UnitVolume) -> f64 {
-        self.scale() / other.scale()
+        convert_base(self, other)
     }
 }
 
@@ -67,6 +70,10 @@ impl BaseUnit for UnitVolume {
     fn base(&self) -> f64 {
         1.0
     }
+
+    fn exact_base(&self) -> Option<ExactFactor> {
+        Some(ExactFactor::ONE)
+    }
 }
 
 #[cfg(test)]
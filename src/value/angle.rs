@@ -0,0 +1,344 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::f64::consts::TAU;
+
+use crate::{
+    errors::V3Error,
+    units::{Convert, Metric, UnitAngle},
+    value::Value,
+};
+
+impl Value {
+    /// Wraps an angle into `[0, 360°)`, in its own units
+    ///
+    /// Returns an error if the [`Value`] is not an angle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let h:Value = Value::new(-90.0, "degrees").unwrap();
+    /// assert_eq!(h.normalize_positive().unwrap().to_string(), "270 °");
+    /// ```
+    pub fn normalize_positive(&self) -> Result<Value, V3Error> {
+        let turn: f64 = self._full_turn("normalize_positive")?;
+        let mut n: Value = *self;
+        n.val = self.val.rem_euclid(turn);
+        if n.val == turn {
+            n.val = 0.0;
+        }
+        Ok(n)
+    }
+
+    /// Wraps an angle into `(-180°, 180°]`, in its own units
+    ///
+    /// Returns an error if the [`Value`] is not an angle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let h:Value = Value::new(270.0, "degrees").unwrap();
+    /// assert_eq!(h.normalize_signed().unwrap().to_string(), "-90 °");
+    /// ```
+    pub fn normalize_signed(&self) -> Result<Value, V3Error> {
+        let turn: f64 = self._full_turn("normalize_signed")?;
+        let mut n: Value = self.normalize_positive()?;
+        if n.val > turn / 2.0 {
+            n.val -= turn;
+        }
+        Ok(n)
+    }
+
+    /// Returns the signed shortest angle from `self` to `other`, in the units of `self`
+    ///
+    /// The result is within `(-180°, 180°]` and is positive when `other` is reached by
+    /// increasing `self`. Returns an error if either [`Value`] is not an angle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(350.0, "degrees").unwrap();
+    /// let b:Value = Value::new(10.0, "degrees").unwrap();
+    /// assert_eq!(a.angle_diff(&b).unwrap().to_string(), "20 °");
+    /// assert_eq!(b.angle_diff(&a).unwrap().to_string(), "-20 °");
+    /// ```
+    pub fn angle_diff(&self, other: &Value) -> Result<Value, V3Error> {
+        self._full_turn("angle_diff")?;
+        other._full_turn("angle_diff")?;
+        let mut n: Value = *self;
+        n.val = self._rhs_in_self_units(other)? - self.val;
+        n.normalize_signed()
+    }
+
+    /// Creates an angle in degrees from sexagesimal notation
+    ///
+    /// Accepts degrees followed by optional minutes and seconds, such as `12°34'56.7"`,
+    /// with `′` and `″` accepted for minutes and seconds. The angle may carry a sign,
+    /// or a leading or trailing hemisphere of `N`, `E` (positive) or `S`, `W` (negative).
+    /// Minutes and seconds must be less than 60.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::from_dms("12°34'56.7\"").unwrap();
+    /// assert!((a.val - 12.582416666666667).abs() < 1e-12);
+    ///
+    /// let lat:Value = Value::from_dms("S 45°30'").unwrap();
+    /// assert_eq!(lat.to_string(), "-45.5 °");
+    /// assert!(Value::from_dms("45°75'").is_err());
+    /// ```
+    pub fn from_dms(s: &str) -> Result<Value, V3Error> {
+        let err = || V3Error::ParsingError(format!("[from_dms] Invalid angle: {s}"));
+        let mut body: &str = s.trim();
+        let mut sign: f64 = 1.0;
+        let mut hemisphere: bool = false;
+        for (h, hs) in [('N', 1.0), ('E', 1.0), ('S', -1.0), ('W', -1.0)] {
+            if let Some(b) = body.strip_prefix(h).or_else(|| body.strip_suffix(h)) {
+                body = b.trim();
+                sign = hs;
+                hemisphere = true;
+                break;
+            }
+        }
+        if let Some(b) = body.strip_prefix('-') {
+            if hemisphere {
+                return Err(err());
+            }
+            body = b;
+            sign = -1.0;
+        } else if let Some(b) = body.strip_prefix('+') {
+            body = b;
+        }
+
+        let mut total: f64 = 0.0;
+        let mut last: usize = 0;
+        let mut rest: &str = body;
+        while !rest.is_empty() {
+            let Some(end) = rest.find(['°', '\'', '′', '"', '″']) else {
+                return Err(err());
+            };
+            let n: f64 = rest[..end].trim().parse::<f64>().map_err(|_| err())?;
+            let mark: char = rest[end..].chars().next().unwrap();
+            let (part, unit): (usize, UnitAngle) = match mark {
+                '°' => (1, UnitAngle::Degree),
+                '\'' | '′' => (2, UnitAngle::Moa),
+                _ => (3, UnitAngle::ArcSec(Metric::None)),
+            };
+            if part <= last || !n.is_finite() || n < 0.0 || (part > 1 && n >= 60.0) {
+                return Err(err());
+            }
+            total += n * unit.convert(&UnitAngle::Degree);
+            last = part;
+            rest = rest[end + mark.len_utf8()..].trim_start();
+        }
+        if last == 0 {
+            return Err(err());
+        }
+        Ok(sign * total * UnitAngle::Degree)
+    }
+
+    /// Formats an angle in sexagesimal notation, rounding seconds to a number of decimals
+    ///
+    /// At most 15 decimals are used. Returns an error if the [`Value`] is not an angle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(-12.582416666666667, "degrees").unwrap();
+    /// assert_eq!(a.to_dms(1).unwrap(), "-12°34'56.7\"");
+    ///
+    /// let r:Value = Value::new(std::f64::consts::PI, "rad").unwrap();
+    /// assert_eq!(r.to_dms(0).unwrap(), "180°0'0\"");
+    /// ```
+    pub fn to_dms(&self, decimals: usize) -> Result<String, V3Error> {
+        let (negative, dms) = self._dms_parts("to_dms", decimals)?;
+        Ok(format!("{}{dms}", if negative { "-" } else { "" }))
+    }
+
+    /// Formats an angle in sexagesimal notation with a leading hemisphere
+    ///
+    /// `positive` is used for angles greater than or equal to zero and `negative` for the
+    /// others, such as `'N'` and `'S'` for latitudes.
+    /// Returns an error if the [`Value`] is not an angle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let lon:Value = Value::new(-122.5, "degrees").unwrap();
+    /// assert_eq!(lon.to_dms_hemisphere(0, 'E', 'W').unwrap(), "W 122°30'0\"");
+    /// ```
+    pub fn to_dms_hemisphere(
+        &self,
+        decimals: usize,
+        positive: char,
+        negative: char,
+    ) -> Result<String, V3Error> {
+        let (is_negative, dms) = self._dms_parts("to_dms_hemisphere", decimals)?;
+        Ok(format!(
+            "{} {dms}",
+            if is_negative { negative } else { positive }
+        ))
+    }
+
+    /// Returns the sign of an angle and its unsigned sexagesimal notation
+    fn _dms_parts(&self, op: &str, decimals: usize) -> Result<(bool, String), V3Error> {
+        self._full_turn(op)?;
+        let unit: UnitAngle = self.v_angle.unwrap();
+        // seconds are rounded first so that they carry into minutes and degrees
+        let decimals: usize = decimals.min(15);
+        let scale: f64 = 10_f64.powi(decimals as i32);
+        let seconds: f64 =
+            (self.val.abs() * unit.convert(&UnitAngle::ArcSec(Metric::None)) * scale).round()
+                / scale;
+        if !seconds.is_finite() {
+            return Err(V3Error::ValueConversionError(
+                "[to_dms] Angle is not finite",
+            ));
+        }
+        let degrees: f64 = (seconds / 3600.0).floor();
+        let minutes: f64 = ((seconds - degrees * 3600.0) / 60.0).floor();
+        let seconds: f64 = seconds - degrees * 3600.0 - minutes * 60.0;
+        Ok((
+            self.val < 0.0 && (degrees, minutes, seconds) != (0.0, 0.0, 0.0),
+            format!("{degrees}°{minutes}'{seconds:.decimals$}\""),
+        ))
+    }
+
+    /// Returns a full turn in the units of an angle
+    fn _full_turn(&self, op: &str) -> Result<f64, V3Error> {
+        if !self.is_angle() {
+            return Err(V3Error::IncompatibleUnits(format!(
+                "[{op}] {self} is not an angle"
+            )));
+        }
+        Ok(match self.v_angle.unwrap() {
+            UnitAngle::Radian(m) => {
+                TAU * UnitAngle::Radian(Metric::None).convert(&UnitAngle::Radian(m))
+            }
+            u => 360.0 * UnitAngle::Degree.convert(&u),
+        })
+    }
+}
+
+#[cfg(test)]
+mod angle_testing {
+    use std::f64::consts::PI;
+
+    use crate::value::Value;
+
+    #[test]
+    fn normalize() {
+        let d = |v: f64| Value::new(v, "degrees").unwrap();
+        assert_eq!(d(725.0).normalize_positive().unwrap(), d(5.0));
+        assert_eq!(d(-360.0).normalize_positive().unwrap(), d(0.0));
+        assert_eq!(d(-1e-20).normalize_positive().unwrap(), d(0.0));
+        assert_eq!(d(180.0).normalize_signed().unwrap(), d(180.0));
+        assert_eq!(d(-180.0).normalize_signed().unwrap(), d(180.0));
+        assert_eq!(d(190.0).normalize_signed().unwrap(), d(-170.0));
+
+        let r: Value = Value::new(3.0 * PI, "rad").unwrap();
+        let p: Value = r.normalize_positive().unwrap();
+        assert_eq!(p.unit_string(), "rad");
+        assert!((p.val - PI).abs() < 1e-12);
+        let m: Value = Value::new(-7000.0, "mil").unwrap();
+        assert!((m.normalize_signed().unwrap().val - (2000.0 * PI - 7000.0)).abs() < 1e-9);
+        assert_eq!(
+            Value::new(-60.0, "moa")
+                .unwrap()
+                .normalize_positive()
+                .unwrap(),
+            Value::new(21540.0, "moa").unwrap()
+        );
+
+        assert!(Value::new(1.0, "m").unwrap().normalize_positive().is_err());
+        assert!(
+            Value::new(1.0, "rad^2")
+                .unwrap()
+                .normalize_signed()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let d = |v: f64| Value::new(v, "degrees").unwrap();
+        assert_eq!(d(10.0).angle_diff(&d(350.0)).unwrap(), d(-20.0));
+        assert_eq!(d(-170.0).angle_diff(&d(170.0)).unwrap(), d(-20.0));
+        assert_eq!(d(0.0).angle_diff(&d(180.0)).unwrap(), d(180.0));
+        assert_eq!(d(90.0).angle_diff(&d(90.0)).unwrap(), d(0.0));
+
+        let r: Value = Value::new(PI / 2.0, "rad").unwrap();
+        let diff: Value = d(0.0).angle_diff(&r).unwrap();
+        assert_eq!(diff.unit_string(), "°");
+        assert!((diff.val - 90.0).abs() < 1e-12);
+        assert!(d(0.0).angle_diff(&Value::new(1.0, "s").unwrap()).is_err());
+    }
+
+    #[test]
+    fn dms() {
+        let a: Value = Value::from_dms("12°34'56.7\"").unwrap();
+        assert!((a.val - (12.0 + 34.0 / 60.0 + 56.7 / 3600.0)).abs() < 1e-12);
+        assert_eq!(a.to_dms(1).unwrap(), "12°34'56.7\"");
+        assert_eq!(Value::from_dms("12° 34′ 56.7″").unwrap(), a);
+        assert_eq!(
+            Value::from_dms("N 45°30'").unwrap(),
+            Value::new(45.5, "degrees").unwrap()
+        );
+        assert_eq!(
+            Value::from_dms("45°30'W").unwrap(),
+            Value::new(-45.5, "degrees").unwrap()
+        );
+        assert_eq!(
+            Value::from_dms("-0°30'").unwrap(),
+            Value::new(-0.5, "degrees").unwrap()
+        );
+        assert_eq!(
+            Value::from_dms("30\"").unwrap().to_dms(0).unwrap(),
+            "0°0'30\""
+        );
+
+        for bad in [
+            "",
+            "45",
+            "45°75'",
+            "30'45°",
+            "S -45°",
+            "45°30'20'",
+            "x°",
+            "N",
+        ] {
+            assert!(Value::from_dms(bad).is_err(), "{bad}");
+        }
+
+        let b: Value = Value::new(29.999999999, "degrees").unwrap();
+        assert_eq!(b.to_dms(2).unwrap(), "30°0'0.00\"");
+        assert_eq!(
+            Value::new(30.5, "degrees")
+                .unwrap()
+                .to_dms(1_000_000)
+                .unwrap(),
+            "30°30'0.000000000000000\""
+        );
+        assert_eq!(
+            Value::new(-1e-9, "degrees").unwrap().to_dms(0).unwrap(),
+            "0°0'0\""
+        );
+        let lat: Value = Value::new(-33.865, "degrees").unwrap();
+        assert_eq!(lat.to_dms_hemisphere(0, 'N', 'S').unwrap(), "S 33°51'54\"");
+        assert!(Value::new(1.0, "m").unwrap().to_dms(0).is_err());
+    }
+}
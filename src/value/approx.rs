@@ -0,0 +1,211 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{units::Convert, value::Value};
+
+/// The tolerance used to compare two [`Value`]s approximately
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Copy)]
+pub enum Tolerance {
+    /// An absolute difference, in any unit compatible with the compared [`Value`]s
+    Absolute(Value),
+    /// A difference relative to the larger magnitude of the compared [`Value`]s
+    Relative(f64),
+    /// A difference in units in the last place of the compared [`Value`]s
+    Ulps(u64),
+}
+
+impl From<Value> for Tolerance {
+    fn from(val: Value) -> Self {
+        Tolerance::Absolute(val)
+    }
+}
+
+impl Value {
+    /// Returns `true` if two [`Value`]s are equal within a given [`Tolerance`]
+    ///
+    /// `other` is converted into the units of `self` before being compared, as is an absolute
+    /// tolerance. Temperature and level tolerances are treated as differences.
+    /// Returns `false` if the units of either [`Value`] or an absolute tolerance are not
+    /// compatible, or if either numeric is `NaN`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::approx::Tolerance;
+    /// use bxvl::units::{Metric, UnitLength};
+    /// let a:Value = 1.0 * UnitLength::Foot;
+    /// let b:Value = 304.8 * UnitLength::Meter(Metric::Milli);
+    ///
+    /// assert!(a.approx_eq(&b, 0.001 * UnitLength::Inch));
+    /// assert!(a.approx_eq(&b, Tolerance::Relative(1e-12)));
+    /// assert!(a.approx_eq(&b, Tolerance::Ulps(4)));
+    /// assert!(!a.approx_eq(&(12.1 * UnitLength::Inch), Tolerance::Relative(1e-3)));
+    /// ```
+    pub fn approx_eq<T: Into<Tolerance>>(&self, other: &Value, tol: T) -> bool {
+        let rhs: f64 = match *other >> *self {
+            Ok(v) => v.val,
+            Err(_) => return false,
+        };
+        // an absolute tolerance is converted first so that equal values do not skip its check
+        let tol: Tolerance = tol.into();
+        let abs_tol: Option<f64> = match tol {
+            Tolerance::Absolute(t) => match self._difference_in_self_units(&t) {
+                Some(t) => Some(t.abs()),
+                None => return false,
+            },
+            _ => None,
+        };
+        if self.val == rhs {
+            return true;
+        } else if self.val.is_nan() || rhs.is_nan() {
+            return false;
+        }
+
+        match tol {
+            Tolerance::Absolute(_) => (self.val - rhs).abs() <= abs_tol.unwrap(),
+            Tolerance::Relative(t) => {
+                (self.val - rhs).abs() <= t.abs() * f64::max(self.val.abs(), rhs.abs())
+            }
+            Tolerance::Ulps(t) => {
+                let d: u128 = (_ordered_bits(self.val) - _ordered_bits(rhs)).unsigned_abs();
+                d <= t as u128
+            }
+        }
+    }
+
+    /// Returns the numeric of a difference, such as a tolerance or step, in the units of `self`
+    pub(in crate::value) fn _difference_in_self_units(&self, tol: &Value) -> Option<f64> {
+        if self.is_temperature() && tol.is_temperature() {
+            return Some(
+                tol.val
+                    * tol
+                        .v_temperature
+                        .unwrap()
+                        .interval_factor(&self.v_temperature.unwrap()),
+            );
+        } else if self.is_sound() && tol.is_sound() {
+            return Some(tol.val * tol.v_sound.unwrap().convert(&self.v_sound.unwrap()));
+        }
+        (*tol >> *self).ok().map(|v| v.val)
+    }
+}
+
+/// Returns the bits of a `f64` as an integer that is ordered like the `f64`
+fn _ordered_bits(x: f64) -> i128 {
+    let b: i64 = x.to_bits() as i64;
+    if b < 0 {
+        i64::MIN as i128 - b as i128
+    } else {
+        b as i128
+    }
+}
+
+#[cfg(test)]
+mod approx_testing {
+    use crate::assert_value_approx;
+    use crate::units::{Metric, UnitLength, UnitSound, UnitTemperature, UnitTime};
+    use crate::value::{Value, approx::Tolerance};
+
+    #[test]
+    fn approx_absolute() {
+        let a = 1.0 * UnitLength::Foot;
+        let b = 12.0001 * UnitLength::Inch;
+
+        assert!(a != (b >> UnitLength::Foot).unwrap());
+        assert!(a.approx_eq(&b, 0.001 * UnitLength::Inch));
+        assert!(a.approx_eq(
+            &b,
+            Tolerance::Absolute(0.01 * UnitLength::Meter(Metric::Milli))
+        ));
+        assert!(!a.approx_eq(&b, 0.00001 * UnitLength::Inch));
+        assert!(!a.approx_eq(&b, 1.0 * UnitTime::Second(Metric::None)));
+        assert!(!a.approx_eq(&a, 1.0 * UnitTime::Second(Metric::None)));
+        assert!(!a.approx_eq(
+            &(1.0 * UnitTime::Second(Metric::None)),
+            1.0 * UnitLength::Foot
+        ));
+    }
+
+    #[test]
+    fn approx_relative() {
+        let a = Value::new(1.0, "N").unwrap();
+        let b = Value::new(1000.0, "g*m/s^2").unwrap();
+        let c = Value::new(-0.9995, "N").unwrap();
+
+        assert!(a.approx_eq(&a, Tolerance::Relative(0.0)));
+        assert!(a.approx_eq(&(c * -1.0), Tolerance::Relative(1e-3)));
+        assert!(!a.approx_eq(&(c * -1.0), Tolerance::Relative(1e-4)));
+        assert!(!c.approx_eq(&a, Tolerance::Relative(1.0)));
+
+        // units must be convertible without reduction
+        assert!(!a.approx_eq(&b, Tolerance::Relative(1e-3)));
+    }
+
+    #[test]
+    fn approx_ulps() {
+        let a = 0.1 * UnitTime::Second(Metric::None);
+        let b = f64::from_bits(0.1_f64.to_bits() + 2) * UnitTime::Second(Metric::None);
+        let c = -0.0 * UnitTime::Second(Metric::None);
+        let d = f64::from_bits(1) * UnitTime::Second(Metric::None);
+        let nd: Value = d * -1.0;
+
+        assert!(a.approx_eq(&b, Tolerance::Ulps(2)));
+        assert!(!a.approx_eq(&b, Tolerance::Ulps(1)));
+        assert!(c.approx_eq(&(c * 0.0), Tolerance::Ulps(0)));
+        assert!(c.approx_eq(&d, Tolerance::Ulps(1)));
+        assert!(nd.approx_eq(&d, Tolerance::Ulps(2)));
+        assert!(!nd.approx_eq(&d, Tolerance::Ulps(1)));
+        assert!(
+            !(f64::NAN * UnitTime::Second(Metric::None)).approx_eq(&a, Tolerance::Ulps(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn approx_intervals() {
+        let a = 20.0 * UnitTemperature::Celsius(Metric::None);
+        let b = 68.1 * UnitTemperature::Fahrenheit;
+
+        assert!(a.approx_eq(&b, 0.1 * UnitTemperature::Kelvin(Metric::None)));
+        assert!(!a.approx_eq(&b, 0.01 * UnitTemperature::DeltaKelvin(Metric::None)));
+
+        let c = 30.0 * UnitSound::DecibelMilliwatt;
+        let d = 0.0 * UnitSound::DecibelWatt;
+        assert!(c.approx_eq(&d, 0.1 * UnitSound::Bel(Metric::Deci)));
+        let e = 30.2 * UnitSound::DecibelMilliwatt;
+        assert!(!e.approx_eq(&d, 0.1 * UnitSound::Bel(Metric::Deci)));
+    }
+
+    #[test]
+    fn approx_macro() {
+        let a = 1.0 * UnitLength::Foot;
+        assert_value_approx!(a, 12.0 * UnitLength::Inch, Tolerance::Ulps(2));
+        assert_value_approx!(
+            a,
+            0.3048 * UnitLength::Meter(Metric::None),
+            1e-9 * UnitLength::Foot,
+            "foot to meter"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn approx_macro_fail() {
+        let a = 1.0 * UnitLength::Foot;
+        assert_value_approx!(a, 13.0 * UnitLength::Inch, Tolerance::Relative(1e-3));
+    }
+}
@@ -0,0 +1,224 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    borrow::Borrow,
+    iter::{Product, Sum},
+};
+
+use crate::{errors::V3Error, units::UnitNone, value::Value};
+
+impl Value {
+    /// Sums [`Value`]s without panicking
+    ///
+    /// Every [`Value`] is converted into the units of the first before being added, following
+    /// the rules of [`Value::try_add`]. An empty iterator sums to a unitless `0`.
+    /// This is the non-panicking counterpart of [`Sum`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength, UnitTime};
+    /// let v:Vec<Value> = vec![1.0 * UnitLength::Foot, 12.0 * UnitLength::Inch];
+    /// assert_eq!(Value::try_sum(&v).unwrap(), 2.0 * UnitLength::Foot);
+    ///
+    /// let w:Vec<Value> = vec![1.0 * UnitLength::Foot, 1.0 * UnitTime::Second(Metric::None)];
+    /// assert!(Value::try_sum(w).is_err());
+    /// ```
+    pub fn try_sum<I, T>(iter: I) -> Result<Value, V3Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Value>,
+    {
+        let mut iter = iter.into_iter();
+        let Some(first) = iter.next() else {
+            return Ok(0.0 * UnitNone::None);
+        };
+        iter.try_fold(*first.borrow(), |acc, v| acc.try_add(v.borrow()))
+    }
+
+    /// Sums [`Value`]s into the given units without panicking
+    ///
+    /// The first [`Value`] is converted into `units` and every other [`Value`] is then added
+    /// following the rules of [`Value::try_add`]. An empty iterator sums to `0` of `units`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength};
+    /// let v:Vec<Value> = vec![1.0 * UnitLength::Foot, 1.0 * UnitLength::Meter(Metric::None)];
+    /// let s:Value = Value::try_sum_in(&v, "cm").unwrap();
+    /// assert_eq!(s, 130.48 * UnitLength::Meter(Metric::Centi));
+    /// ```
+    pub fn try_sum_in<I, T>(iter: I, units: &str) -> Result<Value, V3Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Value>,
+    {
+        let mut iter = iter.into_iter();
+        let Some(first) = iter.next() else {
+            return Value::new(0.0, units);
+        };
+        let mut n: Value = *first.borrow();
+        n.convert(units)?;
+        iter.try_fold(n, |acc, v| acc.try_add(v.borrow()))
+    }
+
+    /// Multiplies [`Value`]s without panicking
+    ///
+    /// Units are combined as they are by [`Value::try_mul`]. An empty iterator multiplies to a
+    /// unitless `1`. This is the non-panicking counterpart of [`Product`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength};
+    /// let v:Vec<Value> = vec![
+    ///     2.0 * UnitLength::Meter(Metric::None),
+    ///     3.0 * UnitLength::Meter(Metric::None),
+    ///     4.0 * UnitLength::Meter(Metric::None),
+    /// ];
+    /// assert_eq!(Value::try_product(&v).unwrap().to_string(), "24 m^3");
+    /// ```
+    pub fn try_product<I, T>(iter: I) -> Result<Value, V3Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Value>,
+    {
+        iter.into_iter()
+            .try_fold(1.0 * UnitNone::None, |acc, v| acc.try_mul(v.borrow()))
+    }
+}
+
+impl Sum<Value> for Value {
+    fn sum<I: Iterator<Item = Value>>(iter: I) -> Self {
+        match Value::try_sum(iter) {
+            Ok(v) => v,
+            Err(e) => panic!("Cannot Sum values: {e}"),
+        }
+    }
+}
+
+impl<'a> Sum<&'a Value> for Value {
+    fn sum<I: Iterator<Item = &'a Value>>(iter: I) -> Self {
+        match Value::try_sum(iter) {
+            Ok(v) => v,
+            Err(e) => panic!("Cannot Sum values: {e}"),
+        }
+    }
+}
+
+impl Product<Value> for Value {
+    fn product<I: Iterator<Item = Value>>(iter: I) -> Self {
+        match Value::try_product(iter) {
+            Ok(v) => v,
+            Err(e) => panic!("Cannot Product values: {e}"),
+        }
+    }
+}
+
+impl<'a> Product<&'a Value> for Value {
+    fn product<I: Iterator<Item = &'a Value>>(iter: I) -> Self {
+        match Value::try_product(iter) {
+            Ok(v) => v,
+            Err(e) => panic!("Cannot Product values: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod iterators_testing {
+    use crate::units::{Metric, UnitLength, UnitNone, UnitSound, UnitTemperature, UnitTime};
+    use crate::value::Value;
+
+    #[test]
+    fn sum() {
+        let v: Vec<Value> = vec![
+            1.0 * UnitLength::Foot,
+            12.0 * UnitLength::Inch,
+            0.3048 * UnitLength::Meter(Metric::None),
+        ];
+        let s: Value = v.iter().sum();
+        assert!((s.val - 3.0).abs() < 1e-12);
+        assert_eq!(s.unit_string(), "ft");
+        let s: Value = v.into_iter().sum();
+        assert_eq!(s.unit_string(), "ft");
+
+        let e: Value = Vec::<Value>::new().into_iter().sum();
+        assert_eq!(e, 0.0 * UnitNone::None);
+        assert!(e.is_empty());
+
+        let t: Vec<Value> = vec![
+            20.0 * UnitTemperature::Celsius(Metric::None),
+            5.0 * UnitTemperature::DeltaCelsius(Metric::None),
+            9.0 * UnitTemperature::DeltaFahrenheit,
+        ];
+        assert_eq!(
+            Value::try_sum(&t).unwrap(),
+            30.0 * UnitTemperature::Celsius(Metric::None)
+        );
+
+        let l: Vec<Value> = vec![60.0 * UnitSound::Bel(Metric::Deci); 2];
+        assert!((Value::try_sum(&l).unwrap().val - 63.0103).abs() < 1e-4);
+    }
+
+    #[test]
+    fn try_sum() {
+        let v: Vec<Value> = vec![1.0 * UnitLength::Foot, 1.0 * UnitTime::Second(Metric::None)];
+        assert!(Value::try_sum(&v).is_err());
+        assert!(Value::try_sum_in(&v, "m").is_err());
+
+        let w: Vec<Value> = vec![1.0 * UnitLength::Foot, 12.0 * UnitLength::Inch];
+        assert_eq!(
+            Value::try_sum_in(&w, "in").unwrap(),
+            24.0 * UnitLength::Inch
+        );
+        assert!(Value::try_sum_in(&w, "s").is_err());
+        assert_eq!(
+            Value::try_sum_in(Vec::<Value>::new(), "m").unwrap(),
+            0.0 * UnitLength::Meter(Metric::None)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn sum_fail() {
+        let v: Vec<Value> = vec![1.0 * UnitLength::Foot, 1.0 * UnitTime::Second(Metric::None)];
+        let _: Value = v.iter().sum();
+    }
+
+    #[test]
+    fn product() {
+        let v: Vec<Value> = vec![
+            2.0 * UnitLength::Meter(Metric::None),
+            50.0 * UnitLength::Meter(Metric::Centi),
+            4.0 / UnitTime::Second(Metric::None),
+        ];
+        let p: Value = v.iter().product();
+        assert_eq!(p, Value::new(4.0, "m^2/s").unwrap());
+        let p: Value = v.into_iter().product();
+        assert_eq!(p.to_string(), "4 m^2/s");
+
+        let e: Value = Vec::<Value>::new().iter().product();
+        assert_eq!(e, 1.0 * UnitNone::None);
+
+        let w: Vec<Value> = vec![2.0 * UnitLength::Foot, 0.5 / UnitLength::Foot];
+        let q: Value = Value::try_product(&w).unwrap();
+        assert!(q.is_empty());
+        assert_eq!(q, 1.0);
+    }
+}
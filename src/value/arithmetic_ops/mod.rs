@@ -19,9 +19,11 @@ use std::ops::Neg;
 
 use super::Value;
 
+/// Module for summing and multiplying iterators of [`Value`]s
+pub(crate) mod iterators;
 /// Module for operations of a [`Value`] against a [`f32`]
 pub(crate) mod type_f32;
-/// Module for operations of a [`Value`] against a [`f64`]
+/// Module for operations of a [`Value`] against its own numeric, such as a [`f64`]
 pub(crate) mod type_f64;
 /// Module for operations of a [`Value`] against a [`i32`]
 pub(crate) mod type_i32;
@@ -17,11 +17,67 @@
 
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
-use crate::value::Value;
+use crate::value::{Value, numeric::Numeric};
 
-use crate::create_defs;
+impl<T: Numeric> Add<T> for Value<T> {
+    type Output = Value<T>;
+    fn add(self, rhs: T) -> Value<T> {
+        let mut n: Value<T> = self;
+        n.val = n.val + rhs;
+        n
+    }
+}
+
+impl<T: Numeric> AddAssign<T> for Value<T> {
+    fn add_assign(&mut self, rhs: T) {
+        self.val = self.val + rhs;
+    }
+}
+
+impl<T: Numeric> Sub<T> for Value<T> {
+    type Output = Value<T>;
+    fn sub(self, rhs: T) -> Value<T> {
+        let mut n: Value<T> = self;
+        n.val = n.val - rhs;
+        n
+    }
+}
+
+impl<T: Numeric> SubAssign<T> for Value<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        self.val = self.val - rhs;
+    }
+}
 
-create_defs!(f64);
+impl<T: Numeric> Mul<T> for Value<T> {
+    type Output = Value<T>;
+    fn mul(self, rhs: T) -> Value<T> {
+        let mut n: Value<T> = self;
+        n.val = n.val * rhs;
+        n
+    }
+}
+
+impl<T: Numeric> MulAssign<T> for Value<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.val = self.val * rhs;
+    }
+}
+
+impl<T: Numeric> Div<T> for Value<T> {
+    type Output = Value<T>;
+    fn div(self, rhs: T) -> Value<T> {
+        let mut n: Value<T> = self;
+        n.val = n.val / rhs;
+        n
+    }
+}
+
+impl<T: Numeric> DivAssign<T> for Value<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.val = self.val / rhs;
+    }
+}
 
 #[cfg(test)]
 mod arithmetic_ops_testing {
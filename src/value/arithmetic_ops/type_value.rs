@@ -15,1714 +15,264 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
 use crate::consts::*;
-use crate::units::Convert;
+use crate::errors::V3Error;
+use crate::units::{BaseUnit, Convert, Metric, UnitSound, UnitTemperature};
 use crate::value::Value;
-
-impl Add<Value> for Value {
-    type Output = Value;
-    fn add(self, other: Value) -> Value {
-        // special case to check if temperature is already the correct unit
-        if self.unit_map & TEMPERATURE_MAP != 0
-            && self.unit_map != TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
-        {
-            // Error cannot convert as part of larger unit
-            panic!("Cannot Add values {self} and {other}");
+use crate::value::exponent::PowRational;
+use crate::value::numeric::{_representable, _scale, _through_f64, Numeric};
+
+impl<T: Numeric> Add<Value<T>> for Value<T> {
+    type Output = Value<T>;
+    fn add(self, other: Value<T>) -> Value<T> {
+        match self.try_add(&other) {
+            Ok(n) => n,
+            Err(_) => panic!("Cannot Add values {self} and {other}"),
         }
+    }
+}
 
-        if !self.__equivalent(&other) {
-            panic!("Cannot Add values {self} and {other}");
+impl<T: Numeric> AddAssign<Value<T>> for Value<T> {
+    fn add_assign(&mut self, other: Value<T>) {
+        match self.try_add(&other) {
+            Ok(n) => *self = n,
+            Err(_) => panic!("Cannot AddAssign values {self} and {other}"),
         }
+    }
+}
 
-        let mut cmp_val: f64 = other.val;
-
-        for i in 0..31_usize {
-            let region: usize = 1 << i;
-            if region & self.unit_map != 0 {
-                match region {
-                    LENGTH_MAP => {
-                        if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(self.exp[LENGTH_INDEX]);
-                        }
-                    }
-                    TIME_MAP => {
-                        if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(self.exp[TIME_INDEX]);
-                        }
-                    }
-                    MASS_MAP => {
-                        if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(self.exp[MASS_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CURRENT_MAP => {
-                        if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(self.exp[ELECTRIC_CURRENT_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CHARGE_MAP => {
-                        if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(self.exp[ELECTRIC_CHARGE_INDEX]);
-                        }
-                    }
-                    ELECTRIC_POTENTIAL_MAP => {
-                        if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(self.exp[ELECTRIC_POTENTIAL_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CONDUCTANCE_MAP => {
-                        if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
-                        }
-                    }
-                    CAPACITANCE_MAP => {
-                        if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(self.exp[CAPACITANCE_INDEX]);
-                        }
-                    }
-                    RESISTANCE_MAP => {
-                        if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(self.exp[RESISTANCE_INDEX]);
-                        }
-                    }
-                    INDUCTANCE_MAP => {
-                        if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(self.exp[INDUCTANCE_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_MAP => {
-                        if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_DENSITY_MAP => {
-                        if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
-                        }
-                    }
-                    TEMPERATURE_MAP => {
-                        if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(self.exp[TEMPERATURE_INDEX]);
-                        }
-                    }
-                    SUBSTANCE_MAP => {
-                        if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(self.exp[SUBSTANCE_INDEX]);
-                        }
-                    }
-                    LUMINOUS_INTENSITY_MAP => {
-                        if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(self.exp[LUMINOUS_INTENSITY_INDEX]);
-                        }
-                    }
-                    LUMINOUS_FLUX_MAP => {
-                        if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(self.exp[LUMINOUS_FLUX_INDEX]);
-                        }
-                    }
-                    ILLUMINANCE_MAP => {
-                        if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(self.exp[ILLUMINANCE_INDEX]);
-                        }
-                    }
-                    VOLUME_MAP => {
-                        if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(self.exp[VOLUME_INDEX]);
-                        }
-                    }
-                    PRESSURE_MAP => {
-                        if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(self.exp[PRESSURE_INDEX]);
-                        }
-                    }
-                    ANGLE_MAP => {
-                        if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(self.exp[ANGLE_INDEX]);
-                        }
-                    }
-                    FREQUENCY_MAP => {
-                        if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(self.exp[FREQUENCY_INDEX]);
-                        }
-                    }
-                    FORCE_MAP => {
-                        if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(self.exp[FORCE_INDEX]);
-                        }
-                    }
-                    ENERGY_MAP => {
-                        if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(self.exp[ENERGY_INDEX]);
-                        }
-                    }
-                    POWER_MAP => {
-                        if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(self.exp[POWER_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_MAP => {
-                        if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_INDEX]);
-                        }
-                    }
-                    ABSORBED_DOSE_MAP => {
-                        if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(self.exp[ABSORBED_DOSE_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_EXPOSURE_MAP => {
-                        if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
-                        }
-                    }
-                    CATALYTIC_ACTIVITY_MAP => {
-                        if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(self.exp[CATALYTIC_ACTIVITY_INDEX]);
-                        }
-                    }
-                    SOUND_MAP => {
-                        if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(self.exp[SOUND_INDEX]);
-                        }
-                    }
-                    INFORMATION_MAP => {
-                        if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(self.exp[INFORMATION_INDEX]);
-                        }
-                    }
-                    SOLID_ANGLE_MAP => {
-                        if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(self.exp[SOLID_ANGLE_INDEX]);
-                        }
-                    }
-                    _ => {
-                        // error
-                        panic!("Cannot Add values {self} and {other}");
-                    }
-                }
-            }
+impl<T: Numeric> Sub<Value<T>> for Value<T> {
+    type Output = Value<T>;
+    fn sub(self, other: Value<T>) -> Value<T> {
+        match self.try_sub(&other) {
+            Ok(n) => n,
+            Err(_) => panic!("Cannot Sub values {self} and {other}"),
         }
-
-        let mut n: Value = self;
-        n.val += cmp_val;
-        n
     }
 }
 
-impl AddAssign<Value> for Value {
-    fn add_assign(&mut self, other: Value) {
-        // special case to check if temperature is already the correct unit
-        if self.unit_map & TEMPERATURE_MAP != 0
-            && self.unit_map > TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
-        {
-            // Error cannot convert as part of larger unit
-            panic!("Cannot AddAssign values {self} and {other}");
+impl<T: Numeric> SubAssign<Value<T>> for Value<T> {
+    fn sub_assign(&mut self, other: Value<T>) {
+        match self.try_sub(&other) {
+            Ok(n) => *self = n,
+            Err(_) => panic!("Cannot SubAssign values {self} and {other}"),
         }
+    }
+}
 
-        if !self.__equivalent(&other) {
-            panic!("Cannot Add values {self} and {other}");
+impl<T: Numeric> Mul<Value<T>> for Value<T> {
+    type Output = Value<T>;
+    fn mul(self, other: Value<T>) -> Value<T> {
+        match self.try_mul(&other) {
+            Ok(n) => n,
+            Err(_) => panic!("Cannot Mul values {self} and {other}"),
         }
+    }
+}
 
-        let mut cmp_val: f64 = other.val;
+impl<T: Numeric> MulAssign<Value<T>> for Value<T> {
+    fn mul_assign(&mut self, other: Value<T>) {
+        match self.try_mul(&other) {
+            Ok(n) => *self = n,
+            Err(_) => panic!("Cannot MulAssign values {self} and {other}"),
+        }
+    }
+}
 
-        for i in 0..31_usize {
-            let region: usize = 1 << i;
-            if region & self.unit_map != 0 {
-                match region {
-                    LENGTH_MAP => {
-                        if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(self.exp[LENGTH_INDEX]);
-                        }
-                    }
-                    TIME_MAP => {
-                        if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(self.exp[TIME_INDEX]);
-                        }
-                    }
-                    MASS_MAP => {
-                        if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(self.exp[MASS_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CURRENT_MAP => {
-                        if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(self.exp[ELECTRIC_CURRENT_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CHARGE_MAP => {
-                        if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(self.exp[ELECTRIC_CHARGE_INDEX]);
-                        }
-                    }
-                    ELECTRIC_POTENTIAL_MAP => {
-                        if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(self.exp[ELECTRIC_POTENTIAL_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CONDUCTANCE_MAP => {
-                        if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
-                        }
-                    }
-                    CAPACITANCE_MAP => {
-                        if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(self.exp[CAPACITANCE_INDEX]);
-                        }
-                    }
-                    RESISTANCE_MAP => {
-                        if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(self.exp[RESISTANCE_INDEX]);
-                        }
-                    }
-                    INDUCTANCE_MAP => {
-                        if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(self.exp[INDUCTANCE_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_MAP => {
-                        if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_DENSITY_MAP => {
-                        if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
-                        }
-                    }
-                    TEMPERATURE_MAP => {
-                        if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(self.exp[TEMPERATURE_INDEX]);
-                        }
-                    }
-                    SUBSTANCE_MAP => {
-                        if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(self.exp[SUBSTANCE_INDEX]);
-                        }
-                    }
-                    LUMINOUS_INTENSITY_MAP => {
-                        if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(self.exp[LUMINOUS_INTENSITY_INDEX]);
-                        }
-                    }
-                    LUMINOUS_FLUX_MAP => {
-                        if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(self.exp[LUMINOUS_FLUX_INDEX]);
-                        }
-                    }
-                    ILLUMINANCE_MAP => {
-                        if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(self.exp[ILLUMINANCE_INDEX]);
-                        }
-                    }
-                    VOLUME_MAP => {
-                        if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(self.exp[VOLUME_INDEX]);
-                        }
-                    }
-                    PRESSURE_MAP => {
-                        if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(self.exp[PRESSURE_INDEX]);
-                        }
-                    }
-                    ANGLE_MAP => {
-                        if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(self.exp[ANGLE_INDEX]);
-                        }
-                    }
-                    FREQUENCY_MAP => {
-                        if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(self.exp[FREQUENCY_INDEX]);
-                        }
-                    }
-                    FORCE_MAP => {
-                        if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(self.exp[FORCE_INDEX]);
-                        }
-                    }
-                    ENERGY_MAP => {
-                        if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(self.exp[ENERGY_INDEX]);
-                        }
-                    }
-                    POWER_MAP => {
-                        if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(self.exp[POWER_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_MAP => {
-                        if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_INDEX]);
-                        }
-                    }
-                    ABSORBED_DOSE_MAP => {
-                        if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(self.exp[ABSORBED_DOSE_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_EXPOSURE_MAP => {
-                        if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
-                        }
-                    }
-                    CATALYTIC_ACTIVITY_MAP => {
-                        if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(self.exp[CATALYTIC_ACTIVITY_INDEX]);
-                        }
-                    }
-                    SOUND_MAP => {
-                        if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(self.exp[SOUND_INDEX]);
-                        }
-                    }
-                    INFORMATION_MAP => {
-                        if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(self.exp[INFORMATION_INDEX]);
-                        }
-                    }
-                    SOLID_ANGLE_MAP => {
-                        if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(self.exp[SOLID_ANGLE_INDEX]);
-                        }
-                    }
-                    _ => {
-                        // error
-                        panic!("Cannot AddAssign values {self} and {other}");
-                    }
-                }
-            }
-        }
-
-        self.val += cmp_val;
-    }
-}
-
-impl Sub<Value> for Value {
-    type Output = Value;
-    fn sub(self, other: Value) -> Value {
-        // special case to check if temperature is already the correct unit
-        if self.unit_map & TEMPERATURE_MAP != 0
-            && self.unit_map > TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
-        {
-            // Error cannot convert as part of larger unit
-            panic!("Cannot Sub values {self} and {other}");
-        }
-
-        if !self.__equivalent(&other) {
-            panic!("Cannot Sub values {self} and {other}");
-        }
-
-        let mut cmp_val: f64 = other.val;
-
-        for i in 0..31_usize {
-            let region: usize = 1 << i;
-            if region & self.unit_map != 0 {
-                match region {
-                    LENGTH_MAP => {
-                        if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(self.exp[LENGTH_INDEX]);
-                        }
-                    }
-                    TIME_MAP => {
-                        if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(self.exp[TIME_INDEX]);
-                        }
-                    }
-                    MASS_MAP => {
-                        if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(self.exp[MASS_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CURRENT_MAP => {
-                        if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(self.exp[ELECTRIC_CURRENT_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CHARGE_MAP => {
-                        if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(self.exp[ELECTRIC_CHARGE_INDEX]);
-                        }
-                    }
-                    ELECTRIC_POTENTIAL_MAP => {
-                        if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(self.exp[ELECTRIC_POTENTIAL_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CONDUCTANCE_MAP => {
-                        if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
-                        }
-                    }
-                    CAPACITANCE_MAP => {
-                        if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(self.exp[CAPACITANCE_INDEX]);
-                        }
-                    }
-                    RESISTANCE_MAP => {
-                        if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(self.exp[RESISTANCE_INDEX]);
-                        }
-                    }
-                    INDUCTANCE_MAP => {
-                        if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(self.exp[INDUCTANCE_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_MAP => {
-                        if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_DENSITY_MAP => {
-                        if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
-                        }
-                    }
-                    TEMPERATURE_MAP => {
-                        if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(self.exp[TEMPERATURE_INDEX]);
-                        }
-                    }
-                    SUBSTANCE_MAP => {
-                        if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(self.exp[SUBSTANCE_INDEX]);
-                        }
-                    }
-                    LUMINOUS_INTENSITY_MAP => {
-                        if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(self.exp[LUMINOUS_INTENSITY_INDEX]);
-                        }
-                    }
-                    LUMINOUS_FLUX_MAP => {
-                        if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(self.exp[LUMINOUS_FLUX_INDEX]);
-                        }
-                    }
-                    ILLUMINANCE_MAP => {
-                        if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(self.exp[ILLUMINANCE_INDEX]);
-                        }
-                    }
-                    VOLUME_MAP => {
-                        if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(self.exp[VOLUME_INDEX]);
-                        }
-                    }
-                    PRESSURE_MAP => {
-                        if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(self.exp[PRESSURE_INDEX]);
-                        }
-                    }
-                    ANGLE_MAP => {
-                        if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(self.exp[ANGLE_INDEX]);
-                        }
-                    }
-                    FREQUENCY_MAP => {
-                        if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(self.exp[FREQUENCY_INDEX]);
-                        }
-                    }
-                    FORCE_MAP => {
-                        if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(self.exp[FORCE_INDEX]);
-                        }
-                    }
-                    ENERGY_MAP => {
-                        if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(self.exp[ENERGY_INDEX]);
-                        }
-                    }
-                    POWER_MAP => {
-                        if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(self.exp[POWER_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_MAP => {
-                        if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_INDEX]);
-                        }
-                    }
-                    ABSORBED_DOSE_MAP => {
-                        if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(self.exp[ABSORBED_DOSE_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_EXPOSURE_MAP => {
-                        if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
-                        }
-                    }
-                    CATALYTIC_ACTIVITY_MAP => {
-                        if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(self.exp[CATALYTIC_ACTIVITY_INDEX]);
-                        }
-                    }
-                    SOUND_MAP => {
-                        if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(self.exp[SOUND_INDEX]);
-                        }
-                    }
-                    INFORMATION_MAP => {
-                        if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(self.exp[INFORMATION_INDEX]);
-                        }
-                    }
-                    SOLID_ANGLE_MAP => {
-                        if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(self.exp[SOLID_ANGLE_INDEX]);
-                        }
-                    }
-                    _ => {
-                        // error
-                        panic!("Cannot Sub values {self} and {other}");
-                    }
-                }
-            }
-        }
-
-        let mut n: Value = self;
-        n.val -= cmp_val;
-        n
-    }
-}
-
-impl SubAssign<Value> for Value {
-    fn sub_assign(&mut self, other: Value) {
-        // special case to check if temperature is already the correct unit
-        if self.unit_map & TEMPERATURE_MAP != 0
-            && self.unit_map > TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
-        {
-            // Error cannot convert as part of larger unit
-            panic!("Cannot SubAssign values {self} and {other}");
-        }
-
-        if !self.__equivalent(&other) {
-            panic!("Cannot SubAssign values {self} and {other}");
-        }
-
-        let mut cmp_val: f64 = other.val;
-
-        for i in 0..31_usize {
-            let region: usize = 1 << i;
-            if region & self.unit_map != 0 {
-                match region {
-                    LENGTH_MAP => {
-                        if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(self.exp[LENGTH_INDEX]);
-                        }
-                    }
-                    TIME_MAP => {
-                        if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(self.exp[TIME_INDEX]);
-                        }
-                    }
-                    MASS_MAP => {
-                        if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(self.exp[MASS_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CURRENT_MAP => {
-                        if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(self.exp[ELECTRIC_CURRENT_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CHARGE_MAP => {
-                        if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(self.exp[ELECTRIC_CHARGE_INDEX]);
-                        }
-                    }
-                    ELECTRIC_POTENTIAL_MAP => {
-                        if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(self.exp[ELECTRIC_POTENTIAL_INDEX]);
-                        }
-                    }
-                    ELECTRIC_CONDUCTANCE_MAP => {
-                        if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
-                        }
-                    }
-                    CAPACITANCE_MAP => {
-                        if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(self.exp[CAPACITANCE_INDEX]);
-                        }
-                    }
-                    RESISTANCE_MAP => {
-                        if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(self.exp[RESISTANCE_INDEX]);
-                        }
-                    }
-                    INDUCTANCE_MAP => {
-                        if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(self.exp[INDUCTANCE_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_MAP => {
-                        if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_INDEX]);
-                        }
-                    }
-                    MAGNETIC_FLUX_DENSITY_MAP => {
-                        if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
-                        }
-                    }
-                    TEMPERATURE_MAP => {
-                        if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(self.exp[TEMPERATURE_INDEX]);
-                        }
-                    }
-                    SUBSTANCE_MAP => {
-                        if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(self.exp[SUBSTANCE_INDEX]);
-                        }
-                    }
-                    LUMINOUS_INTENSITY_MAP => {
-                        if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(self.exp[LUMINOUS_INTENSITY_INDEX]);
-                        }
-                    }
-                    LUMINOUS_FLUX_MAP => {
-                        if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(self.exp[LUMINOUS_FLUX_INDEX]);
-                        }
-                    }
-                    ILLUMINANCE_MAP => {
-                        if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(self.exp[ILLUMINANCE_INDEX]);
-                        }
-                    }
-                    VOLUME_MAP => {
-                        if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(self.exp[VOLUME_INDEX]);
-                        }
-                    }
-                    PRESSURE_MAP => {
-                        if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(self.exp[PRESSURE_INDEX]);
-                        }
-                    }
-                    ANGLE_MAP => {
-                        if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(self.exp[ANGLE_INDEX]);
-                        }
-                    }
-                    FREQUENCY_MAP => {
-                        if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(self.exp[FREQUENCY_INDEX]);
-                        }
-                    }
-                    FORCE_MAP => {
-                        if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(self.exp[FORCE_INDEX]);
-                        }
-                    }
-                    ENERGY_MAP => {
-                        if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(self.exp[ENERGY_INDEX]);
-                        }
-                    }
-                    POWER_MAP => {
-                        if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(self.exp[POWER_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_MAP => {
-                        if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_INDEX]);
-                        }
-                    }
-                    ABSORBED_DOSE_MAP => {
-                        if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(self.exp[ABSORBED_DOSE_INDEX]);
-                        }
-                    }
-                    RADIOACTIVITY_EXPOSURE_MAP => {
-                        if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
-                        }
-                    }
-                    CATALYTIC_ACTIVITY_MAP => {
-                        if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(self.exp[CATALYTIC_ACTIVITY_INDEX]);
-                        }
-                    }
-                    SOUND_MAP => {
-                        if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(self.exp[SOUND_INDEX]);
-                        }
-                    }
-                    INFORMATION_MAP => {
-                        if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(self.exp[INFORMATION_INDEX]);
-                        }
-                    }
-                    SOLID_ANGLE_MAP => {
-                        if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(self.exp[SOLID_ANGLE_INDEX]);
-                        }
-                    }
-                    _ => {
-                        // error
-                        panic!("Cannot SubAssign values {self} and {other}");
-                    }
-                }
-            }
+impl<T: Numeric> Div<Value<T>> for Value<T> {
+    type Output = Value<T>;
+    fn div(self, other: Value<T>) -> Value<T> {
+        match self.try_div(&other) {
+            Ok(n) => n,
+            Err(_) => panic!("Cannot Div values {self} and {other}"),
         }
-
-        self.val -= cmp_val;
     }
-}
-
-impl Mul<Value> for Value {
-    type Output = Value;
-    fn mul(self, other: Value) -> Value {
-        let mut n: Value = self;
-        n.unit_map = 0;
-
-        // special case to check if temperature is already the correct unit
-        if self.unit_map & TEMPERATURE_MAP != 0
-            && self.unit_map > TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
-        {
-            if other.unit_map == TEMPERATURE_MAP {
-                return self * (other >> self.v_temperature.unwrap()).unwrap();
-            }
-
-            // Error cannot convert as part of larger unit
-            panic!("Cannot Mul values {self} and {other}");
-        } else if self.unit_map == TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
-            && other.v_temperature.is_some()
-        {
-            return (self >> other.v_temperature.unwrap()).unwrap() * other;
-        }
-
-        if other.is_radians() && !self.is_angle() {
-            n.val *= other.val;
-            n.unit_map = self.unit_map;
-            return n;
-        } else if self.is_radians() && !other.is_angle() {
-            n.val *= other.val;
-            n.unit_map = other.unit_map;
-            return n;
-        }
-
-        let mut cmp_val: f64 = other.val;
-        for i in 0..31_usize {
-            n.exp[i] = self.exp[i] + other.exp[i];
-            let region: usize = 1 << i;
-            let in_other: bool = region & other.unit_map != 0;
-            let in_self: bool = self.unit_map & region != 0;
-            let must_assign: bool = !in_self && in_other;
-
-            if n.exp[i] != 0 {
-                n.unit_map |= region;
-            }
-
-            if in_self && !in_other {
-                continue;
-            } else if in_other {
-                match region {
-                    LENGTH_MAP => {
-                        if must_assign {
-                            n.v_length = other.v_length;
-                        } else if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    TIME_MAP => {
-                        if must_assign {
-                            n.v_time = other.v_time;
-                        } else if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    MASS_MAP => {
-                        if must_assign {
-                            n.v_mass = other.v_mass;
-                        } else if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ELECTRIC_CURRENT_MAP => {
-                        if must_assign {
-                            n.v_electric_current = other.v_electric_current;
-                        } else if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ELECTRIC_CHARGE_MAP => {
-                        if must_assign {
-                            n.v_electric_charge = other.v_electric_charge;
-                        } else if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ELECTRIC_POTENTIAL_MAP => {
-                        if must_assign {
-                            n.v_electric_potential = other.v_electric_potential;
-                        } else if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ELECTRIC_CONDUCTANCE_MAP => {
-                        if must_assign {
-                            n.v_electric_conductance = other.v_electric_conductance;
-                        } else if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    CAPACITANCE_MAP => {
-                        if must_assign {
-                            n.v_capacitance = other.v_capacitance;
-                        } else if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    RESISTANCE_MAP => {
-                        if must_assign {
-                            n.v_resistance = other.v_resistance;
-                        } else if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    INDUCTANCE_MAP => {
-                        if must_assign {
-                            n.v_inductance = other.v_inductance;
-                        } else if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    MAGNETIC_FLUX_MAP => {
-                        if must_assign {
-                            n.v_magnetic_flux = other.v_magnetic_flux;
-                        } else if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    MAGNETIC_FLUX_DENSITY_MAP => {
-                        if must_assign {
-                            n.v_magnetic_flux_density = other.v_magnetic_flux_density;
-                        } else if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    TEMPERATURE_MAP => {
-                        if must_assign {
-                            n.v_temperature = other.v_temperature;
-                        } else if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    SUBSTANCE_MAP => {
-                        if must_assign {
-                            n.v_substance = other.v_substance;
-                        } else if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    LUMINOUS_INTENSITY_MAP => {
-                        if must_assign {
-                            n.v_luminous_flux_intensity = other.v_luminous_flux_intensity;
-                        } else if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity
-                        {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    LUMINOUS_FLUX_MAP => {
-                        if must_assign {
-                            n.v_luminous_flux = other.v_luminous_flux;
-                        } else if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ILLUMINANCE_MAP => {
-                        if must_assign {
-                            n.v_illuminance = other.v_illuminance;
-                        } else if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    VOLUME_MAP => {
-                        if must_assign {
-                            n.v_volume = other.v_volume;
-                        } else if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    PRESSURE_MAP => {
-                        if must_assign {
-                            n.v_pressure = other.v_pressure;
-                        } else if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ANGLE_MAP => {
-                        if must_assign {
-                            n.v_angle = other.v_angle;
-                        } else if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    FREQUENCY_MAP => {
-                        if must_assign {
-                            n.v_frequency = other.v_frequency;
-                        } else if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    FORCE_MAP => {
-                        if must_assign {
-                            n.v_force = other.v_force;
-                        } else if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ENERGY_MAP => {
-                        if must_assign {
-                            n.v_energy = other.v_energy;
-                        } else if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    POWER_MAP => {
-                        if must_assign {
-                            n.v_power = other.v_power;
-                        } else if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    RADIOACTIVITY_MAP => {
-                        if must_assign {
-                            n.v_radioactivity = other.v_radioactivity;
-                        } else if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    ABSORBED_DOSE_MAP => {
-                        if must_assign {
-                            n.v_ab_dose = other.v_ab_dose;
-                        } else if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    RADIOACTIVITY_EXPOSURE_MAP => {
-                        if must_assign {
-                            n.v_radioactivity_exposure = other.v_radioactivity_exposure;
-                        } else if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    CATALYTIC_ACTIVITY_MAP => {
-                        if must_assign {
-                            n.v_catalytic = other.v_catalytic;
-                        } else if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    SOUND_MAP => {
-                        if must_assign {
-                            n.v_sound = other.v_sound;
-                        } else if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    INFORMATION_MAP => {
-                        if must_assign {
-                            n.v_information = other.v_information;
-                        } else if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    SOLID_ANGLE_MAP => {
-                        if must_assign {
-                            n.v_solid_angle = other.v_solid_angle;
-                        } else if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(other.exp[i]);
-                        }
-                    }
-                    _ => {
-                        // error
-                        panic!("Cannot Mul values {self} and {other}");
-                    }
+}
+
+impl<T: Numeric> DivAssign<Value<T>> for Value<T> {
+    fn div_assign(&mut self, other: Value<T>) {
+        match self.try_div(&other) {
+            Ok(n) => *self = n,
+            Err(_) => panic!("Cannot DivAssign values {self} and {other}"),
+        }
+    }
+}
+
+impl Rem<Value> for Value {
+    type Output = Value;
+    fn rem(self, other: Value) -> Value {
+        match self.try_rem(&other) {
+            Ok(n) => n,
+            Err(_) => panic!("Cannot Rem values {self} and {other}"),
+        }
+    }
+}
+
+impl RemAssign<Value> for Value {
+    fn rem_assign(&mut self, other: Value) {
+        match self.try_rem(&other) {
+            Ok(n) => *self = n,
+            Err(_) => panic!("Cannot RemAssign values {self} and {other}"),
+        }
+    }
+}
+
+impl<T: Numeric> Value<T> {
+    /// Adds two [`Value`]s without panicking
+    ///
+    /// `other` is converted into the units of `self` before being added.
+    /// Adding a temperature interval to a temperature results in a temperature.
+    /// Adding two levels of the same kind results in the level of their summed powers,
+    /// and adding an unreferenced level (a gain) to a referenced level shifts it.
+    /// This is the non-panicking counterpart of `+` and `+=`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength, UnitTime};
+    /// let a:Value = 1.0 * UnitLength::Foot;
+    /// let b:Value = 12.0 * UnitLength::Inch;
+    /// assert_eq!(a.try_add(&b).unwrap(), 2.0 * UnitLength::Foot);
+    ///
+    /// let c:Value = 1.0 * UnitTime::Second(Metric::None);
+    /// assert!(a.try_add(&c).is_err());
+    ///
+    /// let d:Value = Value::new(60.0, "dB").unwrap();
+    /// let e:Value = d.try_add(&d).unwrap();
+    /// assert!((e.val - 63.0103).abs() < 1e-4);
+    /// ```
+    pub fn try_add(&self, other: &Value<T>) -> Result<Value<T>, V3Error> {
+        if self.is_sound() && other.is_sound() {
+            return self._through_f64_value(|s| s._combine_levels(&other.to_f64(), false));
+        }
+        if self.is_temperature_interval()
+            && other.is_temperature()
+            && !other.is_temperature_interval()
+        {
+            let mut n: Value<T> = other.try_add(self)?;
+            n._convert_temperature(self.v_temperature.unwrap().absolute())?;
+            return Ok(n);
+        }
+        let mut n: Value<T> = *self;
+        n.val = n.val + self._rhs_in_self_units(other)?;
+        Ok(n)
+    }
+
+    /// Subtracts two [`Value`]s without panicking
+    ///
+    /// `other` is converted into the units of `self` before being subtracted.
+    /// Subtracting two temperatures results in a temperature interval, a temperature
+    /// cannot be subtracted from a temperature interval.
+    /// Subtracting two referenced levels of the same kind results in their ratio in `dB`,
+    /// while two unreferenced levels are subtracted as a difference of their powers.
+    /// This is the non-panicking counterpart of `-` and `-=`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength, UnitMass};
+    /// let a:Value = 1.0 * UnitLength::Meter(Metric::None);
+    /// let b:Value = 50.0 * UnitLength::Meter(Metric::Centi);
+    /// assert_eq!(a.try_sub(&b).unwrap(), 0.5 * UnitLength::Meter(Metric::None));
+    ///
+    /// let c:Value = 1.0 * UnitMass::Pound;
+    /// assert!(a.try_sub(&c).is_err());
+    /// ```
+    pub fn try_sub(&self, other: &Value<T>) -> Result<Value<T>, V3Error> {
+        if self.is_sound() && other.is_sound() {
+            return self._through_f64_value(|s| s._combine_levels(&other.to_f64(), true));
+        }
+        let mut n: Value<T> = *self;
+        if self.is_temperature() && other.is_temperature() {
+            match (
+                self.is_temperature_interval(),
+                other.is_temperature_interval(),
+            ) {
+                (false, false) => n.v_temperature = Some(self.v_temperature.unwrap().interval()),
+                (true, false) => {
+                    return Err(V3Error::IncompatibleUnits(format!("{self} and {other}")));
                 }
+                _ => {}
             }
         }
-        n.val *= cmp_val;
-        n
+        n.val = n.val - self._rhs_in_self_units(other)?;
+        Ok(n)
     }
-}
 
-impl MulAssign<Value> for Value {
-    fn mul_assign(&mut self, other: Value) {
+    /// Multiplies two [`Value`]s without panicking
+    ///
+    /// This is the non-panicking counterpart of `*` and `*=`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength, UnitTemperature};
+    /// let a:Value = 2.0 * UnitLength::Meter(Metric::None);
+    /// let b:Value = 3.0 * UnitLength::Meter(Metric::None);
+    /// assert!(a.try_mul(&b).unwrap().is_area());
+    ///
+    /// // temperatures within compound units are multiplied as intervals
+    /// let c:Value = a * UnitTemperature::Celsius(Metric::None);
+    /// let d:Value = 1.8 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
+    /// assert_eq!(c.try_mul(&d).unwrap().to_string(), "2 m^2*°c^2");
+    /// ```
+    pub fn try_mul(&self, other: &Value<T>) -> Result<Value<T>, V3Error> {
+        if self.v_ratio.is_some() || other.v_ratio.is_some() {
+            return self._ratio_combine(other, false);
+        }
+        let mut n: Value<T> = *self;
+        n.unit_map = 0;
+
         // special case to check if temperature is already the correct unit
         if self.unit_map & TEMPERATURE_MAP != 0
             && self.unit_map > TEMPERATURE_MAP
+            && other.unit_map == TEMPERATURE_MAP
             && self.v_temperature != other.v_temperature
         {
-            if other.unit_map == TEMPERATURE_MAP {
-                *self *= (other >> self.v_temperature.unwrap()).unwrap();
-                return;
+            let t: UnitTemperature = self
+                .v_temperature
+                .unwrap()
+                ._as_kind_of(&other.v_temperature.unwrap());
+            if t != other.v_temperature.unwrap() {
+                let mut o: Value<T> = *other;
+                o._convert_temperature(t)?;
+                return self.try_mul(&o);
             }
-
-            // Error cannot convert as part of larger unit
-            panic!("Cannot Mul values {self} and {other}");
         } else if self.unit_map == TEMPERATURE_MAP
             && self.v_temperature != other.v_temperature
             && other.v_temperature.is_some()
         {
-            *self >>= other.v_temperature.unwrap();
-            *self *= other;
-            return;
+            let t: UnitTemperature = other
+                .v_temperature
+                .unwrap()
+                ._as_kind_of(&self.v_temperature.unwrap());
+            if t != self.v_temperature.unwrap() {
+                let mut n: Value<T> = *self;
+                n._convert_temperature(t)?;
+                return n.try_mul(other);
+            }
         }
 
         if other.is_radians() && !self.is_angle() {
-            self.val *= other.val;
-            return;
+            n.val = n.val * other.val;
+            n.unit_map = self.unit_map;
+            return Ok(n);
         } else if self.is_radians() && !other.is_angle() {
-            let t: f64 = self.val;
-            *self = other;
-            self.val *= t;
-            return;
+            return Value::_unitless(0.0)._with(self.val).try_mul(other);
         }
 
-        let mut cmp_val: f64 = other.val;
+        let mut cmp_val: T = other.val;
         for i in 0..31_usize {
-            self.exp[i] += other.exp[i];
+            n.exp[i] = self.exp[i] + other.exp[i];
             let region: usize = 1 << i;
-
             let in_other: bool = region & other.unit_map != 0;
             let in_self: bool = self.unit_map & region != 0;
             let must_assign: bool = !in_self && in_other;
 
-            if self.exp[i] != 0 {
-                self.unit_map |= region;
+            if n.exp[i] != 0 {
+                n.unit_map |= region;
             }
 
             if in_self && !in_other {
@@ -1731,393 +281,509 @@ impl MulAssign<Value> for Value {
                 match region {
                     LENGTH_MAP => {
                         if must_assign {
-                            self.v_length = other.v_length;
+                            n.v_length = other.v_length;
                         } else if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_length
+                                    .unwrap()
+                                    .convert(&self.v_length.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     TIME_MAP => {
                         if must_assign {
-                            self.v_time = other.v_time;
+                            n.v_time = other.v_time;
                         } else if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_time
+                                    .unwrap()
+                                    .convert(&self.v_time.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     MASS_MAP => {
                         if must_assign {
-                            self.v_mass = other.v_mass;
+                            n.v_mass = other.v_mass;
                         } else if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_mass
+                                    .unwrap()
+                                    .convert(&self.v_mass.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_CURRENT_MAP => {
                         if must_assign {
-                            self.v_electric_current = other.v_electric_current;
+                            n.v_electric_current = other.v_electric_current;
                         } else if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_current
+                                    .unwrap()
+                                    .convert(&self.v_electric_current.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_CHARGE_MAP => {
                         if must_assign {
-                            self.v_electric_charge = other.v_electric_charge;
+                            n.v_electric_charge = other.v_electric_charge;
                         } else if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_charge
+                                    .unwrap()
+                                    .convert(&self.v_electric_charge.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_POTENTIAL_MAP => {
                         if must_assign {
-                            self.v_electric_potential = other.v_electric_potential;
+                            n.v_electric_potential = other.v_electric_potential;
                         } else if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_potential
+                                    .unwrap()
+                                    .convert(&self.v_electric_potential.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_CONDUCTANCE_MAP => {
                         if must_assign {
-                            self.v_electric_conductance = other.v_electric_conductance;
+                            n.v_electric_conductance = other.v_electric_conductance;
                         } else if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_conductance
+                                    .unwrap()
+                                    .convert(&self.v_electric_conductance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     CAPACITANCE_MAP => {
                         if must_assign {
-                            self.v_capacitance = other.v_capacitance;
+                            n.v_capacitance = other.v_capacitance;
                         } else if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_capacitance
+                                    .unwrap()
+                                    .convert(&self.v_capacitance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     RESISTANCE_MAP => {
                         if must_assign {
-                            self.v_resistance = other.v_resistance;
+                            n.v_resistance = other.v_resistance;
                         } else if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_resistance
+                                    .unwrap()
+                                    .convert(&self.v_resistance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     INDUCTANCE_MAP => {
                         if must_assign {
-                            self.v_inductance = other.v_inductance;
+                            n.v_inductance = other.v_inductance;
                         } else if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_inductance
+                                    .unwrap()
+                                    .convert(&self.v_inductance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     MAGNETIC_FLUX_MAP => {
                         if must_assign {
-                            self.v_magnetic_flux = other.v_magnetic_flux;
+                            n.v_magnetic_flux = other.v_magnetic_flux;
                         } else if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_magnetic_flux
+                                    .unwrap()
+                                    .convert(&self.v_magnetic_flux.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     MAGNETIC_FLUX_DENSITY_MAP => {
                         if must_assign {
-                            self.v_magnetic_flux_density = other.v_magnetic_flux_density;
+                            n.v_magnetic_flux_density = other.v_magnetic_flux_density;
                         } else if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_magnetic_flux_density
+                                    .unwrap()
+                                    .convert(&self.v_magnetic_flux_density.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     TEMPERATURE_MAP => {
                         if must_assign {
-                            self.v_temperature = other.v_temperature;
+                            n.v_temperature = other.v_temperature;
                         } else if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_temperature
+                                    .unwrap()
+                                    .interval_factor(&self.v_temperature.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     SUBSTANCE_MAP => {
                         if must_assign {
-                            self.v_substance = other.v_substance;
+                            n.v_substance = other.v_substance;
                         } else if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_substance
+                                    .unwrap()
+                                    .convert(&self.v_substance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     LUMINOUS_INTENSITY_MAP => {
                         if must_assign {
-                            self.v_luminous_flux_intensity = other.v_luminous_flux_intensity;
+                            n.v_luminous_flux_intensity = other.v_luminous_flux_intensity;
                         } else if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity
                         {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_luminous_flux_intensity
+                                    .unwrap()
+                                    .convert(&self.v_luminous_flux_intensity.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     LUMINOUS_FLUX_MAP => {
                         if must_assign {
-                            self.v_luminous_flux = other.v_luminous_flux;
+                            n.v_luminous_flux = other.v_luminous_flux;
                         } else if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_luminous_flux
+                                    .unwrap()
+                                    .convert(&self.v_luminous_flux.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ILLUMINANCE_MAP => {
                         if must_assign {
-                            self.v_illuminance = other.v_illuminance;
+                            n.v_illuminance = other.v_illuminance;
                         } else if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_illuminance
+                                    .unwrap()
+                                    .convert(&self.v_illuminance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     VOLUME_MAP => {
                         if must_assign {
-                            self.v_volume = other.v_volume;
+                            n.v_volume = other.v_volume;
                         } else if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_volume
+                                    .unwrap()
+                                    .convert(&self.v_volume.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     PRESSURE_MAP => {
                         if must_assign {
-                            self.v_pressure = other.v_pressure;
+                            n.v_pressure = other.v_pressure;
                         } else if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_pressure
+                                    .unwrap()
+                                    .convert(&self.v_pressure.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ANGLE_MAP => {
                         if must_assign {
-                            self.v_angle = other.v_angle;
+                            n.v_angle = other.v_angle;
                         } else if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_angle
+                                    .unwrap()
+                                    .convert(&self.v_angle.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     FREQUENCY_MAP => {
                         if must_assign {
-                            self.v_frequency = other.v_frequency;
+                            n.v_frequency = other.v_frequency;
                         } else if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_frequency
+                                    .unwrap()
+                                    .convert(&self.v_frequency.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     FORCE_MAP => {
                         if must_assign {
-                            self.v_force = other.v_force;
+                            n.v_force = other.v_force;
                         } else if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_force
+                                    .unwrap()
+                                    .convert(&self.v_force.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ENERGY_MAP => {
                         if must_assign {
-                            self.v_energy = other.v_energy;
+                            n.v_energy = other.v_energy;
                         } else if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_energy
+                                    .unwrap()
+                                    .convert(&self.v_energy.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     POWER_MAP => {
                         if must_assign {
-                            self.v_power = other.v_power;
+                            n.v_power = other.v_power;
                         } else if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_power
+                                    .unwrap()
+                                    .convert(&self.v_power.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     RADIOACTIVITY_MAP => {
                         if must_assign {
-                            self.v_radioactivity = other.v_radioactivity;
+                            n.v_radioactivity = other.v_radioactivity;
                         } else if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_radioactivity
+                                    .unwrap()
+                                    .convert(&self.v_radioactivity.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ABSORBED_DOSE_MAP => {
                         if must_assign {
-                            self.v_ab_dose = other.v_ab_dose;
+                            n.v_ab_dose = other.v_ab_dose;
                         } else if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_ab_dose
+                                    .unwrap()
+                                    .convert(&self.v_ab_dose.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     RADIOACTIVITY_EXPOSURE_MAP => {
                         if must_assign {
-                            self.v_radioactivity_exposure = other.v_radioactivity_exposure;
+                            n.v_radioactivity_exposure = other.v_radioactivity_exposure;
                         } else if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_radioactivity_exposure
+                                    .unwrap()
+                                    .convert(&self.v_radioactivity_exposure.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     CATALYTIC_ACTIVITY_MAP => {
                         if must_assign {
-                            self.v_catalytic = other.v_catalytic;
+                            n.v_catalytic = other.v_catalytic;
                         } else if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_catalytic
+                                    .unwrap()
+                                    .convert(&self.v_catalytic.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     SOUND_MAP => {
                         if must_assign {
-                            self.v_sound = other.v_sound;
+                            n.v_sound = other.v_sound;
                         } else if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_sound
+                                    .unwrap()
+                                    .convert(&self.v_sound.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     INFORMATION_MAP => {
                         if must_assign {
-                            self.v_information = other.v_information;
+                            n.v_information = other.v_information;
                         } else if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_information
+                                    .unwrap()
+                                    .convert(&self.v_information.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     SOLID_ANGLE_MAP => {
                         if must_assign {
-                            self.v_solid_angle = other.v_solid_angle;
+                            n.v_solid_angle = other.v_solid_angle;
                         } else if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_solid_angle
+                                    .unwrap()
+                                    .convert(&self.v_solid_angle.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     _ => {
-                        // error
-                        panic!("Cannot MulAssign values {self} and {other}");
+                        return Err(V3Error::IncompatibleUnits(format!("{self} and {other}")));
                     }
                 }
             }
         }
-        self.val *= cmp_val;
+        cmp_val = _scale(cmp_val, n._combine_custom(other, false))?;
+        n.val = n.val * cmp_val;
+        Ok(n)
     }
-}
 
-impl Div<Value> for Value {
-    type Output = Value;
-    fn div(self, other: Value) -> Value {
-        let mut n: Value = self;
+    /// Divides two [`Value`]s without panicking
+    ///
+    /// This is the non-panicking counterpart of `/` and `/=`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength, UnitTime};
+    /// let a:Value = 10.0 * UnitLength::Meter(Metric::None);
+    /// let b:Value = 2.0 * UnitTime::Second(Metric::None);
+    /// assert!(a.try_div(&b).unwrap().is_velocity());
+    /// ```
+    pub fn try_div(&self, other: &Value<T>) -> Result<Value<T>, V3Error> {
+        if self.v_ratio.is_some() || other.v_ratio.is_some() {
+            return self._ratio_combine(other, true);
+        }
+        let mut n: Value<T> = *self;
         n.unit_map = 0;
 
         // special case to check if temperature is already the correct unit
         if self.unit_map & TEMPERATURE_MAP != 0
             && self.unit_map > TEMPERATURE_MAP
+            && other.unit_map == TEMPERATURE_MAP
             && self.v_temperature != other.v_temperature
         {
-            if other.unit_map == TEMPERATURE_MAP {
-                return self / (other >> self.v_temperature.unwrap()).unwrap();
+            let t: UnitTemperature = self
+                .v_temperature
+                .unwrap()
+                ._as_kind_of(&other.v_temperature.unwrap());
+            if t != other.v_temperature.unwrap() {
+                let mut o: Value<T> = *other;
+                o._convert_temperature(t)?;
+                return self.try_div(&o);
             }
-
-            // Error cannot convert as part of larger unit
-            panic!("Cannot Div values {self} and {other}");
         } else if self.unit_map == TEMPERATURE_MAP
             && self.v_temperature != other.v_temperature
             && self.v_temperature.is_some()
             && other.v_temperature.is_some()
         {
-            return (self >> other.v_temperature.unwrap()).unwrap() / other;
+            let t: UnitTemperature = other
+                .v_temperature
+                .unwrap()
+                ._as_kind_of(&self.v_temperature.unwrap());
+            if t != self.v_temperature.unwrap() {
+                let mut n: Value<T> = *self;
+                n._convert_temperature(t)?;
+                return n.try_div(other);
+            }
         }
 
         if other.is_radians() && !self.is_angle() {
-            n.val /= other.val;
+            n.val = n.val / other.val;
             n.unit_map = self.unit_map;
-            return n;
+            return Ok(n);
         } else if self.is_radians() && !other.is_angle() {
-            n.val /= other.val;
-            n.unit_map = other.unit_map;
-            return n;
+            return Value::_unitless(0.0)._with(self.val).try_div(other);
         }
 
-        let mut cmp_val: f64 = other.val;
+        let mut cmp_val: T = other.val;
         for i in 0..31_usize {
             n.exp[i] = self.exp[i] - other.exp[i];
             let region: usize = 1 << i;
@@ -2137,154 +803,196 @@ impl Div<Value> for Value {
                         if must_assign {
                             n.v_length = other.v_length;
                         } else if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_length
+                                    .unwrap()
+                                    .convert(&self.v_length.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     TIME_MAP => {
                         if must_assign {
                             n.v_time = other.v_time;
                         } else if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_time
+                                    .unwrap()
+                                    .convert(&self.v_time.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     MASS_MAP => {
                         if must_assign {
                             n.v_mass = other.v_mass;
                         } else if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_mass
+                                    .unwrap()
+                                    .convert(&self.v_mass.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_CURRENT_MAP => {
                         if must_assign {
                             n.v_electric_current = other.v_electric_current;
                         } else if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_current
+                                    .unwrap()
+                                    .convert(&self.v_electric_current.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_CHARGE_MAP => {
                         if must_assign {
                             n.v_electric_charge = other.v_electric_charge;
                         } else if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_charge
+                                    .unwrap()
+                                    .convert(&self.v_electric_charge.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_POTENTIAL_MAP => {
                         if must_assign {
                             n.v_electric_potential = other.v_electric_potential;
                         } else if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_potential
+                                    .unwrap()
+                                    .convert(&self.v_electric_potential.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ELECTRIC_CONDUCTANCE_MAP => {
                         if must_assign {
                             n.v_electric_conductance = other.v_electric_conductance;
                         } else if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_conductance
+                                    .unwrap()
+                                    .convert(&self.v_electric_conductance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     CAPACITANCE_MAP => {
                         if must_assign {
                             n.v_capacitance = other.v_capacitance;
                         } else if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_capacitance
+                                    .unwrap()
+                                    .convert(&self.v_capacitance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     RESISTANCE_MAP => {
                         if must_assign {
                             n.v_resistance = other.v_resistance;
                         } else if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_resistance
+                                    .unwrap()
+                                    .convert(&self.v_resistance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     INDUCTANCE_MAP => {
                         if must_assign {
                             n.v_inductance = other.v_inductance;
                         } else if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_inductance
+                                    .unwrap()
+                                    .convert(&self.v_inductance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     MAGNETIC_FLUX_MAP => {
                         if must_assign {
                             n.v_magnetic_flux = other.v_magnetic_flux;
                         } else if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_magnetic_flux
+                                    .unwrap()
+                                    .convert(&self.v_magnetic_flux.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     MAGNETIC_FLUX_DENSITY_MAP => {
                         if must_assign {
                             n.v_magnetic_flux_density = other.v_magnetic_flux_density;
                         } else if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_magnetic_flux_density
+                                    .unwrap()
+                                    .convert(&self.v_magnetic_flux_density.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     TEMPERATURE_MAP => {
                         if must_assign {
                             n.v_temperature = other.v_temperature;
                         } else if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_temperature
+                                    .unwrap()
+                                    .interval_factor(&self.v_temperature.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     SUBSTANCE_MAP => {
                         if must_assign {
                             n.v_substance = other.v_substance;
                         } else if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_substance
+                                    .unwrap()
+                                    .convert(&self.v_substance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     LUMINOUS_INTENSITY_MAP => {
@@ -2292,598 +1000,793 @@ impl Div<Value> for Value {
                             n.v_luminous_flux_intensity = other.v_luminous_flux_intensity;
                         } else if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity
                         {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_luminous_flux_intensity
+                                    .unwrap()
+                                    .convert(&self.v_luminous_flux_intensity.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     LUMINOUS_FLUX_MAP => {
                         if must_assign {
                             n.v_luminous_flux = other.v_luminous_flux;
                         } else if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_luminous_flux
+                                    .unwrap()
+                                    .convert(&self.v_luminous_flux.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ILLUMINANCE_MAP => {
                         if must_assign {
                             n.v_illuminance = other.v_illuminance;
                         } else if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_illuminance
+                                    .unwrap()
+                                    .convert(&self.v_illuminance.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     VOLUME_MAP => {
                         if must_assign {
                             n.v_volume = other.v_volume;
                         } else if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_volume
+                                    .unwrap()
+                                    .convert(&self.v_volume.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     PRESSURE_MAP => {
                         if must_assign {
                             n.v_pressure = other.v_pressure;
                         } else if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_pressure
+                                    .unwrap()
+                                    .convert(&self.v_pressure.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ANGLE_MAP => {
                         if must_assign {
                             n.v_angle = other.v_angle;
                         } else if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_angle
+                                    .unwrap()
+                                    .convert(&self.v_angle.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     FREQUENCY_MAP => {
                         if must_assign {
                             n.v_frequency = other.v_frequency;
                         } else if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_frequency
+                                    .unwrap()
+                                    .convert(&self.v_frequency.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     FORCE_MAP => {
                         if must_assign {
                             n.v_force = other.v_force;
                         } else if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_force
+                                    .unwrap()
+                                    .convert(&self.v_force.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ENERGY_MAP => {
                         if must_assign {
                             n.v_energy = other.v_energy;
                         } else if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_energy
+                                    .unwrap()
+                                    .convert(&self.v_energy.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     POWER_MAP => {
                         if must_assign {
                             n.v_power = other.v_power;
                         } else if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_power
+                                    .unwrap()
+                                    .convert(&self.v_power.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     RADIOACTIVITY_MAP => {
                         if must_assign {
                             n.v_radioactivity = other.v_radioactivity;
                         } else if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_radioactivity
+                                    .unwrap()
+                                    .convert(&self.v_radioactivity.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     ABSORBED_DOSE_MAP => {
                         if must_assign {
                             n.v_ab_dose = other.v_ab_dose;
                         } else if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_ab_dose
+                                    .unwrap()
+                                    .convert(&self.v_ab_dose.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     RADIOACTIVITY_EXPOSURE_MAP => {
                         if must_assign {
                             n.v_radioactivity_exposure = other.v_radioactivity_exposure;
                         } else if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_radioactivity_exposure
+                                    .unwrap()
+                                    .convert(&self.v_radioactivity_exposure.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     CATALYTIC_ACTIVITY_MAP => {
                         if must_assign {
                             n.v_catalytic = other.v_catalytic;
                         } else if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_catalytic
+                                    .unwrap()
+                                    .convert(&self.v_catalytic.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     SOUND_MAP => {
                         if must_assign {
                             n.v_sound = other.v_sound;
                         } else if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_sound
+                                    .unwrap()
+                                    .convert(&self.v_sound.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     INFORMATION_MAP => {
                         if must_assign {
                             n.v_information = other.v_information;
                         } else if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_information
+                                    .unwrap()
+                                    .convert(&self.v_information.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     SOLID_ANGLE_MAP => {
                         if must_assign {
                             n.v_solid_angle = other.v_solid_angle;
                         } else if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(other.exp[i]);
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_solid_angle
+                                    .unwrap()
+                                    .convert(&self.v_solid_angle.unwrap())
+                                    .powr(other.exp[i]),
+                            )?;
                         }
                     }
                     _ => {
-                        // error
-                        panic!("Cannot Div values {self} and {other}");
+                        return Err(V3Error::IncompatibleUnits(format!("{self} and {other}")));
                     }
                 }
             }
         }
-        n.val /= cmp_val;
-        n
+        cmp_val = _scale(cmp_val, n._combine_custom(other, true))?;
+        n.val = n.val / cmp_val;
+        Ok(n)
     }
-}
-
-impl DivAssign<Value> for Value {
-    fn div_assign(&mut self, other: Value) {
-        // special case to check if temperature is already the correct unit
-        if self.unit_map & TEMPERATURE_MAP != 0
-            && self.unit_map > TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
-        {
-            if other.unit_map == TEMPERATURE_MAP {
-                *self /= (other >> self.v_temperature.unwrap()).unwrap();
-                return;
-            }
 
-            // Error cannot convert as part of larger unit
-            panic!("Cannot Mul values {self} and {other}");
-        } else if self.unit_map == TEMPERATURE_MAP && self.v_temperature != other.v_temperature {
-            *self >>= other.v_temperature.unwrap();
-            *self /= other;
+    /// Returns the numeric of `other` expressed in the units of `self`
+    ///
+    /// Used by the addition and subtraction operations
+    pub(in crate::value) fn _rhs_in_self_units(&self, other: &Value<T>) -> Result<T, V3Error> {
+        if !self.__equivalent(other) {
+            return Err(V3Error::IncompatibleUnits(format!("{self} and {other}")));
         }
 
-        if other.is_radians() && !self.is_angle() {
-            self.val /= other.val;
-            return;
-        } else if self.is_radians() && !other.is_angle() {
-            let t: f64 = self.val;
-            *self = other;
-            self.val *= 1.0 / t; // TODO : How to divide radian by value?
-            return;
-        }
+        let cmp_val: T = match other._exact_factor_to(self) {
+            Some(f) => {
+                self._rhs_steps(other, 1.0_f64)?;
+                _representable(f._apply_to(other.val))?
+            }
+            None => _scale(
+                self._rhs_steps(other, other.val)?,
+                other._custom_factor_to(self),
+            )?,
+        };
+        Value::_rescale_ratio(cmp_val, other._ratio_factor(), self._ratio_factor())
+    }
 
-        let mut cmp_val: f64 = other.val;
+    /// Applies the conversion of each unit of `other` into the units of `self` to a numeric
+    fn _rhs_steps<U: Numeric>(&self, other: &Value<T>, mut cmp_val: U) -> Result<U, V3Error> {
         for i in 0..31_usize {
-            self.exp[i] -= other.exp[i];
             let region: usize = 1 << i;
-            let in_other: bool = region & other.unit_map != 0;
-            let in_self: bool = self.unit_map & region != 0;
-            let must_assign: bool = !in_self && in_other;
-
-            if self.exp[i] != 0 {
-                self.unit_map |= region;
-            } else {
-                self.unit_map &= !region;
-            }
-
-            if in_self && !in_other {
-                continue;
-            } else if in_other {
+            if region & self.unit_map != 0 {
                 match region {
                     LENGTH_MAP => {
-                        if must_assign {
-                            self.v_length = other.v_length;
-                        } else if self.v_length != other.v_length {
-                            cmp_val *= other
-                                .v_length
-                                .unwrap()
-                                .convert(&self.v_length.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_length != other.v_length {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_length
+                                    .unwrap()
+                                    .convert(&self.v_length.unwrap())
+                                    .powr(self.exp[LENGTH_INDEX]),
+                            )?;
                         }
                     }
                     TIME_MAP => {
-                        if must_assign {
-                            self.v_time = other.v_time;
-                        } else if self.v_time != other.v_time {
-                            cmp_val *= other
-                                .v_time
-                                .unwrap()
-                                .convert(&self.v_time.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_time != other.v_time {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_time
+                                    .unwrap()
+                                    .convert(&self.v_time.unwrap())
+                                    .powr(self.exp[TIME_INDEX]),
+                            )?;
                         }
                     }
                     MASS_MAP => {
-                        if must_assign {
-                            self.v_mass = other.v_mass;
-                        } else if self.v_mass != other.v_mass {
-                            cmp_val *= other
-                                .v_mass
-                                .unwrap()
-                                .convert(&self.v_mass.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_mass != other.v_mass {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_mass
+                                    .unwrap()
+                                    .convert(&self.v_mass.unwrap())
+                                    .powr(self.exp[MASS_INDEX]),
+                            )?;
                         }
                     }
                     ELECTRIC_CURRENT_MAP => {
-                        if must_assign {
-                            self.v_electric_current = other.v_electric_current;
-                        } else if self.v_electric_current != other.v_electric_current {
-                            cmp_val *= other
-                                .v_electric_current
-                                .unwrap()
-                                .convert(&self.v_electric_current.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_electric_current != other.v_electric_current {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_current
+                                    .unwrap()
+                                    .convert(&self.v_electric_current.unwrap())
+                                    .powr(self.exp[ELECTRIC_CURRENT_INDEX]),
+                            )?;
                         }
                     }
                     ELECTRIC_CHARGE_MAP => {
-                        if must_assign {
-                            self.v_electric_charge = other.v_electric_charge;
-                        } else if self.v_electric_charge != other.v_electric_charge {
-                            cmp_val *= other
-                                .v_electric_charge
-                                .unwrap()
-                                .convert(&self.v_electric_charge.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_electric_charge != other.v_electric_charge {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_charge
+                                    .unwrap()
+                                    .convert(&self.v_electric_charge.unwrap())
+                                    .powr(self.exp[ELECTRIC_CHARGE_INDEX]),
+                            )?;
                         }
                     }
                     ELECTRIC_POTENTIAL_MAP => {
-                        if must_assign {
-                            self.v_electric_potential = other.v_electric_potential;
-                        } else if self.v_electric_potential != other.v_electric_potential {
-                            cmp_val *= other
-                                .v_electric_potential
-                                .unwrap()
-                                .convert(&self.v_electric_potential.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_electric_potential != other.v_electric_potential {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_potential
+                                    .unwrap()
+                                    .convert(&self.v_electric_potential.unwrap())
+                                    .powr(self.exp[ELECTRIC_POTENTIAL_INDEX]),
+                            )?;
                         }
                     }
                     ELECTRIC_CONDUCTANCE_MAP => {
-                        if must_assign {
-                            self.v_electric_conductance = other.v_electric_conductance;
-                        } else if self.v_electric_conductance != other.v_electric_conductance {
-                            cmp_val *= other
-                                .v_electric_conductance
-                                .unwrap()
-                                .convert(&self.v_electric_conductance.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_electric_conductance != other.v_electric_conductance {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_electric_conductance
+                                    .unwrap()
+                                    .convert(&self.v_electric_conductance.unwrap())
+                                    .powr(self.exp[ELECTRIC_CONDUCTANCE_INDEX]),
+                            )?;
                         }
                     }
                     CAPACITANCE_MAP => {
-                        if must_assign {
-                            self.v_capacitance = other.v_capacitance;
-                        } else if self.v_capacitance != other.v_capacitance {
-                            cmp_val *= other
-                                .v_capacitance
-                                .unwrap()
-                                .convert(&self.v_capacitance.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_capacitance != other.v_capacitance {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_capacitance
+                                    .unwrap()
+                                    .convert(&self.v_capacitance.unwrap())
+                                    .powr(self.exp[CAPACITANCE_INDEX]),
+                            )?;
                         }
                     }
                     RESISTANCE_MAP => {
-                        if must_assign {
-                            self.v_resistance = other.v_resistance;
-                        } else if self.v_resistance != other.v_resistance {
-                            cmp_val *= other
-                                .v_resistance
-                                .unwrap()
-                                .convert(&self.v_resistance.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_resistance != other.v_resistance {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_resistance
+                                    .unwrap()
+                                    .convert(&self.v_resistance.unwrap())
+                                    .powr(self.exp[RESISTANCE_INDEX]),
+                            )?;
                         }
                     }
                     INDUCTANCE_MAP => {
-                        if must_assign {
-                            self.v_inductance = other.v_inductance;
-                        } else if self.v_inductance != other.v_inductance {
-                            cmp_val *= other
-                                .v_inductance
-                                .unwrap()
-                                .convert(&self.v_inductance.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_inductance != other.v_inductance {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_inductance
+                                    .unwrap()
+                                    .convert(&self.v_inductance.unwrap())
+                                    .powr(self.exp[INDUCTANCE_INDEX]),
+                            )?;
                         }
                     }
                     MAGNETIC_FLUX_MAP => {
-                        if must_assign {
-                            self.v_magnetic_flux = other.v_magnetic_flux;
-                        } else if self.v_magnetic_flux != other.v_magnetic_flux {
-                            cmp_val *= other
-                                .v_magnetic_flux
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_magnetic_flux != other.v_magnetic_flux {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_magnetic_flux
+                                    .unwrap()
+                                    .convert(&self.v_magnetic_flux.unwrap())
+                                    .powr(self.exp[MAGNETIC_FLUX_INDEX]),
+                            )?;
                         }
                     }
                     MAGNETIC_FLUX_DENSITY_MAP => {
-                        if must_assign {
-                            self.v_magnetic_flux_density = other.v_magnetic_flux_density;
-                        } else if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
-                            cmp_val *= other
-                                .v_magnetic_flux_density
-                                .unwrap()
-                                .convert(&self.v_magnetic_flux_density.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_magnetic_flux_density != other.v_magnetic_flux_density {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_magnetic_flux_density
+                                    .unwrap()
+                                    .convert(&self.v_magnetic_flux_density.unwrap())
+                                    .powr(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]),
+                            )?;
                         }
                     }
                     TEMPERATURE_MAP => {
-                        if must_assign {
-                            self.v_temperature = other.v_temperature;
-                        } else if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val)
-                                .powi(other.exp[i]);
+                        if self.v_temperature != other.v_temperature {
+                            let t: UnitTemperature = other.v_temperature.unwrap();
+                            if self.is_temperature() {
+                                cmp_val = _through_f64(cmp_val, |v| {
+                                    t.convert(&self.v_temperature.unwrap(), v)
+                                })?;
+                            } else {
+                                cmp_val = _scale(
+                                    cmp_val,
+                                    t.interval_factor(&self.v_temperature.unwrap())
+                                        .powr(self.exp[TEMPERATURE_INDEX]),
+                                )?;
+                            }
                         }
                     }
                     SUBSTANCE_MAP => {
-                        if must_assign {
-                            self.v_substance = other.v_substance;
-                        } else if self.v_substance != other.v_substance {
-                            cmp_val *= other
-                                .v_substance
-                                .unwrap()
-                                .convert(&self.v_substance.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_substance != other.v_substance {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_substance
+                                    .unwrap()
+                                    .convert(&self.v_substance.unwrap())
+                                    .powr(self.exp[SUBSTANCE_INDEX]),
+                            )?;
                         }
                     }
                     LUMINOUS_INTENSITY_MAP => {
-                        if must_assign {
-                            self.v_luminous_flux_intensity = other.v_luminous_flux_intensity;
-                        } else if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity
-                        {
-                            cmp_val *= other
-                                .v_luminous_flux_intensity
-                                .unwrap()
-                                .convert(&self.v_luminous_flux_intensity.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_luminous_flux_intensity != other.v_luminous_flux_intensity {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_luminous_flux_intensity
+                                    .unwrap()
+                                    .convert(&self.v_luminous_flux_intensity.unwrap())
+                                    .powr(self.exp[LUMINOUS_INTENSITY_INDEX]),
+                            )?;
                         }
                     }
                     LUMINOUS_FLUX_MAP => {
-                        if must_assign {
-                            self.v_luminous_flux = other.v_luminous_flux;
-                        } else if self.v_luminous_flux != other.v_luminous_flux {
-                            cmp_val *= other
-                                .v_luminous_flux
-                                .unwrap()
-                                .convert(&self.v_luminous_flux.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_luminous_flux != other.v_luminous_flux {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_luminous_flux
+                                    .unwrap()
+                                    .convert(&self.v_luminous_flux.unwrap())
+                                    .powr(self.exp[LUMINOUS_FLUX_INDEX]),
+                            )?;
                         }
                     }
                     ILLUMINANCE_MAP => {
-                        if must_assign {
-                            self.v_illuminance = other.v_illuminance;
-                        } else if self.v_illuminance != other.v_illuminance {
-                            cmp_val *= other
-                                .v_illuminance
-                                .unwrap()
-                                .convert(&self.v_illuminance.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_illuminance != other.v_illuminance {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_illuminance
+                                    .unwrap()
+                                    .convert(&self.v_illuminance.unwrap())
+                                    .powr(self.exp[ILLUMINANCE_INDEX]),
+                            )?;
                         }
                     }
                     VOLUME_MAP => {
-                        if must_assign {
-                            self.v_volume = other.v_volume;
-                        } else if self.v_volume != other.v_volume {
-                            cmp_val *= other
-                                .v_volume
-                                .unwrap()
-                                .convert(&self.v_volume.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_volume != other.v_volume {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_volume
+                                    .unwrap()
+                                    .convert(&self.v_volume.unwrap())
+                                    .powr(self.exp[VOLUME_INDEX]),
+                            )?;
                         }
                     }
                     PRESSURE_MAP => {
-                        if must_assign {
-                            self.v_pressure = other.v_pressure;
-                        } else if self.v_pressure != other.v_pressure {
-                            cmp_val *= other
-                                .v_pressure
-                                .unwrap()
-                                .convert(&self.v_pressure.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_pressure != other.v_pressure {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_pressure
+                                    .unwrap()
+                                    .convert(&self.v_pressure.unwrap())
+                                    .powr(self.exp[PRESSURE_INDEX]),
+                            )?;
                         }
                     }
                     ANGLE_MAP => {
-                        if must_assign {
-                            self.v_angle = other.v_angle;
-                        } else if self.v_angle != other.v_angle {
-                            cmp_val *= other
-                                .v_angle
-                                .unwrap()
-                                .convert(&self.v_angle.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_angle != other.v_angle {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_angle
+                                    .unwrap()
+                                    .convert(&self.v_angle.unwrap())
+                                    .powr(self.exp[ANGLE_INDEX]),
+                            )?;
                         }
                     }
                     FREQUENCY_MAP => {
-                        if must_assign {
-                            self.v_frequency = other.v_frequency;
-                        } else if self.v_frequency != other.v_frequency {
-                            cmp_val *= other
-                                .v_frequency
-                                .unwrap()
-                                .convert(&self.v_frequency.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_frequency != other.v_frequency {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_frequency
+                                    .unwrap()
+                                    .convert(&self.v_frequency.unwrap())
+                                    .powr(self.exp[FREQUENCY_INDEX]),
+                            )?;
                         }
                     }
                     FORCE_MAP => {
-                        if must_assign {
-                            self.v_force = other.v_force;
-                        } else if self.v_force != other.v_force {
-                            cmp_val *= other
-                                .v_force
-                                .unwrap()
-                                .convert(&self.v_force.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_force != other.v_force {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_force
+                                    .unwrap()
+                                    .convert(&self.v_force.unwrap())
+                                    .powr(self.exp[FORCE_INDEX]),
+                            )?;
                         }
                     }
                     ENERGY_MAP => {
-                        if must_assign {
-                            self.v_energy = other.v_energy;
-                        } else if self.v_energy != other.v_energy {
-                            cmp_val *= other
-                                .v_energy
-                                .unwrap()
-                                .convert(&self.v_energy.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_energy != other.v_energy {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_energy
+                                    .unwrap()
+                                    .convert(&self.v_energy.unwrap())
+                                    .powr(self.exp[ENERGY_INDEX]),
+                            )?;
                         }
                     }
                     POWER_MAP => {
-                        if must_assign {
-                            self.v_power = other.v_power;
-                        } else if self.v_power != other.v_power {
-                            cmp_val *= other
-                                .v_power
-                                .unwrap()
-                                .convert(&self.v_power.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_power != other.v_power {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_power
+                                    .unwrap()
+                                    .convert(&self.v_power.unwrap())
+                                    .powr(self.exp[POWER_INDEX]),
+                            )?;
                         }
                     }
                     RADIOACTIVITY_MAP => {
-                        if must_assign {
-                            self.v_radioactivity = other.v_radioactivity;
-                        } else if self.v_radioactivity != other.v_radioactivity {
-                            cmp_val *= other
-                                .v_radioactivity
-                                .unwrap()
-                                .convert(&self.v_radioactivity.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_radioactivity != other.v_radioactivity {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_radioactivity
+                                    .unwrap()
+                                    .convert(&self.v_radioactivity.unwrap())
+                                    .powr(self.exp[RADIOACTIVITY_INDEX]),
+                            )?;
                         }
                     }
                     ABSORBED_DOSE_MAP => {
-                        if must_assign {
-                            self.v_ab_dose = other.v_ab_dose;
-                        } else if self.v_ab_dose != other.v_ab_dose {
-                            cmp_val *= other
-                                .v_ab_dose
-                                .unwrap()
-                                .convert(&self.v_ab_dose.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_ab_dose != other.v_ab_dose {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_ab_dose
+                                    .unwrap()
+                                    .convert(&self.v_ab_dose.unwrap())
+                                    .powr(self.exp[ABSORBED_DOSE_INDEX]),
+                            )?;
                         }
                     }
                     RADIOACTIVITY_EXPOSURE_MAP => {
-                        if must_assign {
-                            self.v_radioactivity_exposure = other.v_radioactivity_exposure;
-                        } else if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
-                            cmp_val *= other
-                                .v_radioactivity_exposure
-                                .unwrap()
-                                .convert(&self.v_radioactivity_exposure.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_radioactivity_exposure != other.v_radioactivity_exposure {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_radioactivity_exposure
+                                    .unwrap()
+                                    .convert(&self.v_radioactivity_exposure.unwrap())
+                                    .powr(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]),
+                            )?;
                         }
                     }
                     CATALYTIC_ACTIVITY_MAP => {
-                        if must_assign {
-                            self.v_catalytic = other.v_catalytic;
-                        } else if self.v_catalytic != other.v_catalytic {
-                            cmp_val *= other
-                                .v_catalytic
-                                .unwrap()
-                                .convert(&self.v_catalytic.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_catalytic != other.v_catalytic {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_catalytic
+                                    .unwrap()
+                                    .convert(&self.v_catalytic.unwrap())
+                                    .powr(self.exp[CATALYTIC_ACTIVITY_INDEX]),
+                            )?;
                         }
                     }
                     SOUND_MAP => {
-                        if must_assign {
-                            self.v_sound = other.v_sound;
-                        } else if self.v_sound != other.v_sound {
-                            cmp_val *= other
-                                .v_sound
-                                .unwrap()
-                                .convert(&self.v_sound.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_sound != other.v_sound {
+                            let l: UnitSound = other.v_sound.unwrap();
+                            if self.is_sound() && l.is_compatible(&self.v_sound.unwrap()) {
+                                cmp_val = _through_f64(cmp_val, |v| {
+                                    l.convert_level(&self.v_sound.unwrap(), v)
+                                })?;
+                            } else if self.is_sound()
+                                || l.is_referenced()
+                                || self.v_sound.unwrap().is_referenced()
+                            {
+                                return Err(V3Error::IncompatibleUnits(format!(
+                                    "{self} and {other}"
+                                )));
+                            } else {
+                                cmp_val = _scale(
+                                    cmp_val,
+                                    l.convert(&self.v_sound.unwrap())
+                                        .powr(self.exp[SOUND_INDEX]),
+                                )?;
+                            }
                         }
                     }
                     INFORMATION_MAP => {
-                        if must_assign {
-                            self.v_information = other.v_information;
-                        } else if self.v_information != other.v_information {
-                            cmp_val *= other
-                                .v_information
-                                .unwrap()
-                                .convert(&self.v_information.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_information != other.v_information {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_information
+                                    .unwrap()
+                                    .convert(&self.v_information.unwrap())
+                                    .powr(self.exp[INFORMATION_INDEX]),
+                            )?;
                         }
                     }
                     SOLID_ANGLE_MAP => {
-                        if must_assign {
-                            self.v_solid_angle = other.v_solid_angle;
-                        } else if self.v_solid_angle != other.v_solid_angle {
-                            cmp_val *= other
-                                .v_solid_angle
-                                .unwrap()
-                                .convert(&self.v_solid_angle.unwrap())
-                                .powi(other.exp[i]);
+                        if self.v_solid_angle != other.v_solid_angle {
+                            cmp_val = _scale(
+                                cmp_val,
+                                other
+                                    .v_solid_angle
+                                    .unwrap()
+                                    .convert(&self.v_solid_angle.unwrap())
+                                    .powr(self.exp[SOLID_ANGLE_INDEX]),
+                            )?;
                         }
                     }
                     _ => {
-                        // error
-                        panic!("Cannot DivAssign values {self} and {other}");
+                        return Err(V3Error::IncompatibleUnits(format!("{self} and {other}")));
                     }
                 }
             }
         }
-        self.val /= cmp_val;
+
+        Ok(cmp_val)
+    }
+
+    /// Multiplies or divides two [`Value`]s when either has ratio units
+    ///
+    /// A ratio multiplied or divided by a plain number keeps its ratio units,
+    /// otherwise the ratio units are applied to the numeric and removed.
+    fn _ratio_combine(&self, other: &Value<T>, divide: bool) -> Result<Value<T>, V3Error> {
+        let plain = |v: &Value<T>| v.unit_map == 0 && v.v_ratio.is_none();
+        let mut n: Value<T> = if plain(other) {
+            *self
+        } else if plain(self) && !divide {
+            *other
+        } else if divide {
+            return self._plain_ratio()?.try_div(&other._plain_ratio()?);
+        } else {
+            return self._plain_ratio()?.try_mul(&other._plain_ratio()?);
+        };
+        n.val = if divide {
+            self.val / other.val
+        } else {
+            self.val * other.val
+        };
+        Ok(n)
+    }
+}
+
+impl Value {
+    /// Adds two [`Value`]s with the same dimensions
+    ///
+    /// `other` is converted into the units of `self` even if it is written with different
+    /// unit types, such as `N` and `kg*m/s^2`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(1.0, "N").unwrap();
+    /// let b:Value = Value::new(1.0, "kg*m/s^2").unwrap();
+    /// assert!(a.try_add(&b).is_err());
+    /// assert_eq!(a.try_add_dimensional(&b).unwrap().to_string(), "2 N");
+    /// ```
+    pub fn try_add_dimensional(&self, other: &Value) -> Result<Value, V3Error> {
+        let mut n: Value = *other;
+        n._convert_dimensional(self)?;
+        self.try_add(&n)
+    }
+
+    /// Subtracts two [`Value`]s with the same dimensions
+    ///
+    /// `other` is converted into the units of `self` even if it is written with different
+    /// unit types, such as `Pa` and `N/m^2`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(3.0, "kPa").unwrap();
+    /// let b:Value = Value::new(1000.0, "N/m^2").unwrap();
+    /// assert_eq!(a.try_sub_dimensional(&b).unwrap().to_string(), "2 kPa");
+    /// ```
+    pub fn try_sub_dimensional(&self, other: &Value) -> Result<Value, V3Error> {
+        let mut n: Value = *other;
+        n._convert_dimensional(self)?;
+        self.try_sub(&n)
+    }
+
+    /// Returns the remainder of dividing two [`Value`]s without panicking
+    ///
+    /// `other` is converted into the units of `self` before dividing, and the remainder has the
+    /// units and sign of `self`. Temperature and level divisors are treated as differences.
+    /// This is the non-panicking counterpart of `%` and `%=`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(40.0, "in").unwrap();
+    /// let b:Value = Value::new(1.0, "ft").unwrap();
+    /// assert!((a.try_rem(&b).unwrap().val - 4.0).abs() < 1e-9);
+    /// assert_eq!((a % b).unit_string(), "in");
+    ///
+    /// assert!(a.try_rem(&Value::new(1.0, "s").unwrap()).is_err());
+    /// ```
+    pub fn try_rem(&self, other: &Value) -> Result<Value, V3Error> {
+        let d: f64 = match self._difference_in_self_units(other) {
+            Some(d) => d,
+            None => return Err(V3Error::IncompatibleUnits(format!("{self} and {other}"))),
+        };
+        let mut n: Value = *self;
+        n.val %= d;
+        Ok(n)
+    }
+
+    /// Adds or subtracts two levels
+    ///
+    /// Levels of the same kind are combined through their powers, while unreferenced
+    /// levels (gains) shift referenced levels linearly.
+    fn _combine_levels(&self, other: &Value, subtract: bool) -> Result<Value, V3Error> {
+        let s: UnitSound = self.v_sound.unwrap();
+        let o: UnitSound = other.v_sound.unwrap();
+        let sign: f64 = if subtract { -1.0 } else { 1.0 };
+        let mut n: Value = *self;
+
+        if s.is_referenced() && !o.is_referenced() {
+            n.val += sign * other.val * o.convert(&s);
+            return Ok(n);
+        } else if !s.is_referenced() && o.is_referenced() && !subtract {
+            return other._combine_levels(self, false);
+        } else if !s.is_compatible(&o) {
+            return Err(V3Error::IncompatibleUnits(format!("{self} and {other}")));
+        }
+
+        let rhs: f64 = o.convert_level(&s, other.val);
+        if subtract && s.is_referenced() {
+            // referenced levels are always decibels
+            n.val -= rhs;
+            n.v_sound = Some(UnitSound::Bel(Metric::Deci));
+            return Ok(n);
+        }
+
+        let b: f64 = s.scale() * s.base();
+        n.val = (10.0_f64.powf(self.val * b) + sign * 10.0_f64.powf(rhs * b)).log10() / b;
+        Ok(n)
     }
 }
 
@@ -3037,75 +1940,100 @@ mod arithmetic_ops_testing {
     }
 
     #[test]
-    #[should_panic]
-    fn value_bad_add_temp() {
-        let t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
-
-        let _ = t1 + t2;
-    }
-
-    #[test]
-    #[should_panic]
-    fn value_bad_sub_temp() {
-        let t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
-
-        let _ = t1 - t2;
-    }
-
-    #[test]
-    #[should_panic]
-    fn value_bad_add_temp_mut() {
-        let mut t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
-
-        t1 += t2;
-    }
-
-    #[test]
-    #[should_panic]
-    fn value_bad_sub_temp_mut() {
-        let mut t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
-
-        t1 -= t2;
-    }
-
-    #[test]
-    #[should_panic]
-    fn value_bad_mul_temp() {
+    fn value_compound_temp() {
         let t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
-
-        let _ = t1 * t2;
+        let t2 = 1.8 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
+
+        // temperatures within compound units are intervals
+        assert!(((t1 + t2).val - 5.0).abs() < 1e-9);
+        assert!(((t1 - t2).val - 3.0).abs() < 1e-9);
+        assert!(((t1 * t2).val - 4.0).abs() < 1e-9);
+        assert_eq!((t1 * t2).to_string(), format!("{} m^2*°c^2", (t1 * t2).val));
+        assert!(((t1 / t2).val - 4.0).abs() < 1e-9);
+        assert!((t1 / t2).is_empty());
+
+        let mut t3 = t1;
+        t3 += t2;
+        assert!((t3.val - 5.0).abs() < 1e-9);
+        t3 -= t2;
+        assert!((t3.val - 4.0).abs() < 1e-9);
+        t3 *= t2;
+        assert!((t3.val - 4.0).abs() < 1e-9);
+        t3 /= t2;
+        assert!((t3.val - 4.0).abs() < 1e-9);
+        assert_eq!(t3.to_string(), t1.to_string());
     }
 
     #[test]
-    #[should_panic]
-    fn value_bad_div_temp() {
-        let t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
-
-        let _ = t1 / t2;
+    fn value_temp_intervals() {
+        let t1 = 30.0 * UnitTemperature::Celsius(Metric::None);
+        let t2 = 20.0 * UnitTemperature::Celsius(Metric::None);
+        let d = t1 - t2;
+        assert!(d.is_temperature_interval());
+        assert_eq!(d, 10.0 * UnitTemperature::DeltaCelsius(Metric::None));
+        assert_eq!(t2 + d, t1);
+        assert_eq!(t1 - d, t2);
+        assert_eq!(d + t2, t1);
+        assert_eq!(d + d, 20.0 * UnitTemperature::DeltaCelsius(Metric::None));
+
+        let t3 = 50.0 * UnitTemperature::Fahrenheit;
+        let d = t3 - t2;
+        assert!(d.is_temperature_interval());
+        assert!((d.val - -18.0).abs() < 1e-9);
+        assert_eq!(d.to_string(), format!("{} Δ°f", d.val));
+
+        let t =
+            68.0 * UnitTemperature::Fahrenheit + 10.0 * UnitTemperature::DeltaKelvin(Metric::None);
+        assert!((t.val - 86.0).abs() < 1e-9);
+        assert!(!t.is_temperature_interval());
+
+        let t =
+            10.0 * UnitTemperature::DeltaFahrenheit + 20.0 * UnitTemperature::Celsius(Metric::None);
+        assert_eq!(t.v_temperature, Some(UnitTemperature::Fahrenheit));
+        assert!((t.val - 78.0).abs() < 1e-9);
+
+        assert!(d.try_sub(&t2).is_err());
     }
 
     #[test]
     #[should_panic]
-    fn value_bad_mul_temp_mut() {
-        let mut t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
+    fn value_bad_sub_temp() {
+        let t1 = 4.0 * UnitTemperature::DeltaCelsius(Metric::None);
+        let t2 = 1.0 * UnitTemperature::Fahrenheit;
 
-        t1 *= t2;
+        let _ = t1 - t2;
     }
 
     #[test]
-    #[should_panic]
-    fn value_bad_div_temp_mut() {
-        let mut t1 = 4.0 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
-        let t2 = 1.0 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
-
-        t1 /= t2;
+    fn value_levels() {
+        let a = 60.0 * UnitSound::Bel(Metric::Deci);
+        assert!(((a + a).val - 63.010_299_956_639_81).abs() < 1e-9);
+        assert!(((a + a - a).val - 60.0).abs() < 1e-9);
+
+        // gains shift referenced levels
+        let p = 10.0 * UnitSound::DecibelMilliwatt;
+        let g = 3.0 * UnitSound::Bel(Metric::Deci);
+        assert_eq!((p + g).to_string(), "13 dBm");
+        assert_eq!((g + p).to_string(), "13 dBm");
+        assert_eq!((p - g).to_string(), "7 dBm");
+        assert!(((p + 1.0 * UnitSound::Neper).val - 18.685_889_638_065_035).abs() < 1e-9);
+        assert!(g.try_sub(&p).is_err());
+
+        // referenced levels of the same kind
+        let w = -10.0 * UnitSound::DecibelWatt;
+        assert!(((p + w).val - 20.413_926_851_582_25).abs() < 1e-9);
+        assert_eq!((p - w).to_string(), "-10 dB");
+        assert!(p.try_add(&(1.0 * UnitSound::DecibelVolt)).is_err());
+        assert!(a.try_add(&p).is_ok());
+        assert!(a.try_sub(&p).is_err());
+
+        // levels within compound units are added linearly
+        let r = 2.0 * UnitSound::Bel(Metric::None) / UnitTime::Second(Metric::None);
+        assert_eq!((r + r).val, 4.0);
+        let d = -174.0 * UnitSound::DecibelMilliwatt / UnitFrequency::Hertz(Metric::None);
+        assert_eq!((d + d).val, -348.0);
+        let e = -204.0 * UnitSound::DecibelWatt / UnitFrequency::Hertz(Metric::None);
+        assert!(d.try_add(&e).is_err());
     }
 
     #[test]
@@ -3453,6 +2381,8 @@ mod arithmetic_ops_testing {
         assert_eq!(t1, 8004.0);
         t1 -= t3;
         assert_eq!(t1, 4.0);
+        assert!(t1.is_temperature_interval());
+        let mut t1 = 4.0 * UnitTemperature::Kelvin(Metric::None);
         assert_eq!((t1 * t3).to_string(), "0.032 kK^2");
         t1 *= t3;
         assert_eq!(t1.to_string(), "0.032 kK^2");
@@ -3460,21 +2390,23 @@ mod arithmetic_ops_testing {
         t1 /= t3;
         assert_eq!(t1.to_string(), "0.004 kK");
 
+        // levels are added and subtracted through their powers
         let mut t1 = 4.0 * UnitSound::Bel(Metric::None);
-        let t2 = 16.0 * UnitSound::Bel(Metric::None);
-        let t3 = 8.0 * UnitSound::Bel(Metric::Kilo);
+        let t2 = 4.0 * UnitSound::Bel(Metric::None);
+        let t3 = 60.0 * UnitSound::Bel(Metric::Deci);
 
-        assert_eq!(t1 + t3, 8004.0);
-        assert_eq!(t3 - t1, 7.996);
-        assert_eq!(t1 + t2, 20.0);
-        assert_eq!(t2 - t1, 12.0);
+        assert!(((t1 + t3).val - 6.004_321_373_782_642).abs() < 1e-9);
+        assert!(((t3 - t1).val - 59.956_351_945_975_5).abs() < 1e-9);
+        assert!(((t1 + t2).val - 4.301_029_995_663_981).abs() < 1e-9);
+        assert!((t2 - t1).val.is_infinite());
         t1 += t3;
-        assert_eq!(t1, 8004.0);
+        assert!((t1.val - 6.004_321_373_782_642).abs() < 1e-9);
         t1 -= t3;
-        assert_eq!(t1, 4.0);
-        assert_eq!(t1 * t3, 32000.0);
+        assert!((t1.val - 4.0).abs() < 1e-9);
+        let mut t1 = 4.0 * UnitSound::Bel(Metric::None);
+        assert_eq!(t1 * t3, 24.0);
         t1 *= t3;
-        assert_eq!(t1, 32000.0);
+        assert_eq!(t1, 24.0);
         assert_eq!(t1 / t3, 4.0);
         t1 /= t3;
         assert_eq!(t1, 4.0);
@@ -4030,4 +2962,39 @@ mod arithmetic_ops_testing {
         t1 /= t3;
         assert_eq!(t1, 4.0);
     }
+
+    #[test]
+    fn value_try_ops() {
+        let t1 = 4.0 * UnitLength::Foot;
+        let t2 = 12.0 * UnitLength::Inch;
+        let t3 = 1.0 * UnitTime::Second(Metric::None);
+
+        assert_eq!(t1.try_add(&t2).unwrap(), 5.0);
+        assert_eq!(t1.try_sub(&t2).unwrap(), 3.0);
+        assert_eq!(t1.try_mul(&t2).unwrap(), 4.0);
+        assert!(t1.try_mul(&t2).unwrap().is_area());
+        assert_eq!(t1.try_div(&t3).unwrap(), 4.0);
+        assert!(t1.try_div(&t3).unwrap().is_velocity());
+
+        assert!(t1.try_add(&t3).is_err());
+        assert!(t1.try_sub(&t3).is_err());
+    }
+
+    #[test]
+    fn value_try_ops_temp() {
+        let t1 = 4.0 * UnitLength::Meter(Metric::None) * UnitTemperature::Celsius(Metric::None);
+        let t2 = 4.0 * UnitLength::Meter(Metric::None) * UnitTemperature::Kelvin(Metric::None);
+        let t3 = 1.0 * UnitTemperature::Kelvin(Metric::None);
+
+        assert_eq!(t1.try_add(&t2).unwrap(), t1 * 2.0);
+        assert_eq!(t1.try_sub(&t2).unwrap(), t1 * 0.0);
+        assert!(t1.try_mul(&t2).is_ok());
+        assert!(t1.try_div(&t2).unwrap().is_empty());
+        assert!(t1.try_mul(&t3).is_ok());
+        assert!(t1.try_div(&t3).is_ok());
+
+        let t4 = 4.0 * UnitTemperature::DeltaCelsius(Metric::None);
+        assert!(t4.try_sub(&t3).is_err());
+        assert!(t4.try_add(&t3).unwrap().is_temperature());
+    }
 }
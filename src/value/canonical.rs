@@ -0,0 +1,389 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    consts::{SOUND_MAP, TEMPERATURE_MAP},
+    errors::V3Error,
+    units::{UnitSound, custom::MAX_CUSTOM_DIMENSIONS},
+    value::{Value, exponent::Exponent},
+};
+
+/// The default number of significant digits kept by a [`CanonicalValue`]
+pub const DEFAULT_SIGNIFICANT_DIGITS: u32 = 12;
+
+/// How the numeric of a [`CanonicalValue`] is rounded
+///
+/// The numeric is rounded after it has been converted into SI base units, so
+/// [`Quantization::Step`] is always given in SI base units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantization {
+    /// Round to a number of significant decimal digits, between 1 and 17
+    Significant(u32),
+    /// Round to the nearest multiple of a positive step
+    Step(f64),
+    /// Keep the numeric exactly as converted
+    Exact,
+}
+
+impl Default for Quantization {
+    /// Rounds to [`DEFAULT_SIGNIFICANT_DIGITS`] significant digits
+    fn default() -> Self {
+        Quantization::Significant(DEFAULT_SIGNIFICANT_DIGITS)
+    }
+}
+
+/// A [`Value`] normalized to SI base units with a quantized numeric
+///
+/// Unlike a [`Value`], a [`CanonicalValue`] implements [`Eq`], [`Hash`] and [`Ord`] so it can
+/// be used as a map key or set member. Two [`Value`]s whose units have the same dimensions
+/// and whose numerics round to the same [`Quantization`] produce equal keys.
+///
+/// Keys are first ordered by their dimensions, which is arbitrary but consistent, and then by
+/// their numerics. All `NaN`s produce equal keys that order after every other numeric.
+///
+/// # Example
+/// ```rust
+/// use std::collections::HashSet;
+/// use bxvl::value::Value;
+/// use bxvl::value::canonical::CanonicalValue;
+/// let a:Value = Value::new(5.0, "bar").unwrap();
+/// let b:Value = Value::new(500.0, "kPa").unwrap();
+///
+/// let mut set:HashSet<CanonicalValue> = HashSet::new();
+/// set.insert(a.canonical_key().unwrap());
+/// set.insert(b.canonical_key().unwrap());
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CanonicalValue {
+    /// The quantized value in SI base units
+    value: Value,
+    /// Distinguishes absolute temperatures from temperature intervals
+    temperature: u8,
+    /// Distinguishes the references of levels
+    level: u8,
+}
+
+impl Value {
+    /// Returns the [`CanonicalValue`] of a [`Value`] using the default [`Quantization`]
+    ///
+    /// Returns an error if the [`Value`] cannot be reduced into SI base units.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(1.0, "ft").unwrap();
+    /// let b:Value = Value::new(12.0, "in").unwrap();
+    /// assert!(a.canonical_key().unwrap() == b.canonical_key().unwrap());
+    /// ```
+    pub fn canonical_key(&self) -> Result<CanonicalValue, V3Error> {
+        self.canonical_key_with(Quantization::default())
+    }
+
+    /// Returns the [`CanonicalValue`] of a [`Value`] using a given [`Quantization`]
+    ///
+    /// Returns an error if the [`Value`] cannot be reduced into SI base units or the
+    /// [`Quantization`] is invalid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::canonical::Quantization;
+    /// let a:Value = Value::new(1.04, "m").unwrap();
+    /// let b:Value = Value::new(96.0, "cm").unwrap();
+    /// let q:Quantization = Quantization::Step(0.1);
+    /// assert!(a.canonical_key_with(q).unwrap() == b.canonical_key_with(q).unwrap());
+    /// ```
+    pub fn canonical_key_with(&self, q: Quantization) -> Result<CanonicalValue, V3Error> {
+        let mut value: Value = *self;
+        if value.is_sound() && value.v_sound.unwrap().is_referenced() {
+            let unit: UnitSound = value.v_sound.unwrap();
+            for r in [
+                UnitSound::DecibelWatt,
+                UnitSound::DecibelVolt,
+                UnitSound::DecibelSoundPressure,
+            ] {
+                if unit.is_compatible(&r) {
+                    value = (value >> r)?;
+                }
+            }
+        }
+        let mut value: Value = value._plain_ratio()?._si_base()?;
+        value.val = _quantize(value.val, q)?;
+
+        let temperature: u8 = if value.unit_map & TEMPERATURE_MAP == 0 {
+            0
+        } else if value.v_temperature.unwrap().is_interval() {
+            2
+        } else {
+            1
+        };
+        let level: u8 = if value.unit_map & SOUND_MAP == 0 {
+            0
+        } else {
+            match value.v_sound.unwrap() {
+                UnitSound::Bel(_) | UnitSound::Neper => 1,
+                UnitSound::DecibelMilliwatt => 2,
+                UnitSound::DecibelWatt => 3,
+                UnitSound::DecibelVolt => 4,
+                UnitSound::DecibelMicrovolt => 5,
+                UnitSound::DecibelSoundPressure => 6,
+            }
+        };
+
+        Ok(CanonicalValue {
+            value,
+            temperature,
+            level,
+        })
+    }
+}
+
+impl CanonicalValue {
+    /// Returns the quantized [`Value`] in SI base units
+    pub fn value(&self) -> Value {
+        self.value
+    }
+
+    /// Returns the bits of the numeric with all zeros and all `NaN`s made equal
+    fn _bits(&self) -> u64 {
+        if self.value.val == 0.0 {
+            0.0_f64.to_bits()
+        } else if self.value.val.is_nan() {
+            f64::NAN.to_bits()
+        } else {
+            self.value.val.to_bits()
+        }
+    }
+
+    /// Returns the parts of the key that describe its dimensions
+    fn _dimensions(
+        &self,
+    ) -> (
+        usize,
+        [Exponent; 31],
+        [Exponent; MAX_CUSTOM_DIMENSIONS],
+        u8,
+        u8,
+    ) {
+        (
+            self.value.unit_map,
+            self.value.exp,
+            self.value._custom_exps(),
+            self.temperature,
+            self.level,
+        )
+    }
+}
+
+/// Rounds a numeric with a [`Quantization`]
+fn _quantize(val: f64, q: Quantization) -> Result<f64, V3Error> {
+    match q {
+        Quantization::Significant(n) => {
+            if !(1..=17).contains(&n) {
+                return Err(V3Error::ValueConversionError(
+                    "[canonical_key] Significant digits must be between 1 and 17",
+                ));
+            } else if !val.is_finite() {
+                return Ok(val);
+            }
+            // The formatter rounds correctly, so equal roundings give identical bits
+            Ok(format!("{:.*e}", (n - 1) as usize, val)
+                .parse::<f64>()
+                .unwrap())
+        }
+        Quantization::Step(s) => {
+            if !(s.is_finite() && s > 0.0) {
+                return Err(V3Error::ValueConversionError(
+                    "[canonical_key] Quantization step must be positive and finite",
+                ));
+            } else if !val.is_finite() {
+                return Ok(val);
+            }
+            Ok((val / s).round() * s)
+        }
+        Quantization::Exact => Ok(val),
+    }
+}
+
+impl PartialEq for CanonicalValue {
+    fn eq(&self, other: &CanonicalValue) -> bool {
+        self._bits() == other._bits() && self._dimensions() == other._dimensions()
+    }
+}
+
+impl Eq for CanonicalValue {}
+
+impl Hash for CanonicalValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self._dimensions().hash(state);
+        self._bits().hash(state);
+    }
+}
+
+impl PartialOrd for CanonicalValue {
+    fn partial_cmp(&self, other: &CanonicalValue) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CanonicalValue {
+    fn cmp(&self, other: &CanonicalValue) -> Ordering {
+        self._dimensions()
+            .cmp(&other._dimensions())
+            .then(f64::from_bits(self._bits()).total_cmp(&f64::from_bits(other._bits())))
+    }
+}
+
+impl Display for CanonicalValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl From<CanonicalValue> for Value {
+    fn from(key: CanonicalValue) -> Self {
+        key.value
+    }
+}
+
+#[cfg(test)]
+mod canonical_testing {
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    use crate::units::{Metric, UnitLength, UnitSound, UnitTemperature};
+    use crate::value::{
+        Value,
+        canonical::{CanonicalValue, Quantization},
+    };
+
+    #[test]
+    fn canonical_equal() {
+        let a = Value::new(5.0, "bar").unwrap();
+        let b = Value::new(500.0, "kPa").unwrap();
+        let c = Value::new(5.0, "kPa").unwrap();
+        assert_eq!(a.canonical_key().unwrap(), b.canonical_key().unwrap());
+        assert_ne!(a.canonical_key().unwrap(), c.canonical_key().unwrap());
+
+        let mut m: HashMap<CanonicalValue, &str> = HashMap::new();
+        m.insert(a.canonical_key().unwrap(), "setpoint");
+        assert_eq!(m.get(&b.canonical_key().unwrap()), Some(&"setpoint"));
+        assert_eq!(m.get(&c.canonical_key().unwrap()), None);
+
+        let d = Value::new(1.0, "kN*m").unwrap();
+        let e = Value::new(1000.0, "J").unwrap();
+        let f = Value::new(1.0, "kg*m^2/s^2").unwrap();
+        let s: HashSet<CanonicalValue> = [d, e, f * 1000.0]
+            .iter()
+            .map(|v| v.canonical_key().unwrap())
+            .collect();
+        assert_eq!(s.len(), 1);
+
+        let g = Value::new(60.0, "1/min").unwrap();
+        let h = Value::new(1.0, "Hz").unwrap();
+        assert_eq!(g.canonical_key().unwrap(), h.canonical_key().unwrap());
+        assert_eq!(
+            (0.0 * UnitLength::Foot).canonical_key().unwrap(),
+            (-0.0 * UnitLength::Meter(Metric::None))
+                .canonical_key()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn canonical_dimensions() {
+        let a = 1.0 * UnitLength::Meter(Metric::None);
+        let b = Value::new(1.0, "s").unwrap();
+        let c = Value::new(1.0, "m^2").unwrap();
+        assert_ne!(a.canonical_key().unwrap(), b.canonical_key().unwrap());
+        assert_ne!(a.canonical_key().unwrap(), c.canonical_key().unwrap());
+
+        let t = 273.15 * UnitTemperature::Kelvin(Metric::None);
+        let dt = 273.15 * UnitTemperature::DeltaKelvin(Metric::None);
+        assert_eq!(
+            t.canonical_key().unwrap(),
+            (0.0 * UnitTemperature::Celsius(Metric::None))
+                .canonical_key()
+                .unwrap()
+        );
+        assert_ne!(t.canonical_key().unwrap(), dt.canonical_key().unwrap());
+
+        let p = 30.0 * UnitSound::DecibelMilliwatt;
+        let q = 0.0 * UnitSound::DecibelWatt;
+        let r = 0.0 * UnitSound::DecibelVolt;
+        let s = 0.0 * UnitSound::Bel(Metric::None);
+        assert_eq!(p.canonical_key().unwrap(), q.canonical_key().unwrap());
+        assert_ne!(q.canonical_key().unwrap(), r.canonical_key().unwrap());
+        assert_ne!(q.canonical_key().unwrap(), s.canonical_key().unwrap());
+    }
+
+    #[test]
+    fn canonical_quantization() {
+        let a = Value::new(0.1, "m").unwrap() + Value::new(0.2, "m").unwrap();
+        let b = Value::new(0.3, "m").unwrap();
+        assert_eq!(a.canonical_key().unwrap(), b.canonical_key().unwrap());
+        assert_ne!(
+            a.canonical_key_with(Quantization::Exact).unwrap(),
+            b.canonical_key_with(Quantization::Exact).unwrap()
+        );
+
+        let c = Value::new(1234.0, "m").unwrap();
+        let d = Value::new(1.2, "km").unwrap();
+        let q = Quantization::Significant(2);
+        assert_eq!(
+            c.canonical_key_with(q).unwrap(),
+            d.canonical_key_with(q).unwrap()
+        );
+        assert_eq!(c.canonical_key_with(q).unwrap().value().val, 1200.0);
+        let q = Quantization::Step(500.0);
+        assert_eq!(c.canonical_key_with(q).unwrap().value().val, 1000.0);
+
+        assert!(c.canonical_key_with(Quantization::Significant(0)).is_err());
+        assert!(c.canonical_key_with(Quantization::Significant(18)).is_err());
+        assert!(c.canonical_key_with(Quantization::Step(0.0)).is_err());
+        assert!(c.canonical_key_with(Quantization::Step(f64::NAN)).is_err());
+
+        let n = f64::NAN * UnitLength::Meter(Metric::None);
+        assert_eq!(n.canonical_key().unwrap(), n.canonical_key().unwrap());
+        assert!(n.canonical_key_with(Quantization::Step(-1.0)).is_err());
+    }
+
+    #[test]
+    fn canonical_ordering() {
+        let s: BTreeSet<CanonicalValue> = [
+            Value::new(3.0, "ft").unwrap(),
+            Value::new(1.0, "m").unwrap(),
+            Value::new(-2.0, "in").unwrap(),
+            Value::new(100.0, "cm").unwrap(),
+        ]
+        .iter()
+        .map(|v| v.canonical_key().unwrap())
+        .collect();
+        let v: Vec<f64> = s.iter().map(|k| k.value().val).collect();
+        assert_eq!(v, vec![-0.0508, 0.9144, 1.0]);
+        assert_eq!(
+            Value::from(*s.first().unwrap()).to_string(),
+            "-0.0508 m".to_string()
+        );
+    }
+}
@@ -0,0 +1,519 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Shr, Sub},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts::SOUND_MAP,
+    errors::V3Error,
+    units::{Metric, UnitAngle},
+    value::Value,
+};
+
+/// A complex quantity that shares the units of a [`Value`]
+///
+/// The real and imaginary parts are always expressed in the same units. Only units that convert
+/// linearly are supported, so absolute temperatures and levels cannot be complex.
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::complex::ComplexValue;
+/// let v:ComplexValue = ComplexValue::new(6.0, 8.0, "V").unwrap();
+/// let i:ComplexValue = ComplexValue::new(2.0, 0.0, "A").unwrap();
+///
+/// let z:ComplexValue = (v / i).complex();
+/// assert_eq!(z.to_string(), "(3 + 4i) Ω");
+/// assert_eq!(z.magnitude(), Value::new(5.0, "Ω").unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ComplexValue {
+    /// The real part
+    pub re: f64,
+    /// The imaginary part
+    pub im: f64,
+    /// The units of both parts, with a numeric of `1`
+    units: Value,
+}
+
+impl ComplexValue {
+    /// Creates a [`ComplexValue`] from its rectangular parts and a unit string
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::complex::ComplexValue;
+    /// let z:ComplexValue = ComplexValue::new(1.0, -2.0, "kΩ").unwrap();
+    /// assert_eq!(z.to_string(), "(1 - 2i) kΩ");
+    /// assert!(ComplexValue::new(1.0, 0.0, "°C").is_err());
+    /// ```
+    pub fn new(re: f64, im: f64, units: &str) -> Result<ComplexValue, V3Error> {
+        ComplexValue::_from_units(re, im, Value::new(1.0, units)?)
+    }
+
+    /// Creates a [`ComplexValue`] from a real and an imaginary [`Value`]
+    ///
+    /// The imaginary part is converted into the units of the real part.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::complex::ComplexValue;
+    /// let r:Value = Value::new(1.0, "kΩ").unwrap();
+    /// let x:Value = Value::new(500.0, "Ω").unwrap();
+    /// let z:ComplexValue = ComplexValue::from_rect(r, x).unwrap();
+    /// assert_eq!(z.to_string(), "(1 + 0.5i) kΩ");
+    /// ```
+    pub fn from_rect(re: Value, im: Value) -> Result<ComplexValue, V3Error> {
+        let mut z: ComplexValue = ComplexValue::try_from(re)?;
+        z.im = im.val * z._factor_from(&im)?;
+        Ok(z)
+    }
+
+    /// Creates a [`ComplexValue`] from a magnitude [`Value`] and a phase angle [`Value`]
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::complex::ComplexValue;
+    /// let m:Value = Value::new(2.0, "V").unwrap();
+    /// let p:Value = Value::new(90.0, "°").unwrap();
+    /// let z:ComplexValue = ComplexValue::from_polar(m, p).unwrap();
+    /// assert!(z.re.abs() < 1e-12);
+    /// assert!((z.im - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn from_polar(magnitude: Value, phase: Value) -> Result<ComplexValue, V3Error> {
+        if !phase.is_angle() {
+            return Err(V3Error::IncompatibleUnits(format!(
+                "[from_polar] Phase {phase} is not an angle"
+            )));
+        }
+        let theta: f64 = (phase >> UnitAngle::Radian(Metric::None))?.val;
+        let mut z: ComplexValue = ComplexValue::try_from(magnitude)?;
+        z.re = magnitude.val * theta.cos();
+        z.im = magnitude.val * theta.sin();
+        Ok(z)
+    }
+
+    /// Creates a [`ComplexValue`] after checking that its units convert linearly
+    fn _from_units(re: f64, im: f64, units: Value) -> Result<ComplexValue, V3Error> {
+        if (units.is_temperature() && !units.is_temperature_interval())
+            || units.unit_map & SOUND_MAP != 0
+        {
+            return Err(V3Error::IncompatibleUnits(format!(
+                "[ComplexValue] {} cannot be complex",
+                units.unit_string()
+            )));
+        }
+        let mut units: Value = units;
+        units.val = 1.0;
+        Ok(ComplexValue { re, im, units })
+    }
+
+    /// Returns the factor that converts a numeric in the units of `other` into the units of `self`
+    fn _factor_from(&self, other: &Value) -> Result<f64, V3Error> {
+        let mut u: Value = *other;
+        u.val = 1.0;
+        match u >> self.units {
+            Ok(v) => Ok(v.val),
+            Err(_) => Err(V3Error::IncompatibleUnits(format!(
+                "{} and {}",
+                self.units.unit_string(),
+                other.unit_string()
+            ))),
+        }
+    }
+
+    /// Returns the real part as a [`Value`]
+    pub fn real(&self) -> Value {
+        self.units * self.re
+    }
+
+    /// Returns the imaginary part as a [`Value`]
+    pub fn imag(&self) -> Value {
+        self.units * self.im
+    }
+
+    /// Returns the magnitude as a [`Value`]
+    pub fn magnitude(&self) -> Value {
+        self.units * self.re.hypot(self.im)
+    }
+
+    /// Returns the phase angle in radians
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::complex::ComplexValue;
+    /// use bxvl::units::UnitAngle;
+    /// let z:ComplexValue = ComplexValue::new(1.0, 1.0, "V").unwrap();
+    /// let p = (z.phase() >> UnitAngle::Degree).unwrap();
+    /// assert!((p.val - 45.0).abs() < 1e-12);
+    /// ```
+    pub fn phase(&self) -> Value {
+        self.im.atan2(self.re) * UnitAngle::Radian(Metric::None)
+    }
+
+    /// Returns the complex conjugate
+    pub fn conj(&self) -> ComplexValue {
+        ComplexValue {
+            im: -self.im,
+            ..*self
+        }
+    }
+
+    /// Returns only the unit portion of the [`ComplexValue`] as a string
+    pub fn unit_string(&self) -> String {
+        self.units.unit_string()
+    }
+
+    /// Converts a [`ComplexValue`] into other units
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::complex::ComplexValue;
+    /// let mut z:ComplexValue = ComplexValue::new(1.5, 2.0, "kW").unwrap();
+    /// z.convert("W").unwrap();
+    /// assert_eq!(z.to_string(), "(1500 + 2000i) W");
+    /// ```
+    pub fn convert(&mut self, other: &str) -> Result<(), V3Error> {
+        *self = (*self >> other)?;
+        Ok(())
+    }
+
+    /// Combines the unit types of a [`ComplexValue`] if applicable
+    ///
+    /// See [`Value::complex`].
+    pub fn complex(&self) -> ComplexValue {
+        self._rescaled(self.units.complex())
+    }
+
+    /// Reduces the unit complexity of a [`ComplexValue`]
+    ///
+    /// See [`Value::reduce`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::complex::ComplexValue;
+    /// let mut z:ComplexValue = ComplexValue::new(1.0, 2.0, "kN").unwrap();
+    /// z.reduce("kg*m/s^2").unwrap();
+    /// assert_eq!(z.to_string(), "(1000 + 2000i) m*kg/s^2");
+    /// ```
+    pub fn reduce(&mut self, other: &str) -> Result<(), V3Error> {
+        let mut u: Value = self.units;
+        u.reduce(other)?;
+        *self = self._rescaled(u);
+        Ok(())
+    }
+
+    /// Moves the numeric of a rescaled unit [`Value`] into both parts
+    fn _rescaled(&self, units: Value) -> ComplexValue {
+        ComplexValue {
+            re: self.re * units.val,
+            im: self.im * units.val,
+            units: Value { val: 1.0, ..units },
+        }
+    }
+
+    /// Adds two [`ComplexValue`]s without panicking
+    ///
+    /// `other` is converted into the units of `self` before being added.
+    pub fn try_add(&self, other: &ComplexValue) -> Result<ComplexValue, V3Error> {
+        let f: f64 = self._factor_from(&other.units)?;
+        Ok(ComplexValue {
+            re: self.re + other.re * f,
+            im: self.im + other.im * f,
+            units: self.units,
+        })
+    }
+
+    /// Subtracts two [`ComplexValue`]s without panicking
+    ///
+    /// `other` is converted into the units of `self` before being subtracted.
+    pub fn try_sub(&self, other: &ComplexValue) -> Result<ComplexValue, V3Error> {
+        self.try_add(&-*other)
+    }
+
+    /// Multiplies two [`ComplexValue`]s without panicking
+    ///
+    /// Units are combined as they are by [`Value::try_mul`].
+    pub fn try_mul(&self, other: &ComplexValue) -> Result<ComplexValue, V3Error> {
+        let z: ComplexValue = ComplexValue {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+            units: self.units,
+        };
+        Ok(z._rescaled(self.units.try_mul(&other.units)?))
+    }
+
+    /// Divides two [`ComplexValue`]s without panicking
+    ///
+    /// Units are combined as they are by [`Value::try_div`].
+    pub fn try_div(&self, other: &ComplexValue) -> Result<ComplexValue, V3Error> {
+        let d: f64 = other.re * other.re + other.im * other.im;
+        let z: ComplexValue = ComplexValue {
+            re: (self.re * other.re + self.im * other.im) / d,
+            im: (self.im * other.re - self.re * other.im) / d,
+            units: self.units,
+        };
+        Ok(z._rescaled(self.units.try_div(&other.units)?))
+    }
+}
+
+impl TryFrom<Value> for ComplexValue {
+    type Error = V3Error;
+    fn try_from(v: Value) -> Result<Self, Self::Error> {
+        ComplexValue::_from_units(v.val, 0.0, v)
+    }
+}
+
+impl PartialEq for ComplexValue {
+    fn eq(&self, other: &ComplexValue) -> bool {
+        self.units.__equal(&other.units) && self.re == other.re && self.im == other.im
+    }
+}
+
+impl Display for ComplexValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign: char = if self.im.is_sign_negative() { '-' } else { '+' };
+        let u: String = self.units.unit_string();
+        if u.is_empty() {
+            write!(f, "({} {} {}i)", self.re, sign, self.im.abs())
+        } else {
+            write!(f, "({} {} {}i) {}", self.re, sign, self.im.abs(), u)
+        }
+    }
+}
+
+impl Shr<Value> for ComplexValue {
+    type Output = Result<ComplexValue, V3Error>;
+    fn shr(self, other: Value) -> Self::Output {
+        let target: ComplexValue = ComplexValue::try_from(other)?;
+        let f: f64 = target._factor_from(&self.units)?;
+        Ok(ComplexValue {
+            re: self.re * f,
+            im: self.im * f,
+            units: target.units,
+        })
+    }
+}
+
+impl Shr<&str> for ComplexValue {
+    type Output = Result<ComplexValue, V3Error>;
+    fn shr(self, other: &str) -> Self::Output {
+        self >> Value::new(1.0, other)?
+    }
+}
+
+impl Neg for ComplexValue {
+    type Output = ComplexValue;
+    fn neg(self) -> Self::Output {
+        ComplexValue {
+            re: -self.re,
+            im: -self.im,
+            units: self.units,
+        }
+    }
+}
+
+impl Add<ComplexValue> for ComplexValue {
+    type Output = ComplexValue;
+    fn add(self, other: ComplexValue) -> Self::Output {
+        match self.try_add(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Add values {self} and {other}"),
+        }
+    }
+}
+
+impl Sub<ComplexValue> for ComplexValue {
+    type Output = ComplexValue;
+    fn sub(self, other: ComplexValue) -> Self::Output {
+        match self.try_sub(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Sub values {self} and {other}"),
+        }
+    }
+}
+
+impl Mul<ComplexValue> for ComplexValue {
+    type Output = ComplexValue;
+    fn mul(self, other: ComplexValue) -> Self::Output {
+        match self.try_mul(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Mul values {self} and {other}"),
+        }
+    }
+}
+
+impl Div<ComplexValue> for ComplexValue {
+    type Output = ComplexValue;
+    fn div(self, other: ComplexValue) -> Self::Output {
+        match self.try_div(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Div values {self} and {other}"),
+        }
+    }
+}
+
+impl Mul<Value> for ComplexValue {
+    type Output = ComplexValue;
+    fn mul(self, other: Value) -> Self::Output {
+        match ComplexValue::try_from(other) {
+            Ok(v) => self * v,
+            Err(_) => panic!("Cannot Mul values {self} and {other}"),
+        }
+    }
+}
+
+impl Div<Value> for ComplexValue {
+    type Output = ComplexValue;
+    fn div(self, other: Value) -> Self::Output {
+        match ComplexValue::try_from(other) {
+            Ok(v) => self / v,
+            Err(_) => panic!("Cannot Div values {self} and {other}"),
+        }
+    }
+}
+
+impl Mul<f64> for ComplexValue {
+    type Output = ComplexValue;
+    fn mul(self, other: f64) -> Self::Output {
+        ComplexValue {
+            re: self.re * other,
+            im: self.im * other,
+            units: self.units,
+        }
+    }
+}
+
+impl Div<f64> for ComplexValue {
+    type Output = ComplexValue;
+    fn div(self, other: f64) -> Self::Output {
+        ComplexValue {
+            re: self.re / other,
+            im: self.im / other,
+            units: self.units,
+        }
+    }
+}
+
+#[cfg(test)]
+mod complex_testing {
+    use crate::units::{Metric, UnitElectricResistance, UnitLength, UnitSound};
+    use crate::value::{Value, complex::ComplexValue};
+
+    #[test]
+    fn complex_construction() {
+        let z = ComplexValue::new(3.0, -4.0, "Ω").unwrap();
+        assert_eq!(z.real(), 3.0 * UnitElectricResistance::Ohm(Metric::None));
+        assert_eq!(z.imag(), -4.0 * UnitElectricResistance::Ohm(Metric::None));
+        assert_eq!(
+            z.magnitude(),
+            5.0 * UnitElectricResistance::Ohm(Metric::None)
+        );
+        assert_eq!(z.conj(), ComplexValue::new(3.0, 4.0, "Ω").unwrap());
+        assert_eq!(z.unit_string(), "Ω");
+
+        let p = ComplexValue::from_polar(z.magnitude(), z.phase()).unwrap();
+        assert!((p.re - 3.0).abs() < 1e-12);
+        assert!((p.im + 4.0).abs() < 1e-12);
+        assert!(ComplexValue::from_polar(z.magnitude(), z.magnitude()).is_err());
+
+        let r = ComplexValue::from_rect(
+            1.0 * UnitElectricResistance::Ohm(Metric::Kilo),
+            1.0 * UnitElectricResistance::Ohm(Metric::None),
+        )
+        .unwrap();
+        assert_eq!(r, ComplexValue::new(1.0, 0.001, "kΩ").unwrap());
+        assert!(ComplexValue::from_rect(z.real(), Value::new(1.0, "V").unwrap()).is_err());
+
+        assert!(ComplexValue::new(1.0, 1.0, "K").is_err());
+        assert!(ComplexValue::new(1.0, 1.0, "ΔK").is_ok());
+        assert!(ComplexValue::try_from(1.0 * UnitSound::Bel(Metric::None)).is_err());
+        assert!(ComplexValue::new(1.0, 1.0, "zz").is_err());
+    }
+
+    #[test]
+    fn complex_display() {
+        assert_eq!(
+            ComplexValue::new(1.0, 2.0, "V*A").unwrap().to_string(),
+            "(1 + 2i) A*V"
+        );
+        assert_eq!(
+            ComplexValue::new(1.0, -2.0, "").unwrap().to_string(),
+            "(1 - 2i)"
+        );
+    }
+
+    #[test]
+    fn complex_conversion() {
+        let z = ComplexValue::new(1.0, 2.0, "kΩ").unwrap();
+        let c = (z >> "Ω").unwrap();
+        assert_eq!(c, ComplexValue::new(1000.0, 2000.0, "Ω").unwrap());
+        assert!((z >> "V").is_err());
+        let c = (z >> (1.0 * UnitElectricResistance::Ohm(Metric::Mega))).unwrap();
+        assert_eq!(c.unit_string(), "MΩ");
+
+        let f = ComplexValue::new(1.0, 1.0, "kg*m/s^2").unwrap().complex();
+        assert_eq!(f.to_string(), "(1 + 1i) N");
+        let mut g = ComplexValue::new(1.0, 1.0, "N").unwrap();
+        assert!(g.reduce("kg*m/s^2").is_ok());
+        assert_eq!(g.unit_string(), "m*kg/s^2");
+    }
+
+    #[test]
+    fn complex_arithmetic() {
+        let a = ComplexValue::new(3.0, 4.0, "Ω").unwrap();
+        let b = ComplexValue::new(0.001, 0.0, "kΩ").unwrap();
+        assert_eq!(a + b, ComplexValue::new(4.0, 4.0, "Ω").unwrap());
+        assert_eq!(a - b, ComplexValue::new(2.0, 4.0, "Ω").unwrap());
+        assert_eq!(-a, ComplexValue::new(-3.0, -4.0, "Ω").unwrap());
+
+        let i = ComplexValue::new(0.0, 2.0, "A").unwrap();
+        let v = ComplexValue::new(-8.0, 6.0, "V").unwrap();
+        let z = (v / i).complex();
+        assert_eq!(z, a);
+        assert_eq!((a * i).unit_string(), "A*Ω");
+
+        let s = (v * i.conj()).complex();
+        assert_eq!(s, ComplexValue::new(12.0, 16.0, "W").unwrap());
+
+        let m = ComplexValue::new(1.0, 1.0, "m").unwrap();
+        let cm = ComplexValue::new(100.0, 0.0, "cm").unwrap();
+        assert_eq!((m * cm).to_string(), "(1 + 1i) m^2");
+
+        assert_eq!(a * 2.0, ComplexValue::new(6.0, 8.0, "Ω").unwrap());
+        assert_eq!(a / 2.0, ComplexValue::new(1.5, 2.0, "Ω").unwrap());
+        assert_eq!(
+            a * (2.0 * UnitLength::Meter(Metric::None)),
+            ComplexValue::new(6.0, 8.0, "Ω*m").unwrap()
+        );
+        assert!(a.try_add(&i).is_err());
+        assert!(a.try_sub(&i).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn complex_add_fail() {
+        let _ =
+            ComplexValue::new(3.0, 4.0, "Ω").unwrap() + ComplexValue::new(0.0, 2.0, "A").unwrap();
+    }
+}
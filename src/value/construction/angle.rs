@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ANGLE_INDEX, ANGLE_MAP},
     units::UnitAngle,
@@ -32,7 +33,7 @@ impl Mul<UnitAngle> for f64 {
             unit_map: ANGLE_MAP,
             ..Default::default()
         };
-        ret.exp[ANGLE_INDEX] = 1;
+        ret.exp[ANGLE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitAngle> for f64 {
             unit_map: ANGLE_MAP,
             ..Default::default()
         };
-        ret.exp[ANGLE_INDEX] = -1;
+        ret.exp[ANGLE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitAngle> for Value {
         let mut new: Value = self;
         if self.exp[ANGLE_INDEX] == 0 {
             new.v_angle = Some(other);
-            new.exp[ANGLE_INDEX] = 1;
+            new.exp[ANGLE_INDEX] = Exponent::from(1);
             new.unit_map |= ANGLE_MAP;
         } else if self.exp[ANGLE_INDEX] == -1 {
-            new.exp[ANGLE_INDEX] = 0;
+            new.exp[ANGLE_INDEX] = Exponent::from(0);
             new.v_angle = None;
             new.unit_map ^= ANGLE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitAngle> for Value {
         if self.exp[ANGLE_INDEX] == 0 {
             new.v_angle = Some(other);
             new.unit_map |= ANGLE_MAP;
-            new.exp[ANGLE_INDEX] = -1;
+            new.exp[ANGLE_INDEX] = Exponent::from(-1);
         } else if self.exp[ANGLE_INDEX] == 1 {
-            new.exp[ANGLE_INDEX] = 0;
+            new.exp[ANGLE_INDEX] = Exponent::from(0);
             new.v_angle = None;
             new.unit_map ^= ANGLE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{SOLID_ANGLE_INDEX, SOLID_ANGLE_MAP},
     units::UnitSolidAngle,
@@ -32,7 +33,7 @@ impl Mul<UnitSolidAngle> for f64 {
             unit_map: SOLID_ANGLE_MAP,
             ..Default::default()
         };
-        ret.exp[SOLID_ANGLE_INDEX] = 1;
+        ret.exp[SOLID_ANGLE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitSolidAngle> for f64 {
             unit_map: SOLID_ANGLE_MAP,
             ..Default::default()
         };
-        ret.exp[SOLID_ANGLE_INDEX] = -1;
+        ret.exp[SOLID_ANGLE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitSolidAngle> for Value {
         let mut new: Value = self;
         if self.exp[SOLID_ANGLE_INDEX] == 0 {
             new.v_solid_angle = Some(other);
-            new.exp[SOLID_ANGLE_INDEX] = 1;
+            new.exp[SOLID_ANGLE_INDEX] = Exponent::from(1);
             new.unit_map |= SOLID_ANGLE_MAP;
         } else if self.exp[SOLID_ANGLE_INDEX] == -1 {
-            new.exp[SOLID_ANGLE_INDEX] = 0;
+            new.exp[SOLID_ANGLE_INDEX] = Exponent::from(0);
             new.v_solid_angle = None;
             new.unit_map ^= SOLID_ANGLE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitSolidAngle> for Value {
         if new.exp[SOLID_ANGLE_INDEX] == 0 {
             new.v_solid_angle = Some(other);
             new.unit_map |= SOLID_ANGLE_MAP;
-            new.exp[SOLID_ANGLE_INDEX] = -1;
+            new.exp[SOLID_ANGLE_INDEX] = Exponent::from(-1);
         } else if new.exp[SOLID_ANGLE_INDEX] == 1 {
-            new.exp[SOLID_ANGLE_INDEX] = 0;
+            new.exp[SOLID_ANGLE_INDEX] = Exponent::from(0);
             new.v_solid_angle = None;
             new.unit_map ^= SOLID_ANGLE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{CATALYTIC_ACTIVITY_INDEX, CATALYTIC_ACTIVITY_MAP},
     units::UnitCatalyticActivity,
@@ -32,7 +33,7 @@ impl Mul<UnitCatalyticActivity> for f64 {
             unit_map: CATALYTIC_ACTIVITY_MAP,
             ..Default::default()
         };
-        ret.exp[CATALYTIC_ACTIVITY_INDEX] = 1;
+        ret.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitCatalyticActivity> for f64 {
             unit_map: CATALYTIC_ACTIVITY_MAP,
             ..Default::default()
         };
-        ret.exp[CATALYTIC_ACTIVITY_INDEX] = -1;
+        ret.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitCatalyticActivity> for Value {
         let mut new: Value = self;
         if self.exp[CATALYTIC_ACTIVITY_INDEX] == 0 {
             new.v_catalytic = Some(other);
-            new.exp[CATALYTIC_ACTIVITY_INDEX] = 1;
+            new.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(1);
             new.unit_map |= CATALYTIC_ACTIVITY_MAP;
         } else if self.exp[CATALYTIC_ACTIVITY_INDEX] == -1 {
-            new.exp[CATALYTIC_ACTIVITY_INDEX] = 0;
+            new.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(0);
             new.v_catalytic = None;
             new.unit_map ^= CATALYTIC_ACTIVITY_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitCatalyticActivity> for Value {
         if self.exp[CATALYTIC_ACTIVITY_INDEX] == 0 {
             new.v_catalytic = Some(other);
             new.unit_map |= CATALYTIC_ACTIVITY_MAP;
-            new.exp[CATALYTIC_ACTIVITY_INDEX] = -1;
+            new.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(-1);
         } else if new.exp[CATALYTIC_ACTIVITY_INDEX] == 1 {
-            new.exp[CATALYTIC_ACTIVITY_INDEX] = 0;
+            new.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(0);
             new.v_catalytic = None;
             new.unit_map ^= CATALYTIC_ACTIVITY_MAP;
         } else {
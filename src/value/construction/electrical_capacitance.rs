@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{CAPACITANCE_INDEX, CAPACITANCE_MAP},
     units::UnitElectricCapacitance,
@@ -32,7 +33,7 @@ impl Mul<UnitElectricCapacitance> for f64 {
             unit_map: CAPACITANCE_MAP,
             ..Default::default()
         };
-        ret.exp[CAPACITANCE_INDEX] = 1;
+        ret.exp[CAPACITANCE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitElectricCapacitance> for f64 {
             unit_map: CAPACITANCE_MAP,
             ..Default::default()
         };
-        ret.exp[CAPACITANCE_INDEX] = -1;
+        ret.exp[CAPACITANCE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitElectricCapacitance> for Value {
         let mut new: Value = self;
         if self.exp[CAPACITANCE_INDEX] == 0 {
             new.v_capacitance = Some(other);
-            new.exp[CAPACITANCE_INDEX] = 1;
+            new.exp[CAPACITANCE_INDEX] = Exponent::from(1);
             new.unit_map |= CAPACITANCE_MAP;
         } else if self.exp[CAPACITANCE_INDEX] == -1 {
-            new.exp[CAPACITANCE_INDEX] = 0;
+            new.exp[CAPACITANCE_INDEX] = Exponent::from(0);
             new.v_capacitance = None;
             new.unit_map ^= CAPACITANCE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitElectricCapacitance> for Value {
         if self.exp[CAPACITANCE_INDEX] == 0 {
             new.v_capacitance = Some(other);
             new.unit_map |= CAPACITANCE_MAP;
-            new.exp[CAPACITANCE_INDEX] = -1;
+            new.exp[CAPACITANCE_INDEX] = Exponent::from(-1);
         } else if self.exp[CAPACITANCE_INDEX] == 1 {
-            new.exp[CAPACITANCE_INDEX] = 0;
+            new.exp[CAPACITANCE_INDEX] = Exponent::from(0);
             new.v_capacitance = None;
             new.unit_map ^= CAPACITANCE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ELECTRIC_CHARGE_INDEX, ELECTRIC_CHARGE_MAP},
     units::UnitElectricCharge,
@@ -32,7 +33,7 @@ impl Mul<UnitElectricCharge> for f64 {
             unit_map: ELECTRIC_CHARGE_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_CHARGE_INDEX] = 1;
+        ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitElectricCharge> for f64 {
             unit_map: ELECTRIC_CHARGE_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_CHARGE_INDEX] = -1;
+        ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitElectricCharge> for Value {
         let mut new: Value = self;
         if self.exp[ELECTRIC_CHARGE_INDEX] == 0 {
             new.v_electric_charge = Some(other);
-            new.exp[ELECTRIC_CHARGE_INDEX] = 1;
+            new.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(1);
             new.unit_map |= ELECTRIC_CHARGE_MAP;
         } else if self.exp[ELECTRIC_CHARGE_INDEX] == -1 {
-            new.exp[ELECTRIC_CHARGE_INDEX] = 0;
+            new.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
             new.v_electric_charge = None;
             new.unit_map ^= ELECTRIC_CHARGE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitElectricCharge> for Value {
         if self.exp[ELECTRIC_CHARGE_INDEX] == 0 {
             new.v_electric_charge = Some(other);
             new.unit_map |= ELECTRIC_CHARGE_MAP;
-            new.exp[ELECTRIC_CHARGE_INDEX] = -1;
+            new.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(-1);
         } else if new.exp[ELECTRIC_CHARGE_INDEX] == 1 {
-            new.exp[ELECTRIC_CHARGE_INDEX] = 0;
+            new.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
             new.v_electric_charge = None;
             new.unit_map ^= ELECTRIC_CHARGE_MAP;
         } else {
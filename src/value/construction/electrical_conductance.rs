@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ELECTRIC_CONDUCTANCE_INDEX, ELECTRIC_CONDUCTANCE_MAP},
     units::UnitElectricConductance,
@@ -32,7 +33,7 @@ impl Mul<UnitElectricConductance> for f64 {
             unit_map: ELECTRIC_CONDUCTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = 1;
+        ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitElectricConductance> for f64 {
             unit_map: ELECTRIC_CONDUCTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = -1;
+        ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitElectricConductance> for Value {
         let mut new: Value = self;
         if self.exp[ELECTRIC_CONDUCTANCE_INDEX] == 0 {
             new.v_electric_conductance = Some(other);
-            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = 1;
+            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(1);
             new.unit_map |= ELECTRIC_CONDUCTANCE_MAP;
         } else if self.exp[ELECTRIC_CONDUCTANCE_INDEX] == -1 {
-            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = 0;
+            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(0);
             new.v_electric_conductance = None;
             new.unit_map ^= ELECTRIC_CONDUCTANCE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitElectricConductance> for Value {
         if self.exp[ELECTRIC_CONDUCTANCE_INDEX] == 0 {
             new.v_electric_conductance = Some(other);
             new.unit_map |= ELECTRIC_CONDUCTANCE_MAP;
-            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = -1;
+            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(-1);
         } else if self.exp[ELECTRIC_CONDUCTANCE_INDEX] == 1 {
-            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = 0;
+            new.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(0);
             new.v_electric_conductance = None;
             new.unit_map ^= ELECTRIC_CONDUCTANCE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ELECTRIC_CURRENT_INDEX, ELECTRIC_CURRENT_MAP},
     units::UnitElectricCurrent,
@@ -32,7 +33,7 @@ impl Mul<UnitElectricCurrent> for f64 {
             unit_map: ELECTRIC_CURRENT_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_CURRENT_INDEX] = 1;
+        ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitElectricCurrent> for f64 {
             unit_map: ELECTRIC_CURRENT_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_CURRENT_INDEX] = -1;
+        ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitElectricCurrent> for Value {
         let mut new: Value = self;
         if self.exp[ELECTRIC_CURRENT_INDEX] == 0 {
             new.v_electric_current = Some(other);
-            new.exp[ELECTRIC_CURRENT_INDEX] = 1;
+            new.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(1);
             new.unit_map |= ELECTRIC_CURRENT_MAP;
         } else if self.exp[ELECTRIC_CURRENT_INDEX] == -1 {
-            new.exp[ELECTRIC_CURRENT_INDEX] = 0;
+            new.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
             new.v_electric_current = None;
             new.unit_map ^= ELECTRIC_CURRENT_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitElectricCurrent> for Value {
         if self.exp[ELECTRIC_CURRENT_INDEX] == 0 {
             new.v_electric_current = Some(other);
             new.unit_map |= ELECTRIC_CURRENT_MAP;
-            new.exp[ELECTRIC_CURRENT_INDEX] = -1;
+            new.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
         } else if new.exp[ELECTRIC_CURRENT_INDEX] == 1 {
-            new.exp[ELECTRIC_CURRENT_INDEX] = 0;
+            new.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
             new.v_electric_current = None;
             new.unit_map ^= ELECTRIC_CURRENT_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{INDUCTANCE_INDEX, INDUCTANCE_MAP},
     units::UnitElectricInductance,
@@ -32,7 +33,7 @@ impl Mul<UnitElectricInductance> for f64 {
             unit_map: INDUCTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[INDUCTANCE_INDEX] = 1;
+        ret.exp[INDUCTANCE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitElectricInductance> for f64 {
             unit_map: INDUCTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[INDUCTANCE_INDEX] = -1;
+        ret.exp[INDUCTANCE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitElectricInductance> for Value {
         let mut new: Value = self;
         if self.exp[INDUCTANCE_INDEX] == 0 {
             new.v_inductance = Some(other);
-            new.exp[INDUCTANCE_INDEX] = 1;
+            new.exp[INDUCTANCE_INDEX] = Exponent::from(1);
             new.unit_map |= INDUCTANCE_MAP;
         } else if self.exp[INDUCTANCE_INDEX] == -1 {
-            new.exp[INDUCTANCE_INDEX] = 0;
+            new.exp[INDUCTANCE_INDEX] = Exponent::from(0);
             new.v_inductance = None;
             new.unit_map ^= INDUCTANCE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitElectricInductance> for Value {
         if new.exp[INDUCTANCE_INDEX] == 0 {
             new.v_inductance = Some(other);
             new.unit_map |= INDUCTANCE_MAP;
-            new.exp[INDUCTANCE_INDEX] = -1;
+            new.exp[INDUCTANCE_INDEX] = Exponent::from(-1);
         } else if new.exp[INDUCTANCE_INDEX] == 1 {
-            new.exp[INDUCTANCE_INDEX] = 0;
+            new.exp[INDUCTANCE_INDEX] = Exponent::from(0);
             new.v_inductance = None;
             new.unit_map ^= INDUCTANCE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ELECTRIC_POTENTIAL_INDEX, ELECTRIC_POTENTIAL_MAP},
     units::UnitElectricPotential,
@@ -32,7 +33,7 @@ impl Mul<UnitElectricPotential> for f64 {
             unit_map: ELECTRIC_POTENTIAL_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
+        ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitElectricPotential> for f64 {
             unit_map: ELECTRIC_POTENTIAL_MAP,
             ..Default::default()
         };
-        ret.exp[ELECTRIC_POTENTIAL_INDEX] = -1;
+        ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitElectricPotential> for Value {
         let mut new: Value = self;
         if self.exp[ELECTRIC_POTENTIAL_INDEX] == 0 {
             new.v_electric_potential = Some(other);
-            new.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
+            new.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
             new.unit_map |= ELECTRIC_POTENTIAL_MAP;
         } else if self.exp[ELECTRIC_POTENTIAL_INDEX] == -1 {
-            new.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
+            new.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
             new.v_electric_potential = None;
             new.unit_map ^= ELECTRIC_POTENTIAL_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitElectricPotential> for Value {
         if self.exp[ELECTRIC_POTENTIAL_INDEX] == 0 {
             new.v_electric_potential = Some(other);
             new.unit_map |= ELECTRIC_POTENTIAL_MAP;
-            new.exp[ELECTRIC_POTENTIAL_INDEX] = -1;
+            new.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(-1);
         } else if new.exp[ELECTRIC_POTENTIAL_INDEX] == 1 {
-            new.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
+            new.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
             new.v_electric_potential = None;
             new.unit_map ^= ELECTRIC_POTENTIAL_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{RESISTANCE_INDEX, RESISTANCE_MAP},
     units::UnitElectricResistance,
@@ -32,7 +33,7 @@ impl Mul<UnitElectricResistance> for f64 {
             unit_map: RESISTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[RESISTANCE_INDEX] = 1;
+        ret.exp[RESISTANCE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitElectricResistance> for f64 {
             unit_map: RESISTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[RESISTANCE_INDEX] = -1;
+        ret.exp[RESISTANCE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitElectricResistance> for Value {
         let mut new: Value = self;
         if self.exp[RESISTANCE_INDEX] == 0 {
             new.v_resistance = Some(other);
-            new.exp[RESISTANCE_INDEX] = 1;
+            new.exp[RESISTANCE_INDEX] = Exponent::from(1);
             new.unit_map |= RESISTANCE_MAP;
         } else if self.exp[RESISTANCE_INDEX] == -1 {
-            new.exp[RESISTANCE_INDEX] = 0;
+            new.exp[RESISTANCE_INDEX] = Exponent::from(0);
             new.v_resistance = None;
             new.unit_map ^= RESISTANCE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitElectricResistance> for Value {
         if new.exp[RESISTANCE_INDEX] == 0 {
             new.v_resistance = Some(other);
             new.unit_map |= RESISTANCE_MAP;
-            new.exp[RESISTANCE_INDEX] = -1;
+            new.exp[RESISTANCE_INDEX] = Exponent::from(-1);
         } else if new.exp[RESISTANCE_INDEX] == 1 {
-            new.exp[RESISTANCE_INDEX] = 0;
+            new.exp[RESISTANCE_INDEX] = Exponent::from(0);
             new.v_resistance = None;
             new.unit_map ^= RESISTANCE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ENERGY_INDEX, ENERGY_MAP},
     units::UnitEnergy,
@@ -32,7 +33,7 @@ impl Mul<UnitEnergy> for f64 {
             unit_map: ENERGY_MAP,
             ..Default::default()
         };
-        ret.exp[ENERGY_INDEX] = 1;
+        ret.exp[ENERGY_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitEnergy> for f64 {
             unit_map: ENERGY_MAP,
             ..Default::default()
         };
-        ret.exp[ENERGY_INDEX] = -1;
+        ret.exp[ENERGY_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitEnergy> for Value {
         let mut new: Value = self;
         if self.exp[ENERGY_INDEX] == 0 {
             new.v_energy = Some(other);
-            new.exp[ENERGY_INDEX] = 1;
+            new.exp[ENERGY_INDEX] = Exponent::from(1);
             new.unit_map |= ENERGY_MAP;
         } else if self.exp[ENERGY_INDEX] == -1 {
-            new.exp[ENERGY_INDEX] = 0;
+            new.exp[ENERGY_INDEX] = Exponent::from(0);
             new.v_energy = None;
             new.unit_map ^= ENERGY_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitEnergy> for Value {
         if self.exp[ENERGY_INDEX] == 0 {
             new.v_energy = Some(other);
             new.unit_map |= ENERGY_MAP;
-            new.exp[ENERGY_INDEX] = -1;
+            new.exp[ENERGY_INDEX] = Exponent::from(-1);
         } else if new.exp[ENERGY_INDEX] == 1 {
-            new.exp[ENERGY_INDEX] = 0;
+            new.exp[ENERGY_INDEX] = Exponent::from(0);
             new.v_energy = None;
             new.unit_map ^= ENERGY_MAP;
         } else {
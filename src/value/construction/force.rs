@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{FORCE_INDEX, FORCE_MAP},
     units::UnitForce,
@@ -32,7 +33,7 @@ impl Mul<UnitForce> for f64 {
             unit_map: FORCE_MAP,
             ..Default::default()
         };
-        ret.exp[FORCE_INDEX] = 1;
+        ret.exp[FORCE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitForce> for f64 {
             unit_map: FORCE_MAP,
             ..Default::default()
         };
-        ret.exp[FORCE_INDEX] = -1;
+        ret.exp[FORCE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitForce> for Value {
         let mut new: Value = self;
         if self.exp[FORCE_INDEX] == 0 {
             new.v_force = Some(other);
-            new.exp[FORCE_INDEX] = 1;
+            new.exp[FORCE_INDEX] = Exponent::from(1);
             new.unit_map |= FORCE_MAP;
         } else if self.exp[FORCE_INDEX] == -1 {
-            new.exp[FORCE_INDEX] = 0;
+            new.exp[FORCE_INDEX] = Exponent::from(0);
             new.v_force = None;
             new.unit_map ^= FORCE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitForce> for Value {
         if self.exp[FORCE_INDEX] == 0 {
             new.v_force = Some(other);
             new.unit_map |= FORCE_MAP;
-            new.exp[FORCE_INDEX] = -1;
+            new.exp[FORCE_INDEX] = Exponent::from(-1);
         } else if new.exp[FORCE_INDEX] == 1 {
-            new.exp[FORCE_INDEX] = 0;
+            new.exp[FORCE_INDEX] = Exponent::from(0);
             new.v_force = None;
             new.unit_map ^= FORCE_MAP;
         } else {
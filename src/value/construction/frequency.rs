@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{FREQUENCY_INDEX, FREQUENCY_MAP},
     units::UnitFrequency,
@@ -32,7 +33,7 @@ impl Mul<UnitFrequency> for f64 {
             unit_map: FREQUENCY_MAP,
             ..Default::default()
         };
-        ret.exp[FREQUENCY_INDEX] = 1;
+        ret.exp[FREQUENCY_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitFrequency> for f64 {
             unit_map: FREQUENCY_MAP,
             ..Default::default()
         };
-        ret.exp[FREQUENCY_INDEX] = -1;
+        ret.exp[FREQUENCY_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitFrequency> for Value {
         let mut new: Value = self;
         if self.exp[FREQUENCY_INDEX] == 0 {
             new.v_frequency = Some(other);
-            new.exp[FREQUENCY_INDEX] = 1;
+            new.exp[FREQUENCY_INDEX] = Exponent::from(1);
             new.unit_map |= FREQUENCY_MAP;
         } else if self.exp[FREQUENCY_INDEX] == -1 {
-            new.exp[FREQUENCY_INDEX] = 0;
+            new.exp[FREQUENCY_INDEX] = Exponent::from(0);
             new.v_frequency = None;
             new.unit_map ^= FREQUENCY_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitFrequency> for Value {
         if self.exp[FREQUENCY_INDEX] == 0 {
             new.v_frequency = Some(other);
             new.unit_map |= FREQUENCY_MAP;
-            new.exp[FREQUENCY_INDEX] = -1;
+            new.exp[FREQUENCY_INDEX] = Exponent::from(-1);
         } else if new.exp[FREQUENCY_INDEX] == 1 {
-            new.exp[FREQUENCY_INDEX] = 0;
+            new.exp[FREQUENCY_INDEX] = Exponent::from(0);
             new.v_frequency = None;
             new.unit_map ^= FREQUENCY_MAP;
         } else {
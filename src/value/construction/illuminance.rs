@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ILLUMINANCE_INDEX, ILLUMINANCE_MAP},
     units::UnitIlluminance,
@@ -32,7 +33,7 @@ impl Mul<UnitIlluminance> for f64 {
             unit_map: ILLUMINANCE_MAP,
             ..Default::default()
         };
-        ret.exp[ILLUMINANCE_INDEX] = 1;
+        ret.exp[ILLUMINANCE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitIlluminance> for f64 {
             unit_map: ILLUMINANCE_MAP,
             ..Default::default()
         };
-        ret.exp[ILLUMINANCE_INDEX] = -1;
+        ret.exp[ILLUMINANCE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitIlluminance> for Value {
         let mut new: Value = self;
         if self.exp[ILLUMINANCE_INDEX] == 0 {
             new.v_illuminance = Some(other);
-            new.exp[ILLUMINANCE_INDEX] = 1;
+            new.exp[ILLUMINANCE_INDEX] = Exponent::from(1);
             new.unit_map |= ILLUMINANCE_MAP;
         } else if self.exp[ILLUMINANCE_INDEX] == -1 {
-            new.exp[ILLUMINANCE_INDEX] = 0;
+            new.exp[ILLUMINANCE_INDEX] = Exponent::from(0);
             new.v_illuminance = None;
             new.unit_map ^= ILLUMINANCE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitIlluminance> for Value {
         if self.exp[ILLUMINANCE_INDEX] == 0 {
             new.v_illuminance = Some(other);
             new.unit_map |= ILLUMINANCE_MAP;
-            new.exp[ILLUMINANCE_INDEX] = -1;
+            new.exp[ILLUMINANCE_INDEX] = Exponent::from(-1);
         } else if new.exp[ILLUMINANCE_INDEX] == 1 {
-            new.exp[ILLUMINANCE_INDEX] = 0;
+            new.exp[ILLUMINANCE_INDEX] = Exponent::from(0);
             new.v_illuminance = None;
             new.unit_map ^= ILLUMINANCE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{INFORMATION_INDEX, INFORMATION_MAP},
     units::{Metric, UnitInformation},
@@ -49,7 +50,7 @@ impl Mul<UnitInformation> for f64 {
             unit_map: INFORMATION_MAP,
             ..Default::default()
         };
-        ret.exp[INFORMATION_INDEX] = 1;
+        ret.exp[INFORMATION_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -80,7 +81,7 @@ impl Div<UnitInformation> for f64 {
             unit_map: INFORMATION_MAP,
             ..Default::default()
         };
-        ret.exp[INFORMATION_INDEX] = -1;
+        ret.exp[INFORMATION_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -108,10 +109,10 @@ impl Mul<UnitInformation> for Value {
         let mut new: Value = self;
         if self.exp[INFORMATION_INDEX] == 0 {
             new.v_information = Some(other);
-            new.exp[INFORMATION_INDEX] = 1;
+            new.exp[INFORMATION_INDEX] = Exponent::from(1);
             new.unit_map |= INFORMATION_MAP;
         } else if self.exp[INFORMATION_INDEX] == -1 {
-            new.exp[INFORMATION_INDEX] = 0;
+            new.exp[INFORMATION_INDEX] = Exponent::from(0);
             new.v_information = None;
             new.unit_map ^= INFORMATION_MAP;
         } else {
@@ -155,9 +156,9 @@ impl Div<UnitInformation> for Value {
         if new.exp[INFORMATION_INDEX] == 0 {
             new.v_information = Some(other);
             new.unit_map |= INFORMATION_MAP;
-            new.exp[INFORMATION_INDEX] = -1;
+            new.exp[INFORMATION_INDEX] = Exponent::from(-1);
         } else if new.exp[INFORMATION_INDEX] == 1 {
-            new.exp[INFORMATION_INDEX] = 0;
+            new.exp[INFORMATION_INDEX] = Exponent::from(0);
             new.v_information = None;
             new.unit_map ^= INFORMATION_MAP;
         } else {
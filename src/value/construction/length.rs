@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{LENGTH_INDEX, LENGTH_MAP},
     units::UnitLength,
@@ -32,7 +33,7 @@ impl Mul<UnitLength> for f64 {
             unit_map: LENGTH_MAP,
             ..Default::default()
         };
-        ret.exp[LENGTH_INDEX] = 1;
+        ret.exp[LENGTH_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitLength> for f64 {
             unit_map: LENGTH_MAP,
             ..Default::default()
         };
-        ret.exp[LENGTH_INDEX] = -1;
+        ret.exp[LENGTH_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitLength> for Value {
         let mut new: Value = self;
         if self.exp[LENGTH_INDEX] == 0 {
             new.v_length = Some(other);
-            new.exp[LENGTH_INDEX] = 1;
+            new.exp[LENGTH_INDEX] = Exponent::from(1);
             new.unit_map |= LENGTH_MAP;
         } else if self.exp[LENGTH_INDEX] == -1 {
-            new.exp[LENGTH_INDEX] = 0;
+            new.exp[LENGTH_INDEX] = Exponent::from(0);
             new.v_length = None;
             new.unit_map ^= LENGTH_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitLength> for Value {
         if self.exp[LENGTH_INDEX] == 0 {
             new.v_length = Some(other);
             new.unit_map |= LENGTH_MAP;
-            new.exp[LENGTH_INDEX] = -1;
+            new.exp[LENGTH_INDEX] = Exponent::from(-1);
         } else if self.exp[LENGTH_INDEX] == 1 {
-            new.exp[LENGTH_INDEX] = 0;
+            new.exp[LENGTH_INDEX] = Exponent::from(0);
             new.v_length = None;
             new.unit_map ^= LENGTH_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{LUMINOUS_FLUX_INDEX, LUMINOUS_FLUX_MAP},
     units::UnitLuminousFlux,
@@ -32,7 +33,7 @@ impl Mul<UnitLuminousFlux> for f64 {
             unit_map: LUMINOUS_FLUX_MAP,
             ..Default::default()
         };
-        ret.exp[LUMINOUS_FLUX_INDEX] = 1;
+        ret.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitLuminousFlux> for f64 {
             unit_map: LUMINOUS_FLUX_MAP,
             ..Default::default()
         };
-        ret.exp[LUMINOUS_FLUX_INDEX] = -1;
+        ret.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitLuminousFlux> for Value {
         let mut new: Value = self;
         if self.exp[LUMINOUS_FLUX_INDEX] == 0 {
             new.v_luminous_flux = Some(other);
-            new.exp[LUMINOUS_FLUX_INDEX] = 1;
+            new.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(1);
             new.unit_map |= LUMINOUS_FLUX_MAP;
         } else if self.exp[LUMINOUS_FLUX_INDEX] == -1 {
-            new.exp[LUMINOUS_FLUX_INDEX] = 0;
+            new.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(0);
             new.v_luminous_flux = None;
             new.unit_map ^= LUMINOUS_FLUX_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitLuminousFlux> for Value {
         if new.exp[LUMINOUS_FLUX_INDEX] == 0 {
             new.v_luminous_flux = Some(other);
             new.unit_map |= LUMINOUS_FLUX_MAP;
-            new.exp[LUMINOUS_FLUX_INDEX] = -1;
+            new.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(-1);
         } else if new.exp[LUMINOUS_FLUX_INDEX] == 1 {
-            new.exp[LUMINOUS_FLUX_INDEX] = 0;
+            new.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(0);
             new.v_luminous_flux = None;
             new.unit_map ^= LUMINOUS_FLUX_MAP;
         } else {
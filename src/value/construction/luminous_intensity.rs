@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{LUMINOUS_INTENSITY_INDEX, LUMINOUS_INTENSITY_MAP},
     units::UnitLuminousIntensity,
@@ -32,7 +33,7 @@ impl Mul<UnitLuminousIntensity> for f64 {
             unit_map: LUMINOUS_INTENSITY_MAP,
             ..Default::default()
         };
-        ret.exp[LUMINOUS_INTENSITY_INDEX] = 1;
+        ret.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitLuminousIntensity> for f64 {
             unit_map: LUMINOUS_INTENSITY_MAP,
             ..Default::default()
         };
-        ret.exp[LUMINOUS_INTENSITY_INDEX] = -1;
+        ret.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitLuminousIntensity> for Value {
         let mut new: Value = self;
         if self.exp[LUMINOUS_INTENSITY_INDEX] == 0 {
             new.v_luminous_flux_intensity = Some(other);
-            new.exp[LUMINOUS_INTENSITY_INDEX] = 1;
+            new.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(1);
             new.unit_map |= LUMINOUS_INTENSITY_MAP;
         } else if self.exp[LUMINOUS_INTENSITY_INDEX] == -1 {
-            new.exp[LUMINOUS_INTENSITY_INDEX] = 0;
+            new.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(0);
             new.v_luminous_flux_intensity = None;
             new.unit_map ^= LUMINOUS_INTENSITY_MAP;
         } else {
@@ -88,9 +89,9 @@ impl Div<UnitLuminousIntensity> for Value {
         if new.exp[LUMINOUS_INTENSITY_INDEX] == 0 {
             new.v_luminous_flux_intensity = Some(other);
             new.unit_map |= LUMINOUS_INTENSITY_MAP;
-            new.exp[LUMINOUS_INTENSITY_INDEX] = -1;
+            new.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(-1);
         } else if new.exp[LUMINOUS_INTENSITY_INDEX] == 1 {
-            new.exp[LUMINOUS_INTENSITY_INDEX] = 0;
+            new.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(0);
             new.v_luminous_flux_intensity = None;
             new.unit_map ^= LUMINOUS_INTENSITY_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{MAGNETIC_FLUX_INDEX, MAGNETIC_FLUX_MAP},
     units::UnitMagneticFlux,
@@ -32,7 +33,7 @@ impl Mul<UnitMagneticFlux> for f64 {
             unit_map: MAGNETIC_FLUX_MAP,
             ..Default::default()
         };
-        ret.exp[MAGNETIC_FLUX_INDEX] = 1;
+        ret.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitMagneticFlux> for f64 {
             unit_map: MAGNETIC_FLUX_MAP,
             ..Default::default()
         };
-        ret.exp[MAGNETIC_FLUX_INDEX] = -1;
+        ret.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitMagneticFlux> for Value {
         let mut new: Value = self;
         if self.exp[MAGNETIC_FLUX_INDEX] == 0 {
             new.v_magnetic_flux = Some(other);
-            new.exp[MAGNETIC_FLUX_INDEX] = 1;
+            new.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(1);
             new.unit_map |= MAGNETIC_FLUX_MAP;
         } else if self.exp[MAGNETIC_FLUX_INDEX] == -1 {
-            new.exp[MAGNETIC_FLUX_INDEX] = 0;
+            new.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
             new.v_magnetic_flux = None;
             new.unit_map ^= MAGNETIC_FLUX_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitMagneticFlux> for Value {
         if new.exp[MAGNETIC_FLUX_INDEX] == 0 {
             new.v_magnetic_flux = Some(other);
             new.unit_map |= MAGNETIC_FLUX_MAP;
-            new.exp[MAGNETIC_FLUX_INDEX] = -1;
+            new.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(-1);
         } else if new.exp[MAGNETIC_FLUX_INDEX] == 1 {
-            new.exp[MAGNETIC_FLUX_INDEX] = 0;
+            new.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
             new.v_magnetic_flux = None;
             new.unit_map ^= MAGNETIC_FLUX_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{MAGNETIC_FLUX_DENSITY_INDEX, MAGNETIC_FLUX_DENSITY_MAP},
     units::UnitMagneticFluxDensity,
@@ -32,7 +33,7 @@ impl Mul<UnitMagneticFluxDensity> for f64 {
             unit_map: MAGNETIC_FLUX_DENSITY_MAP,
             ..Default::default()
         };
-        ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 1;
+        ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitMagneticFluxDensity> for f64 {
             unit_map: MAGNETIC_FLUX_DENSITY_MAP,
             ..Default::default()
         };
-        ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = -1;
+        ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitMagneticFluxDensity> for Value {
         let mut new: Value = self;
         if self.exp[MAGNETIC_FLUX_DENSITY_INDEX] == 0 {
             new.v_magnetic_flux_density = Some(other);
-            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 1;
+            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(1);
             new.unit_map |= MAGNETIC_FLUX_DENSITY_MAP;
         } else if self.exp[MAGNETIC_FLUX_DENSITY_INDEX] == -1 {
-            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 0;
+            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(0);
             new.v_magnetic_flux_density = None;
             new.unit_map ^= MAGNETIC_FLUX_DENSITY_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitMagneticFluxDensity> for Value {
         if new.exp[MAGNETIC_FLUX_DENSITY_INDEX] == 0 {
             new.v_magnetic_flux_density = Some(other);
             new.unit_map |= MAGNETIC_FLUX_DENSITY_MAP;
-            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = -1;
+            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(-1);
         } else if new.exp[MAGNETIC_FLUX_DENSITY_INDEX] == 1 {
-            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 0;
+            new.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(0);
             new.v_magnetic_flux_density = None;
             new.unit_map ^= MAGNETIC_FLUX_DENSITY_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{MASS_INDEX, MASS_MAP},
     units::UnitMass,
@@ -32,7 +33,7 @@ impl Mul<UnitMass> for f64 {
             unit_map: MASS_MAP,
             ..Default::default()
         };
-        ret.exp[MASS_INDEX] = 1;
+        ret.exp[MASS_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitMass> for f64 {
             unit_map: MASS_MAP,
             ..Default::default()
         };
-        ret.exp[MASS_INDEX] = -1;
+        ret.exp[MASS_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitMass> for Value {
         let mut new: Value = self;
         if self.exp[MASS_INDEX] == 0 {
             new.v_mass = Some(other);
-            new.exp[MASS_INDEX] = 1;
+            new.exp[MASS_INDEX] = Exponent::from(1);
             new.unit_map |= MASS_MAP;
         } else if self.exp[MASS_INDEX] == -1 {
-            new.exp[MASS_INDEX] = 0;
+            new.exp[MASS_INDEX] = Exponent::from(0);
             new.v_mass = None;
             new.unit_map ^= MASS_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitMass> for Value {
         if new.exp[MASS_INDEX] == 0 {
             new.v_mass = Some(other);
             new.unit_map |= MASS_MAP;
-            new.exp[MASS_INDEX] = -1;
+            new.exp[MASS_INDEX] = Exponent::from(-1);
         } else if new.exp[MASS_INDEX] == 1 {
-            new.exp[MASS_INDEX] = 0;
+            new.exp[MASS_INDEX] = Exponent::from(0);
             new.v_mass = None;
             new.unit_map ^= MASS_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{POWER_INDEX, POWER_MAP},
     units::UnitPower,
@@ -32,7 +33,7 @@ impl Mul<UnitPower> for f64 {
             unit_map: POWER_MAP,
             ..Default::default()
         };
-        ret.exp[POWER_INDEX] = 1;
+        ret.exp[POWER_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitPower> for f64 {
             unit_map: POWER_MAP,
             ..Default::default()
         };
-        ret.exp[POWER_INDEX] = -1;
+        ret.exp[POWER_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitPower> for Value {
         let mut new: Value = self;
         if self.exp[POWER_INDEX] == 0 {
             new.v_power = Some(other);
-            new.exp[POWER_INDEX] = 1;
+            new.exp[POWER_INDEX] = Exponent::from(1);
             new.unit_map |= POWER_MAP;
         } else if self.exp[POWER_INDEX] == -1 {
-            new.exp[POWER_INDEX] = 0;
+            new.exp[POWER_INDEX] = Exponent::from(0);
             new.v_power = None;
             new.unit_map ^= POWER_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitPower> for Value {
         if new.exp[POWER_INDEX] == 0 {
             new.v_power = Some(other);
             new.unit_map |= POWER_MAP;
-            new.exp[POWER_INDEX] = -1;
+            new.exp[POWER_INDEX] = Exponent::from(-1);
         } else if new.exp[POWER_INDEX] == 1 {
-            new.exp[POWER_INDEX] = 0;
+            new.exp[POWER_INDEX] = Exponent::from(0);
             new.v_power = None;
             new.unit_map ^= POWER_MAP;
         } else {
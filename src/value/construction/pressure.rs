@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{PRESSURE_INDEX, PRESSURE_MAP},
     units::UnitPressure,
@@ -32,7 +33,7 @@ impl Mul<UnitPressure> for f64 {
             unit_map: PRESSURE_MAP,
             ..Default::default()
         };
-        ret.exp[PRESSURE_INDEX] = 1;
+        ret.exp[PRESSURE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitPressure> for f64 {
             unit_map: PRESSURE_MAP,
             ..Default::default()
         };
-        ret.exp[PRESSURE_INDEX] = -1;
+        ret.exp[PRESSURE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitPressure> for Value {
         let mut new: Value = self;
         if self.exp[PRESSURE_INDEX] == 0 {
             new.v_pressure = Some(other);
-            new.exp[PRESSURE_INDEX] = 1;
+            new.exp[PRESSURE_INDEX] = Exponent::from(1);
             new.unit_map |= PRESSURE_MAP;
         } else if self.exp[PRESSURE_INDEX] == -1 {
-            new.exp[PRESSURE_INDEX] = 0;
+            new.exp[PRESSURE_INDEX] = Exponent::from(0);
             new.v_pressure = None;
             new.unit_map ^= PRESSURE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitPressure> for Value {
         if new.exp[PRESSURE_INDEX] == 0 {
             new.v_pressure = Some(other);
             new.unit_map |= PRESSURE_MAP;
-            new.exp[PRESSURE_INDEX] = -1;
+            new.exp[PRESSURE_INDEX] = Exponent::from(-1);
         } else if new.exp[PRESSURE_INDEX] == 1 {
-            new.exp[PRESSURE_INDEX] = 0;
+            new.exp[PRESSURE_INDEX] = Exponent::from(0);
             new.v_pressure = None;
             new.unit_map ^= PRESSURE_MAP;
         } else {
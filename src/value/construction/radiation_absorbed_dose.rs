@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{ABSORBED_DOSE_INDEX, ABSORBED_DOSE_MAP},
     units::UnitAbsorbedDose,
@@ -32,7 +33,7 @@ impl Mul<UnitAbsorbedDose> for f64 {
             unit_map: ABSORBED_DOSE_MAP,
             ..Default::default()
         };
-        ret.exp[ABSORBED_DOSE_INDEX] = 1;
+        ret.exp[ABSORBED_DOSE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitAbsorbedDose> for f64 {
             unit_map: ABSORBED_DOSE_MAP,
             ..Default::default()
         };
-        ret.exp[ABSORBED_DOSE_INDEX] = -1;
+        ret.exp[ABSORBED_DOSE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitAbsorbedDose> for Value {
         let mut new: Value = self;
         if self.exp[ABSORBED_DOSE_INDEX] == 0 {
             new.v_ab_dose = Some(other);
-            new.exp[ABSORBED_DOSE_INDEX] = 1;
+            new.exp[ABSORBED_DOSE_INDEX] = Exponent::from(1);
             new.unit_map |= ABSORBED_DOSE_MAP;
         } else if self.exp[ABSORBED_DOSE_INDEX] == -1 {
-            new.exp[ABSORBED_DOSE_INDEX] = 0;
+            new.exp[ABSORBED_DOSE_INDEX] = Exponent::from(0);
             new.v_ab_dose = None;
             new.unit_map ^= ABSORBED_DOSE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitAbsorbedDose> for Value {
         if self.exp[ABSORBED_DOSE_INDEX] == 0 {
             new.v_ab_dose = Some(other);
             new.unit_map |= ABSORBED_DOSE_MAP;
-            new.exp[ABSORBED_DOSE_INDEX] = -1;
+            new.exp[ABSORBED_DOSE_INDEX] = Exponent::from(-1);
         } else if self.exp[ABSORBED_DOSE_INDEX] == 1 {
-            new.exp[ABSORBED_DOSE_INDEX] = 0;
+            new.exp[ABSORBED_DOSE_INDEX] = Exponent::from(0);
             new.v_ab_dose = None;
             new.unit_map ^= ABSORBED_DOSE_MAP;
         } else {
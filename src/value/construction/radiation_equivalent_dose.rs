@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{RADIOACTIVITY_EXPOSURE_INDEX, RADIOACTIVITY_EXPOSURE_MAP},
     units::UnitRadioactivityExposure,
@@ -32,7 +33,7 @@ impl Mul<UnitRadioactivityExposure> for f64 {
             unit_map: RADIOACTIVITY_EXPOSURE_MAP,
             ..Default::default()
         };
-        ret.exp[RADIOACTIVITY_EXPOSURE_INDEX] = 1;
+        ret.exp[RADIOACTIVITY_EXPOSURE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitRadioactivityExposure> for f64 {
             unit_map: RADIOACTIVITY_EXPOSURE_MAP,
             ..Default::default()
         };
-        ret.exp[RADIOACTIVITY_EXPOSURE_INDEX] = -1;
+        ret.exp[RADIOACTIVITY_EXPOSURE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitRadioactivityExposure> for Value {
         let mut new: Value = self;
         if self.exp[RADIOACTIVITY_EXPOSURE_INDEX] == 0 {
             new.v_radioactivity_exposure = Some(other);
-            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = 1;
+            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = Exponent::from(1);
             new.unit_map |= RADIOACTIVITY_EXPOSURE_MAP;
         } else if self.exp[RADIOACTIVITY_EXPOSURE_INDEX] == -1 {
-            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = 0;
+            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = Exponent::from(0);
             new.v_radioactivity_exposure = None;
             new.unit_map ^= RADIOACTIVITY_EXPOSURE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitRadioactivityExposure> for Value {
         if new.exp[RADIOACTIVITY_EXPOSURE_INDEX] == 0 {
             new.v_radioactivity_exposure = Some(other);
             new.unit_map |= RADIOACTIVITY_EXPOSURE_MAP;
-            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = -1;
+            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = Exponent::from(-1);
         } else if new.exp[RADIOACTIVITY_EXPOSURE_INDEX] == 1 {
-            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = 0;
+            new.exp[RADIOACTIVITY_EXPOSURE_INDEX] = Exponent::from(0);
             new.v_radioactivity_exposure = None;
             new.unit_map ^= RADIOACTIVITY_EXPOSURE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{RADIOACTIVITY_INDEX, RADIOACTIVITY_MAP},
     units::UnitRadioactivity,
@@ -32,7 +33,7 @@ impl Mul<UnitRadioactivity> for f64 {
             unit_map: RADIOACTIVITY_MAP,
             ..Default::default()
         };
-        ret.exp[RADIOACTIVITY_INDEX] = 1;
+        ret.exp[RADIOACTIVITY_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitRadioactivity> for f64 {
             unit_map: RADIOACTIVITY_MAP,
             ..Default::default()
         };
-        ret.exp[RADIOACTIVITY_INDEX] = -1;
+        ret.exp[RADIOACTIVITY_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitRadioactivity> for Value {
         let mut new: Value = self;
         if self.exp[RADIOACTIVITY_INDEX] == 0 {
             new.v_radioactivity = Some(other);
-            new.exp[RADIOACTIVITY_INDEX] = 1;
+            new.exp[RADIOACTIVITY_INDEX] = Exponent::from(1);
             new.unit_map |= RADIOACTIVITY_MAP;
         } else if self.exp[RADIOACTIVITY_INDEX] == -1 {
-            new.exp[RADIOACTIVITY_INDEX] = 0;
+            new.exp[RADIOACTIVITY_INDEX] = Exponent::from(0);
             new.v_radioactivity = None;
             new.unit_map ^= RADIOACTIVITY_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitRadioactivity> for Value {
         if new.exp[RADIOACTIVITY_INDEX] == 0 {
             new.v_radioactivity = Some(other);
             new.unit_map |= RADIOACTIVITY_MAP;
-            new.exp[RADIOACTIVITY_INDEX] = -1;
+            new.exp[RADIOACTIVITY_INDEX] = Exponent::from(-1);
         } else if new.exp[RADIOACTIVITY_INDEX] == 1 {
-            new.exp[RADIOACTIVITY_INDEX] = 0;
+            new.exp[RADIOACTIVITY_INDEX] = Exponent::from(0);
             new.v_radioactivity = None;
             new.unit_map ^= RADIOACTIVITY_MAP;
         } else {
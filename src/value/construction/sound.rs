@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{SOUND_INDEX, SOUND_MAP},
     units::UnitSound,
@@ -32,7 +33,7 @@ impl Mul<UnitSound> for f64 {
             unit_map: SOUND_MAP,
             ..Default::default()
         };
-        ret.exp[SOUND_INDEX] = 1;
+        ret.exp[SOUND_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitSound> for f64 {
             unit_map: SOUND_MAP,
             ..Default::default()
         };
-        ret.exp[SOUND_INDEX] = -1;
+        ret.exp[SOUND_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitSound> for Value {
         let mut new: Value = self;
         if self.exp[SOUND_INDEX] == 0 {
             new.v_sound = Some(other);
-            new.exp[SOUND_INDEX] = 1;
+            new.exp[SOUND_INDEX] = Exponent::from(1);
             new.unit_map |= SOUND_MAP;
         } else if self.exp[SOUND_INDEX] == -1 {
-            new.exp[SOUND_INDEX] = 0;
+            new.exp[SOUND_INDEX] = Exponent::from(0);
             new.v_sound = None;
             new.unit_map ^= SOUND_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitSound> for Value {
         if new.exp[SOUND_INDEX] == 0 {
             new.v_sound = Some(other);
             new.unit_map |= SOUND_MAP;
-            new.exp[SOUND_INDEX] = -1;
+            new.exp[SOUND_INDEX] = Exponent::from(-1);
         } else if new.exp[SOUND_INDEX] == 1 {
-            new.exp[SOUND_INDEX] = 0;
+            new.exp[SOUND_INDEX] = Exponent::from(0);
             new.v_sound = None;
             new.unit_map ^= SOUND_MAP;
         } else {
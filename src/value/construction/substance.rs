@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{SUBSTANCE_INDEX, SUBSTANCE_MAP},
     units::UnitSubstance,
@@ -32,7 +33,7 @@ impl Mul<UnitSubstance> for f64 {
             unit_map: SUBSTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[SUBSTANCE_INDEX] = 1;
+        ret.exp[SUBSTANCE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitSubstance> for f64 {
             unit_map: SUBSTANCE_MAP,
             ..Default::default()
         };
-        ret.exp[SUBSTANCE_INDEX] = -1;
+        ret.exp[SUBSTANCE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitSubstance> for Value {
         let mut new: Value = self;
         if self.exp[SUBSTANCE_INDEX] == 0 {
             new.v_substance = Some(other);
-            new.exp[SUBSTANCE_INDEX] = 1;
+            new.exp[SUBSTANCE_INDEX] = Exponent::from(1);
             new.unit_map |= SUBSTANCE_MAP;
         } else if self.exp[SUBSTANCE_INDEX] == -1 {
-            new.exp[SUBSTANCE_INDEX] = 0;
+            new.exp[SUBSTANCE_INDEX] = Exponent::from(0);
             new.v_substance = None;
             new.unit_map ^= SUBSTANCE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitSubstance> for Value {
         if self.exp[SUBSTANCE_INDEX] == 0 {
             new.v_substance = Some(other);
             new.unit_map |= SUBSTANCE_MAP;
-            new.exp[SUBSTANCE_INDEX] = -1;
+            new.exp[SUBSTANCE_INDEX] = Exponent::from(-1);
         } else if new.exp[SUBSTANCE_INDEX] == 1 {
-            new.exp[SUBSTANCE_INDEX] = 0;
+            new.exp[SUBSTANCE_INDEX] = Exponent::from(0);
             new.v_substance = None;
             new.unit_map ^= SUBSTANCE_MAP;
         } else {
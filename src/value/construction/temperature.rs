@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{TEMPERATURE_INDEX, TEMPERATURE_MAP},
     units::UnitTemperature,
@@ -32,7 +33,7 @@ impl Mul<UnitTemperature> for f64 {
             unit_map: TEMPERATURE_MAP,
             ..Default::default()
         };
-        ret.exp[TEMPERATURE_INDEX] = 1;
+        ret.exp[TEMPERATURE_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitTemperature> for f64 {
             unit_map: TEMPERATURE_MAP,
             ..Default::default()
         };
-        ret.exp[TEMPERATURE_INDEX] = -1;
+        ret.exp[TEMPERATURE_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitTemperature> for Value {
         let mut new: Value = self;
         if self.exp[TEMPERATURE_INDEX] == 0 {
             new.v_temperature = Some(other);
-            new.exp[TEMPERATURE_INDEX] = 1;
+            new.exp[TEMPERATURE_INDEX] = Exponent::from(1);
             new.unit_map |= TEMPERATURE_MAP;
         } else if self.exp[TEMPERATURE_INDEX] == -1 {
-            new.exp[TEMPERATURE_INDEX] = 0;
+            new.exp[TEMPERATURE_INDEX] = Exponent::from(0);
             new.v_temperature = None;
             new.unit_map ^= TEMPERATURE_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitTemperature> for Value {
         if self.exp[TEMPERATURE_INDEX] == 0 {
             new.v_temperature = Some(other);
             new.unit_map |= TEMPERATURE_MAP;
-            new.exp[TEMPERATURE_INDEX] = -1;
+            new.exp[TEMPERATURE_INDEX] = Exponent::from(-1);
         } else if new.exp[TEMPERATURE_INDEX] == 1 {
-            new.exp[TEMPERATURE_INDEX] = 0;
+            new.exp[TEMPERATURE_INDEX] = Exponent::from(0);
             new.v_temperature = None;
             new.unit_map ^= TEMPERATURE_MAP;
         } else {
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{TIME_INDEX, TIME_MAP},
     units::UnitTime,
@@ -32,7 +33,7 @@ impl Mul<UnitTime> for f64 {
             unit_map: TIME_MAP,
             ..Default::default()
         };
-        ret.exp[TIME_INDEX] = 1;
+        ret.exp[TIME_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitTime> for f64 {
             unit_map: TIME_MAP,
             ..Default::default()
         };
-        ret.exp[TIME_INDEX] = -1;
+        ret.exp[TIME_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitTime> for Value {
         let mut new: Value = self;
         if self.exp[TIME_INDEX] == 0 {
             new.v_time = Some(other);
-            new.exp[TIME_INDEX] = 1;
+            new.exp[TIME_INDEX] = Exponent::from(1);
             new.unit_map |= TIME_MAP;
         } else if self.exp[TIME_INDEX] == -1 {
-            new.exp[TIME_INDEX] = 0;
+            new.exp[TIME_INDEX] = Exponent::from(0);
             new.v_time = None;
             new.unit_map ^= TIME_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitTime> for Value {
         if self.exp[TIME_INDEX] == 0 {
             new.v_time = Some(other);
             new.unit_map |= TIME_MAP;
-            new.exp[TIME_INDEX] = -1;
+            new.exp[TIME_INDEX] = Exponent::from(-1);
         } else if self.exp[TIME_INDEX] == 1 {
-            new.exp[TIME_INDEX] = 0;
+            new.exp[TIME_INDEX] = Exponent::from(0);
             new.v_time = None;
             new.unit_map ^= TIME_MAP;
         } else {
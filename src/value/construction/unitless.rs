@@ -21,9 +21,10 @@ use crate::{units::UnitNone, value::Value};
 
 impl Mul<UnitNone> for f64 {
     type Output = Value;
-    fn mul(self, _: UnitNone) -> Self::Output {
+    fn mul(self, other: UnitNone) -> Self::Output {
         Value {
             val: self,
+            v_ratio: Value::_ratio_unit(other),
             ..Default::default()
         }
     }
@@ -31,25 +32,41 @@ impl Mul<UnitNone> for f64 {
 
 impl Div<UnitNone> for f64 {
     type Output = Value;
-    fn div(self, _: UnitNone) -> Self::Output {
+    fn div(self, other: UnitNone) -> Self::Output {
         Value {
             val: self,
             ..Default::default()
-        }
+        } / other
     }
 }
 
 impl Mul<UnitNone> for Value {
     type Output = Value;
-    fn mul(self, _: UnitNone) -> Self::Output {
-        self
+    fn mul(self, other: UnitNone) -> Self::Output {
+        if other == UnitNone::None {
+            return self;
+        }
+        self * (1.0 * other)
     }
 }
 
 impl Div<UnitNone> for Value {
     type Output = Value;
-    fn div(self, _: UnitNone) -> Value {
-        self
+    fn div(self, other: UnitNone) -> Value {
+        if other == UnitNone::None {
+            return self;
+        }
+        self / (1.0 * other)
+    }
+}
+
+impl Value {
+    /// Returns the ratio units stored for a [`UnitNone`]
+    fn _ratio_unit(unit: UnitNone) -> Option<UnitNone> {
+        match unit {
+            UnitNone::None => None,
+            r => Some(r),
+        }
     }
 }
 
@@ -94,4 +111,25 @@ mod construction_testing {
         assert_eq!(v.exp[USED_INDEX], 1);
         assert_eq!(v, 1.1);
     }
+
+    #[test]
+    fn ratios() {
+        let p = 5.0 * UnitNone::Percentage;
+        assert!(p.is_empty());
+        assert_eq!(p.to_string(), "5 %");
+        assert_eq!(
+            (2.0 * UnitNone::None * UnitNone::Percentage).to_string(),
+            "2 %"
+        );
+        assert_eq!(
+            (40.0 * UnitLuminousIntensity::Candela(Metric::None) * UnitNone::Percentage)
+                .to_string(),
+            "0.4 cd"
+        );
+        assert_eq!(2.0 / UnitNone::PerMille, 2000.0);
+        assert_eq!(
+            (3.0 * UnitNone::Percentage / UnitNone::Percentage).to_string(),
+            "3"
+        );
+    }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Div, Mul};
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{VOLUME_INDEX, VOLUME_MAP},
     units::UnitVolume,
@@ -32,7 +33,7 @@ impl Mul<UnitVolume> for f64 {
             unit_map: VOLUME_MAP,
             ..Default::default()
         };
-        ret.exp[VOLUME_INDEX] = 1;
+        ret.exp[VOLUME_INDEX] = Exponent::from(1);
         ret
     }
 }
@@ -46,7 +47,7 @@ impl Div<UnitVolume> for f64 {
             unit_map: VOLUME_MAP,
             ..Default::default()
         };
-        ret.exp[VOLUME_INDEX] = -1;
+        ret.exp[VOLUME_INDEX] = Exponent::from(-1);
         ret
     }
 }
@@ -57,10 +58,10 @@ impl Mul<UnitVolume> for Value {
         let mut new: Value = self;
         if self.exp[VOLUME_INDEX] == 0 {
             new.v_volume = Some(other);
-            new.exp[VOLUME_INDEX] = 1;
+            new.exp[VOLUME_INDEX] = Exponent::from(1);
             new.unit_map |= VOLUME_MAP;
         } else if self.exp[VOLUME_INDEX] == -1 {
-            new.exp[VOLUME_INDEX] = 0;
+            new.exp[VOLUME_INDEX] = Exponent::from(0);
             new.v_volume = None;
             new.unit_map ^= VOLUME_MAP;
         } else {
@@ -87,9 +88,9 @@ impl Div<UnitVolume> for Value {
         if self.exp[VOLUME_INDEX] == 0 {
             new.v_volume = Some(other);
             new.unit_map |= VOLUME_MAP;
-            new.exp[VOLUME_INDEX] = -1;
+            new.exp[VOLUME_INDEX] = Exponent::from(-1);
         } else if new.exp[VOLUME_INDEX] == 1 {
-            new.exp[VOLUME_INDEX] = 0;
+            new.exp[VOLUME_INDEX] = Exponent::from(0);
             new.v_volume = None;
             new.unit_map ^= VOLUME_MAP;
         } else {
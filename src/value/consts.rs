@@ -15,6 +15,7 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{
         CAPACITANCE_INDEX, CAPACITANCE_MAP, ELECTRIC_CHARGE_INDEX, ELECTRIC_CHARGE_MAP,
@@ -27,7 +28,7 @@ use crate::{
     },
     units::{
         Metric, UnitElectricCapacitance, UnitElectricCharge, UnitEnergy, UnitFrequency, UnitLength,
-        UnitMass, UnitSubstance, UnitTemperature, UnitTime,
+        UnitMass, UnitSubstance, UnitTemperature, UnitTime, custom::MAX_CUSTOM_DIMENSIONS,
     },
     value::Value,
 };
@@ -37,9 +38,9 @@ pub const EARTH_GRAVITY: Value = Value {
     val: VAL_EARTH_GRAV,
     unit_map: LENGTH_MAP | TIME_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[LENGTH_INDEX] = 1;
-        r[TIME_INDEX] = -2;
+        let mut r = [Exponent::ZERO; 31];
+        r[LENGTH_INDEX] = Exponent::from_int(1);
+        r[TIME_INDEX] = Exponent::from_int(-2);
         r
     },
     v_ab_dose: None,
@@ -73,6 +74,8 @@ pub const EARTH_GRAVITY: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be absolute zero
@@ -80,8 +83,8 @@ pub const ABS_ZERO: Value = Value {
     val: VAL_ABS_ZERO,
     unit_map: TEMPERATURE_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[TEMPERATURE_INDEX] = 1;
+        let mut r = [Exponent::ZERO; 31];
+        r[TEMPERATURE_INDEX] = Exponent::from_int(1);
         r
     },
     v_ab_dose: None,
@@ -115,6 +118,8 @@ pub const ABS_ZERO: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be Avogadro's Number
@@ -122,8 +127,8 @@ pub const AVOGADROS_NUMBER: Value = Value {
     val: VAL_AVOGADROS,
     unit_map: SUBSTANCE_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[SUBSTANCE_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[SUBSTANCE_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -157,6 +162,8 @@ pub const AVOGADROS_NUMBER: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be Faraday's Constant
@@ -164,9 +171,9 @@ pub const FARADAY: Value = Value {
     val: VAL_FARADAY,
     unit_map: SUBSTANCE_MAP | ELECTRIC_CHARGE_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[ELECTRIC_CHARGE_INDEX] = 1;
-        r[SUBSTANCE_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[ELECTRIC_CHARGE_INDEX] = Exponent::from_int(1);
+        r[SUBSTANCE_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -200,6 +207,8 @@ pub const FARADAY: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the Atomic Mass Constant
@@ -207,8 +216,8 @@ pub const ATOMIC_MASS: Value = Value {
     val: VAL_ATOMIC_MASS,
     unit_map: MASS_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[MASS_INDEX] = 1;
+        let mut r = [Exponent::ZERO; 31];
+        r[MASS_INDEX] = Exponent::from_int(1);
         r
     },
     v_ab_dose: None,
@@ -242,6 +251,8 @@ pub const ATOMIC_MASS: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the molar gas constant
@@ -249,10 +260,10 @@ pub const MOLAR_GAS: Value = Value {
     val: VAL_MOLAR_GAS,
     unit_map: SUBSTANCE_MAP | TEMPERATURE_MAP | ENERGY_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[ENERGY_INDEX] = 1;
-        r[TEMPERATURE_INDEX] = -1;
-        r[SUBSTANCE_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[ENERGY_INDEX] = Exponent::from_int(1);
+        r[TEMPERATURE_INDEX] = Exponent::from_int(-1);
+        r[SUBSTANCE_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -286,6 +297,8 @@ pub const MOLAR_GAS: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be Coulomb's Constant
@@ -293,8 +306,8 @@ pub const COULOMB: Value = Value {
     val: VAL_COULOMBS,
     unit_map: SUBSTANCE_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[SUBSTANCE_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[SUBSTANCE_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -328,6 +341,8 @@ pub const COULOMB: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the speed of light
@@ -335,9 +350,9 @@ pub const SPEED_OF_LIGHT: Value = Value {
     val: VAL_LIGHT_SPEED,
     unit_map: TIME_MAP | LENGTH_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[LENGTH_INDEX] = 1;
-        r[TIME_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[LENGTH_INDEX] = Exponent::from_int(1);
+        r[TIME_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -371,6 +386,8 @@ pub const SPEED_OF_LIGHT: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the Boltzmann Constant
@@ -378,9 +395,9 @@ pub const BOLTZMANN: Value = Value {
     val: VAL_BOLTZMANN,
     unit_map: ENERGY_MAP | TEMPERATURE_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[ENERGY_INDEX] = 1;
-        r[TEMPERATURE_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[ENERGY_INDEX] = Exponent::from_int(1);
+        r[TEMPERATURE_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -414,6 +431,8 @@ pub const BOLTZMANN: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the newtonian gravitational constant
@@ -421,10 +440,10 @@ pub const NEWTONIAN_GRAVITATION: Value = Value {
     val: VAL_NEWTONIAN_GRAVITATION,
     unit_map: LENGTH_MAP | MASS_MAP | TIME_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[LENGTH_INDEX] = 3;
-        r[TIME_INDEX] = -2;
-        r[MASS_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[LENGTH_INDEX] = Exponent::from_int(3);
+        r[TIME_INDEX] = Exponent::from_int(-2);
+        r[MASS_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -458,6 +477,8 @@ pub const NEWTONIAN_GRAVITATION: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the charge of an electron
@@ -465,8 +486,8 @@ pub const ELECTRON_CHARGE: Value = Value {
     val: VAL_ELECTRON_CHARGE,
     unit_map: ELECTRIC_CHARGE_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[ELECTRIC_CHARGE_INDEX] = 1;
+        let mut r = [Exponent::ZERO; 31];
+        r[ELECTRIC_CHARGE_INDEX] = Exponent::from_int(1);
         r
     },
     v_ab_dose: None,
@@ -500,6 +521,8 @@ pub const ELECTRON_CHARGE: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the Rydberg Constant
@@ -507,8 +530,8 @@ pub const RYDBERG: Value = Value {
     val: VAL_RYDBERG,
     unit_map: LENGTH_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[LENGTH_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[LENGTH_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -542,6 +565,8 @@ pub const RYDBERG: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the Plank Constant
@@ -549,9 +574,9 @@ pub const PLANK: Value = Value {
     val: VAL_PLANKS,
     unit_map: ENERGY_MAP | FREQUENCY_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[ENERGY_INDEX] = 1;
-        r[FREQUENCY_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[ENERGY_INDEX] = Exponent::from_int(1);
+        r[FREQUENCY_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -585,6 +610,8 @@ pub const PLANK: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 /// Defines a `Value` preset to be the Vacuum Electric Permittivity Constant
@@ -592,9 +619,9 @@ pub const VACUUM_PERMITTIVITY: Value = Value {
     val: VAL_VACUUM_ELECTRIC_PERMITTIVITY,
     unit_map: LENGTH_MAP | CAPACITANCE_MAP,
     exp: {
-        let mut r = [0; 31];
-        r[CAPACITANCE_INDEX] = 1;
-        r[LENGTH_INDEX] = -1;
+        let mut r = [Exponent::ZERO; 31];
+        r[CAPACITANCE_INDEX] = Exponent::from_int(1);
+        r[LENGTH_INDEX] = Exponent::from_int(-1);
         r
     },
     v_ab_dose: None,
@@ -628,6 +655,8 @@ pub const VACUUM_PERMITTIVITY: Value = Value {
     v_magnetic_flux: None,
     v_magnetic_flux_density: None,
     v_solid_angle: None,
+    v_ratio: None,
+    v_custom: [None; MAX_CUSTOM_DIMENSIONS],
 };
 
 #[cfg(test)]
@@ -0,0 +1,334 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::ops::{Div, Mul};
+
+use crate::{
+    consts::CUSTOM_MAP,
+    errors::V3Error,
+    units::{Convert, UnitCustom, custom::MAX_CUSTOM_DIMENSIONS},
+    value::{
+        Value,
+        exponent::{Exponent, PowRational},
+        numeric::{_scale, Numeric},
+    },
+};
+
+impl Mul<UnitCustom> for f64 {
+    type Output = Value;
+    fn mul(self, other: UnitCustom) -> Self::Output {
+        let mut ret: Value = Value::_unitless(self);
+        ret._set_custom(other, Exponent::ONE);
+        ret
+    }
+}
+
+impl Div<UnitCustom> for f64 {
+    type Output = Value;
+    fn div(self, other: UnitCustom) -> Self::Output {
+        let mut ret: Value = Value::_unitless(self);
+        ret._set_custom(other, -Exponent::ONE);
+        ret
+    }
+}
+
+impl Mul<UnitCustom> for Value {
+    type Output = Value;
+    fn mul(self, other: UnitCustom) -> Self::Output {
+        self * (1.0 * other)
+    }
+}
+
+impl Div<UnitCustom> for Value {
+    type Output = Value;
+    fn div(self, other: UnitCustom) -> Self::Output {
+        self / (1.0 * other)
+    }
+}
+
+impl<T> Value<T> {
+    /// Returns the unit map bits of the user-defined dimensions held by a [`Value`]
+    pub(in crate::value) fn _custom_map(&self) -> usize {
+        self.v_custom
+            .iter()
+            .flatten()
+            .fold(0, |m, (u, _)| m | CUSTOM_MAP << u.index())
+    }
+}
+
+impl<T: Numeric> Value<T> {
+    /// Returns `true` if a [`Value`] has any unit of a user-defined dimension
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::units::custom::register_dimension;
+    /// use bxvl::value::Value;
+    ///
+    /// let event = register_dimension("events", &[("event", 1.0)]).unwrap();
+    /// let v:Value = 4.0 * event / Value::new(1.0, "m^2").unwrap();
+    /// assert!(v.has_custom_units());
+    /// assert_eq!(v.to_string(), "4 event/m^2");
+    /// ```
+    pub fn has_custom_units(&self) -> bool {
+        self.unit_map >= CUSTOM_MAP
+    }
+
+    /// Assigns a unit of a user-defined dimension with an exponent, or removes it if zero
+    pub(in crate::value) fn _set_custom(&mut self, unit: UnitCustom, exp: Exponent) {
+        let i: usize = unit.index();
+        if exp == 0 {
+            self.v_custom[i] = None;
+            self.unit_map &= !(CUSTOM_MAP << i);
+        } else {
+            self.v_custom[i] = Some((unit, exp));
+            self.unit_map |= CUSTOM_MAP << i;
+        }
+    }
+
+    /// Returns the exponents of the user-defined dimensions of a [`Value`]
+    pub(in crate::value) fn _custom_exps(&self) -> [Exponent; MAX_CUSTOM_DIMENSIONS] {
+        self.v_custom.map(|c| c.map_or(Exponent::ZERO, |(_, e)| e))
+    }
+
+    /// Adds the user-defined dimensions of `other` into a product or quotient held by `self`
+    ///
+    /// Returns the factor converting the numeric of `other` into the units already present.
+    pub(in crate::value) fn _combine_custom<U>(&mut self, other: &Value<U>, divide: bool) -> f64 {
+        // products rebuild the unit map of the built-in units only
+        self.unit_map |= self._custom_map();
+        let mut factor: f64 = 1.0;
+        for (o, o_exp) in other.v_custom.into_iter().flatten() {
+            let (u, exp): (UnitCustom, Exponent) = match self.v_custom[o.index()] {
+                Some((u, exp)) => {
+                    if u != o {
+                        factor *= o.convert(&u).powr(o_exp);
+                    }
+                    (u, exp)
+                }
+                None => (o, Exponent::ZERO),
+            };
+            self._set_custom(u, if divide { exp - o_exp } else { exp + o_exp });
+        }
+        factor
+    }
+
+    /// Returns the factor converting the user-defined units of `self` into those of `other`
+    ///
+    /// Both [`Value`]s are expected to have the same user-defined dimensions.
+    pub(in crate::value) fn _custom_factor_to<U>(&self, other: &Value<U>) -> f64 {
+        let mut factor: f64 = 1.0;
+        for (u, exp) in self.v_custom.into_iter().flatten() {
+            if let Some((o, _)) = other.v_custom[u.index()]
+                && u != o
+            {
+                factor *= u.convert(&o).powr(exp);
+            }
+        }
+        factor
+    }
+
+    /// Converts the user-defined units of a [`Value`] into those of `other`
+    pub(in crate::value) fn _convert_custom<U: Numeric>(
+        &mut self,
+        other: &Value<U>,
+    ) -> Result<(), V3Error> {
+        if self._custom_exps() != other._custom_exps() {
+            return Err(V3Error::ValueConversionError(
+                "[_convert] Mismatched value exponents",
+            ));
+        }
+        self.val = _scale(self.val, self._custom_factor_to(other))?;
+        self.v_custom = other.v_custom;
+        Ok(())
+    }
+
+    /// Multiplies the exponents of the user-defined dimensions by a power
+    pub(in crate::value) fn _pow_custom(&mut self, p: Exponent) -> Result<(), V3Error> {
+        for (u, exp) in self.v_custom.into_iter().flatten() {
+            self._set_custom(u, exp.checked_mul(p)?);
+        }
+        Ok(())
+    }
+
+    /// Appends the user-defined units of a [`Value`] to the numerator and denominator strings
+    pub(in crate::value) fn _custom_unit_strings(
+        &self,
+        nums: &mut Vec<String>,
+        denoms: &mut Vec<String>,
+    ) {
+        for (u, exp) in self.v_custom.into_iter().flatten() {
+            if exp < 0 {
+                denoms.push(Value::_unit_power(u.to_string(), -exp));
+            } else {
+                nums.push(Value::_unit_power(u.to_string(), exp));
+            }
+        }
+    }
+}
+
+/// Serializes the user-defined units of a [`Value`] as a list of units and exponents
+///
+/// Units are stored by symbol and placed by the dimensions registered when deserialized.
+pub(in crate::value) mod custom_units {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use crate::{
+        units::{UnitCustom, custom::MAX_CUSTOM_DIMENSIONS},
+        value::exponent::Exponent,
+    };
+
+    /// Serializes the units that are present
+    pub fn serialize<S: Serializer>(
+        units: &[Option<(UnitCustom, Exponent)>; MAX_CUSTOM_DIMENSIONS],
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        let present: Vec<(UnitCustom, Exponent)> = units.iter().flatten().copied().collect();
+        present.serialize(s)
+    }
+
+    /// Deserializes the units and places them by dimension
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<[Option<(UnitCustom, Exponent)>; MAX_CUSTOM_DIMENSIONS], D::Error> {
+        let mut units: [Option<(UnitCustom, Exponent)>; MAX_CUSTOM_DIMENSIONS] =
+            [None; MAX_CUSTOM_DIMENSIONS];
+        for (u, exp) in Vec::<(UnitCustom, Exponent)>::deserialize(d)? {
+            if exp == 0 || units[u.index()].is_some() {
+                return Err(D::Error::custom(format!("Invalid exponent for unit {u}")));
+            }
+            units[u.index()] = Some((u, exp));
+        }
+        Ok(units)
+    }
+}
+
+#[cfg(test)]
+mod custom_testing {
+    use crate::{
+        units::{
+            UnitCustom, UnitTime,
+            custom::{CustomDimension, MAX_CUSTOM_UNITS, register_dimension},
+        },
+        value::Value,
+    };
+
+    const REQUESTS: CustomDimension =
+        CustomDimension::new("requests", &[("req", 1.0), ("kreq", 1000.0)]);
+
+    #[test]
+    fn parse_convert_display() {
+        REQUESTS.register().unwrap();
+        let r: Value = Value::new(1200.0, "req/min").unwrap();
+        let s: Value = (r >> "req/s").unwrap();
+        assert_eq!(s.to_string(), "20 req/s");
+        assert_eq!(s.unit_string(), "req/s");
+        assert!(s.has_custom_units());
+
+        let k: Value = Value::new(5.0, "kreq").unwrap();
+        assert_eq!((k >> "req").unwrap().val, 5000.0);
+        assert_eq!(Value::new(2.0, "1/req^2").unwrap().to_string(), "2 1/req^2");
+        assert!((k >> "m").is_err());
+        assert!((k >> "req^2").is_err());
+        assert!((r >> "s").is_err());
+        assert!(!Value::new(1.0, "m*req").unwrap().is_length());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let req: UnitCustom = REQUESTS.unit("req").unwrap();
+        let kreq: UnitCustom = REQUESTS.unit("kreq").unwrap();
+        assert!(REQUESTS.unit("m").is_err());
+
+        let rate: Value = 20.0 * req / UnitTime::Second(crate::units::Metric::None);
+        let total: Value = rate * Value::new(2.0, "min").unwrap();
+        assert_eq!(total.to_string(), "2400 req");
+
+        let ratio: Value = total / (1.2 * kreq);
+        assert!(!ratio.has_custom_units());
+        assert!(ratio.is_empty());
+        assert!((ratio.val - 2.0).abs() < 1e-12);
+
+        let sum: Value = 1.0 * kreq + 500.0 * req;
+        assert_eq!(sum, 1.5 * kreq);
+        assert!(1.0 * kreq > 999.0 * req);
+        assert!(
+            (1.0 * kreq)
+                .try_add(&Value::new(1.0, "m").unwrap())
+                .is_err()
+        );
+        assert_eq!(
+            (1.0 * kreq).canonical_key().unwrap(),
+            (1000.0 * req).canonical_key().unwrap()
+        );
+
+        let sq: Value = (3.0 * req).powv(2);
+        assert_eq!(sq.to_string(), "9 req^2");
+        assert_eq!(sq.sqrt(), 3.0 * req);
+        let mut inv: Value = 4.0 * req;
+        inv.inv();
+        assert_eq!(inv.to_string(), "0.25 1/req");
+    }
+
+    #[test]
+    fn registration() {
+        let req: UnitCustom = REQUESTS.register().unwrap();
+        assert_eq!(
+            register_dimension("requests", &[("req", 1.0), ("kreq", 1000.0)]).unwrap(),
+            req
+        );
+        assert_eq!(req.dimension(), "requests");
+        assert_eq!(UnitCustom::find("kreq").unwrap().base_unit(), req);
+
+        assert!(register_dimension("requests", &[("req", 1.0)]).is_err());
+        assert!(register_dimension("lengths", &[("m", 1.0)]).is_err());
+        assert!(register_dimension("queries", &[("req", 1.0)]).is_err());
+        assert!(register_dimension("queries", &[("q ry", 1.0)]).is_err());
+        assert!(register_dimension("queries", &[("qry", 2.0)]).is_err());
+        assert!(register_dimension("queries", &[("qry", 1.0), ("kqry", 0.0)]).is_err());
+        assert!(register_dimension("queries", &[]).is_err());
+        assert!(UnitCustom::find("qry").is_none());
+
+        let symbols: Vec<String> = (0..=MAX_CUSTOM_UNITS)
+            .map(|i| {
+                format!(
+                    "qry{}{}",
+                    (b'a' + (i / 26) as u8) as char,
+                    (b'a' + (i % 26) as u8) as char
+                )
+            })
+            .collect();
+        let units: Vec<(&str, f64)> = symbols.iter().map(|s| (s.as_str(), 1.0)).collect();
+        assert!(register_dimension("queries", &units).is_err());
+        assert!(register_dimension("queries", &units[..MAX_CUSTOM_UNITS]).is_ok());
+        assert_eq!(
+            UnitCustom::find(&symbols[MAX_CUSTOM_UNITS - 1])
+                .unwrap()
+                .to_string(),
+            symbols[MAX_CUSTOM_UNITS - 1]
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let kreq: UnitCustom = REQUESTS.unit("kreq").unwrap();
+        let v: Value = 3.0 * kreq / Value::new(1.0, "s^2").unwrap();
+        let s: String = toml::to_string(&v).unwrap();
+        let d: Value = toml::from_str(&s).unwrap();
+        assert_eq!(d, v);
+        assert_eq!(d.to_string(), "3 kreq/s^2");
+    }
+}
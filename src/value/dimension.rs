@@ -0,0 +1,473 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::Display;
+
+use crate::{
+    consts::*,
+    units::{UnitCustom, custom::MAX_CUSTOM_DIMENSIONS},
+    value::{Value, exponent::Exponent},
+};
+
+/// The base dimensions of a [`Value`]
+///
+/// Holds the exponents of the seven SI base dimensions, along with angles, information
+/// and any user-defined dimension. Derived units are reduced into their definitions, so
+/// `N` and `kg*m/s^2` have the same [`Dimension`].
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::dimension::Dimension;
+/// let d:Dimension = Value::new(1.0, "N").unwrap().dimension();
+/// assert_eq!(d, Value::new(1.0, "lb*ft/s^2").unwrap().dimension());
+/// assert_eq!(d.time, -2);
+/// assert_eq!(d.to_string(), "L M T^-2");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimension {
+    /// Length, `L`
+    pub length: Exponent,
+    /// Mass, `M`
+    pub mass: Exponent,
+    /// Time, `T`
+    pub time: Exponent,
+    /// Electric current, `I`
+    pub current: Exponent,
+    /// Thermodynamic temperature, `Θ`
+    pub temperature: Exponent,
+    /// Amount of substance, `N`
+    pub substance: Exponent,
+    /// Luminous intensity, `J`
+    pub luminous_intensity: Exponent,
+    /// Plane angle, with solid angles counted as squared angles
+    pub angle: Exponent,
+    /// Information
+    pub information: Exponent,
+    /// User-defined dimensions, by the base unit of each dimension
+    custom: [Option<(UnitCustom, Exponent)>; MAX_CUSTOM_DIMENSIONS],
+}
+
+impl Dimension {
+    /// Returns `true` if every exponent of the [`Dimension`] is zero
+    pub fn is_dimensionless(&self) -> bool {
+        *self == Dimension::default()
+    }
+
+    /// Returns the exponent of a user-defined dimension given any of its units
+    pub fn custom(&self, unit: UnitCustom) -> Exponent {
+        self.custom[unit.index()].map_or(Exponent::ZERO, |(_, e)| e)
+    }
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts: Vec<String> = vec![];
+        for (symbol, exp) in [
+            ("L", self.length),
+            ("M", self.mass),
+            ("T", self.time),
+            ("I", self.current),
+            ("Θ", self.temperature),
+            ("N", self.substance),
+            ("J", self.luminous_intensity),
+            ("angle", self.angle),
+            ("information", self.information),
+        ] {
+            if exp != 0 {
+                parts.push(Value::_unit_power(symbol.into(), exp));
+            }
+        }
+        for (u, exp) in self.custom.into_iter().flatten() {
+            parts.push(Value::_unit_power(u.dimension(), exp));
+        }
+        if parts.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(f, "{}", parts.join(" "))
+        }
+    }
+}
+
+/// The kinds of quantity a [`Value`] can be recognized as
+///
+/// Kinds that share a [`Dimension`], such as [`QuantityKind::Energy`] and
+/// [`QuantityKind::Torque`], cannot be told apart by their units alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuantityKind {
+    /// A pure number, ratio or level
+    Dimensionless,
+    /// Length
+    Length,
+    /// Mass
+    Mass,
+    /// Time
+    Time,
+    /// Electric current
+    ElectricCurrent,
+    /// Temperature
+    Temperature,
+    /// Amount of substance
+    Substance,
+    /// Luminous intensity
+    LuminousIntensity,
+    /// Plane angle
+    Angle,
+    /// Solid angle
+    SolidAngle,
+    /// Information
+    Information,
+    /// Area
+    Area,
+    /// Volume
+    Volume,
+    /// Velocity
+    Velocity,
+    /// Acceleration
+    Acceleration,
+    /// Mass density
+    Density,
+    /// Frequency
+    Frequency,
+    /// Radioactivity
+    Radioactivity,
+    /// Force
+    Force,
+    /// Pressure
+    Pressure,
+    /// Energy
+    Energy,
+    /// Torque
+    Torque,
+    /// Power
+    Power,
+    /// Electric charge
+    ElectricCharge,
+    /// Electric potential
+    ElectricPotential,
+    /// Electric capacitance
+    ElectricCapacitance,
+    /// Electric resistance
+    ElectricResistance,
+    /// Electric conductance
+    ElectricConductance,
+    /// Magnetic flux
+    MagneticFlux,
+    /// Magnetic flux density
+    MagneticFluxDensity,
+    /// Electric inductance
+    ElectricInductance,
+    /// Catalytic activity
+    CatalyticActivity,
+    /// Luminous flux
+    LuminousFlux,
+    /// Illuminance
+    Illuminance,
+    /// Absorbed dose of ionizing radiation
+    AbsorbedDose,
+    /// Equivalent dose of ionizing radiation
+    EquivalentDose,
+}
+
+impl QuantityKind {
+    /// Every [`QuantityKind`], in the order returned by [`Value::quantity_kinds`]
+    pub const ALL: [QuantityKind; 36] = [
+        QuantityKind::Dimensionless,
+        QuantityKind::Length,
+        QuantityKind::Mass,
+        QuantityKind::Time,
+        QuantityKind::ElectricCurrent,
+        QuantityKind::Temperature,
+        QuantityKind::Substance,
+        QuantityKind::LuminousIntensity,
+        QuantityKind::Angle,
+        QuantityKind::SolidAngle,
+        QuantityKind::Information,
+        QuantityKind::Area,
+        QuantityKind::Volume,
+        QuantityKind::Velocity,
+        QuantityKind::Acceleration,
+        QuantityKind::Density,
+        QuantityKind::Frequency,
+        QuantityKind::Radioactivity,
+        QuantityKind::Force,
+        QuantityKind::Pressure,
+        QuantityKind::Energy,
+        QuantityKind::Torque,
+        QuantityKind::Power,
+        QuantityKind::ElectricCharge,
+        QuantityKind::ElectricPotential,
+        QuantityKind::ElectricCapacitance,
+        QuantityKind::ElectricResistance,
+        QuantityKind::ElectricConductance,
+        QuantityKind::MagneticFlux,
+        QuantityKind::MagneticFluxDensity,
+        QuantityKind::ElectricInductance,
+        QuantityKind::CatalyticActivity,
+        QuantityKind::LuminousFlux,
+        QuantityKind::Illuminance,
+        QuantityKind::AbsorbedDose,
+        QuantityKind::EquivalentDose,
+    ];
+
+    /// Returns the SI units that define the [`QuantityKind`]
+    pub fn si_units(&self) -> &'static str {
+        match self {
+            QuantityKind::Dimensionless => "",
+            QuantityKind::Length => "m",
+            QuantityKind::Mass => "kg",
+            QuantityKind::Time => "s",
+            QuantityKind::ElectricCurrent => "A",
+            QuantityKind::Temperature => "K",
+            QuantityKind::Substance => "mol",
+            QuantityKind::LuminousIntensity => "cd",
+            QuantityKind::Angle => "rad",
+            QuantityKind::SolidAngle => "sr",
+            QuantityKind::Information => "bits",
+            QuantityKind::Area => "m^2",
+            QuantityKind::Volume => "m^3",
+            QuantityKind::Velocity => "m/s",
+            QuantityKind::Acceleration => "m/s^2",
+            QuantityKind::Density => "kg/m^3",
+            QuantityKind::Frequency => "Hz",
+            QuantityKind::Radioactivity => "Bq",
+            QuantityKind::Force => "N",
+            QuantityKind::Pressure => "Pa",
+            QuantityKind::Energy => "J",
+            QuantityKind::Torque => "N*m",
+            QuantityKind::Power => "W",
+            QuantityKind::ElectricCharge => "C",
+            QuantityKind::ElectricPotential => "V",
+            QuantityKind::ElectricCapacitance => "F",
+            QuantityKind::ElectricResistance => "Ω",
+            QuantityKind::ElectricConductance => "S",
+            QuantityKind::MagneticFlux => "Wb",
+            QuantityKind::MagneticFluxDensity => "T",
+            QuantityKind::ElectricInductance => "H",
+            QuantityKind::CatalyticActivity => "kat",
+            QuantityKind::LuminousFlux => "lm",
+            QuantityKind::Illuminance => "lx",
+            QuantityKind::AbsorbedDose => "Gy",
+            QuantityKind::EquivalentDose => "Sv",
+        }
+    }
+
+    /// Returns the [`Dimension`] of the [`QuantityKind`]
+    pub fn dimension(&self) -> Dimension {
+        Value::new(1.0, self.si_units()).unwrap().dimension()
+    }
+}
+
+impl Display for QuantityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name: &str = match self {
+            QuantityKind::Dimensionless => "dimensionless",
+            QuantityKind::Length => "length",
+            QuantityKind::Mass => "mass",
+            QuantityKind::Time => "time",
+            QuantityKind::ElectricCurrent => "electric current",
+            QuantityKind::Temperature => "temperature",
+            QuantityKind::Substance => "amount of substance",
+            QuantityKind::LuminousIntensity => "luminous intensity",
+            QuantityKind::Angle => "angle",
+            QuantityKind::SolidAngle => "solid angle",
+            QuantityKind::Information => "information",
+            QuantityKind::Area => "area",
+            QuantityKind::Volume => "volume",
+            QuantityKind::Velocity => "velocity",
+            QuantityKind::Acceleration => "acceleration",
+            QuantityKind::Density => "density",
+            QuantityKind::Frequency => "frequency",
+            QuantityKind::Radioactivity => "radioactivity",
+            QuantityKind::Force => "force",
+            QuantityKind::Pressure => "pressure",
+            QuantityKind::Energy => "energy",
+            QuantityKind::Torque => "torque",
+            QuantityKind::Power => "power",
+            QuantityKind::ElectricCharge => "electric charge",
+            QuantityKind::ElectricPotential => "electric potential",
+            QuantityKind::ElectricCapacitance => "electric capacitance",
+            QuantityKind::ElectricResistance => "electric resistance",
+            QuantityKind::ElectricConductance => "electric conductance",
+            QuantityKind::MagneticFlux => "magnetic flux",
+            QuantityKind::MagneticFluxDensity => "magnetic flux density",
+            QuantityKind::ElectricInductance => "electric inductance",
+            QuantityKind::CatalyticActivity => "catalytic activity",
+            QuantityKind::LuminousFlux => "luminous flux",
+            QuantityKind::Illuminance => "illuminance",
+            QuantityKind::AbsorbedDose => "absorbed dose",
+            QuantityKind::EquivalentDose => "equivalent dose",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Value {
+    /// Returns the base [`Dimension`] of a [`Value`]
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(3.0, "kJ/hr").unwrap();
+    /// assert_eq!(v.dimension(), Value::new(1.0, "W").unwrap().dimension());
+    /// assert_eq!(v.dimension().to_string(), "L^2 M T^-3");
+    /// ```
+    pub fn dimension(&self) -> Dimension {
+        let v: Value = self._with(1.0)._reduce_derived();
+        let mut d: Dimension = Dimension::default();
+        for i in 0..31_usize {
+            let region: usize = 1 << i;
+            if v.unit_map & region == 0 {
+                continue;
+            }
+            let exp: Exponent = v.exp[i];
+            match region {
+                LENGTH_MAP => d.length += exp,
+                MASS_MAP => d.mass += exp,
+                TIME_MAP => d.time += exp,
+                ELECTRIC_CURRENT_MAP => d.current += exp,
+                TEMPERATURE_MAP => d.temperature += exp,
+                SUBSTANCE_MAP => d.substance += exp,
+                LUMINOUS_INTENSITY_MAP => d.luminous_intensity += exp,
+                ANGLE_MAP => d.angle += exp,
+                SOLID_ANGLE_MAP => d.angle += exp * 2,
+                INFORMATION_MAP => d.information += exp,
+                RADIOACTIVITY_MAP => d.time -= exp,
+                ABSORBED_DOSE_MAP | RADIOACTIVITY_EXPOSURE_MAP => {
+                    d.length += exp * 2;
+                    d.time -= exp * 2;
+                }
+                // levels are logarithmic ratios and carry no dimension
+                _ => {}
+            }
+        }
+        for (u, exp) in v.v_custom.into_iter().flatten() {
+            d.custom[u.index()] = Some((u.base_unit(), exp));
+        }
+        d
+    }
+
+    /// Returns every [`QuantityKind`] that a [`Value`] can be recognized as
+    ///
+    /// The list is empty if the units match no known kind, and holds several kinds when
+    /// they share a [`Dimension`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::dimension::QuantityKind;
+    /// let f:Value = Value::new(1.0, "kg*m/s^2").unwrap();
+    /// assert_eq!(f.quantity_kinds(), vec![QuantityKind::Force]);
+    ///
+    /// let t:Value = Value::new(1.0, "lbfr*ft").unwrap();
+    /// assert_eq!(t.quantity_kinds(), vec![QuantityKind::Energy, QuantityKind::Torque]);
+    /// assert!(Value::new(1.0, "m^5").unwrap().quantity_kinds().is_empty());
+    /// ```
+    pub fn quantity_kinds(&self) -> Vec<QuantityKind> {
+        let d: Dimension = self.dimension();
+        QuantityKind::ALL
+            .into_iter()
+            .filter(|k| k.dimension() == d)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod dimension_testing {
+    use crate::{
+        units::custom::register_dimension,
+        value::{
+            Value,
+            dimension::{Dimension, QuantityKind},
+        },
+    };
+
+    fn kinds(units: &str) -> Vec<QuantityKind> {
+        Value::new(1.0, units).unwrap().quantity_kinds()
+    }
+
+    #[test]
+    fn dimensions() {
+        let d: Dimension = Value::new(2.0, "V").unwrap().dimension();
+        assert_eq!(d.to_string(), "L^2 M T^-3 I^-1");
+        assert_eq!(d, Value::new(1.0, "W/A").unwrap().dimension());
+        assert_ne!(d, Value::new(1.0, "W").unwrap().dimension());
+
+        assert!(Value::new(1.0, "").unwrap().dimension().is_dimensionless());
+        assert!(Value::new(1.0, "%").unwrap().dimension().is_dimensionless());
+        let ratio: Value = Value::new(1.0, "m").unwrap() / Value::new(1.0, "ft").unwrap();
+        assert!(ratio.dimension().is_dimensionless());
+        assert_eq!(Value::new(1.0, "").unwrap().dimension().to_string(), "1");
+
+        assert_eq!(Value::new(1.0, "°c").unwrap().dimension().to_string(), "Θ");
+        assert_eq!(
+            Value::new(1.0, "sr").unwrap().dimension().to_string(),
+            "angle^2"
+        );
+        assert_eq!(
+            Value::new(1.0, "Mb/s").unwrap().dimension().to_string(),
+            "T^-1 information"
+        );
+        assert_eq!(
+            Value::new(1.0, "m^(1/2)").unwrap().dimension().to_string(),
+            "L^(1/2)"
+        );
+        assert_eq!(
+            Value::new(1.0, "Bq").unwrap().dimension(),
+            Value::new(1.0, "Hz").unwrap().dimension()
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let px = register_dimension("pixels", &[("px", 1.0)]).unwrap();
+        let d: Dimension = Value::new(300.0, "px/in").unwrap().dimension();
+        assert_eq!(d.to_string(), "L^-1 pixels");
+        assert_eq!(d.custom(px), 1);
+        assert_eq!(d.length, -1);
+        assert!(Value::new(1.0, "px").unwrap().quantity_kinds().is_empty());
+    }
+
+    #[test]
+    fn quantity_kinds() {
+        assert_eq!(kinds("kg*m/s^2"), vec![QuantityKind::Force]);
+        assert_eq!(kinds("psi"), vec![QuantityKind::Pressure]);
+        assert_eq!(kinds("mph"), vec![QuantityKind::Velocity]);
+        assert_eq!(kinds("ft^2"), vec![QuantityKind::Area]);
+        assert_eq!(kinds("l"), vec![QuantityKind::Volume]);
+        assert_eq!(
+            kinds("1/s"),
+            vec![QuantityKind::Frequency, QuantityKind::Radioactivity]
+        );
+        assert_eq!(
+            kinds("J/kg"),
+            vec![QuantityKind::AbsorbedDose, QuantityKind::EquivalentDose]
+        );
+        assert_eq!(kinds("A*s"), vec![QuantityKind::ElectricCharge]);
+        assert_eq!(kinds("degrees"), vec![QuantityKind::Angle]);
+        assert_eq!(kinds("dB"), vec![QuantityKind::Dimensionless]);
+        assert_eq!(kinds("mol/s"), vec![QuantityKind::CatalyticActivity]);
+
+        for k in QuantityKind::ALL {
+            assert!(kinds(k.si_units()).contains(&k), "{k}");
+        }
+        assert_eq!(
+            QuantityKind::MagneticFluxDensity.to_string(),
+            "magnetic flux density"
+        );
+    }
+}
@@ -0,0 +1,502 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::V3Error;
+
+/// The largest denominator that [`Exponent::from_f64`] will search for
+pub const MAX_EXPONENT_DENOMINATOR: i32 = 1000;
+
+/// A rational unit exponent
+///
+/// Exponents are always kept in lowest terms with a positive denominator, so `2/4`
+/// and `1/2` are the same [`Exponent`].
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::exponent::Exponent;
+/// let e:Exponent = Exponent::new(2, 4).unwrap();
+/// assert_eq!(e.to_string(), "1/2");
+/// assert_eq!(e + e, 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "ExponentRepr", into = "ExponentRepr")]
+pub struct Exponent {
+    /// The numerator of the exponent
+    num: i32,
+    /// The denominator of the exponent, always positive
+    den: i32,
+}
+
+/// The serialized form of an [`Exponent`]
+///
+/// Integer exponents are stored as plain integers so that previously serialized
+/// [`Value`](crate::value::Value)s remain readable.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum ExponentRepr {
+    /// An integer exponent
+    Int(i32),
+    /// A `[numerator, denominator]` pair
+    Ratio(i32, i32),
+}
+
+impl From<Exponent> for ExponentRepr {
+    fn from(e: Exponent) -> Self {
+        if e.den == 1 {
+            ExponentRepr::Int(e.num)
+        } else {
+            ExponentRepr::Ratio(e.num, e.den)
+        }
+    }
+}
+
+impl TryFrom<ExponentRepr> for Exponent {
+    type Error = V3Error;
+    fn try_from(r: ExponentRepr) -> Result<Self, Self::Error> {
+        match r {
+            ExponentRepr::Int(n) => Ok(Exponent::from(n)),
+            ExponentRepr::Ratio(n, d) => Exponent::new(n, d),
+        }
+    }
+}
+
+/// Greatest common divisor used to keep an [`Exponent`] in lowest terms
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+impl Exponent {
+    /// The zero exponent
+    pub const ZERO: Exponent = Exponent { num: 0, den: 1 };
+
+    /// The unit exponent
+    pub const ONE: Exponent = Exponent { num: 1, den: 1 };
+
+    /// Creates a whole number [`Exponent`]
+    pub const fn from_int(num: i32) -> Exponent {
+        Exponent { num, den: 1 }
+    }
+
+    /// Creates a new [`Exponent`] of `num/den` in lowest terms
+    ///
+    /// Returns an error if `den` is zero.
+    pub fn new(num: i32, den: i32) -> Result<Exponent, V3Error> {
+        if den == 0 {
+            return Err(V3Error::InvalidUnitExponent(format!(
+                "[new] Zero denominator in {num}/{den}"
+            )));
+        }
+        Exponent::_reduce(num as i64, den as i64)
+    }
+
+    /// Reduces a wide ratio into an [`Exponent`]
+    fn _reduce(num: i64, den: i64) -> Result<Exponent, V3Error> {
+        let g: i64 = gcd(num, den).max(1);
+        let sign: i64 = if den < 0 { -1 } else { 1 };
+        let n: i64 = sign * num / g;
+        let d: i64 = sign * den / g;
+        match (i32::try_from(n), i32::try_from(d)) {
+            (Ok(num), Ok(den)) => Ok(Exponent { num, den }),
+            _ => Err(V3Error::InvalidUnitExponent(format!(
+                "[reduce] Exponent overflow {num}/{den}"
+            ))),
+        }
+    }
+
+    /// Creates the closest [`Exponent`] to a floating point number
+    ///
+    /// The denominator is limited to [`MAX_EXPONENT_DENOMINATOR`], an error is returned
+    /// if no such ratio is within `1e-9` of the given number.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::exponent::Exponent;
+    /// assert_eq!(Exponent::from_f64(0.5).unwrap(), Exponent::new(1, 2).unwrap());
+    /// assert_eq!(Exponent::from_f64(-1.25).unwrap().to_string(), "-5/4");
+    /// assert!(Exponent::from_f64(std::f64::consts::PI).is_err());
+    /// ```
+    pub fn from_f64(v: f64) -> Result<Exponent, V3Error> {
+        if !v.is_finite() || v.abs() > i32::MAX as f64 {
+            return Err(V3Error::InvalidUnitExponent(format!(
+                "[from_f64] Cannot represent {v} as an exponent"
+            )));
+        }
+
+        // Continued fraction expansion
+        let (mut p0, mut q0, mut p1, mut q1): (i64, i64, i64, i64) = (0, 1, 1, 0);
+        let mut x: f64 = v;
+        loop {
+            let a: f64 = x.floor();
+            let p2: i64 = a as i64 * p1 + p0;
+            let q2: i64 = a as i64 * q1 + q0;
+            if q2 > MAX_EXPONENT_DENOMINATOR as i64 {
+                break;
+            }
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+            if (p1 as f64 / q1 as f64 - v).abs() < 1e-9 || x - a == 0.0 {
+                break;
+            }
+            x = 1.0 / (x - a);
+        }
+
+        if q1 == 0 || (p1 as f64 / q1 as f64 - v).abs() >= 1e-9 {
+            return Err(V3Error::InvalidUnitExponent(format!(
+                "[from_f64] Cannot represent {v} as an exponent"
+            )));
+        }
+        Exponent::_reduce(p1, q1)
+    }
+
+    /// Returns the numerator of the [`Exponent`]
+    pub fn numer(&self) -> i32 {
+        self.num
+    }
+
+    /// Returns the denominator of the [`Exponent`]
+    pub fn denom(&self) -> i32 {
+        self.den
+    }
+
+    /// Returns `true` if the [`Exponent`] is a whole number
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    /// Returns the [`Exponent`] as a floating point number
+    pub fn as_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    /// Returns the absolute value of the [`Exponent`]
+    pub fn abs(&self) -> Exponent {
+        Exponent {
+            num: self.num.abs(),
+            den: self.den,
+        }
+    }
+
+    /// Adds two [`Exponent`]s, returning an error on overflow
+    pub fn checked_add(&self, other: Exponent) -> Result<Exponent, V3Error> {
+        Exponent::_reduce(
+            self.num as i64 * other.den as i64 + other.num as i64 * self.den as i64,
+            self.den as i64 * other.den as i64,
+        )
+    }
+
+    /// Multiplies two [`Exponent`]s, returning an error on overflow
+    pub fn checked_mul(&self, other: Exponent) -> Result<Exponent, V3Error> {
+        Exponent::_reduce(
+            self.num as i64 * other.num as i64,
+            self.den as i64 * other.den as i64,
+        )
+    }
+
+    /// Raises a numeric to the power of the [`Exponent`]
+    ///
+    /// Integer exponents use [`f64::powi`] so that whole powers stay exact.
+    pub fn apply(&self, base: f64) -> f64 {
+        if self.den == 1 {
+            base.powi(self.num)
+        } else {
+            base.powf(self.as_f64())
+        }
+    }
+}
+
+impl Default for Exponent {
+    fn default() -> Self {
+        Exponent::ZERO
+    }
+}
+
+impl From<i32> for Exponent {
+    fn from(num: i32) -> Self {
+        Exponent::from_int(num)
+    }
+}
+
+impl Display for Exponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl FromStr for Exponent {
+    type Err = V3Error;
+    /// Parses an [`Exponent`] from `2`, `-3`, `1/2`, `(1/2)`, `(-1/2)` or `0.5`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let t: &str = s.trim();
+        let t: &str = match t.strip_prefix('(').and_then(|x| x.strip_suffix(')')) {
+            Some(inner) => inner.trim(),
+            None => t,
+        };
+        if let Ok(n) = t.parse::<i32>() {
+            return Ok(Exponent::from(n));
+        }
+        if let Some((n, d)) = t.split_once('/') {
+            return match (n.trim().parse::<i32>(), d.trim().parse::<i32>()) {
+                (Ok(n), Ok(d)) => Exponent::new(n, d),
+                _ => Err(V3Error::ParsingError(format!(
+                    "[from_str] Cannot parse exponent: {s}"
+                ))),
+            };
+        }
+        match t.parse::<f64>() {
+            Ok(v) => Exponent::from_f64(v),
+            Err(_) => Err(V3Error::ParsingError(format!(
+                "[from_str] Cannot parse exponent: {s}"
+            ))),
+        }
+    }
+}
+
+impl PartialEq<i32> for Exponent {
+    fn eq(&self, other: &i32) -> bool {
+        self.den == 1 && self.num == *other
+    }
+}
+
+impl PartialOrd for Exponent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Exponent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num as i64 * other.den as i64).cmp(&(other.num as i64 * self.den as i64))
+    }
+}
+
+impl PartialOrd<i32> for Exponent {
+    fn partial_cmp(&self, other: &i32) -> Option<Ordering> {
+        Some(self.cmp(&Exponent::from(*other)))
+    }
+}
+
+impl Neg for Exponent {
+    type Output = Exponent;
+    fn neg(self) -> Self::Output {
+        Exponent {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Add<Exponent> for Exponent {
+    type Output = Exponent;
+    fn add(self, other: Exponent) -> Self::Output {
+        match self.checked_add(other) {
+            Ok(e) => e,
+            Err(_) => panic!("[add] Exponent overflow {self} + {other}"),
+        }
+    }
+}
+
+impl Sub<Exponent> for Exponent {
+    type Output = Exponent;
+    fn sub(self, other: Exponent) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul<Exponent> for Exponent {
+    type Output = Exponent;
+    fn mul(self, other: Exponent) -> Self::Output {
+        match self.checked_mul(other) {
+            Ok(e) => e,
+            Err(_) => panic!("[mul] Exponent overflow {self} * {other}"),
+        }
+    }
+}
+
+impl Div<Exponent> for Exponent {
+    type Output = Exponent;
+    fn div(self, other: Exponent) -> Self::Output {
+        if other.num == 0 {
+            panic!("[div] Exponent division by zero");
+        }
+        self * Exponent::new(other.den, other.num).unwrap()
+    }
+}
+
+impl Add<i32> for Exponent {
+    type Output = Exponent;
+    fn add(self, other: i32) -> Self::Output {
+        self + Exponent::from(other)
+    }
+}
+
+impl Sub<i32> for Exponent {
+    type Output = Exponent;
+    fn sub(self, other: i32) -> Self::Output {
+        self - Exponent::from(other)
+    }
+}
+
+impl Mul<i32> for Exponent {
+    type Output = Exponent;
+    fn mul(self, other: i32) -> Self::Output {
+        self * Exponent::from(other)
+    }
+}
+
+impl Div<i32> for Exponent {
+    type Output = Exponent;
+    fn div(self, other: i32) -> Self::Output {
+        self / Exponent::from(other)
+    }
+}
+
+impl AddAssign<Exponent> for Exponent {
+    fn add_assign(&mut self, other: Exponent) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<Exponent> for Exponent {
+    fn sub_assign(&mut self, other: Exponent) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<Exponent> for Exponent {
+    fn mul_assign(&mut self, other: Exponent) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<Exponent> for Exponent {
+    fn div_assign(&mut self, other: Exponent) {
+        *self = *self / other;
+    }
+}
+
+impl AddAssign<i32> for Exponent {
+    fn add_assign(&mut self, other: i32) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<i32> for Exponent {
+    fn sub_assign(&mut self, other: i32) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<i32> for Exponent {
+    fn mul_assign(&mut self, other: i32) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign<i32> for Exponent {
+    fn div_assign(&mut self, other: i32) {
+        *self = *self / other;
+    }
+}
+
+/// Allows a numeric to be raised to a rational [`Exponent`]
+pub(crate) trait PowRational {
+    /// Raises `self` to the power of the given [`Exponent`]
+    fn powr(self, e: Exponent) -> f64;
+}
+
+impl PowRational for f64 {
+    fn powr(self, e: Exponent) -> f64 {
+        e.apply(self)
+    }
+}
+
+#[cfg(test)]
+mod exponent_testing {
+    use super::{Exponent, PowRational};
+
+    #[test]
+    fn reduce() {
+        let e = Exponent::new(4, -8).unwrap();
+        assert_eq!(e.numer(), -1);
+        assert_eq!(e.denom(), 2);
+        assert!(!e.is_integer());
+        assert_eq!(Exponent::new(6, 3).unwrap(), 2);
+        assert!(Exponent::new(1, 0).is_err());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let h = Exponent::new(1, 2).unwrap();
+        let t = Exponent::new(1, 3).unwrap();
+        assert_eq!(h + t, Exponent::new(5, 6).unwrap());
+        assert_eq!(h - t, Exponent::new(1, 6).unwrap());
+        assert_eq!(h * t, Exponent::new(1, 6).unwrap());
+        assert_eq!(h / t, Exponent::new(3, 2).unwrap());
+        assert_eq!(h * 2, 1);
+        assert_eq!(-h, Exponent::new(-1, 2).unwrap());
+
+        let mut e = Exponent::from(3);
+        e /= 2;
+        assert_eq!(e.to_string(), "3/2");
+        e -= 1;
+        assert_eq!(e, h);
+        assert!(e < 1);
+        assert!(e > 0);
+    }
+
+    #[test]
+    fn parse() {
+        let h = Exponent::new(1, 2).unwrap();
+        assert_eq!("1/2".parse::<Exponent>().unwrap(), h);
+        assert_eq!("(1/2)".parse::<Exponent>().unwrap(), h);
+        assert_eq!("0.5".parse::<Exponent>().unwrap(), h);
+        assert_eq!("(-1/2)".parse::<Exponent>().unwrap(), -h);
+        assert_eq!("-3".parse::<Exponent>().unwrap(), -3);
+        assert!("x".parse::<Exponent>().is_err());
+        assert!("1/x".parse::<Exponent>().is_err());
+        assert!("1/0".parse::<Exponent>().is_err());
+    }
+
+    #[test]
+    fn from_f64() {
+        assert_eq!(Exponent::from_f64(1.0 / 3.0).unwrap().to_string(), "1/3");
+        assert_eq!(Exponent::from_f64(-2.0).unwrap(), -2);
+        assert!(Exponent::from_f64(f64::NAN).is_err());
+        assert!(Exponent::from_f64(0.0001).is_err());
+    }
+
+    #[test]
+    fn powr() {
+        assert_eq!(3.0_f64.powr(Exponent::from(2)), 9.0);
+        assert_eq!(16.0_f64.powr(Exponent::new(1, 2).unwrap()), 4.0);
+    }
+}
@@ -15,6 +15,7 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{
         CAPACITANCE_INDEX, CAPACITANCE_MAP, CATALYTIC_ACTIVITY_INDEX, CATALYTIC_ACTIVITY_MAP,
@@ -65,11 +66,11 @@ impl Value {
             (ret >>= UnitMass::Gram(Metric::Kilo));
             (ret >>= UnitLength::Meter(Metric::None));
             (ret >>= UnitTime::Second(Metric::None));
-            ret.exp[MASS_INDEX] = 0;
-            ret.exp[LENGTH_INDEX] = 0;
-            ret.exp[TIME_INDEX] = 0;
+            ret.exp[MASS_INDEX] = Exponent::from(0);
+            ret.exp[LENGTH_INDEX] = Exponent::from(0);
+            ret.exp[TIME_INDEX] = Exponent::from(0);
             ret.unit_map = FORCE_MAP;
-            ret.exp[FORCE_INDEX] = 1;
+            ret.exp[FORCE_INDEX] = Exponent::from(1);
             ret.v_force = Some(UnitForce::Newton(Metric::None));
             ret.v_length = None;
             ret.v_mass = None;
@@ -78,296 +79,296 @@ impl Value {
             if ret.unit_map & FORCE_MAP == FORCE_MAP {
                 (ret >>= UnitForce::Newton(Metric::None));
                 (ret >>= UnitLength::Meter(Metric::None));
-                ret.exp[FORCE_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
+                ret.exp[FORCE_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
                 ret.v_force = None;
                 ret.v_length = None;
             } else {
                 ret >>= UnitMass::Gram(Metric::Kilo);
                 ret >>= UnitLength::Meter(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
-                ret.exp[MASS_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
+                ret.exp[MASS_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
                 ret.v_mass = None;
                 ret.v_time = None;
                 ret.v_length = None;
             }
             ret.unit_map = PRESSURE_MAP;
-            ret.exp[PRESSURE_INDEX] = 1;
+            ret.exp[PRESSURE_INDEX] = Exponent::from(1);
             ret.v_pressure = Some(UnitPressure::Pascal(Metric::None));
         } else if ret.is_energy() && ret.unit_map != ENERGY_MAP {
             if ret.unit_map & FORCE_MAP == FORCE_MAP {
                 ret >>= UnitForce::Newton(Metric::None);
                 ret >>= UnitLength::Meter(Metric::None);
-                ret.exp[FORCE_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
+                ret.exp[FORCE_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
                 ret.v_force = None;
                 ret.v_length = None;
             } else if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 ret >>= UnitElectricCharge::Coulomb(Metric::None);
                 ret >>= UnitElectricPotential::Volt(Metric::None);
-                ret.exp[ELECTRIC_CHARGE_INDEX] = 0;
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
+                ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
                 ret.v_electric_potential = None;
                 ret.v_electric_charge = None;
             } else if ret.unit_map & POWER_MAP == POWER_MAP {
                 ret >>= UnitPower::Watt(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
-                ret.exp[POWER_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
+                ret.exp[POWER_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
                 ret.v_power = None;
                 ret.v_time = None;
             } else {
                 ret >>= UnitMass::Gram(Metric::Kilo);
                 ret >>= UnitLength::Meter(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
-                ret.exp[MASS_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
+                ret.exp[MASS_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
                 ret.v_mass = None;
                 ret.v_length = None;
                 ret.v_time = None;
             }
             ret.unit_map = ENERGY_MAP;
-            ret.exp[ENERGY_INDEX] = 1;
+            ret.exp[ENERGY_INDEX] = Exponent::from(1);
             ret.v_energy = Some(UnitEnergy::Joule(Metric::None));
         } else if ret.is_power() && ret.unit_map != POWER_MAP {
             if ret.unit_map & ENERGY_MAP == ENERGY_MAP {
                 ret >>= UnitEnergy::Joule(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
-                ret.exp[ENERGY_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
+                ret.exp[ENERGY_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
                 ret.v_energy = None;
                 ret.v_time = None;
             } else if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 ret >>= UnitElectricPotential::Volt(Metric::None);
                 ret >>= UnitElectricCurrent::Ampere(Metric::None);
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_electric_potential = None;
                 ret.v_electric_current = None;
             } else {
                 ret >>= UnitMass::Gram(Metric::Kilo);
                 ret >>= UnitLength::Meter(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
-                ret.exp[MASS_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
+                ret.exp[MASS_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
             }
             ret.unit_map = POWER_MAP;
-            ret.exp[POWER_INDEX] = 1;
+            ret.exp[POWER_INDEX] = Exponent::from(1);
             ret.v_power = Some(UnitPower::Watt(Metric::None));
         } else if ret.is_electric_charge() && self.unit_map != ELECTRIC_CHARGE_MAP {
             if ret.unit_map & TIME_MAP == TIME_MAP {
                 (ret >>= UnitTime::Second(Metric::None));
                 (ret >>= UnitElectricCurrent::Ampere(Metric::None));
-                ret.exp[TIME_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[TIME_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_time = None;
                 ret.v_electric_current = None;
             } else if ret.unit_map & CAPACITANCE_MAP == CAPACITANCE_MAP {
                 (ret >>= UnitElectricCapacitance::Farad(Metric::None));
                 (ret >>= UnitElectricPotential::Volt(Metric::None));
-                ret.exp[CAPACITANCE_INDEX] = 0;
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
+                ret.exp[CAPACITANCE_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
                 ret.v_capacitance = None;
                 ret.v_electric_potential = None;
             }
             ret.unit_map = ELECTRIC_CHARGE_MAP;
-            ret.exp[ELECTRIC_CHARGE_INDEX] = 1;
+            ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(1);
             ret.v_electric_charge = Some(UnitElectricCharge::Coulomb(Metric::None));
         } else if ret.is_electric_potential() && self.unit_map != ELECTRIC_POTENTIAL_MAP {
             if ret.unit_map & POWER_MAP == POWER_MAP {
                 (ret >>= UnitPower::Watt(Metric::None));
                 (ret >>= UnitElectricCurrent::Ampere(Metric::None));
-                ret.exp[POWER_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[POWER_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_power = None;
                 ret.v_electric_current = None;
             } else if ret.unit_map & ENERGY_MAP == ENERGY_MAP {
                 (ret >>= UnitEnergy::Joule(Metric::None));
                 (ret >>= UnitElectricCharge::Coulomb(Metric::None));
-                ret.exp[ENERGY_INDEX] = 0;
-                ret.exp[ELECTRIC_CHARGE_INDEX] = 0;
+                ret.exp[ENERGY_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
                 ret.v_energy = None;
                 ret.v_electric_charge = None;
             }
             ret.unit_map = ELECTRIC_POTENTIAL_MAP;
-            ret.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
+            ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
             ret.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
         } else if ret.is_capacitance() && self.unit_map != CAPACITANCE_MAP {
             if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 (ret >>= UnitElectricCharge::Coulomb(Metric::None));
                 (ret >>= UnitElectricPotential::Volt(Metric::None));
-                ret.exp[ELECTRIC_CHARGE_INDEX] = 0;
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
+                ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
                 ret.v_electric_potential = None;
                 ret.v_electric_charge = None;
             } else if ret.unit_map & ENERGY_MAP == ENERGY_MAP {
                 (ret >>= UnitElectricCharge::Coulomb(Metric::None));
                 (ret >>= UnitEnergy::Joule(Metric::None));
-                ret.exp[ELECTRIC_CHARGE_INDEX] = 0;
-                ret.exp[ENERGY_INDEX] = 0;
+                ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
+                ret.exp[ENERGY_INDEX] = Exponent::from(0);
                 ret.v_electric_charge = None;
                 ret.v_energy = None;
             }
             ret.unit_map = CAPACITANCE_MAP;
-            ret.exp[CAPACITANCE_INDEX] = 1;
+            ret.exp[CAPACITANCE_INDEX] = Exponent::from(1);
             ret.v_capacitance = Some(UnitElectricCapacitance::Farad(Metric::None));
         } else if ret.is_resistance() && self.unit_map != RESISTANCE_MAP {
             if ret.unit_map & ELECTRIC_CONDUCTANCE_MAP == ELECTRIC_CONDUCTANCE_MAP {
                 (ret >>= UnitElectricConductance::Siemens(Metric::None));
-                ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = 0;
+                ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(0);
                 ret.v_electric_conductance = None;
             } else if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 (ret >>= UnitElectricPotential::Volt(Metric::None));
                 (ret >>= UnitElectricCurrent::Ampere(Metric::None));
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_electric_potential = None;
                 ret.v_electric_current = None;
             }
 
             ret.unit_map = RESISTANCE_MAP;
-            ret.exp[RESISTANCE_INDEX] = 1;
+            ret.exp[RESISTANCE_INDEX] = Exponent::from(1);
             ret.v_resistance = Some(UnitElectricResistance::Ohm(Metric::None));
         } else if ret.is_conductance() && self.unit_map != ELECTRIC_CONDUCTANCE_MAP {
             if ret.unit_map & RESISTANCE_MAP == RESISTANCE_MAP {
                 (ret >>= UnitElectricResistance::Ohm(Metric::None));
-                ret.exp[RESISTANCE_INDEX] = 0;
+                ret.exp[RESISTANCE_INDEX] = Exponent::from(0);
                 ret.v_resistance = None;
             } else if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 (ret >>= UnitElectricPotential::Volt(Metric::None));
                 (ret >>= UnitElectricCurrent::Ampere(Metric::None));
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_electric_potential = None;
                 ret.v_electric_current = None;
             }
 
             ret.unit_map = ELECTRIC_CONDUCTANCE_MAP;
-            ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = 1;
+            ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(1);
             ret.v_electric_conductance = Some(UnitElectricConductance::Siemens(Metric::None));
         } else if ret.is_magnetic_flux() && self.unit_map != MAGNETIC_FLUX_MAP {
             if ret.unit_map & ENERGY_MAP == ENERGY_MAP {
                 (ret >>= UnitEnergy::Joule(Metric::None));
                 (ret >>= UnitElectricCurrent::Ampere(Metric::None));
-                ret.exp[ENERGY_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[ENERGY_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_energy = None;
                 ret.v_electric_current = None;
             } else if ret.unit_map & MAGNETIC_FLUX_DENSITY_MAP == MAGNETIC_FLUX_DENSITY_MAP {
                 (ret >>= UnitMagneticFluxDensity::Tesla(Metric::None));
                 (ret >>= UnitLength::Meter(Metric::None));
-                ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
+                ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
                 ret.v_length = None;
                 ret.v_magnetic_flux_density = None;
             } else if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 (ret >>= UnitElectricPotential::Volt(Metric::None));
                 (ret >>= UnitTime::Second(Metric::None));
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
                 ret.v_electric_potential = None;
                 ret.v_time = None;
             }
 
             ret.unit_map = MAGNETIC_FLUX_MAP;
-            ret.exp[MAGNETIC_FLUX_INDEX] = 1;
+            ret.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(1);
             ret.v_magnetic_flux = Some(UnitMagneticFlux::Weber(Metric::None));
         } else if ret.is_magnetic_flux_density() && self.unit_map != MAGNETIC_FLUX_DENSITY_MAP {
             if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 ret >>= UnitElectricPotential::Volt(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
                 ret >>= UnitLength::Meter(Metric::None);
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
                 ret.v_length = None;
                 ret.v_time = None;
                 ret.v_length = None;
             } else if ret.unit_map & MAGNETIC_FLUX_MAP == MAGNETIC_FLUX_MAP {
                 ret >>= UnitMagneticFlux::Weber(Metric::None);
                 ret >>= UnitLength::Meter(Metric::None);
-                ret.exp[LENGTH_INDEX] = 0;
-                ret.exp[MAGNETIC_FLUX_INDEX] = 0;
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
+                ret.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
                 ret.v_magnetic_flux = None;
                 ret.v_length = None;
             } else if ret.unit_map & FORCE_MAP == FORCE_MAP {
                 ret >>= UnitForce::Newton(Metric::None);
                 ret >>= UnitElectricCurrent::Ampere(Metric::None);
                 ret >>= UnitLength::Meter(Metric::None);
-                ret.exp[FORCE_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
-                ret.exp[LENGTH_INDEX] = 0;
+                ret.exp[FORCE_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
+                ret.exp[LENGTH_INDEX] = Exponent::from(0);
                 ret.v_length = None;
                 ret.v_electric_current = None;
                 ret.v_force = None;
             }
 
             ret.unit_map = MAGNETIC_FLUX_DENSITY_MAP;
-            ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 1;
+            ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(1);
             ret.v_magnetic_flux_density = Some(UnitMagneticFluxDensity::Tesla(Metric::None));
         } else if ret.is_inductance() && self.unit_map != INDUCTANCE_MAP {
             if ret.unit_map & ELECTRIC_POTENTIAL_MAP == ELECTRIC_POTENTIAL_MAP {
                 ret >>= UnitElectricPotential::Volt(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
                 ret >>= UnitElectricCurrent::Ampere(Metric::None);
-                ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_electric_potential = None;
                 ret.v_time = None;
                 ret.v_electric_current = None;
             } else if ret.unit_map & RESISTANCE_MAP == RESISTANCE_MAP {
                 ret >>= UnitElectricResistance::Ohm(Metric::None);
                 ret >>= UnitTime::Second(Metric::None);
-                ret.exp[RESISTANCE_INDEX] = 0;
-                ret.exp[TIME_INDEX] = 0;
+                ret.exp[RESISTANCE_INDEX] = Exponent::from(0);
+                ret.exp[TIME_INDEX] = Exponent::from(0);
                 ret.v_resistance = None;
                 ret.v_time = None;
             } else if ret.unit_map & MAGNETIC_FLUX_MAP == MAGNETIC_FLUX_MAP {
                 ret >>= UnitMagneticFlux::Weber(Metric::None);
                 ret >>= UnitElectricCurrent::Ampere(Metric::None);
-                ret.exp[MAGNETIC_FLUX_INDEX] = 0;
-                ret.exp[ELECTRIC_CURRENT_INDEX] = 0;
+                ret.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
+                ret.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(0);
                 ret.v_magnetic_flux = None;
                 ret.v_electric_current = None;
             }
 
             ret.unit_map = INDUCTANCE_MAP;
-            ret.exp[INDUCTANCE_INDEX] = 1;
+            ret.exp[INDUCTANCE_INDEX] = Exponent::from(1);
             ret.v_inductance = Some(UnitElectricInductance::Henry(Metric::None));
         } else if ret.is_luminous_flux() && self.unit_map != LUMINOUS_FLUX_MAP {
             ret >>= UnitLuminousIntensity::Candela(Metric::None);
             ret >>= UnitSolidAngle::Steradian(Metric::None);
-            ret.exp[LUMINOUS_INTENSITY_INDEX] = 0;
-            ret.exp[SOLID_ANGLE_INDEX] = 0;
+            ret.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(0);
+            ret.exp[SOLID_ANGLE_INDEX] = Exponent::from(0);
             ret.v_solid_angle = None;
             ret.v_luminous_flux_intensity = None;
             ret.unit_map = LUMINOUS_FLUX_MAP;
-            ret.exp[LUMINOUS_FLUX_INDEX] = 1;
+            ret.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(1);
             ret.v_luminous_flux = Some(UnitLuminousFlux::Lumen(Metric::None));
         } else if ret.is_illuminance() && self.unit_map != ILLUMINANCE_MAP {
             ret >>= UnitLuminousFlux::Lumen(Metric::None);
             ret >>= UnitLength::Meter(Metric::None);
-            ret.exp[LUMINOUS_FLUX_INDEX] = 0;
-            ret.exp[LENGTH_INDEX] = 0;
+            ret.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(0);
+            ret.exp[LENGTH_INDEX] = Exponent::from(0);
             ret.v_length = None;
             ret.v_luminous_flux = None;
             ret.unit_map = ILLUMINANCE_MAP;
-            ret.exp[ILLUMINANCE_INDEX] = 1;
+            ret.exp[ILLUMINANCE_INDEX] = Exponent::from(1);
             ret.v_illuminance = Some(UnitIlluminance::Lux(Metric::None));
         } else if ret.is_catalytic_activity() && self.unit_map != CATALYTIC_ACTIVITY_MAP {
             ret >>= UnitSubstance::Mole(Metric::None);
             ret >>= UnitTime::Second(Metric::None);
-            ret.exp[SUBSTANCE_INDEX] = 0;
-            ret.exp[TIME_INDEX] = 0;
+            ret.exp[SUBSTANCE_INDEX] = Exponent::from(0);
+            ret.exp[TIME_INDEX] = Exponent::from(0);
             ret.v_time = None;
             ret.v_substance = None;
             ret.unit_map = CATALYTIC_ACTIVITY_MAP;
-            ret.exp[CATALYTIC_ACTIVITY_INDEX] = 1;
+            ret.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(1);
             ret.v_catalytic = Some(UnitCatalyticActivity::Katal(Metric::None));
         }
         ret
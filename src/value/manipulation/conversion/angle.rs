@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ANGLE_INDEX, ANGLE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitAngle> for Value {
             .v_angle
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ANGLE_INDEX]);
+            .powr(self.exp[ANGLE_INDEX]);
         n.v_angle = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitAngle> for Value {
             .v_angle
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ANGLE_INDEX]);
+            .powr(self.exp[ANGLE_INDEX]);
         self.v_angle = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{SOLID_ANGLE_INDEX, SOLID_ANGLE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitSolidAngle> for Value {
             .v_solid_angle
             .unwrap()
             .convert(&other)
-            .powi(self.exp[SOLID_ANGLE_INDEX]);
+            .powr(self.exp[SOLID_ANGLE_INDEX]);
         n.v_solid_angle = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitSolidAngle> for Value {
             .v_solid_angle
             .unwrap()
             .convert(&other)
-            .powi(self.exp[SOLID_ANGLE_INDEX]);
+            .powr(self.exp[SOLID_ANGLE_INDEX]);
         self.v_solid_angle = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{CATALYTIC_ACTIVITY_INDEX, CATALYTIC_ACTIVITY_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitCatalyticActivity> for Value {
             .v_catalytic
             .unwrap()
             .convert(&other)
-            .powi(self.exp[CATALYTIC_ACTIVITY_INDEX]);
+            .powr(self.exp[CATALYTIC_ACTIVITY_INDEX]);
         n.v_catalytic = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitCatalyticActivity> for Value {
             .v_catalytic
             .unwrap()
             .convert(&other)
-            .powi(self.exp[CATALYTIC_ACTIVITY_INDEX]);
+            .powr(self.exp[CATALYTIC_ACTIVITY_INDEX]);
         self.v_catalytic = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{CAPACITANCE_INDEX, CAPACITANCE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitElectricCapacitance> for Value {
             .v_capacitance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[CAPACITANCE_INDEX]);
+            .powr(self.exp[CAPACITANCE_INDEX]);
         n.v_capacitance = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitElectricCapacitance> for Value {
             .v_capacitance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[CAPACITANCE_INDEX]);
+            .powr(self.exp[CAPACITANCE_INDEX]);
         self.v_capacitance = Some(other);
     }
 }
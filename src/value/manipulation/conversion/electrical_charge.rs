@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ELECTRIC_CHARGE_INDEX, ELECTRIC_CHARGE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitElectricCharge> for Value {
             .v_electric_charge
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_CHARGE_INDEX]);
+            .powr(self.exp[ELECTRIC_CHARGE_INDEX]);
         n.v_electric_charge = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitElectricCharge> for Value {
             .v_electric_charge
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_CHARGE_INDEX]);
+            .powr(self.exp[ELECTRIC_CHARGE_INDEX]);
         self.v_electric_charge = Some(other);
     }
 }
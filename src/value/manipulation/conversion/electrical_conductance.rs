@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ELECTRIC_CONDUCTANCE_INDEX, ELECTRIC_CONDUCTANCE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitElectricConductance> for Value {
             .v_electric_conductance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
+            .powr(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
         n.v_electric_conductance = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitElectricConductance> for Value {
             .v_electric_conductance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
+            .powr(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
         self.v_electric_conductance = Some(other);
     }
 }
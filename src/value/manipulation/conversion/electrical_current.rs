@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ELECTRIC_CURRENT_INDEX, ELECTRIC_CURRENT_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitElectricCurrent> for Value {
             .v_electric_current
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_CURRENT_INDEX]);
+            .powr(self.exp[ELECTRIC_CURRENT_INDEX]);
         n.v_electric_current = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitElectricCurrent> for Value {
             .v_electric_current
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_CURRENT_INDEX]);
+            .powr(self.exp[ELECTRIC_CURRENT_INDEX]);
         self.v_electric_current = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{INDUCTANCE_INDEX, INDUCTANCE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitElectricInductance> for Value {
             .v_inductance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[INDUCTANCE_INDEX]);
+            .powr(self.exp[INDUCTANCE_INDEX]);
         n.v_inductance = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitElectricInductance> for Value {
             .v_inductance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[INDUCTANCE_INDEX]);
+            .powr(self.exp[INDUCTANCE_INDEX]);
         self.v_inductance = Some(other);
     }
 }
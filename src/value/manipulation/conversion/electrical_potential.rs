@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ELECTRIC_POTENTIAL_INDEX, ELECTRIC_POTENTIAL_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitElectricPotential> for Value {
             .v_electric_potential
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_POTENTIAL_INDEX]);
+            .powr(self.exp[ELECTRIC_POTENTIAL_INDEX]);
         n.v_electric_potential = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitElectricPotential> for Value {
             .v_electric_potential
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ELECTRIC_POTENTIAL_INDEX]);
+            .powr(self.exp[ELECTRIC_POTENTIAL_INDEX]);
         self.v_electric_potential = Some(other);
     }
 }
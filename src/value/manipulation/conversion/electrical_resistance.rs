@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{RESISTANCE_INDEX, RESISTANCE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitElectricResistance> for Value {
             .v_resistance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[RESISTANCE_INDEX]);
+            .powr(self.exp[RESISTANCE_INDEX]);
         n.v_resistance = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitElectricResistance> for Value {
             .v_resistance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[RESISTANCE_INDEX]);
+            .powr(self.exp[RESISTANCE_INDEX]);
         self.v_resistance = Some(other);
     }
 }
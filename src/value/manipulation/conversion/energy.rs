@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ENERGY_INDEX, ENERGY_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitEnergy> for Value {
             .v_energy
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ENERGY_INDEX]);
+            .powr(self.exp[ENERGY_INDEX]);
         n.v_energy = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitEnergy> for Value {
             .v_energy
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ENERGY_INDEX]);
+            .powr(self.exp[ENERGY_INDEX]);
         self.v_energy = Some(other);
     }
 }
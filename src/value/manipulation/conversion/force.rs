@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{FORCE_INDEX, FORCE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitForce> for Value {
             .v_force
             .unwrap()
             .convert(&other)
-            .powi(self.exp[FORCE_INDEX]);
+            .powr(self.exp[FORCE_INDEX]);
         n.v_force = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitForce> for Value {
             .v_force
             .unwrap()
             .convert(&other)
-            .powi(self.exp[FORCE_INDEX]);
+            .powr(self.exp[FORCE_INDEX]);
         self.v_force = Some(other);
     }
 }
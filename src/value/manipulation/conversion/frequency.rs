@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::{Exponent, PowRational};
 use crate::{
     consts::{FREQUENCY_INDEX, FREQUENCY_MAP, TIME_INDEX, TIME_MAP},
     errors::V3Error,
@@ -32,8 +33,8 @@ impl Shr<UnitFrequency> for Value {
             n.val *= self.v_time.unwrap().convert(&other);
             n.v_time = None;
             n.v_frequency = Some(other);
-            n.exp[TIME_INDEX] = 0;
-            n.exp[FREQUENCY_INDEX] = 1;
+            n.exp[TIME_INDEX] = Exponent::from(0);
+            n.exp[FREQUENCY_INDEX] = Exponent::from(1);
             n.unit_map &= !TIME_MAP;
             n.unit_map |= FREQUENCY_MAP;
             return Ok(n);
@@ -44,7 +45,7 @@ impl Shr<UnitFrequency> for Value {
             .v_frequency
             .unwrap()
             .convert(&other)
-            .powi(self.exp[FREQUENCY_INDEX]);
+            .powr(self.exp[FREQUENCY_INDEX]);
         n.v_frequency = Some(other);
         Ok(n)
     }
@@ -56,8 +57,8 @@ impl ShrAssign<UnitFrequency> for Value {
             self.val *= self.v_time.unwrap().convert(&other);
             self.v_time = None;
             self.v_frequency = Some(other);
-            self.exp[TIME_INDEX] = 0;
-            self.exp[FREQUENCY_INDEX] = 1;
+            self.exp[TIME_INDEX] = Exponent::from(0);
+            self.exp[FREQUENCY_INDEX] = Exponent::from(1);
             self.unit_map &= !TIME_MAP;
             self.unit_map |= FREQUENCY_MAP;
             return;
@@ -68,7 +69,7 @@ impl ShrAssign<UnitFrequency> for Value {
             .v_frequency
             .unwrap()
             .convert(&other)
-            .powi(self.exp[FREQUENCY_INDEX]);
+            .powr(self.exp[FREQUENCY_INDEX]);
         self.v_frequency = Some(other);
     }
 }
@@ -141,6 +142,20 @@ mod conversion_testing {
         let t2 = (t1_2 >> UnitFrequency::Hertz(Metric::None)).unwrap();
         assert_eq!(t2.to_string(), "4.1 m*Hz");
 
+        let t2 = 120.0 / UnitTime::Minute;
+        assert_eq!(
+            (t2 >> UnitFrequency::Hertz(Metric::None))
+                .unwrap()
+                .to_string(),
+            "2 Hz"
+        );
+        assert_eq!(
+            (t2 >> UnitFrequency::Hertz(Metric::Kilo))
+                .unwrap()
+                .to_string(),
+            "0.002 kHz"
+        );
+
         for i in TEST_METRIC {
             // Scale the metric value by converting
             let t2 = (t1 >> UnitFrequency::Hertz(i.0)).unwrap();
@@ -160,7 +175,7 @@ mod conversion_testing {
             let t2 = 4.1 / UnitTime::Second(i.0);
 
             let t3 = (t2 >> UnitFrequency::Hertz(i.0)).unwrap();
-            assert_apr!(t3.val, t2.val);
+            assert_apr!((t3.val * i.0.scale() * i.0.scale()) / t2.val, 1.0);
             assert_eq!(
                 t3.to_string().split(' ').collect::<Vec<&str>>()[1],
                 format!("{}Hz", i.1)
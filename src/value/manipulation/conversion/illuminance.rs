@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ILLUMINANCE_INDEX, ILLUMINANCE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitIlluminance> for Value {
             .v_illuminance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ILLUMINANCE_INDEX]);
+            .powr(self.exp[ILLUMINANCE_INDEX]);
         n.v_illuminance = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitIlluminance> for Value {
             .v_illuminance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ILLUMINANCE_INDEX]);
+            .powr(self.exp[ILLUMINANCE_INDEX]);
         self.v_illuminance = Some(other);
     }
 }
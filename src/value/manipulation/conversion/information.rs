@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{INFORMATION_INDEX, INFORMATION_MAP},
     errors::V3Error,
@@ -41,7 +42,7 @@ impl Shr<UnitInformation> for Value {
             .v_information
             .unwrap()
             .convert(&other)
-            .powi(self.exp[INFORMATION_INDEX]);
+            .powr(self.exp[INFORMATION_INDEX]);
         n.v_information = Some(other);
         Ok(n)
     }
@@ -62,7 +63,7 @@ impl ShrAssign<UnitInformation> for Value {
             .v_information
             .unwrap()
             .convert(&other)
-            .powi(self.exp[INFORMATION_INDEX]);
+            .powr(self.exp[INFORMATION_INDEX]);
         self.v_information = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{LUMINOUS_FLUX_INDEX, LUMINOUS_FLUX_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitLuminousFlux> for Value {
             .v_luminous_flux
             .unwrap()
             .convert(&other)
-            .powi(self.exp[LUMINOUS_FLUX_INDEX]);
+            .powr(self.exp[LUMINOUS_FLUX_INDEX]);
         n.v_luminous_flux = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitLuminousFlux> for Value {
             .v_luminous_flux
             .unwrap()
             .convert(&other)
-            .powi(self.exp[LUMINOUS_FLUX_INDEX]);
+            .powr(self.exp[LUMINOUS_FLUX_INDEX]);
         self.v_luminous_flux = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{LUMINOUS_INTENSITY_INDEX, LUMINOUS_INTENSITY_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitLuminousIntensity> for Value {
             .v_luminous_flux_intensity
             .unwrap()
             .convert(&other)
-            .powi(self.exp[LUMINOUS_INTENSITY_INDEX]);
+            .powr(self.exp[LUMINOUS_INTENSITY_INDEX]);
         n.v_luminous_flux_intensity = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitLuminousIntensity> for Value {
             .v_luminous_flux_intensity
             .unwrap()
             .convert(&other)
-            .powi(self.exp[LUMINOUS_INTENSITY_INDEX]);
+            .powr(self.exp[LUMINOUS_INTENSITY_INDEX]);
         self.v_luminous_flux_intensity = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{MAGNETIC_FLUX_INDEX, MAGNETIC_FLUX_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitMagneticFlux> for Value {
             .v_magnetic_flux
             .unwrap()
             .convert(&other)
-            .powi(self.exp[MAGNETIC_FLUX_INDEX]);
+            .powr(self.exp[MAGNETIC_FLUX_INDEX]);
         n.v_magnetic_flux = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitMagneticFlux> for Value {
             .v_magnetic_flux
             .unwrap()
             .convert(&other)
-            .powi(self.exp[MAGNETIC_FLUX_INDEX]);
+            .powr(self.exp[MAGNETIC_FLUX_INDEX]);
         self.v_magnetic_flux = Some(other);
     }
 }
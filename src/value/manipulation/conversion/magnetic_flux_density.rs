@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{MAGNETIC_FLUX_DENSITY_INDEX, MAGNETIC_FLUX_DENSITY_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitMagneticFluxDensity> for Value {
             .v_magnetic_flux_density
             .unwrap()
             .convert(&other)
-            .powi(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
+            .powr(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
         n.v_magnetic_flux_density = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitMagneticFluxDensity> for Value {
             .v_magnetic_flux_density
             .unwrap()
             .convert(&other)
-            .powi(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
+            .powr(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
         self.v_magnetic_flux_density = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{MASS_INDEX, MASS_MAP},
     errors::V3Error,
@@ -31,7 +32,7 @@ impl Shr<UnitMass> for Value {
         if self.unit_map & MASS_MAP == 0 {
             return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
         }
-        n.val *= n.v_mass.unwrap().convert(&other).powi(self.exp[MASS_INDEX]);
+        n.val *= n.v_mass.unwrap().convert(&other).powr(self.exp[MASS_INDEX]);
         n.v_mass = Some(other);
         Ok(n)
     }
@@ -46,7 +47,7 @@ impl ShrAssign<UnitMass> for Value {
             .v_mass
             .unwrap()
             .convert(&other)
-            .powi(self.exp[MASS_INDEX]);
+            .powr(self.exp[MASS_INDEX]);
         self.v_mass = Some(other);
     }
 }
@@ -80,6 +80,7 @@ pub mod volume;
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::{Exponent, PowRational};
 use crate::{
     consts::{
         ABSORBED_DOSE_INDEX, ABSORBED_DOSE_MAP, ANGLE_INDEX, ANGLE_MAP, CAPACITANCE_INDEX,
@@ -98,15 +99,25 @@ use crate::{
         TEMPERATURE_INDEX, TEMPERATURE_MAP, TIME_INDEX, TIME_MAP, VOLUME_INDEX, VOLUME_MAP,
     },
     errors::V3Error,
-    units::{Convert, Metric, UnitAngle, UnitLength},
-    value::Value,
+    units::{
+        BaseUnit, Convert, Metric, UnitAngle, UnitEnergy, UnitLength, UnitPower, UnitSound,
+        UnitTime,
+        exact::{ExactFactor, exact_convert},
+    },
+    value::{
+        Value,
+        numeric::{_representable, _scale, Numeric},
+    },
 };
 
-impl Shr<Value> for Value {
-    type Output = Result<Value, V3Error>;
+impl<T: Numeric> Shr<Value> for Value<T> {
+    type Output = Result<Value<T>, V3Error>;
     fn shr(self, other: Value) -> Self::Output {
-        if self.__equivalent(&other) {
-            let mut ret: Value = self;
+        if self.__equivalent(&other)
+            || self._level_equivalent(&other)
+            || self._power_equivalent(&other)
+        {
+            let mut ret: Value<T> = self;
             ret._convert(&other)?;
             return Ok(ret);
         }
@@ -114,25 +125,28 @@ impl Shr<Value> for Value {
     }
 }
 
-impl Shr<&str> for Value {
-    type Output = Result<Value, V3Error>;
+impl<T: Numeric> Shr<&str> for Value<T> {
+    type Output = Result<Value<T>, V3Error>;
     fn shr(self, other: &str) -> Self::Output {
         let n: Value = Value::new(1.0, other)?;
         self >> n
     }
 }
 
-impl Shr<String> for Value {
-    type Output = Result<Value, V3Error>;
+impl<T: Numeric> Shr<String> for Value<T> {
+    type Output = Result<Value<T>, V3Error>;
     fn shr(self, other: String) -> Self::Output {
         let n: Value = Value::new(1.0, other.as_str())?;
         self >> n
     }
 }
 
-impl ShrAssign<Value> for Value {
+impl<T: Numeric> ShrAssign<Value> for Value<T> {
     fn shr_assign(&mut self, other: Value) {
-        if self.__equivalent(&other) {
+        if self.__equivalent(&other)
+            || self._level_equivalent(&other)
+            || self._power_equivalent(&other)
+        {
             match self._convert(&other) {
                 Ok(_) => {}
                 Err(_) => panic!("[shr_assign] Incompatible value types: {self}, {other}"),
@@ -143,7 +157,7 @@ impl ShrAssign<Value> for Value {
     }
 }
 
-impl ShrAssign<&str> for Value {
+impl<T: Numeric> ShrAssign<&str> for Value<T> {
     fn shr_assign(&mut self, other: &str) {
         let n: Value = match Value::new(1.0, other) {
             Ok(t) => t,
@@ -153,7 +167,7 @@ impl ShrAssign<&str> for Value {
     }
 }
 
-impl ShrAssign<String> for Value {
+impl<T: Numeric> ShrAssign<String> for Value<T> {
     fn shr_assign(&mut self, other: String) {
         let n: Value = match Value::new(1.0, other.as_str()) {
             Ok(t) => t,
@@ -163,7 +177,7 @@ impl ShrAssign<String> for Value {
     }
 }
 
-impl Value {
+impl<T: Numeric> Value<T> {
     /// Convert a [`Value`] to another of the same base unit types.
     ///
     /// `convert` uses a `&str` as an argument and parses it into the relevant units.
@@ -193,13 +207,46 @@ impl Value {
         self._convert(&temp)
     }
 
+    /// Returns `true` if two [`Value`]s only differ by power being written as energy per time
+    ///
+    /// e.g. W/(m*K) and BTU/(h*ft*°F)
+    pub(crate) fn _power_equivalent(&self, other: &Value) -> bool {
+        (self.unit_map | other.unit_map) & POWER_MAP != 0
+            && !self.__equivalent(other)
+            && self
+                .to_f64()
+                ._power_as_energy_rate()
+                .__equivalent(&other._power_as_energy_rate())
+    }
+
     /// Actual convert functionality with a given [`Value`] argument
     pub(crate) fn _convert(&mut self, other: &Value) -> Result<(), V3Error> {
-        if self.unit_map == VOLUME_MAP && other.unit_map == LENGTH_MAP {
+        if self._power_equivalent(other) {
+            *self = self._through_f64_value(|mut n| {
+                n._convert_power(other)?;
+                Ok(n)
+            })?;
+            return Ok(());
+        }
+        if self.unit_map == 0 && other.unit_map == 0 {
+            self.val =
+                Value::_rescale_ratio(self.val, self._ratio_factor(), other._ratio_factor())?;
+            self.v_ratio = other.v_ratio;
+            return Ok(());
+        } else if self._level_equivalent(other) {
+            *self = self._through_f64_value(|mut n| {
+                n._convert_level(other)?;
+                Ok(n)
+            })?;
+            return Ok(());
+        } else if self.unit_map == VOLUME_MAP && other.unit_map == LENGTH_MAP {
             if self.exp[VOLUME_INDEX] == 1 && other.exp[LENGTH_INDEX] == 3 {
-                self.val *= self.v_volume.unwrap().convert(&other.v_length.unwrap());
-                self.exp[LENGTH_INDEX] = 3;
-                self.exp[VOLUME_INDEX] = 0;
+                self.val = _scale(
+                    self.val,
+                    self.v_volume.unwrap().convert(&other.v_length.unwrap()),
+                )?;
+                self.exp[LENGTH_INDEX] = Exponent::from(3);
+                self.exp[VOLUME_INDEX] = Exponent::from(0);
                 self.unit_map = LENGTH_MAP;
                 self.v_volume = None;
                 self.v_length = other.v_length;
@@ -210,15 +257,21 @@ impl Value {
             ));
         } else if self.unit_map == LENGTH_MAP && other.unit_map == VOLUME_MAP {
             if self.exp[LENGTH_INDEX] == 3 && other.exp[VOLUME_INDEX] == 1 {
-                self.val *= f64::powf(
-                    self.v_length
-                        .unwrap()
-                        .convert(&UnitLength::Meter(Metric::None)),
-                    3.0,
-                );
-                self.val *= self.v_length.unwrap().convert(&other.v_volume.unwrap());
-                self.exp[LENGTH_INDEX] = 0;
-                self.exp[VOLUME_INDEX] = 1;
+                self.val = _scale(
+                    self.val,
+                    f64::powf(
+                        self.v_length
+                            .unwrap()
+                            .convert(&UnitLength::Meter(Metric::None)),
+                        3.0,
+                    ),
+                )?;
+                self.val = _scale(
+                    self.val,
+                    self.v_length.unwrap().convert(&other.v_volume.unwrap()),
+                )?;
+                self.exp[LENGTH_INDEX] = Exponent::from(0);
+                self.exp[VOLUME_INDEX] = Exponent::from(1);
                 self.unit_map = VOLUME_MAP;
                 self.v_volume = other.v_volume;
                 self.v_length = None;
@@ -229,7 +282,7 @@ impl Value {
             ));
         } else if self.unit_map == 0 && other.unit_map == ANGLE_MAP {
             if other.v_angle.unwrap() == UnitAngle::Radian(Metric::None) {
-                self.exp[ANGLE_INDEX] = 1;
+                self.exp[ANGLE_INDEX] = Exponent::from(1);
                 self.unit_map = ANGLE_MAP;
                 self.v_angle = Some(UnitAngle::Radian(Metric::None));
                 return Ok(());
@@ -242,11 +295,14 @@ impl Value {
             && self.exp[FREQUENCY_INDEX] == 1
             && other.exp[TIME_INDEX] == -1
         {
-            self.exp[FREQUENCY_INDEX] = 0;
-            self.exp[TIME_INDEX] = -1;
+            self.exp[FREQUENCY_INDEX] = Exponent::from(0);
+            self.exp[TIME_INDEX] = Exponent::from(-1);
             self.unit_map &= !FREQUENCY_MAP;
             self.unit_map |= TIME_MAP;
-            self.val *= self.v_frequency.unwrap().convert(&other.v_time.unwrap());
+            self.val = _scale(
+                self.val,
+                self.v_frequency.unwrap().convert(&other.v_time.unwrap()),
+            )?;
             self.v_frequency = None;
             self.v_time = other.v_time;
             return Ok(());
@@ -255,11 +311,14 @@ impl Value {
             && self.exp[TIME_INDEX] == -1
             && other.exp[FREQUENCY_INDEX] == 1
         {
-            self.exp[FREQUENCY_INDEX] = 1;
-            self.exp[TIME_INDEX] = 0;
+            self.exp[FREQUENCY_INDEX] = Exponent::from(1);
+            self.exp[TIME_INDEX] = Exponent::from(0);
             self.unit_map &= !TIME_MAP;
             self.unit_map |= FREQUENCY_MAP;
-            self.val *= self.v_time.unwrap().convert(&other.v_frequency.unwrap());
+            self.val = _scale(
+                self.val,
+                self.v_time.unwrap().convert(&other.v_frequency.unwrap()),
+            )?;
             self.v_frequency = other.v_frequency;
             self.v_time = None;
             return Ok(());
@@ -272,22 +331,19 @@ impl Value {
         }
 
         // check against temperature
-        if self.unit_map & TEMPERATURE_MAP < self.unit_map {
-            if self.v_temperature != other.v_temperature {
+        if self.unit_map == TEMPERATURE_MAP {
+            if self.exp[TEMPERATURE_INDEX] != other.exp[TEMPERATURE_INDEX] {
                 return Err(V3Error::ValueConversionError(
-                    "[_convert] Temperature unit mismatch",
+                    "[_convert] Mismatched value exponents",
                 ));
             }
-        } else if self.unit_map == TEMPERATURE_MAP {
-            self.val = self
-                .v_temperature
-                .unwrap()
-                .convert(&other.v_temperature.unwrap(), self.val)
-                .powi(self.exp[TEMPERATURE_INDEX]);
-            self.v_temperature = other.v_temperature;
-            return Ok(());
+            return self._convert_temperature(other.v_temperature.unwrap());
         }
 
+        // exact factors are composed across every unit and rounded once
+        let exact: Option<ExactFactor> = self._exact_factor_to(other);
+        let val: T = self.val;
+
         for i in 0..31_usize {
             if self.exp[i] != other.exp[i] {
                 return Err(V3Error::ValueConversionError(
@@ -297,13 +353,13 @@ impl Value {
             let region: usize = 1 << i;
             if region & self.unit_map != 0 {
                 let tmp: f64;
-                self.val *= match region {
+                let factor: f64 = match region {
                     LENGTH_MAP => {
                         tmp = self
                             .v_length
                             .unwrap()
                             .convert(&other.v_length.unwrap())
-                            .powi(self.exp[LENGTH_INDEX]);
+                            .powr(self.exp[LENGTH_INDEX]);
                         self.v_length = other.v_length;
                         tmp
                     }
@@ -312,7 +368,7 @@ impl Value {
                             .v_time
                             .unwrap()
                             .convert(&other.v_time.unwrap())
-                            .powi(self.exp[TIME_INDEX]);
+                            .powr(self.exp[TIME_INDEX]);
                         self.v_time = other.v_time;
                         tmp
                     }
@@ -321,7 +377,7 @@ impl Value {
                             .v_mass
                             .unwrap()
                             .convert(&other.v_mass.unwrap())
-                            .powi(self.exp[MASS_INDEX]);
+                            .powr(self.exp[MASS_INDEX]);
                         self.v_mass = other.v_mass;
                         tmp
                     }
@@ -330,7 +386,7 @@ impl Value {
                             .v_electric_current
                             .unwrap()
                             .convert(&other.v_electric_current.unwrap())
-                            .powi(self.exp[ELECTRIC_CURRENT_INDEX]);
+                            .powr(self.exp[ELECTRIC_CURRENT_INDEX]);
                         self.v_electric_current = other.v_electric_current;
                         tmp
                     }
@@ -339,7 +395,7 @@ impl Value {
                             .v_electric_charge
                             .unwrap()
                             .convert(&other.v_electric_charge.unwrap())
-                            .powi(self.exp[ELECTRIC_CHARGE_INDEX]);
+                            .powr(self.exp[ELECTRIC_CHARGE_INDEX]);
                         self.v_electric_charge = other.v_electric_charge;
                         tmp
                     }
@@ -348,7 +404,7 @@ impl Value {
                             .v_electric_potential
                             .unwrap()
                             .convert(&other.v_electric_potential.unwrap())
-                            .powi(self.exp[ELECTRIC_POTENTIAL_INDEX]);
+                            .powr(self.exp[ELECTRIC_POTENTIAL_INDEX]);
                         self.v_electric_potential = other.v_electric_potential;
                         tmp
                     }
@@ -357,7 +413,7 @@ impl Value {
                             .v_electric_conductance
                             .unwrap()
                             .convert(&other.v_electric_conductance.unwrap())
-                            .powi(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
+                            .powr(self.exp[ELECTRIC_CONDUCTANCE_INDEX]);
                         self.v_electric_conductance = other.v_electric_conductance;
                         tmp
                     }
@@ -366,7 +422,7 @@ impl Value {
                             .v_capacitance
                             .unwrap()
                             .convert(&other.v_capacitance.unwrap())
-                            .powi(self.exp[CAPACITANCE_INDEX]);
+                            .powr(self.exp[CAPACITANCE_INDEX]);
                         self.v_capacitance = other.v_capacitance;
                         tmp
                     }
@@ -375,7 +431,7 @@ impl Value {
                             .v_resistance
                             .unwrap()
                             .convert(&other.v_resistance.unwrap())
-                            .powi(self.exp[RESISTANCE_INDEX]);
+                            .powr(self.exp[RESISTANCE_INDEX]);
                         self.v_resistance = other.v_resistance;
                         tmp
                     }
@@ -384,7 +440,7 @@ impl Value {
                             .v_inductance
                             .unwrap()
                             .convert(&other.v_inductance.unwrap())
-                            .powi(self.exp[INDUCTANCE_INDEX]);
+                            .powr(self.exp[INDUCTANCE_INDEX]);
                         self.v_inductance = other.v_inductance;
                         tmp
                     }
@@ -393,7 +449,7 @@ impl Value {
                             .v_magnetic_flux
                             .unwrap()
                             .convert(&other.v_magnetic_flux.unwrap())
-                            .powi(self.exp[MAGNETIC_FLUX_INDEX]);
+                            .powr(self.exp[MAGNETIC_FLUX_INDEX]);
                         self.v_magnetic_flux = other.v_magnetic_flux;
                         tmp
                     }
@@ -402,17 +458,26 @@ impl Value {
                             .v_magnetic_flux_density
                             .unwrap()
                             .convert(&other.v_magnetic_flux_density.unwrap())
-                            .powi(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
+                            .powr(self.exp[MAGNETIC_FLUX_DENSITY_INDEX]);
                         self.v_magnetic_flux_density = other.v_magnetic_flux_density;
                         tmp
                     }
-                    TEMPERATURE_MAP => unreachable!("This cannot be reached!"),
+                    TEMPERATURE_MAP => {
+                        // temperatures within compound units are intervals
+                        tmp = self
+                            .v_temperature
+                            .unwrap()
+                            .interval_factor(&other.v_temperature.unwrap())
+                            .powr(self.exp[TEMPERATURE_INDEX]);
+                        self.v_temperature = other.v_temperature;
+                        tmp
+                    }
                     SUBSTANCE_MAP => {
                         tmp = self
                             .v_substance
                             .unwrap()
                             .convert(&other.v_substance.unwrap())
-                            .powi(self.exp[SUBSTANCE_INDEX]);
+                            .powr(self.exp[SUBSTANCE_INDEX]);
                         self.v_substance = other.v_substance;
                         tmp
                     }
@@ -421,7 +486,7 @@ impl Value {
                             .v_luminous_flux_intensity
                             .unwrap()
                             .convert(&other.v_luminous_flux_intensity.unwrap())
-                            .powi(self.exp[LUMINOUS_INTENSITY_INDEX]);
+                            .powr(self.exp[LUMINOUS_INTENSITY_INDEX]);
                         self.v_luminous_flux_intensity = other.v_luminous_flux_intensity;
                         tmp
                     }
@@ -430,7 +495,7 @@ impl Value {
                             .v_luminous_flux
                             .unwrap()
                             .convert(&other.v_luminous_flux.unwrap())
-                            .powi(self.exp[LUMINOUS_FLUX_INDEX]);
+                            .powr(self.exp[LUMINOUS_FLUX_INDEX]);
                         self.v_luminous_flux = other.v_luminous_flux;
                         tmp
                     }
@@ -439,7 +504,7 @@ impl Value {
                             .v_illuminance
                             .unwrap()
                             .convert(&other.v_illuminance.unwrap())
-                            .powi(self.exp[ILLUMINANCE_INDEX]);
+                            .powr(self.exp[ILLUMINANCE_INDEX]);
                         self.v_illuminance = other.v_illuminance;
                         tmp
                     }
@@ -448,7 +513,7 @@ impl Value {
                             .v_volume
                             .unwrap()
                             .convert(&other.v_volume.unwrap())
-                            .powi(self.exp[VOLUME_INDEX]);
+                            .powr(self.exp[VOLUME_INDEX]);
                         self.v_volume = other.v_volume;
                         tmp
                     }
@@ -457,7 +522,7 @@ impl Value {
                             .v_pressure
                             .unwrap()
                             .convert(&other.v_pressure.unwrap())
-                            .powi(self.exp[PRESSURE_INDEX]);
+                            .powr(self.exp[PRESSURE_INDEX]);
                         self.v_pressure = other.v_pressure;
                         tmp
                     }
@@ -466,7 +531,7 @@ impl Value {
                             .v_angle
                             .unwrap()
                             .convert(&other.v_angle.unwrap())
-                            .powi(self.exp[ANGLE_INDEX]);
+                            .powr(self.exp[ANGLE_INDEX]);
                         self.v_angle = other.v_angle;
                         tmp
                     }
@@ -475,7 +540,7 @@ impl Value {
                             .v_frequency
                             .unwrap()
                             .convert(&other.v_frequency.unwrap())
-                            .powi(self.exp[FREQUENCY_INDEX]);
+                            .powr(self.exp[FREQUENCY_INDEX]);
                         self.v_frequency = other.v_frequency;
                         tmp
                     }
@@ -484,7 +549,7 @@ impl Value {
                             .v_force
                             .unwrap()
                             .convert(&other.v_force.unwrap())
-                            .powi(self.exp[FORCE_INDEX]);
+                            .powr(self.exp[FORCE_INDEX]);
                         self.v_force = other.v_force;
                         tmp
                     }
@@ -493,7 +558,7 @@ impl Value {
                             .v_energy
                             .unwrap()
                             .convert(&other.v_energy.unwrap())
-                            .powi(self.exp[ENERGY_INDEX]);
+                            .powr(self.exp[ENERGY_INDEX]);
                         self.v_energy = other.v_energy;
                         tmp
                     }
@@ -502,7 +567,7 @@ impl Value {
                             .v_power
                             .unwrap()
                             .convert(&other.v_power.unwrap())
-                            .powi(self.exp[POWER_INDEX]);
+                            .powr(self.exp[POWER_INDEX]);
                         self.v_power = other.v_power;
                         tmp
                     }
@@ -511,7 +576,7 @@ impl Value {
                             .v_radioactivity
                             .unwrap()
                             .convert(&other.v_radioactivity.unwrap())
-                            .powi(self.exp[RADIOACTIVITY_INDEX]);
+                            .powr(self.exp[RADIOACTIVITY_INDEX]);
                         self.v_radioactivity = other.v_radioactivity;
                         tmp
                     }
@@ -520,7 +585,7 @@ impl Value {
                             .v_ab_dose
                             .unwrap()
                             .convert(&other.v_ab_dose.unwrap())
-                            .powi(self.exp[ABSORBED_DOSE_INDEX]);
+                            .powr(self.exp[ABSORBED_DOSE_INDEX]);
                         self.v_ab_dose = other.v_ab_dose;
                         tmp
                     }
@@ -529,7 +594,7 @@ impl Value {
                             .v_radioactivity_exposure
                             .unwrap()
                             .convert(&other.v_radioactivity_exposure.unwrap())
-                            .powi(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
+                            .powr(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
                         self.v_radioactivity_exposure = other.v_radioactivity_exposure;
                         tmp
                     }
@@ -538,16 +603,22 @@ impl Value {
                             .v_catalytic
                             .unwrap()
                             .convert(&other.v_catalytic.unwrap())
-                            .powi(self.exp[CATALYTIC_ACTIVITY_INDEX]);
+                            .powr(self.exp[CATALYTIC_ACTIVITY_INDEX]);
                         self.v_catalytic = other.v_catalytic;
                         tmp
                     }
                     SOUND_MAP => {
-                        tmp = self
-                            .v_sound
-                            .unwrap()
+                        let l: UnitSound = self.v_sound.unwrap();
+                        if l != other.v_sound.unwrap()
+                            && (l.is_referenced() || other.v_sound.unwrap().is_referenced())
+                        {
+                            return Err(V3Error::ValueConversionError(
+                                "[_convert] Cannot convert between levels of different references",
+                            ));
+                        }
+                        tmp = l
                             .convert(&other.v_sound.unwrap())
-                            .powi(self.exp[SOUND_INDEX]);
+                            .powr(self.exp[SOUND_INDEX]);
                         self.v_sound = other.v_sound;
                         tmp
                     }
@@ -556,7 +627,7 @@ impl Value {
                             .v_information
                             .unwrap()
                             .convert(&other.v_information.unwrap())
-                            .powi(self.exp[INFORMATION_INDEX]);
+                            .powr(self.exp[INFORMATION_INDEX]);
                         self.v_information = other.v_information;
                         tmp
                     }
@@ -565,7 +636,7 @@ impl Value {
                             .v_solid_angle
                             .unwrap()
                             .convert(&other.v_solid_angle.unwrap())
-                            .powi(self.exp[SOLID_ANGLE_INDEX]);
+                            .powr(self.exp[SOLID_ANGLE_INDEX]);
                         self.v_solid_angle = other.v_solid_angle;
                         tmp
                     }
@@ -573,12 +644,261 @@ impl Value {
                         return Err(V3Error::UnknownError("[_convert] Value conversion"));
                     }
                 };
+                if exact.is_none() {
+                    self.val = _scale(self.val, factor)?;
+                }
             }
         }
+        if let Some(f) = exact {
+            self.val = _representable(f._apply_to(val))?;
+        }
+        self._convert_custom(other)
+    }
+
+    /// Returns the exact factor converting the units of `self` into those of `other`
+    ///
+    /// Returns `None` if any unit, such as a temperature or level, has no exact conversion.
+    pub(in crate::value) fn _exact_factor_to<U>(&self, other: &Value<U>) -> Option<ExactFactor> {
+        if self.unit_map != other.unit_map || self.has_custom_units() {
+            return None;
+        }
+        let mut f: ExactFactor = ExactFactor::ONE;
+        for i in 0..31_usize {
+            let region: usize = 1 << i;
+            if region & self.unit_map == 0 {
+                continue;
+            }
+            f = f.checked_mul(match region {
+                LENGTH_MAP => _exact_pair(self.v_length, other.v_length, self.exp[LENGTH_INDEX])?,
+                TIME_MAP => _exact_pair(self.v_time, other.v_time, self.exp[TIME_INDEX])?,
+                MASS_MAP => _exact_pair(self.v_mass, other.v_mass, self.exp[MASS_INDEX])?,
+                ELECTRIC_CURRENT_MAP => _exact_pair(
+                    self.v_electric_current,
+                    other.v_electric_current,
+                    self.exp[ELECTRIC_CURRENT_INDEX],
+                )?,
+                ELECTRIC_CHARGE_MAP => _exact_pair(
+                    self.v_electric_charge,
+                    other.v_electric_charge,
+                    self.exp[ELECTRIC_CHARGE_INDEX],
+                )?,
+                ELECTRIC_POTENTIAL_MAP => _exact_pair(
+                    self.v_electric_potential,
+                    other.v_electric_potential,
+                    self.exp[ELECTRIC_POTENTIAL_INDEX],
+                )?,
+                ELECTRIC_CONDUCTANCE_MAP => _exact_pair(
+                    self.v_electric_conductance,
+                    other.v_electric_conductance,
+                    self.exp[ELECTRIC_CONDUCTANCE_INDEX],
+                )?,
+                CAPACITANCE_MAP => _exact_pair(
+                    self.v_capacitance,
+                    other.v_capacitance,
+                    self.exp[CAPACITANCE_INDEX],
+                )?,
+                RESISTANCE_MAP => _exact_pair(
+                    self.v_resistance,
+                    other.v_resistance,
+                    self.exp[RESISTANCE_INDEX],
+                )?,
+                INDUCTANCE_MAP => _exact_pair(
+                    self.v_inductance,
+                    other.v_inductance,
+                    self.exp[INDUCTANCE_INDEX],
+                )?,
+                MAGNETIC_FLUX_MAP => _exact_pair(
+                    self.v_magnetic_flux,
+                    other.v_magnetic_flux,
+                    self.exp[MAGNETIC_FLUX_INDEX],
+                )?,
+                MAGNETIC_FLUX_DENSITY_MAP => _exact_pair(
+                    self.v_magnetic_flux_density,
+                    other.v_magnetic_flux_density,
+                    self.exp[MAGNETIC_FLUX_DENSITY_INDEX],
+                )?,
+                SUBSTANCE_MAP => _exact_pair(
+                    self.v_substance,
+                    other.v_substance,
+                    self.exp[SUBSTANCE_INDEX],
+                )?,
+                LUMINOUS_INTENSITY_MAP => _exact_pair(
+                    self.v_luminous_flux_intensity,
+                    other.v_luminous_flux_intensity,
+                    self.exp[LUMINOUS_INTENSITY_INDEX],
+                )?,
+                LUMINOUS_FLUX_MAP => _exact_pair(
+                    self.v_luminous_flux,
+                    other.v_luminous_flux,
+                    self.exp[LUMINOUS_FLUX_INDEX],
+                )?,
+                ILLUMINANCE_MAP => _exact_pair(
+                    self.v_illuminance,
+                    other.v_illuminance,
+                    self.exp[ILLUMINANCE_INDEX],
+                )?,
+                VOLUME_MAP => _exact_pair(self.v_volume, other.v_volume, self.exp[VOLUME_INDEX])?,
+                PRESSURE_MAP => {
+                    _exact_pair(self.v_pressure, other.v_pressure, self.exp[PRESSURE_INDEX])?
+                }
+                ANGLE_MAP => _exact_pair(self.v_angle, other.v_angle, self.exp[ANGLE_INDEX])?,
+                FREQUENCY_MAP => _exact_pair(
+                    self.v_frequency,
+                    other.v_frequency,
+                    self.exp[FREQUENCY_INDEX],
+                )?,
+                FORCE_MAP => _exact_pair(self.v_force, other.v_force, self.exp[FORCE_INDEX])?,
+                ENERGY_MAP => _exact_pair(self.v_energy, other.v_energy, self.exp[ENERGY_INDEX])?,
+                POWER_MAP => _exact_pair(self.v_power, other.v_power, self.exp[POWER_INDEX])?,
+                RADIOACTIVITY_MAP => _exact_pair(
+                    self.v_radioactivity,
+                    other.v_radioactivity,
+                    self.exp[RADIOACTIVITY_INDEX],
+                )?,
+                ABSORBED_DOSE_MAP => _exact_pair(
+                    self.v_ab_dose,
+                    other.v_ab_dose,
+                    self.exp[ABSORBED_DOSE_INDEX],
+                )?,
+                RADIOACTIVITY_EXPOSURE_MAP => _exact_pair(
+                    self.v_radioactivity_exposure,
+                    other.v_radioactivity_exposure,
+                    self.exp[RADIOACTIVITY_EXPOSURE_INDEX],
+                )?,
+                CATALYTIC_ACTIVITY_MAP => _exact_pair(
+                    self.v_catalytic,
+                    other.v_catalytic,
+                    self.exp[CATALYTIC_ACTIVITY_INDEX],
+                )?,
+                INFORMATION_MAP => _exact_pair(
+                    self.v_information,
+                    other.v_information,
+                    self.exp[INFORMATION_INDEX],
+                )?,
+                SOLID_ANGLE_MAP => _exact_pair(
+                    self.v_solid_angle,
+                    other.v_solid_angle,
+                    self.exp[SOLID_ANGLE_INDEX],
+                )?,
+                _ => return None,
+            })?;
+        }
+        Some(f)
+    }
+}
+
+impl Value {
+    /// Convert a [`Value`] to any unit with the same dimensions.
+    ///
+    /// Unlike [`Value::convert`], the given unit string may be written with different
+    /// unit types. Both units are reduced to their SI base units and must match there.
+    ///
+    /// e.g. N and kg*m/s^2, or W/(m*K) and BTU/(h*ft*°F)
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let mut f:Value = Value::new(1.0, "N").unwrap();
+    ///
+    /// f.convert_dimensional("g*cm/s^2").unwrap();
+    /// assert!((f.val - 100_000.0).abs() < 1e-6);
+    /// assert_eq!(f.unit_string(), "cm*g/s^2");
+    ///
+    /// assert!(f.convert_dimensional("J").is_err());
+    /// ```
+    pub fn convert_dimensional(&mut self, other: &str) -> Result<(), V3Error> {
+        let temp: Value = Value::new(1.0, other)?;
+        self._convert_dimensional(&temp)
+    }
+
+    /// Actual dimensional convert functionality with a given [`Value`] argument
+    pub(crate) fn _convert_dimensional(&mut self, other: &Value) -> Result<(), V3Error> {
+        if self.__equivalent(other) || self._level_equivalent(other) {
+            return self._convert(other);
+        }
+
+        let mut unit: Value = *other;
+        unit.val = 1.0;
+        let lhs: Value = self._si_base()?;
+        let rhs: Value = unit._si_base()?;
+        if !lhs.__equal(&rhs) {
+            return Err(V3Error::ValueConversionError(
+                "[_convert_dimensional] Nonequivalent dimensions",
+            ));
+        }
+
+        unit.val = lhs.val / rhs.val;
+        *self = unit;
+        Ok(())
+    }
+
+    /// Converts a [`Value`] in place without panicking
+    ///
+    /// This is the non-panicking counterpart of `>>=` and accepts anything that `>>` does,
+    /// such as another [`Value`], a unit string, or a single unit type.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{UnitLength, UnitTime};
+    /// let mut d:Value = 3.0 * UnitLength::Foot;
+    ///
+    /// d.try_convert_assign(UnitLength::Inch).unwrap();
+    /// assert_eq!(d.unit_string(), "in");
+    ///
+    /// assert!(d.try_convert_assign("yd").is_ok());
+    /// assert!(d.try_convert_assign(UnitTime::Hour).is_err());
+    /// assert!(d.try_convert_assign("zz").is_err());
+    /// ```
+    pub fn try_convert_assign<T>(&mut self, other: T) -> Result<(), V3Error>
+    where
+        Value: Shr<T, Output = Result<Value, V3Error>>,
+    {
+        *self = (*self >> other)?;
+        Ok(())
+    }
+
+    /// Rewrites any power in a [`Value`] as J/s
+    fn _power_as_energy_rate(&self) -> Value {
+        if self.unit_map & POWER_MAP == 0 {
+            return *self;
+        }
+        let exp: Exponent = self.exp[POWER_INDEX];
+        let mut ret: Value = *self;
+        ret.val *= self
+            .v_power
+            .unwrap()
+            .convert(&UnitPower::Watt(Metric::None))
+            .powr(exp);
+        ret.v_power = None;
+        ret.exp[POWER_INDEX] = Exponent::ZERO;
+        ret.unit_map &= !POWER_MAP;
+
+        let mut rate: Value =
+            1.0 * UnitEnergy::Joule(Metric::None) / UnitTime::Second(Metric::None);
+        rate.exp[ENERGY_INDEX] = exp;
+        rate.exp[TIME_INDEX] = -exp;
+        ret * rate
+    }
+
+    /// Converts between [`Value`]s that are only equivalent once power is written as J/s
+    fn _convert_power(&mut self, other: &Value) -> Result<(), V3Error> {
+        let mut unit: Value = *other;
+        unit.val = 1.0;
+        let rate: Value = unit._power_as_energy_rate();
+        let mut ret: Value = self._power_as_energy_rate();
+        ret._convert(&rate)?;
+        unit.val = ret.val / rate.val;
+        *self = unit;
         Ok(())
     }
 }
 
+/// Returns the exact factor between two units of the same kind raised to an exponent
+fn _exact_pair<U: BaseUnit>(from: Option<U>, to: Option<U>, e: Exponent) -> Option<ExactFactor> {
+    exact_convert(&from?, &to?)?.checked_powr(e)
+}
+
 #[cfg(test)]
 mod conversion_testing {
     use crate::units::{
@@ -590,6 +910,7 @@ mod conversion_testing {
         UnitRadioactivity, UnitRadioactivityExposure, UnitSolidAngle, UnitSound, UnitSubstance,
         UnitTemperature, UnitTime, UnitVolume,
     };
+    use crate::value::Value;
 
     #[test]
     fn explicit_convert() {
@@ -604,11 +925,45 @@ mod conversion_testing {
         t1.convert("zz").unwrap();
     }
 
+    #[test]
+    fn exact_round_trips() {
+        // composite units convert through a single rounding
+        for v in [1.0, 4.5, 17.3, 1234.567] {
+            let ft: Value = Value::new(v, "ft").unwrap();
+            let back: Value = ((ft >> "in").unwrap() >> "ft").unwrap();
+            assert_eq!(back.val.to_bits(), ft.val.to_bits());
+
+            let x: Value = Value::new(v, "lb*ft/s^2").unwrap();
+            let back: Value = ((x >> "g*m/min^2").unwrap() >> "lb*ft/s^2").unwrap();
+            assert_eq!(back.val.to_bits(), x.val.to_bits());
+
+            let m: Value = Value::new(v, "km").unwrap();
+            let back: Value = ((m >> "nm").unwrap() >> "km").unwrap();
+            assert_eq!(back.val.to_bits(), m.val.to_bits());
+        }
+
+        let v: Value = Value::new(2.0, "ft^3").unwrap();
+        assert_eq!((v >> "in^3").unwrap().val, 3456.0);
+        let v: Value = Value::new(1.0, "miles/hr").unwrap();
+        assert_eq!((v >> "ft/s").unwrap().val, 5280.0 / 3600.0);
+        let v: Value = Value::new(3.0, "oz").unwrap();
+        assert_eq!((v >> "lb").unwrap().val, 0.1875);
+        let v: Value = Value::new(180.0, "degrees").unwrap();
+        assert_eq!((v >> "rad").unwrap().val, std::f64::consts::PI);
+        let v: Value = Value::new(1.0, "kb").unwrap();
+        assert_eq!((v >> "bits").unwrap().val, 8192.0);
+
+        // sums are taken in exact units as well
+        let a: Value = Value::new(1.0, "ft").unwrap();
+        assert_eq!((a + Value::new(6.0, "in").unwrap()).val, 1.5);
+        assert_eq!(a, (Value::new(12.0, "in").unwrap() >> "ft").unwrap());
+    }
+
     #[test]
     fn str_slide() {
         let t1 = 4.5 * UnitLength::Foot;
         let t2 = (t1 >> "m").unwrap();
-        assert_eq!(t2.val, 1.3716000000000002);
+        assert_eq!(t2.val, 1.3716);
     }
 
     #[test]
@@ -622,7 +977,7 @@ mod conversion_testing {
     fn str_slide_mut() {
         let mut t1 = 4.5 * UnitLength::Foot;
         t1 >>= "m";
-        assert_eq!(t1.val, 1.3716000000000002);
+        assert_eq!(t1.val, 1.3716);
     }
 
     #[test]
@@ -636,14 +991,14 @@ mod conversion_testing {
     fn string_slide() {
         let t1 = 4.5 * UnitLength::Foot;
         let t2 = (t1 >> String::from("m")).unwrap();
-        assert_eq!(t2.val, 1.3716000000000002);
+        assert_eq!(t2.val, 1.3716);
     }
 
     #[test]
     fn string_slide_mut() {
         let mut t1 = 4.5 * UnitLength::Foot;
         t1 >>= String::from("m");
-        assert_eq!(t1.val, 1.3716000000000002);
+        assert_eq!(t1.val, 1.3716);
     }
 
     #[test]
@@ -728,11 +1083,36 @@ mod conversion_testing {
     }
 
     #[test]
-    #[should_panic]
-    fn temperature_conversion_multiple_types_bad() {
+    fn temperature_conversion_multiple_types() {
         let mut t1 = 4.5 * UnitLength::Meter(Metric::None) * UnitTemperature::Celsius(Metric::None);
         let t2 = 1.0 * UnitLength::Meter(Metric::None) * UnitTemperature::Kelvin(Metric::None);
 
+        t1._convert(&t2).unwrap();
+        assert_eq!(t1.to_string(), "4.5 m*K");
+
+        let t = Value::new(1.0, "J/(s*m*K)").unwrap();
+        let t = (t >> "BTU/(h*ft*°F)").unwrap();
+        assert!((t.val - 0.577_789_3).abs() < 1e-6);
+
+        let k = Value::new(1.0, "W/(m*K)").unwrap();
+        let b = (k >> "BTU/(h*ft*°F)").unwrap();
+        assert!((b.val - 0.577_789_3).abs() < 1e-6);
+        assert_eq!(b.unit_string(), "BTU/ft*hr*°f");
+        let back = (b >> "W/(m*K)").unwrap();
+        assert!((back.val - 1.0).abs() < 1e-12);
+        assert!((Value::new(1.0, "W").unwrap() >> "J/s").unwrap().val == 1.0);
+        assert!((Value::new(1.0, "W").unwrap() >> "J/m").is_err());
+
+        let t = Value::new(1.0, "J/(kg*°F)").unwrap();
+        assert!(((t >> "J/(kg*K)").unwrap().val - 1.8).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn temperature_conversion_interval_bad() {
+        let mut t1 = 4.5 * UnitTemperature::Celsius(Metric::None);
+        let t2 = 1.0 * UnitTemperature::DeltaKelvin(Metric::None);
+
         t1._convert(&t2).unwrap();
     }
 
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{POWER_INDEX, POWER_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitPower> for Value {
             .v_power
             .unwrap()
             .convert(&other)
-            .powi(self.exp[POWER_INDEX]);
+            .powr(self.exp[POWER_INDEX]);
         n.v_power = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitPower> for Value {
             .v_power
             .unwrap()
             .convert(&other)
-            .powi(self.exp[POWER_INDEX]);
+            .powr(self.exp[POWER_INDEX]);
         self.v_power = Some(other);
     }
 }
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{PRESSURE_INDEX, PRESSURE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitPressure> for Value {
             .v_pressure
             .unwrap()
             .convert(&other)
-            .powi(self.exp[PRESSURE_INDEX]);
+            .powr(self.exp[PRESSURE_INDEX]);
         n.v_pressure = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitPressure> for Value {
             .v_pressure
             .unwrap()
             .convert(&other)
-            .powi(self.exp[PRESSURE_INDEX]);
+            .powr(self.exp[PRESSURE_INDEX]);
         self.v_pressure = Some(other);
     }
 }
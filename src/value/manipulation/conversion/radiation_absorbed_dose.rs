@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{ABSORBED_DOSE_INDEX, ABSORBED_DOSE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitAbsorbedDose> for Value {
             .v_ab_dose
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ABSORBED_DOSE_INDEX]);
+            .powr(self.exp[ABSORBED_DOSE_INDEX]);
         n.v_ab_dose = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitAbsorbedDose> for Value {
             .v_ab_dose
             .unwrap()
             .convert(&other)
-            .powi(self.exp[ABSORBED_DOSE_INDEX]);
+            .powr(self.exp[ABSORBED_DOSE_INDEX]);
         self.v_ab_dose = Some(other);
     }
 }
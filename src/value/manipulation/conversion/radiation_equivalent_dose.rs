@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{RADIOACTIVITY_EXPOSURE_INDEX, RADIOACTIVITY_EXPOSURE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitRadioactivityExposure> for Value {
             .v_radioactivity_exposure
             .unwrap()
             .convert(&other)
-            .powi(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
+            .powr(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
         n.v_radioactivity_exposure = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitRadioactivityExposure> for Value {
             .v_radioactivity_exposure
             .unwrap()
             .convert(&other)
-            .powi(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
+            .powr(self.exp[RADIOACTIVITY_EXPOSURE_INDEX]);
         self.v_radioactivity_exposure = Some(other);
     }
 }
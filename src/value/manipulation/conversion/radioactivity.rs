@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{RADIOACTIVITY_INDEX, RADIOACTIVITY_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitRadioactivity> for Value {
             .v_radioactivity
             .unwrap()
             .convert(&other)
-            .powi(self.exp[RADIOACTIVITY_INDEX]);
+            .powr(self.exp[RADIOACTIVITY_INDEX]);
         n.v_radioactivity = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitRadioactivity> for Value {
             .v_radioactivity
             .unwrap()
             .convert(&other)
-            .powi(self.exp[RADIOACTIVITY_INDEX]);
+            .powr(self.exp[RADIOACTIVITY_INDEX]);
         self.v_radioactivity = Some(other);
     }
 }
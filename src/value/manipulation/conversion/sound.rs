@@ -17,41 +17,103 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{SOUND_INDEX, SOUND_MAP},
     errors::V3Error,
-    units::{Convert, UnitSound},
-    value::Value,
+    units::{Convert, Metric, UnitElectricPotential, UnitPower, UnitPressure, UnitSound},
+    value::{Value, numeric::Numeric},
 };
 
 impl Shr<UnitSound> for Value {
     type Output = Result<Value, V3Error>;
     fn shr(self, other: UnitSound) -> Self::Output {
         let mut n: Value = self;
-        if self.unit_map & SOUND_MAP == 0 {
-            return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
-        }
-        n.val *= n
-            .v_sound
-            .unwrap()
-            .convert(&other)
-            .powi(self.exp[SOUND_INDEX]);
-        n.v_sound = Some(other);
+        n._convert_sound(other)?;
         Ok(n)
     }
 }
 
 impl ShrAssign<UnitSound> for Value {
     fn shr_assign(&mut self, other: UnitSound) {
-        if self.unit_map & SOUND_MAP == 0 {
+        if self._convert_sound(other).is_err() {
             panic!("[shr_assign] Incompatible value types");
         }
-        self.val *= self
-            .v_sound
-            .unwrap()
-            .convert(&other)
-            .powi(self.exp[SOUND_INDEX]);
+    }
+}
+
+impl Value {
+    /// Converts the sound unit of a [`Value`]
+    ///
+    /// A lone level is converted with the offsets between its references, while
+    /// referenced levels cannot be converted within compound units.
+    fn _convert_sound(&mut self, other: UnitSound) -> Result<(), V3Error> {
+        if self.unit_map & SOUND_MAP == 0 {
+            return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
+        }
+        let l: UnitSound = self.v_sound.unwrap();
+        if self.is_sound() && l.is_compatible(&other) {
+            self.val = l.convert_level(&other, self.val);
+        } else if l != other && (self.is_sound() || l.is_referenced() || other.is_referenced()) {
+            return Err(V3Error::ValueConversionError(
+                "[shr] Cannot convert between levels of different references",
+            ));
+        } else {
+            self.val *= l.convert(&other).powr(self.exp[SOUND_INDEX]);
+        }
         self.v_sound = Some(other);
+        Ok(())
+    }
+
+    /// Returns the linear quantity that a referenced level is measured against
+    fn _level_reference(unit: UnitSound) -> Option<Value> {
+        match unit {
+            UnitSound::DecibelMilliwatt => Some(1.0 * UnitPower::Watt(Metric::Milli)),
+            UnitSound::DecibelWatt => Some(1.0 * UnitPower::Watt(Metric::None)),
+            UnitSound::DecibelVolt => Some(1.0 * UnitElectricPotential::Volt(Metric::None)),
+            UnitSound::DecibelMicrovolt => Some(1.0 * UnitElectricPotential::Volt(Metric::Micro)),
+            UnitSound::DecibelSoundPressure => Some(20.0 * UnitPressure::Pascal(Metric::Micro)),
+            _ => None,
+        }
+    }
+
+    /// Converts a level into another level or into its linear quantity, or the reverse
+    ///
+    /// `self` and `other` must be equivalent as levels, see `_level_equivalent`.
+    pub(in crate::value) fn _convert_level(&mut self, other: &Value) -> Result<(), V3Error> {
+        if self.is_sound() && other.is_sound() {
+            return self._convert_sound(other.v_sound.unwrap());
+        } else if self.is_sound() {
+            let l: UnitSound = self.v_sound.unwrap();
+            let mut n: Value = Value::_level_reference(l).unwrap();
+            n.val *= 10.0_f64.powf(self.val / l.level_multiplier().unwrap());
+            n._convert(other)?;
+            *self = n;
+            return Ok(());
+        }
+        let l: UnitSound = other.v_sound.unwrap();
+        let r: Value = Value::_level_reference(l).unwrap();
+        let mut n: Value = *self;
+        n._convert(&r)?;
+        *self = (l.level_multiplier().unwrap() * (n.val / r.val).log10()) * l;
+        Ok(())
+    }
+}
+
+impl<T: Numeric> Value<T> {
+    /// Returns `true` if `self` can be converted into `other` as a level
+    ///
+    /// This covers conversions between compatible levels, and between a referenced
+    /// level and its linear quantity.
+    pub(in crate::value) fn _level_equivalent(&self, other: &Value) -> bool {
+        match (self.is_sound(), other.is_sound()) {
+            (true, true) => self.v_sound.unwrap().is_compatible(&other.v_sound.unwrap()),
+            (true, false) => Value::_level_reference(self.v_sound.unwrap())
+                .is_some_and(|r| r.__equivalent(other)),
+            (false, true) => Value::_level_reference(other.v_sound.unwrap())
+                .is_some_and(|r| r.__equivalent(self)),
+            (false, false) => false,
+        }
     }
 }
 
@@ -73,7 +135,8 @@ mod conversion_testing {
         };
     }
 
-    use crate::units::{Metric, UnitLength, UnitSound};
+    use crate::units::{Metric, UnitFrequency, UnitLength, UnitSound};
+    use crate::value::Value;
 
     const TEST_METRIC: [(Metric, &str); 25] = [
         (Metric::Quetta, "Q"),
@@ -156,4 +219,66 @@ mod conversion_testing {
             t1 = 4.1 * UnitSound::Bel(Metric::None);
         }
     }
+
+    #[test]
+    fn level_conversions() {
+        let p = 30.0 * UnitSound::DecibelMilliwatt;
+        assert_eq!((p >> UnitSound::DecibelWatt).unwrap().to_string(), "0 dBW");
+        assert!((p >> UnitSound::Bel(Metric::Deci)).is_err());
+        assert!((p >> UnitSound::DecibelVolt).is_err());
+
+        let v = 1.0 * UnitSound::Neper;
+        assert_apr!(
+            (v >> UnitSound::Bel(Metric::Deci)).unwrap().val,
+            8.685_889_638
+        );
+        assert_apr!((v >> "dB").unwrap().val, 8.685_889_638);
+        let mut n = 20.0 * UnitSound::Bel(Metric::Deci);
+        n >>= UnitSound::Bel(Metric::None);
+        assert_apr!(n.val, 2.0);
+
+        let mut d = -174.0 * UnitSound::DecibelMilliwatt / UnitFrequency::Hertz(Metric::None);
+        assert!((d >> "dBW/Hz").is_err());
+        assert!(d.convert("dBm/kHz").is_ok());
+        assert_apr!(d.val, -174_000.0);
+    }
+
+    #[test]
+    fn level_linear_conversions() {
+        let p = 20.0 * UnitSound::DecibelMilliwatt;
+        assert_apr!((p >> "mW").unwrap().val, 100.0);
+        assert_apr!((p >> "W").unwrap().val, 0.1);
+        assert_apr!(
+            ((-10.0 * UnitSound::DecibelWatt) >> "mW").unwrap().val,
+            100.0
+        );
+        assert_apr!(
+            ((6.0 * UnitSound::DecibelVolt) >> "V").unwrap().val,
+            1.995_262_314_968_879_6
+        );
+        assert_apr!(
+            ((0.0 * UnitSound::DecibelMicrovolt) >> "V").unwrap().val,
+            1.0e-6
+        );
+        assert_apr!(
+            ((94.0 * UnitSound::DecibelSoundPressure) >> "Pa")
+                .unwrap()
+                .val,
+            1.002_374,
+            1e-6
+        );
+
+        let w = Value::new(2.0, "W").unwrap();
+        assert_apr!((w >> "dBm").unwrap().val, 33.010_299_956_639_81);
+        assert_apr!((w >> "dBW").unwrap().val, 3.010_299_956_639_812);
+        let mut pa = Value::new(1.0, "Pa").unwrap();
+        pa >>= "dBSPL";
+        assert_apr!(pa.val, 93.979_400_086_720_38);
+        assert_eq!(pa.unit_string(), "dBSPL");
+
+        assert!((w >> "dBV").is_err());
+        assert!((w >> "dB").is_err());
+        assert!((p >> "V").is_err());
+        assert!(((1.0 * UnitSound::Bel(Metric::Deci)) >> "W").is_err());
+    }
 }
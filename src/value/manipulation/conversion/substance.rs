@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{SUBSTANCE_INDEX, SUBSTANCE_MAP},
     errors::V3Error,
@@ -35,7 +36,7 @@ impl Shr<UnitSubstance> for Value {
             .v_substance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[SUBSTANCE_INDEX]);
+            .powr(self.exp[SUBSTANCE_INDEX]);
         n.v_substance = Some(other);
         Ok(n)
     }
@@ -50,7 +51,7 @@ impl ShrAssign<UnitSubstance> for Value {
             .v_substance
             .unwrap()
             .convert(&other)
-            .powi(self.exp[SUBSTANCE_INDEX]);
+            .powr(self.exp[SUBSTANCE_INDEX]);
         self.v_substance = Some(other);
     }
 }
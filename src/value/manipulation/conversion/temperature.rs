@@ -17,47 +17,62 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::PowRational;
 use crate::{
     consts::{TEMPERATURE_INDEX, TEMPERATURE_MAP},
     errors::V3Error,
     units::UnitTemperature,
-    value::Value,
+    value::{
+        Value,
+        numeric::{_scale, _through_f64, Numeric},
+    },
 };
 
 impl Shr<UnitTemperature> for Value {
     type Output = Result<Value, V3Error>;
     fn shr(self, other: UnitTemperature) -> Self::Output {
         let mut n: Value = self;
-        if self.unit_map & TEMPERATURE_MAP != TEMPERATURE_MAP {
-            return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
-        }
-        if self.exp[TEMPERATURE_INDEX] != 1 && self.exp[TEMPERATURE_INDEX] != -1 {
-            return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
-        }
-        n.val = n
-            .v_temperature
-            .unwrap()
-            .convert(&other, n.val)
-            .powi(self.exp[TEMPERATURE_INDEX]);
-        n.v_temperature = Some(other);
+        n._convert_temperature(other)?;
         Ok(n)
     }
 }
 
 impl ShrAssign<UnitTemperature> for Value {
     fn shr_assign(&mut self, other: UnitTemperature) {
-        if self.unit_map & TEMPERATURE_MAP != TEMPERATURE_MAP {
+        if self._convert_temperature(other).is_err() {
             panic!("[shr_assign] Incompatible value types");
         }
-        if self.exp[TEMPERATURE_INDEX] != 1 && self.exp[TEMPERATURE_INDEX] != -1 {
-            panic!("[shr_assign] Incompatible value types");
+    }
+}
+
+impl<T: Numeric> Value<T> {
+    /// Converts the temperature unit of a [`Value`]
+    ///
+    /// A lone temperature is converted with the offsets between scales. Temperatures
+    /// within compound units, or raised to a power, are converted as intervals.
+    pub(in crate::value) fn _convert_temperature(
+        &mut self,
+        other: UnitTemperature,
+    ) -> Result<(), V3Error> {
+        if self.unit_map & TEMPERATURE_MAP != TEMPERATURE_MAP {
+            return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
+        }
+        let t: UnitTemperature = self.v_temperature.unwrap();
+        if self.is_temperature() {
+            if t.is_interval() != other.is_interval() {
+                return Err(V3Error::ValueConversionError(
+                    "[shr] Cannot convert between temperatures and temperature intervals",
+                ));
+            }
+            self.val = _through_f64(self.val, |v| t.convert(&other, v))?;
+        } else {
+            self.val = _scale(
+                self.val,
+                t.interval_factor(&other).powr(self.exp[TEMPERATURE_INDEX]),
+            )?;
         }
-        self.val = self
-            .v_temperature
-            .unwrap()
-            .convert(&other, self.val)
-            .powi(self.exp[TEMPERATURE_INDEX]);
         self.v_temperature = Some(other);
+        Ok(())
     }
 }
 
@@ -117,16 +132,45 @@ mod conversion_testing {
     }
 
     #[test]
-    #[should_panic]
-    fn covert_exp_fail() {
+    fn covert_exp() {
         let y =
             1.0 / UnitTemperature::Celsius(Metric::None) / UnitTemperature::Celsius(Metric::None);
 
-        assert!((y >> UnitTemperature::Fahrenheit).is_err());
+        // raised temperatures are converted as intervals
+        let t = (y >> UnitTemperature::Fahrenheit).unwrap();
+        assert_apr!(t.val, 1.0 / (1.8 * 1.8));
 
         let mut x =
             1.0 / UnitTemperature::Celsius(Metric::None) / UnitTemperature::Celsius(Metric::None);
         x >>= UnitTemperature::Fahrenheit;
+        assert_eq!(x, t);
+    }
+
+    #[test]
+    fn covert_interval() {
+        let t = 10.0 * UnitTemperature::DeltaCelsius(Metric::None);
+        assert_apr!((t >> UnitTemperature::DeltaFahrenheit).unwrap().val, 18.0);
+        assert_apr!(
+            (t >> UnitTemperature::DeltaKelvin(Metric::Milli))
+                .unwrap()
+                .val,
+            10000.0
+        );
+        assert!((t >> UnitTemperature::Kelvin(Metric::None)).is_err());
+
+        let t = 10.0 * UnitTemperature::Celsius(Metric::None);
+        assert!((t >> UnitTemperature::DeltaKelvin(Metric::None)).is_err());
+        assert_apr!(
+            (t >> UnitTemperature::Kelvin(Metric::None)).unwrap().val,
+            283.15
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn covert_interval_mut_fail() {
+        let mut x = 10.0 * UnitTemperature::DeltaCelsius(Metric::None);
+        x >>= UnitTemperature::Fahrenheit;
     }
 
     #[test]
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::{Exponent, PowRational};
 use crate::{
     consts::{FREQUENCY_INDEX, FREQUENCY_MAP, TIME_INDEX, TIME_MAP},
     errors::V3Error,
@@ -34,13 +35,13 @@ impl Shr<UnitTime> for Value {
             n.v_time = Some(other);
             n.unit_map &= !FREQUENCY_MAP;
             n.unit_map |= TIME_MAP;
-            n.exp[TIME_INDEX] = -1;
-            n.exp[FREQUENCY_INDEX] = 0;
+            n.exp[TIME_INDEX] = Exponent::from(-1);
+            n.exp[FREQUENCY_INDEX] = Exponent::from(0);
             return Ok(n);
         } else if self.unit_map & TIME_MAP == 0 {
             return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
         }
-        n.val *= n.v_time.unwrap().convert(&other).powi(self.exp[TIME_INDEX]);
+        n.val *= n.v_time.unwrap().convert(&other).powr(self.exp[TIME_INDEX]);
         n.v_time = Some(other);
         Ok(n)
     }
@@ -54,8 +55,8 @@ impl ShrAssign<UnitTime> for Value {
             self.v_time = Some(other);
             self.unit_map &= !FREQUENCY_MAP;
             self.unit_map |= TIME_MAP;
-            self.exp[TIME_INDEX] = -1;
-            self.exp[FREQUENCY_INDEX] = 0;
+            self.exp[TIME_INDEX] = Exponent::from(-1);
+            self.exp[FREQUENCY_INDEX] = Exponent::from(0);
             return;
         } else if self.unit_map & TIME_MAP == 0 {
             panic!("[shr_assign] Incompatible value types");
@@ -64,7 +65,7 @@ impl ShrAssign<UnitTime> for Value {
             .v_time
             .unwrap()
             .convert(&other)
-            .powi(self.exp[TIME_INDEX]);
+            .powr(self.exp[TIME_INDEX]);
         self.v_time = Some(other);
     }
 }
@@ -155,13 +156,19 @@ mod conversion_testing {
 
             let t2 = 4.1 * UnitFrequency::Hertz(i.0);
             let t3 = (t2 >> UnitTime::Second(i.0)).unwrap();
-            assert_apr!(t3.val, t2.val);
+            assert_apr!(t3.val / (t2.val * i.0.scale() * i.0.scale()), 1.0);
             assert_eq!(
                 t3.to_string().split(' ').collect::<Vec<&str>>()[1],
                 format!("1/{}s", i.1)
             );
         }
 
+        let t2 = 1.0 * UnitFrequency::Hertz(Metric::None);
+        let t3 = (t2 >> UnitTime::Minute).unwrap();
+        assert_eq!(t3.to_string(), "60 1/min");
+        let t3 = (t2 >> UnitTime::Second(Metric::Milli)).unwrap();
+        assert_eq!(t3.to_string(), "0.001 1/ms");
+
         let t2 = 90.0 * UnitTime::Second(Metric::None);
         let t3 = (t2 >> UnitTime::Minute).unwrap();
         assert_eq!(t3.to_string(), "1.5 min");
@@ -17,6 +17,7 @@
 
 use std::ops::{Shr, ShrAssign};
 
+use crate::value::exponent::{Exponent, PowRational};
 use crate::{
     consts::{LENGTH_INDEX, LENGTH_MAP, VOLUME_INDEX, VOLUME_MAP},
     errors::V3Error,
@@ -33,8 +34,8 @@ impl Shr<UnitVolume> for Value {
             n.v_volume = Some(other);
             n.v_length = None;
             n.unit_map = VOLUME_MAP;
-            n.exp[VOLUME_INDEX] = 1;
-            n.exp[LENGTH_INDEX] = 0;
+            n.exp[VOLUME_INDEX] = Exponent::from(1);
+            n.exp[LENGTH_INDEX] = Exponent::from(0);
             return Ok(n);
         } else if self.unit_map & VOLUME_MAP == 0 {
             return Err(V3Error::ValueConversionError("[shr] Incompatible types"));
@@ -43,7 +44,7 @@ impl Shr<UnitVolume> for Value {
             .v_volume
             .unwrap()
             .convert(&other)
-            .powi(self.exp[VOLUME_INDEX]);
+            .powr(self.exp[VOLUME_INDEX]);
         n.v_volume = Some(other);
         Ok(n)
     }
@@ -56,8 +57,8 @@ impl ShrAssign<UnitVolume> for Value {
             self.v_volume = Some(other);
             self.v_length = None;
             self.unit_map = VOLUME_MAP;
-            self.exp[VOLUME_INDEX] = 1;
-            self.exp[LENGTH_INDEX] = 0;
+            self.exp[VOLUME_INDEX] = Exponent::from(1);
+            self.exp[LENGTH_INDEX] = Exponent::from(0);
             return;
         } else if self.unit_map & VOLUME_MAP == 0 {
             panic!("[shr_assign] Incompatible value types");
@@ -67,7 +68,7 @@ impl ShrAssign<UnitVolume> for Value {
             .v_volume
             .unwrap()
             .convert(&other)
-            .powi(self.exp[VOLUME_INDEX]);
+            .powr(self.exp[VOLUME_INDEX]);
         self.v_volume = Some(other);
     }
 }
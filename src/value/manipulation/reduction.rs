@@ -15,28 +15,32 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{
-        CAPACITANCE_INDEX, CAPACITANCE_MAP, CATALYTIC_ACTIVITY_INDEX, CATALYTIC_ACTIVITY_MAP,
-        ELECTRIC_CHARGE_INDEX, ELECTRIC_CHARGE_MAP, ELECTRIC_CONDUCTANCE_INDEX,
-        ELECTRIC_CONDUCTANCE_MAP, ELECTRIC_CURRENT_INDEX, ELECTRIC_CURRENT_MAP,
-        ELECTRIC_POTENTIAL_INDEX, ELECTRIC_POTENTIAL_MAP, ENERGY_INDEX, ENERGY_MAP, FORCE_INDEX,
-        FORCE_MAP, FREQUENCY_INDEX, FREQUENCY_MAP, ILLUMINANCE_INDEX, ILLUMINANCE_MAP,
-        INDUCTANCE_INDEX, INDUCTANCE_MAP, LENGTH_INDEX, LENGTH_MAP, LUMINOUS_FLUX_INDEX,
-        LUMINOUS_FLUX_MAP, LUMINOUS_INTENSITY_INDEX, LUMINOUS_INTENSITY_MAP,
-        MAGNETIC_FLUX_DENSITY_INDEX, MAGNETIC_FLUX_DENSITY_MAP, MAGNETIC_FLUX_INDEX,
-        MAGNETIC_FLUX_MAP, MASS_INDEX, MASS_MAP, POWER_INDEX, POWER_MAP, PRESSURE_INDEX,
-        PRESSURE_MAP, RESISTANCE_INDEX, RESISTANCE_MAP, SOLID_ANGLE_INDEX, SOLID_ANGLE_MAP,
-        SUBSTANCE_INDEX, SUBSTANCE_MAP, TIME_INDEX, TIME_MAP,
+        ABSORBED_DOSE_MAP, ANGLE_MAP, CAPACITANCE_INDEX, CAPACITANCE_MAP, CATALYTIC_ACTIVITY_INDEX,
+        CATALYTIC_ACTIVITY_MAP, ELECTRIC_CHARGE_INDEX, ELECTRIC_CHARGE_MAP,
+        ELECTRIC_CONDUCTANCE_INDEX, ELECTRIC_CONDUCTANCE_MAP, ELECTRIC_CURRENT_INDEX,
+        ELECTRIC_CURRENT_MAP, ELECTRIC_POTENTIAL_INDEX, ELECTRIC_POTENTIAL_MAP, ENERGY_INDEX,
+        ENERGY_MAP, FORCE_INDEX, FORCE_MAP, FREQUENCY_INDEX, FREQUENCY_MAP, ILLUMINANCE_INDEX,
+        ILLUMINANCE_MAP, INDUCTANCE_INDEX, INDUCTANCE_MAP, INFORMATION_MAP, LENGTH_INDEX,
+        LENGTH_MAP, LUMINOUS_FLUX_INDEX, LUMINOUS_FLUX_MAP, LUMINOUS_INTENSITY_INDEX,
+        LUMINOUS_INTENSITY_MAP, MAGNETIC_FLUX_DENSITY_INDEX, MAGNETIC_FLUX_DENSITY_MAP,
+        MAGNETIC_FLUX_INDEX, MAGNETIC_FLUX_MAP, MASS_INDEX, MASS_MAP, POWER_INDEX, POWER_MAP,
+        PRESSURE_INDEX, PRESSURE_MAP, RADIOACTIVITY_EXPOSURE_MAP, RADIOACTIVITY_MAP,
+        RESISTANCE_INDEX, RESISTANCE_MAP, SOLID_ANGLE_INDEX, SOLID_ANGLE_MAP, SOUND_MAP,
+        SUBSTANCE_INDEX, SUBSTANCE_MAP, TEMPERATURE_MAP, TIME_INDEX, TIME_MAP, VOLUME_INDEX,
+        VOLUME_MAP,
     },
     errors::V3Error,
     units::{
-        Metric, UnitCatalyticActivity, UnitElectricCapacitance, UnitElectricCharge,
-        UnitElectricConductance, UnitElectricCurrent, UnitElectricInductance,
+        Metric, UnitAbsorbedDose, UnitAngle, UnitCatalyticActivity, UnitElectricCapacitance,
+        UnitElectricCharge, UnitElectricConductance, UnitElectricCurrent, UnitElectricInductance,
         UnitElectricPotential, UnitElectricResistance, UnitEnergy, UnitForce, UnitFrequency,
-        UnitIlluminance, UnitLength, UnitLuminousFlux, UnitLuminousIntensity, UnitMagneticFlux,
-        UnitMagneticFluxDensity, UnitMass, UnitPower, UnitPressure, UnitSolidAngle, UnitSubstance,
-        UnitTime,
+        UnitIlluminance, UnitInformation, UnitLength, UnitLuminousFlux, UnitLuminousIntensity,
+        UnitMagneticFlux, UnitMagneticFluxDensity, UnitMass, UnitPower, UnitPressure,
+        UnitRadioactivity, UnitRadioactivityExposure, UnitSolidAngle, UnitSound, UnitSubstance,
+        UnitTemperature, UnitTime, UnitVolume,
     },
     value::Value,
 };
@@ -169,7 +173,7 @@ impl Value {
         let mut ret = (*self >> UnitPressure::Pascal(Metric::None)).unwrap();
 
         ret.v_pressure = None;
-        ret.exp[PRESSURE_INDEX] = 0;
+        ret.exp[PRESSURE_INDEX] = Exponent::from(0);
         ret.unit_map &= !PRESSURE_MAP;
 
         Some(ret * temp)
@@ -195,7 +199,7 @@ impl Value {
         let mut ret = (*self >> UnitEnergy::Joule(Metric::None)).unwrap();
 
         ret.v_energy = None;
-        ret.exp[ENERGY_INDEX] = 0;
+        ret.exp[ENERGY_INDEX] = Exponent::from(0);
         ret.unit_map &= !ENERGY_MAP;
 
         Some(ret * temp)
@@ -218,7 +222,7 @@ impl Value {
         let mut ret = (*self >> UnitForce::Newton(Metric::None)).unwrap();
 
         ret.v_force = None;
-        ret.exp[FORCE_INDEX] = 0;
+        ret.exp[FORCE_INDEX] = Exponent::from(0);
         ret.unit_map &= !FORCE_MAP;
 
         Some(ret * temp)
@@ -245,7 +249,7 @@ impl Value {
         let mut ret = (*self >> UnitPower::Watt(Metric::None)).unwrap();
 
         ret.v_power = None;
-        ret.exp[POWER_INDEX] = 0;
+        ret.exp[POWER_INDEX] = Exponent::from(0);
         ret.unit_map &= !POWER_MAP;
 
         Some(ret * temp)
@@ -266,7 +270,7 @@ impl Value {
         let mut ret = (*self >> UnitElectricCharge::Coulomb(Metric::None)).unwrap();
 
         ret.v_electric_charge = None;
-        ret.exp[ELECTRIC_CHARGE_INDEX] = 0;
+        ret.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
         ret.unit_map &= !ELECTRIC_CHARGE_MAP;
 
         Some(ret * temp)
@@ -295,8 +299,8 @@ impl Value {
         let mut ret = (*self >> UnitElectricPotential::Volt(Metric::None)).unwrap();
 
         ret.v_electric_potential = None;
-        ret.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
-        ret.unit_map &= !ELECTRIC_POTENTIAL_INDEX;
+        ret.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
+        ret.unit_map &= !ELECTRIC_POTENTIAL_MAP;
 
         Some(ret * temp)
     }
@@ -326,7 +330,7 @@ impl Value {
         let mut ret = (*self >> UnitElectricCapacitance::Farad(Metric::None)).unwrap();
 
         ret.v_capacitance = None;
-        ret.exp[CAPACITANCE_INDEX] = 0;
+        ret.exp[CAPACITANCE_INDEX] = Exponent::from(0);
         ret.unit_map &= !CAPACITANCE_MAP;
 
         Some(ret * temp)
@@ -356,7 +360,7 @@ impl Value {
         let mut ret = (*self >> UnitElectricResistance::Ohm(Metric::None)).unwrap();
 
         ret.v_resistance = None;
-        ret.exp[RESISTANCE_INDEX] = 0;
+        ret.exp[RESISTANCE_INDEX] = Exponent::from(0);
         ret.unit_map &= !RESISTANCE_MAP;
 
         Some(ret * temp)
@@ -386,7 +390,7 @@ impl Value {
         let mut ret = (*self >> UnitElectricConductance::Siemens(Metric::None)).unwrap();
 
         ret.v_electric_conductance = None;
-        ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = 0;
+        ret.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(0);
         ret.unit_map &= !ELECTRIC_CONDUCTANCE_MAP;
 
         Some(ret * temp)
@@ -414,7 +418,7 @@ impl Value {
         let mut ret = (*self >> UnitMagneticFlux::Weber(Metric::None)).unwrap();
 
         ret.v_magnetic_flux = None;
-        ret.exp[MAGNETIC_FLUX_INDEX] = 0;
+        ret.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
         ret.unit_map &= !MAGNETIC_FLUX_MAP;
 
         Some(ret * temp)
@@ -438,7 +442,7 @@ impl Value {
         let mut ret = (*self >> UnitMagneticFluxDensity::Tesla(Metric::None)).unwrap();
 
         ret.v_magnetic_flux_density = None;
-        ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 0;
+        ret.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(0);
         ret.unit_map &= !MAGNETIC_FLUX_DENSITY_MAP;
 
         Some(ret * temp)
@@ -467,7 +471,7 @@ impl Value {
         let mut ret = (*self >> UnitElectricInductance::Henry(Metric::None)).unwrap();
 
         ret.v_inductance = None;
-        ret.exp[INDUCTANCE_INDEX] = 0;
+        ret.exp[INDUCTANCE_INDEX] = Exponent::from(0);
         ret.unit_map &= !INDUCTANCE_MAP;
 
         Some(ret * temp)
@@ -487,7 +491,7 @@ impl Value {
         let mut ret = (*self >> UnitCatalyticActivity::Katal(Metric::None)).unwrap();
 
         ret.v_catalytic = None;
-        ret.exp[CATALYTIC_ACTIVITY_INDEX] = 0;
+        ret.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(0);
         ret.unit_map &= !CATALYTIC_ACTIVITY_MAP;
 
         Some(ret * temp)
@@ -508,7 +512,7 @@ impl Value {
         let mut ret = (*self >> UnitLuminousFlux::Lumen(Metric::None)).unwrap();
 
         ret.v_luminous_flux = None;
-        ret.exp[LUMINOUS_FLUX_INDEX] = 0;
+        ret.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(0);
         ret.unit_map &= !LUMINOUS_FLUX_MAP;
 
         Some(ret * temp)
@@ -533,12 +537,146 @@ impl Value {
         let mut ret = (*self >> UnitIlluminance::Lux(Metric::None)).unwrap();
 
         ret.v_illuminance = None;
-        ret.exp[ILLUMINANCE_INDEX] = 0;
+        ret.exp[ILLUMINANCE_INDEX] = Exponent::from(0);
         ret.unit_map &= !ILLUMINANCE_MAP;
 
         Some(ret * temp)
     }
 
+    /// Reduces the [`UnitVolume`] in a given [`Value`]
+    pub fn reduce_volume(&self) -> Option<Value> {
+        if self.unit_map & VOLUME_MAP == 0 {
+            return None;
+        }
+
+        let mut temp = 1.0
+            * UnitLength::Meter(Metric::None)
+            * UnitLength::Meter(Metric::None)
+            * UnitLength::Meter(Metric::None);
+
+        temp.exp[LENGTH_INDEX] *= self.exp[VOLUME_INDEX];
+
+        let mut ret = (*self >> UnitVolume::Liter(Metric::Kilo)).unwrap();
+
+        ret.v_volume = None;
+        ret.exp[VOLUME_INDEX] = Exponent::from(0);
+        ret.unit_map &= !VOLUME_MAP;
+
+        Some(ret * temp)
+    }
+
+    /// Reduces the [`UnitFrequency`] in a given [`Value`]
+    pub fn reduce_frequency(&self) -> Option<Value> {
+        if self.unit_map & FREQUENCY_MAP == 0 {
+            return None;
+        }
+
+        let mut temp = 1.0 / UnitTime::Second(Metric::None);
+
+        temp.exp[TIME_INDEX] *= self.exp[FREQUENCY_INDEX];
+
+        let mut ret = (*self >> UnitFrequency::Hertz(Metric::None)).unwrap();
+
+        ret.v_frequency = None;
+        ret.exp[FREQUENCY_INDEX] = Exponent::from(0);
+        ret.unit_map &= !FREQUENCY_MAP;
+
+        Some(ret * temp)
+    }
+
+    /// Returns the [`Value`] with every derived unit reduced into its definition
+    pub(crate) fn _reduce_derived(&self) -> Value {
+        let reductions: [fn(&Value) -> Option<Value>; 17] = [
+            Value::reduce_pressure,
+            Value::reduce_energy,
+            Value::reduce_force,
+            Value::reduce_power,
+            Value::reduce_electric_charge,
+            Value::reduce_electric_potential,
+            Value::reduce_electric_capacitance,
+            Value::reduce_electric_resistance,
+            Value::reduce_electric_conductance,
+            Value::reduce_magnetic_flux,
+            Value::reduce_magnetic_flux_density,
+            Value::reduce_electric_inductance,
+            Value::reduce_catalytic_activity,
+            Value::reduce_luminous_flux,
+            Value::reduce_illuminance,
+            Value::reduce_volume,
+            Value::reduce_frequency,
+        ];
+
+        let mut ret: Value = *self;
+        for reduce in reductions {
+            if let Some(v) = reduce(&ret) {
+                ret = v;
+            }
+        }
+        ret
+    }
+
+    /// Returns the [`Value`] expressed in coherent SI base units
+    ///
+    /// Every derived unit is reduced and the remaining units are converted to their SI
+    /// counterparts, so two dimensionally equivalent [`Value`]s end up with identical units.
+    pub(crate) fn _si_base(&self) -> Result<Value, V3Error> {
+        let mut ret: Value = self._reduce_derived();
+        let mut si: Value = ret;
+        for i in 0..31_usize {
+            let region: usize = 1 << i;
+            if region & ret.unit_map == 0 {
+                continue;
+            }
+            match region {
+                LENGTH_MAP => si.v_length = Some(UnitLength::Meter(Metric::None)),
+                TIME_MAP => si.v_time = Some(UnitTime::Second(Metric::None)),
+                MASS_MAP => si.v_mass = Some(UnitMass::Gram(Metric::Kilo)),
+                ELECTRIC_CURRENT_MAP => {
+                    si.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None))
+                }
+                TEMPERATURE_MAP => {
+                    si.v_temperature = Some(
+                        UnitTemperature::Kelvin(Metric::None)
+                            ._as_kind_of(&ret.v_temperature.unwrap()),
+                    )
+                }
+                SUBSTANCE_MAP => si.v_substance = Some(UnitSubstance::Mole(Metric::None)),
+                LUMINOUS_INTENSITY_MAP => {
+                    si.v_luminous_flux_intensity =
+                        Some(UnitLuminousIntensity::Candela(Metric::None))
+                }
+                ANGLE_MAP => si.v_angle = Some(UnitAngle::Radian(Metric::None)),
+                SOLID_ANGLE_MAP => si.v_solid_angle = Some(UnitSolidAngle::Steradian(Metric::None)),
+                INFORMATION_MAP => si.v_information = Some(UnitInformation::Byte(Metric::None)),
+                RADIOACTIVITY_MAP => {
+                    si.v_radioactivity = Some(UnitRadioactivity::Becquerel(Metric::None))
+                }
+                ABSORBED_DOSE_MAP => si.v_ab_dose = Some(UnitAbsorbedDose::Gray(Metric::None)),
+                RADIOACTIVITY_EXPOSURE_MAP => {
+                    si.v_radioactivity_exposure =
+                        Some(UnitRadioactivityExposure::Sievert(Metric::None))
+                }
+                SOUND_MAP => {
+                    if !ret.v_sound.unwrap().is_referenced() {
+                        si.v_sound = Some(UnitSound::Bel(Metric::None))
+                    }
+                }
+                _ => {
+                    return Err(V3Error::UnitReductionError(format!(
+                        "[_si_base] Value {self} cannot be reduced"
+                    )));
+                }
+            }
+        }
+
+        for (u, _) in si.v_custom.iter_mut().flatten() {
+            *u = u.base_unit();
+        }
+
+        ret._convert(&si)?;
+        Ok(ret)
+    }
+
     /// Actual reduce function that operates on a [`Value`] type
     fn _reduce(&mut self, other: &Value) -> bool {
         if self.unit_map == other.unit_map {
@@ -552,10 +690,10 @@ impl Value {
             self.v_length = Some(UnitLength::Meter(Metric::None));
             self.v_force = None;
 
-            self.exp[LENGTH_INDEX] = 1;
-            self.exp[MASS_INDEX] = 1;
-            self.exp[TIME_INDEX] = -2;
-            self.exp[FORCE_INDEX] = 0;
+            self.exp[LENGTH_INDEX] = Exponent::from(1);
+            self.exp[MASS_INDEX] = Exponent::from(1);
+            self.exp[TIME_INDEX] = Exponent::from(-2);
+            self.exp[FORCE_INDEX] = Exponent::from(0);
             self.unit_map = LENGTH_MAP | TIME_MAP | MASS_MAP;
 
             *self >>= *other;
@@ -567,9 +705,9 @@ impl Value {
                 self.v_length = Some(UnitLength::Meter(Metric::None));
                 self.v_pressure = None;
 
-                self.exp[LENGTH_INDEX] = -2;
-                self.exp[FORCE_INDEX] = 1;
-                self.exp[PRESSURE_INDEX] = 0;
+                self.exp[LENGTH_INDEX] = Exponent::from(-2);
+                self.exp[FORCE_INDEX] = Exponent::from(1);
+                self.exp[PRESSURE_INDEX] = Exponent::from(0);
                 self.unit_map = FORCE_MAP | LENGTH_MAP;
                 *self >>= *other;
                 return true;
@@ -580,10 +718,10 @@ impl Value {
                 self.v_time = Some(UnitTime::Second(Metric::None));
                 self.v_pressure = None;
 
-                self.exp[TIME_INDEX] = -2;
-                self.exp[LENGTH_INDEX] = -1;
-                self.exp[MASS_INDEX] = 1;
-                self.exp[PRESSURE_INDEX] = 0;
+                self.exp[TIME_INDEX] = Exponent::from(-2);
+                self.exp[LENGTH_INDEX] = Exponent::from(-1);
+                self.exp[MASS_INDEX] = Exponent::from(1);
+                self.exp[PRESSURE_INDEX] = Exponent::from(0);
                 self.unit_map = TIME_MAP | MASS_MAP | LENGTH_MAP;
                 *self >>= *other;
                 return true;
@@ -594,9 +732,9 @@ impl Value {
                 self.v_force = Some(UnitForce::Newton(Metric::None));
                 self.v_length = Some(UnitLength::Meter(Metric::None));
                 self.v_energy = None;
-                self.exp[FORCE_INDEX] = 1;
-                self.exp[LENGTH_INDEX] = 1;
-                self.exp[ENERGY_INDEX] = 0;
+                self.exp[FORCE_INDEX] = Exponent::from(1);
+                self.exp[LENGTH_INDEX] = Exponent::from(1);
+                self.exp[ENERGY_INDEX] = Exponent::from(0);
                 self.unit_map = FORCE_MAP | LENGTH_MAP;
                 *self >>= *other;
                 return true;
@@ -605,9 +743,9 @@ impl Value {
                 self.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
                 self.v_electric_charge = Some(UnitElectricCharge::Coulomb(Metric::None));
                 self.v_energy = None;
-                self.exp[ELECTRIC_CHARGE_INDEX] = 1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
-                self.exp[ENERGY_INDEX] = 0;
+                self.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
+                self.exp[ENERGY_INDEX] = Exponent::from(0);
                 self.unit_map = ELECTRIC_CHARGE_MAP | ELECTRIC_POTENTIAL_MAP;
                 *self >>= *other;
                 return true;
@@ -616,9 +754,9 @@ impl Value {
                 self.v_power = Some(UnitPower::Watt(Metric::None));
                 self.v_time = Some(UnitTime::Second(Metric::None));
                 self.v_energy = None;
-                self.exp[POWER_INDEX] = 1;
-                self.exp[TIME_INDEX] = 1;
-                self.exp[ENERGY_INDEX] = 0;
+                self.exp[POWER_INDEX] = Exponent::from(1);
+                self.exp[TIME_INDEX] = Exponent::from(1);
+                self.exp[ENERGY_INDEX] = Exponent::from(0);
                 self.unit_map = POWER_MAP | TIME_MAP;
                 *self >>= *other;
                 return true;
@@ -627,10 +765,10 @@ impl Value {
                 self.v_mass = Some(UnitMass::Gram(Metric::Kilo));
                 self.v_length = Some(UnitLength::Meter(Metric::None));
                 self.v_time = Some(UnitTime::Second(Metric::None));
-                self.exp[LENGTH_INDEX] = 2;
-                self.exp[MASS_INDEX] = 1;
-                self.exp[TIME_INDEX] = -2;
-                self.exp[ENERGY_INDEX] = 0;
+                self.exp[LENGTH_INDEX] = Exponent::from(2);
+                self.exp[MASS_INDEX] = Exponent::from(1);
+                self.exp[TIME_INDEX] = Exponent::from(-2);
+                self.exp[ENERGY_INDEX] = Exponent::from(0);
                 self.unit_map = LENGTH_MAP | MASS_MAP | TIME_MAP;
                 *self >>= *other;
                 return true;
@@ -639,8 +777,8 @@ impl Value {
             *self >>= UnitFrequency::Hertz(Metric::None);
             self.v_frequency = None;
             self.v_time = Some(UnitTime::Second(Metric::None));
-            self.exp[TIME_INDEX] = -1;
-            self.exp[FREQUENCY_INDEX] = 0;
+            self.exp[TIME_INDEX] = Exponent::from(-1);
+            self.exp[FREQUENCY_INDEX] = Exponent::from(0);
             self.unit_map = TIME_MAP;
             *self >>= *other;
             return true;
@@ -650,9 +788,9 @@ impl Value {
                 self.v_power = None;
                 self.v_energy = Some(UnitEnergy::Joule(Metric::None));
                 self.v_time = Some(UnitTime::Second(Metric::None));
-                self.exp[ENERGY_INDEX] = 1;
-                self.exp[TIME_INDEX] = -1;
-                self.exp[POWER_INDEX] = 0;
+                self.exp[ENERGY_INDEX] = Exponent::from(1);
+                self.exp[TIME_INDEX] = Exponent::from(-1);
+                self.exp[POWER_INDEX] = Exponent::from(0);
                 self.unit_map = ENERGY_MAP | TIME_MAP;
                 *self >>= *other;
                 return true;
@@ -661,9 +799,9 @@ impl Value {
                 self.v_power = None;
                 self.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
-                self.exp[POWER_INDEX] = 0;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
-                self.exp[ELECTRIC_CURRENT_INDEX] = 1;
+                self.exp[POWER_INDEX] = Exponent::from(0);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(1);
                 self.unit_map = ELECTRIC_POTENTIAL_MAP | ELECTRIC_CURRENT_MAP;
                 *self >>= *other;
                 return true;
@@ -673,10 +811,10 @@ impl Value {
                 self.v_mass = Some(UnitMass::Gram(Metric::Kilo));
                 self.v_length = Some(UnitLength::Meter(Metric::None));
                 self.v_time = Some(UnitTime::Second(Metric::None));
-                self.exp[MASS_INDEX] = 1;
-                self.exp[LENGTH_INDEX] = 2;
-                self.exp[TIME_INDEX] = -3;
-                self.exp[POWER_INDEX] = 0;
+                self.exp[MASS_INDEX] = Exponent::from(1);
+                self.exp[LENGTH_INDEX] = Exponent::from(2);
+                self.exp[TIME_INDEX] = Exponent::from(-3);
+                self.exp[POWER_INDEX] = Exponent::from(0);
                 self.unit_map = MASS_MAP | LENGTH_MAP | TIME_MAP;
                 *self >>= *other;
                 return true;
@@ -687,9 +825,9 @@ impl Value {
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_time = Some(UnitTime::Second(Metric::None));
                 self.v_electric_charge = None;
-                self.exp[ELECTRIC_CHARGE_INDEX] = 0;
-                self.exp[ELECTRIC_CURRENT_INDEX] = 1;
-                self.exp[TIME_INDEX] = 1;
+                self.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(1);
+                self.exp[TIME_INDEX] = Exponent::from(1);
                 self.unit_map = ELECTRIC_CURRENT_MAP | TIME_MAP;
                 *self >>= *other;
                 return true;
@@ -698,9 +836,9 @@ impl Value {
                 self.v_capacitance = Some(UnitElectricCapacitance::Farad(Metric::None));
                 self.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
                 self.v_electric_charge = None;
-                self.exp[CAPACITANCE_INDEX] = 1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
-                self.exp[ELECTRIC_CHARGE_INDEX] = 0;
+                self.exp[CAPACITANCE_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(0);
                 self.unit_map = CAPACITANCE_MAP | ELECTRIC_POTENTIAL_MAP;
                 *self >>= *other;
                 return true;
@@ -711,9 +849,9 @@ impl Value {
                 self.v_power = Some(UnitPower::Watt(Metric::None));
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_electric_potential = None;
-                self.exp[POWER_INDEX] = 1;
-                self.exp[ELECTRIC_CURRENT_INDEX] = -1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
+                self.exp[POWER_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
                 self.unit_map = POWER_MAP | ELECTRIC_CURRENT_MAP;
                 *self >>= *other;
                 return true;
@@ -722,9 +860,9 @@ impl Value {
                 self.v_energy = Some(UnitEnergy::Joule(Metric::None));
                 self.v_electric_charge = Some(UnitElectricCharge::Coulomb(Metric::None));
                 self.v_electric_potential = None;
-                self.exp[ENERGY_INDEX] = 1;
-                self.exp[ELECTRIC_CHARGE_INDEX] = -1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 0;
+                self.exp[ENERGY_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(-1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(0);
                 self.unit_map = ENERGY_MAP | ELECTRIC_CHARGE_MAP;
                 *self >>= *other;
                 return true;
@@ -735,9 +873,9 @@ impl Value {
                 self.v_electric_charge = Some(UnitElectricCharge::Coulomb(Metric::None));
                 self.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
                 self.v_capacitance = None;
-                self.exp[ELECTRIC_CHARGE_INDEX] = 1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = -1;
-                self.exp[CAPACITANCE_INDEX] = 0;
+                self.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(-1);
+                self.exp[CAPACITANCE_INDEX] = Exponent::from(0);
                 self.unit_map = ELECTRIC_CHARGE_MAP | ELECTRIC_POTENTIAL_MAP;
                 *self >>= *other;
                 return true;
@@ -746,9 +884,9 @@ impl Value {
                 self.v_electric_charge = Some(UnitElectricCharge::Coulomb(Metric::None));
                 self.v_energy = Some(UnitEnergy::Joule(Metric::None));
                 self.v_capacitance = None;
-                self.exp[CAPACITANCE_INDEX] = 0;
-                self.exp[ELECTRIC_CHARGE_INDEX] = 2;
-                self.exp[ENERGY_INDEX] = -1;
+                self.exp[CAPACITANCE_INDEX] = Exponent::from(0);
+                self.exp[ELECTRIC_CHARGE_INDEX] = Exponent::from(2);
+                self.exp[ENERGY_INDEX] = Exponent::from(-1);
                 self.unit_map = ELECTRIC_CHARGE_MAP | ENERGY_MAP;
                 *self >>= *other;
                 return true;
@@ -758,8 +896,8 @@ impl Value {
                 *self >>= UnitElectricResistance::Ohm(Metric::None);
                 self.v_electric_conductance = Some(UnitElectricConductance::Siemens(Metric::None));
                 self.v_resistance = None;
-                self.exp[ELECTRIC_CONDUCTANCE_INDEX] = -1;
-                self.exp[RESISTANCE_INDEX] = 0;
+                self.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(-1);
+                self.exp[RESISTANCE_INDEX] = Exponent::from(0);
                 self.unit_map = ELECTRIC_CONDUCTANCE_MAP;
                 *self >>= *other;
                 return true;
@@ -768,9 +906,9 @@ impl Value {
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
                 self.v_resistance = None;
-                self.exp[ELECTRIC_CURRENT_INDEX] = -1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
-                self.exp[RESISTANCE_INDEX] = 0;
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
+                self.exp[RESISTANCE_INDEX] = Exponent::from(0);
                 self.unit_map = ELECTRIC_CURRENT_MAP | ELECTRIC_POTENTIAL_MAP;
                 *self >>= *other;
                 return true;
@@ -780,8 +918,8 @@ impl Value {
                 *self >>= UnitElectricConductance::Siemens(Metric::None);
                 self.v_resistance = Some(UnitElectricResistance::Ohm(Metric::None));
                 self.v_electric_conductance = None;
-                self.exp[RESISTANCE_INDEX] = -1;
-                self.exp[ELECTRIC_CONDUCTANCE_INDEX] = 0;
+                self.exp[RESISTANCE_INDEX] = Exponent::from(-1);
+                self.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(0);
                 self.unit_map = RESISTANCE_MAP;
                 *self >>= *other;
                 return true;
@@ -789,9 +927,9 @@ impl Value {
                 *self >>= UnitElectricConductance::Siemens(Metric::None);
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
-                self.exp[ELECTRIC_CONDUCTANCE_INDEX] = 0;
-                self.exp[ELECTRIC_CURRENT_INDEX] = 1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = -1;
+                self.exp[ELECTRIC_CONDUCTANCE_INDEX] = Exponent::from(0);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(-1);
                 self.unit_map = ELECTRIC_CURRENT_MAP | ELECTRIC_POTENTIAL_MAP;
                 *self >>= *other;
                 return true;
@@ -802,9 +940,9 @@ impl Value {
                 self.v_energy = Some(UnitEnergy::Joule(Metric::None));
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_magnetic_flux = None;
-                self.exp[ENERGY_INDEX] = 1;
-                self.exp[ELECTRIC_CURRENT_INDEX] = -1;
-                self.exp[MAGNETIC_FLUX_INDEX] = 0;
+                self.exp[ENERGY_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
+                self.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
                 self.unit_map = ENERGY_MAP | ELECTRIC_CURRENT_MAP;
                 *self >>= *other;
                 return true;
@@ -813,9 +951,9 @@ impl Value {
                 self.v_magnetic_flux_density = Some(UnitMagneticFluxDensity::Tesla(Metric::None));
                 self.v_length = Some(UnitLength::Meter(Metric::None));
                 self.v_magnetic_flux = None;
-                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 1;
-                self.exp[LENGTH_INDEX] = 2;
-                self.exp[MAGNETIC_FLUX_INDEX] = 0;
+                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(1);
+                self.exp[LENGTH_INDEX] = Exponent::from(2);
+                self.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
                 self.unit_map = MAGNETIC_FLUX_DENSITY_MAP | LENGTH_MAP;
                 *self >>= *other;
                 return true;
@@ -824,9 +962,9 @@ impl Value {
                 self.v_electric_potential = Some(UnitElectricPotential::Volt(Metric::None));
                 self.v_time = Some(UnitTime::Second(Metric::None));
                 self.v_magnetic_flux = None;
-                self.exp[TIME_INDEX] = 1;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
-                self.exp[MAGNETIC_FLUX_INDEX] = 0;
+                self.exp[TIME_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
+                self.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(0);
                 self.unit_map = TIME_MAP | ELECTRIC_POTENTIAL_MAP;
                 *self >>= *other;
                 return true;
@@ -838,10 +976,10 @@ impl Value {
                 self.v_time = Some(UnitTime::Second(Metric::None));
                 self.v_length = Some(UnitLength::Meter(Metric::None));
                 self.v_magnetic_flux_density = None;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
-                self.exp[TIME_INDEX] = 1;
-                self.exp[LENGTH_INDEX] = -2;
-                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 0;
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
+                self.exp[TIME_INDEX] = Exponent::from(1);
+                self.exp[LENGTH_INDEX] = Exponent::from(-2);
+                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(0);
                 self.unit_map = ELECTRIC_POTENTIAL_MAP | TIME_MAP | LENGTH_MAP;
                 *self >>= *other;
                 return true;
@@ -850,9 +988,9 @@ impl Value {
                 self.v_magnetic_flux = Some(UnitMagneticFlux::Weber(Metric::None));
                 self.v_length = Some(UnitLength::Meter(Metric::None));
                 self.v_magnetic_flux_density = None;
-                self.exp[LENGTH_INDEX] = -2;
-                self.exp[MAGNETIC_FLUX_INDEX] = 1;
-                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 0;
+                self.exp[LENGTH_INDEX] = Exponent::from(-2);
+                self.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(1);
+                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(0);
                 self.unit_map = LENGTH_MAP | MAGNETIC_FLUX_MAP;
                 *self >>= *other;
                 return true;
@@ -861,10 +999,10 @@ impl Value {
                 self.v_force = Some(UnitForce::Newton(Metric::None));
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_length = Some(UnitLength::Meter(Metric::None));
-                self.exp[FORCE_INDEX] = 1;
-                self.exp[ELECTRIC_CURRENT_INDEX] = -1;
-                self.exp[LENGTH_INDEX] = -1;
-                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = 0;
+                self.exp[FORCE_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
+                self.exp[LENGTH_INDEX] = Exponent::from(-1);
+                self.exp[MAGNETIC_FLUX_DENSITY_INDEX] = Exponent::from(0);
                 self.unit_map = FORCE_MAP | ELECTRIC_CURRENT_MAP | LENGTH_MAP;
                 *self >>= *other;
                 return true;
@@ -876,10 +1014,10 @@ impl Value {
                 self.v_time = Some(UnitTime::Second(Metric::None));
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_inductance = None;
-                self.exp[INDUCTANCE_INDEX] = 0;
-                self.exp[ELECTRIC_POTENTIAL_INDEX] = 1;
-                self.exp[TIME_INDEX] = 1;
-                self.exp[ELECTRIC_CURRENT_INDEX] = -1;
+                self.exp[INDUCTANCE_INDEX] = Exponent::from(0);
+                self.exp[ELECTRIC_POTENTIAL_INDEX] = Exponent::from(1);
+                self.exp[TIME_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
                 self.unit_map = ELECTRIC_POTENTIAL_MAP | TIME_MAP | ELECTRIC_CURRENT_MAP;
                 *self >>= *other;
                 return true;
@@ -888,9 +1026,9 @@ impl Value {
                 self.v_inductance = None;
                 self.v_resistance = Some(UnitElectricResistance::Ohm(Metric::None));
                 self.v_time = Some(UnitTime::Second(Metric::None));
-                self.exp[RESISTANCE_INDEX] = 1;
-                self.exp[TIME_INDEX] = 1;
-                self.exp[INDUCTANCE_INDEX] = 0;
+                self.exp[RESISTANCE_INDEX] = Exponent::from(1);
+                self.exp[TIME_INDEX] = Exponent::from(1);
+                self.exp[INDUCTANCE_INDEX] = Exponent::from(0);
                 self.unit_map = RESISTANCE_MAP | TIME_MAP;
                 *self >>= *other;
                 return true;
@@ -899,9 +1037,9 @@ impl Value {
                 self.v_magnetic_flux = Some(UnitMagneticFlux::Weber(Metric::None));
                 self.v_electric_current = Some(UnitElectricCurrent::Ampere(Metric::None));
                 self.v_inductance = None;
-                self.exp[MAGNETIC_FLUX_INDEX] = 1;
-                self.exp[ELECTRIC_CURRENT_INDEX] = -1;
-                self.exp[INDUCTANCE_INDEX] = 0;
+                self.exp[MAGNETIC_FLUX_INDEX] = Exponent::from(1);
+                self.exp[ELECTRIC_CURRENT_INDEX] = Exponent::from(-1);
+                self.exp[INDUCTANCE_INDEX] = Exponent::from(0);
                 self.unit_map = MAGNETIC_FLUX_MAP | ELECTRIC_CURRENT_MAP;
                 *self >>= *other;
                 return true;
@@ -911,9 +1049,9 @@ impl Value {
             self.v_luminous_flux_intensity = Some(UnitLuminousIntensity::Candela(Metric::None));
             self.v_solid_angle = Some(UnitSolidAngle::Steradian(Metric::None));
             self.v_luminous_flux = None;
-            self.exp[LUMINOUS_FLUX_INDEX] = 0;
-            self.exp[LUMINOUS_INTENSITY_INDEX] = 1;
-            self.exp[SOLID_ANGLE_INDEX] = -1;
+            self.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(0);
+            self.exp[LUMINOUS_INTENSITY_INDEX] = Exponent::from(1);
+            self.exp[SOLID_ANGLE_INDEX] = Exponent::from(-1);
             self.unit_map = LUMINOUS_INTENSITY_MAP | SOLID_ANGLE_MAP;
             *self >>= *other;
             return true;
@@ -922,9 +1060,9 @@ impl Value {
             self.v_luminous_flux = Some(UnitLuminousFlux::Lumen(Metric::None));
             self.v_length = Some(UnitLength::Meter(Metric::None));
             self.v_illuminance = None;
-            self.exp[LUMINOUS_FLUX_INDEX] = 1;
-            self.exp[LENGTH_INDEX] = -2;
-            self.exp[ILLUMINANCE_INDEX] = 0;
+            self.exp[LUMINOUS_FLUX_INDEX] = Exponent::from(1);
+            self.exp[LENGTH_INDEX] = Exponent::from(-2);
+            self.exp[ILLUMINANCE_INDEX] = Exponent::from(0);
             self.unit_map = LUMINOUS_FLUX_MAP | LENGTH_MAP;
             *self >>= *other;
             return true;
@@ -933,9 +1071,9 @@ impl Value {
             self.v_substance = Some(UnitSubstance::Mole(Metric::None));
             self.v_time = Some(UnitTime::Second(Metric::None));
             self.v_catalytic = None;
-            self.exp[SUBSTANCE_INDEX] = 1;
-            self.exp[TIME_INDEX] = -1;
-            self.exp[CATALYTIC_ACTIVITY_INDEX] = 0;
+            self.exp[SUBSTANCE_INDEX] = Exponent::from(1);
+            self.exp[TIME_INDEX] = Exponent::from(-1);
+            self.exp[CATALYTIC_ACTIVITY_INDEX] = Exponent::from(0);
             self.unit_map = SUBSTANCE_MAP | TIME_MAP;
             *self >>= *other;
             return true;
@@ -952,6 +1090,8 @@ mod reduction_testing {
         UnitElectricResistance, UnitEnergy, UnitForce, UnitFrequency, UnitIlluminance, UnitLength,
         UnitLuminousFlux, UnitMagneticFlux, UnitMagneticFluxDensity, UnitPower, UnitPressure,
     };
+    use crate::value::Value;
+    use std::cmp::Ordering;
 
     #[test]
     fn reduce_catalytic_activity() {
@@ -1159,6 +1299,92 @@ mod reduction_testing {
         assert_eq!(t1.to_string(), "100000 N/m^2");
     }
 
+    #[test]
+    fn reduce_to_si_base() {
+        let t1 = (2.0 * UnitElectricPotential::Volt(Metric::Kilo))
+            .reduce_electric_potential()
+            .unwrap();
+        assert_eq!(t1.to_string(), "2000 m^2*kg/s^3*A");
+
+        let t1 = Value::new(2.0, "l/s").unwrap().reduce_volume().unwrap();
+        assert!((t1.val - 0.002).abs() < 1e-12);
+        assert_eq!(t1.unit_string(), "m^3/s");
+
+        let t1 = Value::new(3.0, "kHz*m")
+            .unwrap()
+            .reduce_frequency()
+            .unwrap();
+        assert_eq!(t1.to_string(), "3000 m/s");
+
+        let t1 = Value::new(1.0, "kW*h/ft").unwrap()._si_base().unwrap();
+        assert!((t1.val - 3_600_000.0 / 0.3048).abs() < 1e-6);
+        assert_eq!(t1.unit_string(), "m*kg/s^2");
+
+        let t1 = Value::new(1.0, "°C*mW").unwrap()._si_base().unwrap();
+        assert_eq!(t1.to_string(), "0.001 m^2*kg*K/s^3");
+    }
+
+    #[test]
+    fn dimensional_conversions() {
+        let mut t1 = Value::new(1.0, "W/(m*K)").unwrap();
+        t1.convert_dimensional("BTU/(h*ft*°F)").unwrap();
+        assert!((t1.val - 0.577_789_3).abs() < 1e-6);
+        assert_eq!(t1.unit_string(), "BTU/ft*hr*°f");
+
+        let mut t1 = Value::new(1.0, "N*m").unwrap();
+        t1.convert_dimensional("J").unwrap();
+        assert_eq!(t1.to_string(), "1 J");
+
+        let mut t1 = Value::new(5.0, "l").unwrap();
+        t1.convert_dimensional("cm^3").unwrap();
+        assert!((t1.val - 5000.0).abs() < 1e-9);
+
+        let mut t1 = Value::new(1.0, "kPa").unwrap();
+        t1.convert_dimensional("lbfr/in^2").unwrap();
+        assert!((t1.val - 0.145_037_738).abs() < 1e-6);
+
+        let mut t1 = Value::new(20.0, "dBm").unwrap();
+        t1.convert_dimensional("mW").unwrap();
+        assert!((t1.val - 100.0).abs() < 1e-9);
+
+        let mut t1 = Value::new(1.0, "N").unwrap();
+        assert!(t1.convert_dimensional("W").is_err());
+        assert!(t1.convert_dimensional("zz").is_err());
+        assert!(t1.convert_dimensional("Bq").is_err());
+        assert_eq!(t1.to_string(), "1 N");
+    }
+
+    #[test]
+    fn dimensional_comparisons() {
+        let t1 = Value::new(1.0, "N").unwrap();
+        let t2 = Value::new(1000.0, "g*m/s^2").unwrap();
+        let t3 = Value::new(1.0, "J/m").unwrap();
+
+        assert!(t1 != t2);
+        assert!(t1.eq_dimensional(&t2));
+        assert!(t2.eq_dimensional(&t1));
+        assert!(t1.eq_dimensional(&t3));
+        assert!(!t1.eq_dimensional(&Value::new(2.0, "N").unwrap()));
+        assert!(!t1.eq_dimensional(&Value::new(1.0, "J").unwrap()));
+
+        let t4 = Value::new(1.0, "lbfr").unwrap();
+        assert_eq!(t1.cmp_dimensional(&t4), Some(Ordering::Less));
+        assert_eq!(t4.cmp_dimensional(&t2), Some(Ordering::Greater));
+        assert_eq!(t1.cmp_dimensional(&Value::new(1.0, "s").unwrap()), None);
+
+        let t5 = t1.try_add_dimensional(&t2).unwrap();
+        assert_eq!(t5.to_string(), "2 N");
+        let t5 = t2.try_sub_dimensional(&t1).unwrap();
+        assert_eq!(t5.to_string(), "0 m*g/s^2");
+        assert!(
+            t1.try_add_dimensional(&Value::new(1.0, "J").unwrap())
+                .is_err()
+        );
+
+        let t6 = Value::new(1.0, "Hz").unwrap();
+        assert!(t6.eq_dimensional(&Value::new(60.0, "1/min").unwrap()));
+    }
+
     #[test]
     fn is_not_reducible() {
         let t1 = 4.0 * UnitLength::Meter(Metric::None);
@@ -0,0 +1,325 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{errors::V3Error, value::Value};
+
+impl Value {
+    /// Returns the largest integer numeric less than or equal to a [`Value`], in its own units
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(-3.7, "ft").unwrap();
+    /// assert_eq!(v.floor().to_string(), "-4 ft");
+    /// ```
+    pub fn floor(&self) -> Value {
+        self._map_val(f64::floor)
+    }
+
+    /// Returns the smallest integer numeric greater than or equal to a [`Value`], in its own units
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(-3.7, "ft").unwrap();
+    /// assert_eq!(v.ceil().to_string(), "-3 ft");
+    /// ```
+    pub fn ceil(&self) -> Value {
+        self._map_val(f64::ceil)
+    }
+
+    /// Returns the nearest integer numeric to a [`Value`], in its own units
+    ///
+    /// Half-way cases are rounded away from zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(2.5, "kg").unwrap();
+    /// assert_eq!(v.round().to_string(), "3 kg");
+    /// ```
+    pub fn round(&self) -> Value {
+        self._map_val(f64::round)
+    }
+
+    /// Returns the integer part of a [`Value`], in its own units
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(-3.7, "s").unwrap();
+    /// assert_eq!(v.trunc().to_string(), "-3 s");
+    /// ```
+    pub fn trunc(&self) -> Value {
+        self._map_val(f64::trunc)
+    }
+
+    /// Rounds a [`Value`] to the nearest multiple of a step
+    ///
+    /// The step is converted into the units of `self`, and the result is in the units of
+    /// `self`. Temperature and level steps are treated as differences.
+    /// Returns an error if the units are not compatible or the step is zero or not finite.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(12.34, "mm").unwrap();
+    /// let step:Value = Value::new(0.5, "mm").unwrap();
+    /// assert_eq!(v.round_to(&step).unwrap().to_string(), "12.5 mm");
+    ///
+    /// let w:Value = Value::new(1.0, "ft").unwrap();
+    /// let cm:Value = Value::new(1.0, "cm").unwrap();
+    /// assert!(((w.round_to(&cm).unwrap() >> "cm").unwrap().val - 30.0).abs() < 1e-9);
+    /// ```
+    pub fn round_to(&self, step: &Value) -> Result<Value, V3Error> {
+        let d: f64 = match self._difference_in_self_units(step) {
+            Some(d) => d,
+            None => return Err(V3Error::IncompatibleUnits(format!("{self} and {step}"))),
+        };
+        if d == 0.0 || !d.is_finite() {
+            return Err(V3Error::ValueConversionError("[round_to] Invalid step"));
+        }
+        Ok(self._map_val(|v| (v / d).round() * d))
+    }
+
+    /// Returns the smaller of two [`Value`]s with compatible units
+    ///
+    /// The result is in the units of `self`. If one numeric is `NaN`, the other is returned.
+    /// Returns an error if the units are not compatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(1.0, "m").unwrap();
+    /// let b:Value = Value::new(50.0, "cm").unwrap();
+    /// assert_eq!(a.min(&b).unwrap().to_string(), "0.5 m");
+    /// assert_eq!(b.min(&a).unwrap().to_string(), "50 cm");
+    /// ```
+    pub fn min(&self, other: &Value) -> Result<Value, V3Error> {
+        let mut n: Value = *self;
+        n.val = self.val.min(self._numeric_of(other)?);
+        Ok(n)
+    }
+
+    /// Returns the larger of two [`Value`]s with compatible units
+    ///
+    /// The result is in the units of `self`. If one numeric is `NaN`, the other is returned.
+    /// Returns an error if the units are not compatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(1.0, "m").unwrap();
+    /// let b:Value = Value::new(50.0, "cm").unwrap();
+    /// assert_eq!(b.max(&a).unwrap().to_string(), "100 cm");
+    /// ```
+    pub fn max(&self, other: &Value) -> Result<Value, V3Error> {
+        let mut n: Value = *self;
+        n.val = self.val.max(self._numeric_of(other)?);
+        Ok(n)
+    }
+
+    /// Restricts a [`Value`] to an interval of [`Value`]s with compatible units
+    ///
+    /// The bounds are converted into the units of `self`, and the result is in the units of
+    /// `self`. Returns an error if the units are not compatible, or if `min` is greater than
+    /// `max` or either is `NaN`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let lo:Value = Value::new(0.0, "°C").unwrap();
+    /// let hi:Value = Value::new(100.0, "°F").unwrap();
+    ///
+    /// let t:Value = Value::new(320.0, "K").unwrap();
+    /// assert!((t.clamp(&lo, &hi).unwrap().val - 310.927_777).abs() < 1e-6);
+    /// assert_eq!(Value::new(-5.0, "°C").unwrap().clamp(&lo, &hi).unwrap(), lo);
+    /// assert!(t.clamp(&hi, &lo).is_err());
+    /// ```
+    pub fn clamp(&self, min: &Value, max: &Value) -> Result<Value, V3Error> {
+        let lo: f64 = self._numeric_of(min)?;
+        let hi: f64 = self._numeric_of(max)?;
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            return Err(V3Error::ValueConversionError("[clamp] Empty interval"));
+        }
+        let mut n: Value = *self;
+        n.val = self.val.clamp(lo, hi);
+        Ok(n)
+    }
+
+    /// Returns the length of the hypotenuse of a right triangle with two [`Value`]s as legs
+    ///
+    /// `other` is converted into the units of `self`, and the result is in the units of
+    /// `self`. Returns an error if the units are not compatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(3.0, "m").unwrap();
+    /// let b:Value = Value::new(400.0, "cm").unwrap();
+    /// assert_eq!(a.hypot(&b).unwrap().to_string(), "5 m");
+    /// ```
+    pub fn hypot(&self, other: &Value) -> Result<Value, V3Error> {
+        let rhs: f64 = self._rhs_in_self_units(other)?;
+        Ok(self._map_val(|v| v.hypot(rhs)))
+    }
+
+    /// Computes `self * a + b` with a single rounding error
+    ///
+    /// The product has the units of `self * a`, and `b` is converted into them before being
+    /// added. Returns an error if `self` and `a` cannot be multiplied, or if `b` is not
+    /// compatible with their product.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(2.0, "m/s").unwrap();
+    /// let t:Value = Value::new(10.0, "s").unwrap();
+    /// let x:Value = Value::new(50.0, "cm").unwrap();
+    /// assert_eq!(v.mul_add(&t, &x).unwrap().to_string(), "20.5 m");
+    /// ```
+    pub fn mul_add(&self, a: &Value, b: &Value) -> Result<Value, V3Error> {
+        // the product of the units alone holds any conversion between them
+        let mut n: Value = self._with(1.0_f64).try_mul(&a._with(1.0_f64))?;
+        let rhs: f64 = n._rhs_in_self_units(b)?;
+        n.val = self.val.mul_add(a.val * n.val, rhs);
+        Ok(n)
+    }
+
+    /// Returns the numeric of `other` converted into the units of `self`
+    fn _numeric_of(&self, other: &Value) -> Result<f64, V3Error> {
+        match *other >> *self {
+            Ok(v) => Ok(v.val),
+            Err(_) => Err(V3Error::IncompatibleUnits(format!("{self} and {other}"))),
+        }
+    }
+
+    /// Returns a copy of a [`Value`] with a function applied to its numeric
+    fn _map_val<F: Fn(f64) -> f64>(&self, f: F) -> Value {
+        let mut n: Value = *self;
+        n.val = f(self.val);
+        n
+    }
+}
+
+#[cfg(test)]
+mod math_testing {
+    use crate::value::Value;
+
+    #[test]
+    fn rounding() {
+        let v: Value = Value::new(-2.5, "m").unwrap();
+        assert_eq!(v.floor(), Value::new(-3.0, "m").unwrap());
+        assert_eq!(v.ceil(), Value::new(-2.0, "m").unwrap());
+        assert_eq!(v.round(), Value::new(-3.0, "m").unwrap());
+        assert_eq!(v.trunc(), Value::new(-2.0, "m").unwrap());
+        assert_eq!(v.round().unit_string(), "m");
+
+        let t: Value = Value::new(21.6, "°C").unwrap();
+        assert_eq!(t.round(), Value::new(22.0, "°C").unwrap());
+    }
+
+    #[test]
+    fn round_to() {
+        let v: Value = Value::new(12.74, "mm").unwrap();
+        let step: Value = Value::new(0.5, "mm").unwrap();
+        assert_eq!(v.round_to(&step).unwrap().to_string(), "12.5 mm");
+        let neg: Value = Value::new(-0.5, "mm").unwrap();
+        assert_eq!(v.round_to(&neg).unwrap().to_string(), "12.5 mm");
+
+        let inch: Value = Value::new(1.3, "in").unwrap();
+        let r: Value = inch.round_to(&Value::new(1.0, "cm").unwrap()).unwrap();
+        assert_eq!(r.unit_string(), "in");
+        assert!(((r >> "cm").unwrap().val - 3.0).abs() < 1e-9);
+
+        // temperature steps are differences
+        let t: Value = Value::new(21.3, "°C").unwrap();
+        let dt: Value = Value::new(0.9, "°F").unwrap();
+        assert!((t.round_to(&dt).unwrap().val - 21.5).abs() < 1e-9);
+
+        assert!(v.round_to(&Value::new(0.0, "mm").unwrap()).is_err());
+        assert!(v.round_to(&Value::new(f64::NAN, "mm").unwrap()).is_err());
+        assert!(v.round_to(&Value::new(1.0, "s").unwrap()).is_err());
+    }
+
+    #[test]
+    fn rem() {
+        let a: Value = Value::new(7.0, "ft").unwrap();
+        let b: Value = Value::new(24.0, "in").unwrap();
+        assert_eq!(a % b, Value::new(1.0, "ft").unwrap());
+        assert_eq!((-a % b).val, -1.0);
+        let mut c: Value = a;
+        c %= Value::new(1.0, "m").unwrap();
+        assert!((c.val - (7.0 - 1.0 / 0.3048 * 2.0)).abs() < 1e-9);
+
+        let t: Value = Value::new(370.0, "°C").unwrap();
+        assert!(((t % Value::new(180.0, "°F").unwrap()).val - 70.0).abs() < 1e-9);
+        assert!(a.try_rem(&Value::new(1.0, "kg").unwrap()).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_incompatible() {
+        let _ = Value::new(7.0, "ft").unwrap() % Value::new(1.0, "s").unwrap();
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        let a: Value = Value::new(1.0, "ft").unwrap();
+        let b: Value = Value::new(1.0, "m").unwrap();
+        assert_eq!(a.min(&b).unwrap(), a);
+        assert_eq!(a.max(&b).unwrap().unit_string(), "ft");
+        assert!((b.min(&a).unwrap().val - 0.3048).abs() < 1e-12);
+        let n: Value = Value::new(f64::NAN, "m").unwrap();
+        assert_eq!(a.min(&n).unwrap(), a);
+        assert!(a.max(&Value::new(1.0, "s").unwrap()).is_err());
+
+        let lo: Value = Value::new(0.0, "°C").unwrap();
+        let hi: Value = Value::new(100.0, "°F").unwrap();
+        let hot: Value = Value::new(120.0, "°F").unwrap();
+        assert_eq!(hot.clamp(&lo, &hi).unwrap(), hi);
+        let cold: Value = Value::new(10.0, "°F").unwrap();
+        assert!((cold.clamp(&lo, &hi).unwrap().val - 32.0).abs() < 1e-9);
+        let mid: Value = Value::new(20.0, "°C").unwrap();
+        assert_eq!(mid.clamp(&lo, &hi).unwrap(), mid);
+        assert!(mid.clamp(&hi, &lo).is_err());
+        assert!(mid.clamp(&lo, &Value::new(1.0, "m").unwrap()).is_err());
+    }
+
+    #[test]
+    fn hypot_mul_add() {
+        let a: Value = Value::new(3.0, "m").unwrap();
+        let b: Value = Value::new(400.0, "cm").unwrap();
+        assert_eq!(a.hypot(&b).unwrap(), Value::new(5.0, "m").unwrap());
+        assert!(a.hypot(&Value::new(4.0, "s").unwrap()).is_err());
+
+        let f: Value = Value::new(2.0, "N").unwrap();
+        let d: Value = Value::new(300.0, "cm").unwrap();
+        let e: Value = Value::new(4.0, "J").unwrap();
+        assert!(f.mul_add(&d, &e).is_err());
+        let w: Value = Value::new(2.0, "kW").unwrap();
+        let t: Value = Value::new(30.0, "min").unwrap();
+        let r: Value = w.mul_add(&t, &Value::new(60.0, "kW*min").unwrap()).unwrap();
+        assert_eq!(r.unit_string(), "min*kW");
+        assert!((r.val - 120.0).abs() < 1e-9);
+
+        let l: Value = Value::new(2.0, "ft").unwrap();
+        let m: Value = Value::new(1.0, "m").unwrap();
+        let area: Value = l.mul_add(&m, &Value::new(1.0, "ft^2").unwrap()).unwrap();
+        assert!((area.val - (2.0 / 0.3048 + 1.0)).abs() < 1e-9);
+    }
+}
@@ -36,28 +36,77 @@ mod value_std_ops;
 /// Module used to define various [`Value`] constants
 pub mod consts;
 
+/// Module used to define the rational exponent of a unit
+pub mod exponent;
+
+/// Module used to compare [`Value`]s approximately
+pub mod approx;
+
+/// Module used to key [`Value`]s by their canonical SI representation
+pub mod canonical;
+
+/// Module used to totally order and sort [`Value`]s
+pub mod ordering;
+
+/// Module used to represent complex [`Value`]s
+pub mod complex;
+
+/// Module used to represent vectors of [`Value`]s
+pub mod vector;
+
+/// Module used to represent ranges of [`Value`]s
+pub mod range;
+
+/// Module used to propagate the uncertainty of [`Value`]s
+pub mod uncertain;
+
+/// Module used to store [`Value`]s with numerics other than `f64`
+pub mod numeric;
+
+/// Module used to restrict [`Value`]s to a dimension at compile time
+pub mod quantity;
+
+/// Module used to round and combine the numerics of [`Value`]s
+pub mod math;
+
+/// Module used to carry units of user-defined dimensions within a [`Value`]
+pub mod custom;
+
+/// Module used to wrap, compare and format angular [`Value`]s
+pub mod angle;
+
+/// Module used to describe the base dimensions and quantity kinds of a [`Value`]
+pub mod dimension;
+
+use crate::consts::CUSTOM_MAP;
 use crate::units::{
-    UnitAbsorbedDose, UnitAngle, UnitCatalyticActivity, UnitElectricCapacitance,
+    UnitAbsorbedDose, UnitAngle, UnitCatalyticActivity, UnitCustom, UnitElectricCapacitance,
     UnitElectricCharge, UnitElectricConductance, UnitElectricCurrent, UnitElectricInductance,
     UnitElectricPotential, UnitElectricResistance, UnitEnergy, UnitForce, UnitFrequency,
     UnitIlluminance, UnitInformation, UnitLength, UnitLuminousFlux, UnitLuminousIntensity,
-    UnitMagneticFlux, UnitMagneticFluxDensity, UnitMass, UnitPower, UnitPressure,
+    UnitMagneticFlux, UnitMagneticFluxDensity, UnitMass, UnitNone, UnitPower, UnitPressure,
     UnitRadioactivity, UnitRadioactivityExposure, UnitSolidAngle, UnitSound, UnitSubstance,
-    UnitTemperature, UnitTime, UnitVolume,
+    UnitTemperature, UnitTime, UnitVolume, custom::MAX_CUSTOM_DIMENSIONS,
 };
-use serde::{Deserialize, Serialize};
+use exponent::Exponent;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// The [`Value`] struct definition
+///
+/// The numerical value is an `f64` by default, which supports every operation of the library.
+/// Other numerics implementing [`numeric::Numeric`] support construction, conversion,
+/// arithmetic and display.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub struct Value {
+#[serde(remote = "Self")]
+pub struct Value<T = f64> {
     /// The numerical value for the `Value` struct
-    pub val: f64,
+    pub val: T,
 
     /// The unit map which specifies which units are present in the `Value`
     unit_map: usize,
 
     /// The exponent storage of all the units within a `Value`
-    exp: [i32; 31],
+    exp: [Exponent; 31],
 
     /// the absorbed dose of ionizing radiation measure
     v_ab_dose: Option<UnitAbsorbedDose>,
@@ -151,6 +200,29 @@ pub struct Value {
 
     /// The solid angle measure
     v_solid_angle: Option<UnitSolidAngle>,
+
+    /// The ratio units of a dimensionless `Value`, such as `%` or `ppm`
+    #[serde(default)]
+    v_ratio: Option<UnitNone>,
+
+    /// The user-defined dimension measures and their exponents, indexed by dimension
+    #[serde(default, with = "custom::custom_units")]
+    v_custom: [Option<(UnitCustom, Exponent)>; MAX_CUSTOM_DIMENSIONS],
+}
+
+impl<T: Serialize> Serialize for Value<T> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        Value::serialize(self, s)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Value<T> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let mut v: Value<T> = Value::deserialize(d)?;
+        // user-defined dimensions are placed by the registry of this process
+        v.unit_map = (v.unit_map & (CUSTOM_MAP - 1)) | v._custom_map();
+        Ok(v)
+    }
 }
 
 /// Macro to create a new [`Value`]
@@ -161,6 +233,49 @@ macro_rules! value {
     };
 }
 
+/// Macro to assert that two [`Value`]s are approximately equal
+///
+/// The tolerance is anything accepted by [`Value::approx_eq`].
+///
+/// # Example
+/// ```rust
+/// use bxvl::assert_value_approx;
+/// use bxvl::value::{Value, approx::Tolerance};
+/// use bxvl::units::{Metric, UnitLength};
+/// let a:Value = 1.0 * UnitLength::Foot;
+/// assert_value_approx!(a, 0.3048 * UnitLength::Meter(Metric::None), Tolerance::Relative(1e-12));
+/// assert_value_approx!(a, 12.0 * UnitLength::Inch, 1e-9 * UnitLength::Inch, "{a} is not a foot");
+/// ```
+#[macro_export]
+macro_rules! assert_value_approx {
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {
+        match (&$left, &$right) {
+            (l, r) => {
+                if !l.approx_eq(r, $tol) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n  left: {}\n right: {}",
+                        l, r
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tol:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (l, r) => {
+                if !l.approx_eq(r, $tol) {
+                    panic!(
+                        "assertion `left ≈ right` failed: {}\n  left: {}\n right: {}",
+                        format_args!($($arg)+),
+                        l,
+                        r
+                    );
+                }
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod value_serialization_testing {
     use crate::{
@@ -0,0 +1,397 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{consts::SOUND_MAP, errors::V3Error, units::exact::_apply_ratio, value::Value};
+
+/// A numeric that can be stored in a [`Value`]
+///
+/// `f64` is the default numeric of a [`Value`] and the only one supporting every operation of
+/// the library. Every numeric supports construction, conversion, comparison, arithmetic and
+/// display through the same code as `f64`, with conversion factors applied in the numeric
+/// itself through [`Numeric::scale`], so an integer [`Value`] only converts into units it can
+/// represent exactly.
+///
+/// The following remain `f64` only:
+/// - levels, which are logarithmic
+/// - the offsets between absolute temperature scales and conversions between power and
+///   energy per time, which are computed as `f64` and must be representable in the numeric
+/// - math functions such as roots, powers and trigonometry, reductions, parsing and the
+///   numerics of the other [`Value`] types in this crate
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::numeric::Numeric;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// struct Cents(i64);
+///
+/// impl std::fmt::Display for Cents {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{}.{:02}", self.0 / 100, self.0 % 100)
+///     }
+/// }
+/// # impl std::ops::Add for Cents { type Output = Cents; fn add(self, o:Cents) -> Cents { Cents(self.0 + o.0) } }
+/// # impl std::ops::Sub for Cents { type Output = Cents; fn sub(self, o:Cents) -> Cents { Cents(self.0 - o.0) } }
+/// # impl std::ops::Mul for Cents { type Output = Cents; fn mul(self, o:Cents) -> Cents { Cents(self.0 * o.0 / 100) } }
+/// # impl std::ops::Div for Cents { type Output = Cents; fn div(self, o:Cents) -> Cents { Cents(self.0 * 100 / o.0) } }
+///
+/// impl Numeric for Cents {
+///     fn from_f64(v: f64) -> Option<Cents> {
+///         v.is_finite().then(|| Cents((v * 100.0).round() as i64))
+///     }
+///     fn to_f64(self) -> f64 {
+///         self.0 as f64 / 100.0
+///     }
+/// }
+///
+/// let v:Value<Cents> = Value::from_numeric(Cents(150), "kJ").unwrap();
+/// assert_eq!((v >> "J").unwrap().to_string(), "1500.00 J");
+/// ```
+pub trait Numeric:
+    Copy
+    + Debug
+    + Display
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Returns the numeric equal to an `f64`, `None` if it cannot be represented
+    fn from_f64(v: f64) -> Option<Self>;
+
+    /// Returns the numeric as an `f64`
+    fn to_f64(self) -> f64;
+
+    /// Multiplies the numeric by a conversion factor, `None` if the result cannot be represented
+    fn scale(self, factor: f64) -> Option<Self> {
+        if factor == 1.0 {
+            return Some(self);
+        }
+        Some(self * Self::from_f64(factor)?)
+    }
+
+    /// Multiplies the numeric by the ratio of two whole numbers, `None` if the result cannot be
+    /// represented
+    fn scale_ratio(self, num: f64, den: f64) -> Option<Self> {
+        self.scale(num)?.scale(1.0 / den)
+    }
+}
+
+impl Numeric for f64 {
+    fn from_f64(v: f64) -> Option<f64> {
+        Some(v)
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn scale(self, factor: f64) -> Option<f64> {
+        Some(self * factor)
+    }
+
+    fn scale_ratio(self, num: f64, den: f64) -> Option<f64> {
+        Some(_apply_ratio(self, num, den))
+    }
+}
+
+impl Numeric for f32 {
+    fn from_f64(v: f64) -> Option<f32> {
+        Some(v as f32)
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+/// Implements [`Numeric`] for an integer type
+///
+/// Integers only take factors that are whole numbers or the reciprocal of a whole number
+/// that divides them exactly.
+macro_rules! numeric_int_defs {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn from_f64(v: f64) -> Option<$t> {
+                    if v.fract() != 0.0 || (v as $t) as f64 != v {
+                        return None;
+                    }
+                    Some(v as $t)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn scale(self, factor: f64) -> Option<$t> {
+                    if let Some(m) = <$t>::from_f64(factor) {
+                        return self.checked_mul(m);
+                    }
+                    let d: f64 = (1.0 / factor).round();
+                    if (d * factor - 1.0).abs() > 4.0 * f64::EPSILON {
+                        return None;
+                    }
+                    let d: $t = <$t>::from_f64(d)?;
+                    if d == 0 || self % d != 0 {
+                        return None;
+                    }
+                    Some(self / d)
+                }
+            }
+        )*
+    };
+}
+
+numeric_int_defs!(i32, i64, isize, u32, u64, usize);
+
+/// Returns a converted numeric, or an error if it cannot be represented
+pub(in crate::value) fn _representable<T>(val: Option<T>) -> Result<T, V3Error> {
+    match val {
+        Some(n) => Ok(n),
+        None => Err(V3Error::ValueConversionError(
+            "[numeric] The converted numeric cannot be represented",
+        )),
+    }
+}
+
+/// Multiplies a numeric by a conversion factor
+pub(in crate::value) fn _scale<T: Numeric>(val: T, factor: f64) -> Result<T, V3Error> {
+    _representable(val.scale(factor))
+}
+
+/// Applies a conversion that is only defined for `f64`, such as the offset of a temperature
+pub(in crate::value) fn _through_f64<T: Numeric>(
+    val: T,
+    f: impl FnOnce(f64) -> f64,
+) -> Result<T, V3Error> {
+    _representable(T::from_f64(f(val.to_f64())))
+}
+
+impl<T> Value<T> {
+    /// Returns a [`Value`] with the same units and the given numeric
+    pub(in crate::value) fn _with<U>(&self, val: U) -> Value<U> {
+        Value {
+            val,
+            unit_map: self.unit_map,
+            exp: self.exp,
+            v_ab_dose: self.v_ab_dose,
+            v_angle: self.v_angle,
+            v_capacitance: self.v_capacitance,
+            v_catalytic: self.v_catalytic,
+            v_electric_charge: self.v_electric_charge,
+            v_electric_conductance: self.v_electric_conductance,
+            v_electric_current: self.v_electric_current,
+            v_electric_potential: self.v_electric_potential,
+            v_energy: self.v_energy,
+            v_force: self.v_force,
+            v_frequency: self.v_frequency,
+            v_illuminance: self.v_illuminance,
+            v_inductance: self.v_inductance,
+            v_information: self.v_information,
+            v_length: self.v_length,
+            v_luminous_flux: self.v_luminous_flux,
+            v_luminous_flux_intensity: self.v_luminous_flux_intensity,
+            v_mass: self.v_mass,
+            v_power: self.v_power,
+            v_pressure: self.v_pressure,
+            v_radioactivity: self.v_radioactivity,
+            v_radioactivity_exposure: self.v_radioactivity_exposure,
+            v_resistance: self.v_resistance,
+            v_sound: self.v_sound,
+            v_substance: self.v_substance,
+            v_temperature: self.v_temperature,
+            v_time: self.v_time,
+            v_volume: self.v_volume,
+            v_magnetic_flux: self.v_magnetic_flux,
+            v_magnetic_flux_density: self.v_magnetic_flux_density,
+            v_solid_angle: self.v_solid_angle,
+            v_ratio: self.v_ratio,
+            v_custom: self.v_custom,
+        }
+    }
+
+    /// Returns an error if the units of a [`Value`] cannot be stored with another numeric
+    fn _check_numeric_units(&self) -> Result<(), V3Error> {
+        if self.unit_map & SOUND_MAP != 0 {
+            return Err(V3Error::ValueConversionError(
+                "[numeric] Levels can only be stored as f64",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Value {
+    /// Returns a [`Value`] with the same units and its numeric stored as another type
+    ///
+    /// Returns an error if the numeric cannot be represented or the [`Value`] is a level.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value = Value::new(1500.0, "J").unwrap();
+    /// let n:Value<i64> = v.to_numeric().unwrap();
+    /// assert_eq!(n.val, 1500);
+    /// assert!(Value::new(1.5, "J").unwrap().to_numeric::<i64>().is_err());
+    /// ```
+    pub fn to_numeric<T: Numeric>(&self) -> Result<Value<T>, V3Error> {
+        self._check_numeric_units()?;
+        match T::from_f64(self.val) {
+            Some(n) => Ok(self._with(n)),
+            None => Err(V3Error::ValueConversionError(
+                "[numeric] The numeric cannot be represented",
+            )),
+        }
+    }
+}
+
+impl<T: Numeric> Value<T> {
+    /// Creates a [`Value`] with a numeric other than `f64`
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let v:Value<u64> = Value::from_numeric(3, "kb").unwrap();
+    /// assert_eq!((v >> "b").unwrap().val, 3072);
+    /// ```
+    pub fn from_numeric(val: T, units: &str) -> Result<Value<T>, V3Error> {
+        let n: Value = Value::new(1.0, units)?;
+        n._check_numeric_units()?;
+        Ok(n._with(val))
+    }
+
+    /// Returns the [`Value`] with its numeric as an `f64`
+    pub fn to_f64(&self) -> Value {
+        self._with(self.val.to_f64())
+    }
+
+    /// Applies an operation that is only defined for `f64` [`Value`]s, such as those of levels
+    pub(in crate::value) fn _through_f64_value(
+        &self,
+        op: impl FnOnce(Value) -> Result<Value, V3Error>,
+    ) -> Result<Value<T>, V3Error> {
+        let n: Value = op(self.to_f64())?;
+        Ok(n._with(_representable(T::from_f64(n.val))?))
+    }
+}
+
+#[cfg(test)]
+mod numeric_testing {
+    use crate::value::{Value, numeric::Numeric};
+
+    #[test]
+    fn numeric_scale() {
+        assert_eq!(12_i64.scale(12.0), Some(144));
+        assert_eq!(1500_i64.scale(0.001), None);
+        assert_eq!(2000_i64.scale(0.001), Some(2));
+        assert_eq!(1_i64.scale(0.3048), None);
+        assert_eq!(1_u32.scale(-1.0), None);
+        assert_eq!(i64::MAX.scale(2.0), None);
+        assert_eq!(2.0_f32.scale(0.5), Some(1.0));
+        assert_eq!(i32::from_f64(1.5), None);
+        assert_eq!(u64::from_f64(-1.0), None);
+        assert_eq!(u64::from_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn numeric_construction() {
+        let v: Value<f32> = Value::from_numeric(1.5, "m/s").unwrap();
+        assert_eq!(v.to_string(), "1.5 m/s");
+        assert_eq!(v.to_f64(), Value::new(1.5, "m/s").unwrap());
+        assert_eq!(
+            Value::new(1.5, "m/s").unwrap().to_numeric::<f32>().unwrap(),
+            v
+        );
+        assert!(Value::<i64>::from_numeric(1, "dBm").is_err());
+        assert!(Value::new(1.0, "dB").unwrap().to_numeric::<i64>().is_err());
+        assert!(Value::<i64>::from_numeric(1, "nope").is_err());
+        assert_eq!(Value::<i32>::from_numeric(4, "").unwrap().to_string(), "4");
+    }
+
+    #[test]
+    fn numeric_conversion() {
+        let b: Value<u64> = Value::from_numeric(2, "kb").unwrap();
+        assert_eq!((b >> "b").unwrap().val, 2048);
+        assert_eq!((b >> "bits").unwrap().val, 16384);
+        assert!((b >> "Mb").is_err());
+        assert!((b >> "s").is_err());
+        assert_eq!((b >> String::from("b")).unwrap().unit_string(), "b");
+
+        let f: Value<f32> = Value::from_numeric(1.0, "ft").unwrap();
+        assert_eq!((f >> "in").unwrap().val, 12.0_f32);
+
+        let t: Value<f32> = Value::from_numeric(100.0, "°C").unwrap();
+        let k = (t >> "°F").unwrap();
+        assert_eq!(k.val, 212.0);
+        assert!(((k >> "°C").unwrap().val - 100.0).abs() < 1e-4);
+        let t: Value<i64> = Value::from_numeric(100, "°C").unwrap();
+        assert_eq!((t >> "°F").unwrap().val, 212);
+        assert!((t >> "K").is_err());
+
+        let w: Value<i64> = Value::from_numeric(2, "kW").unwrap();
+        assert_eq!((w >> "J/s").unwrap().val, 2000);
+        assert_eq!((w >> "kJ/hr").unwrap().val, 7200);
+        assert!((w >> "BTU/hr").is_err());
+    }
+
+    #[test]
+    fn numeric_arithmetic() {
+        let a: Value<i64> = Value::from_numeric(2, "kJ").unwrap();
+        let b: Value<i64> = Value::from_numeric(500, "J").unwrap();
+        assert!(a.try_add(&b).is_err());
+        let c = b + a;
+        assert_eq!(c, Value::from_numeric(2500, "J").unwrap());
+        assert_eq!((b - a).val, -1500);
+        assert_eq!((b * 3).val, 1500);
+        assert_eq!((b / 3).val, 166);
+
+        let d: Value<i64> = Value::from_numeric(10, "m").unwrap();
+        let s: Value<i64> = Value::from_numeric(4, "s").unwrap();
+        assert_eq!((d / s).to_string(), "2 m/s");
+        assert_eq!((d * d).to_string(), "100 m^2");
+        let km: Value<i64> = Value::from_numeric(2, "km").unwrap();
+        assert_eq!((d * km).val, 20000);
+        assert!(km.try_mul(&Value::from_numeric(1, "ft").unwrap()).is_err());
+
+        let c: Value<f32> = Value::from_numeric(30.0, "°C").unwrap();
+        let f: Value<f32> = Value::from_numeric(50.0, "°F").unwrap();
+        let i = c - f;
+        assert!(i.to_f64().is_temperature_interval());
+        assert_eq!(i.val, 20.0);
+        assert_eq!((i + c).to_string(), "50 °c");
+        let m: Value<f32> = Value::from_numeric(2.0, "m*°C").unwrap();
+        assert_eq!(m.try_mul(&f).unwrap().val, 20.0);
+        assert_eq!(m.try_mul(&c).unwrap().val, 60.0);
+        assert!(i.try_sub(&c).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn numeric_bad_add() {
+        let a: Value<i64> = Value::from_numeric(2, "kJ").unwrap();
+        let _ = a + Value::from_numeric(1, "s").unwrap();
+    }
+}
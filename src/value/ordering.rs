@@ -0,0 +1,353 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    errors::V3Error,
+    value::{Value, canonical::CanonicalValue},
+};
+
+impl Value {
+    /// Totally orders two [`Value`]s with compatible units
+    ///
+    /// `other` is converted into the units of `self` before being compared. Positive and
+    /// negative zero are equal, and `NaN` is equal to itself and greater than every other
+    /// numeric. Returns an error if the units are not compatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength};
+    /// use std::cmp::Ordering;
+    /// let a:Value = 1.0 * UnitLength::Foot;
+    /// let b:Value = 1.0 * UnitLength::Meter(Metric::None);
+    /// let n:Value = f64::NAN * UnitLength::Inch;
+    ///
+    /// assert_eq!(a.total_cmp(&b).unwrap(), Ordering::Less);
+    /// assert_eq!(a.total_cmp(&n).unwrap(), Ordering::Less);
+    /// assert_eq!(n.total_cmp(&n).unwrap(), Ordering::Equal);
+    /// assert!(a.total_cmp(&Value::new(1.0, "s").unwrap()).is_err());
+    /// ```
+    pub fn total_cmp(&self, other: &Value) -> Result<Ordering, V3Error> {
+        let rhs: Value = (*other >> *self)
+            .map_err(|_| V3Error::IncompatibleUnits(format!("{self} and {other}")))?;
+        Ok(_total_cmp_f64(self.val, rhs.val))
+    }
+}
+
+/// Totally orders two numerics with equal zeros and all `NaN`s last
+fn _total_cmp_f64(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+/// Returns the numerics of all [`Value`]s in the units of the first
+fn _common_numerics(values: &[Value]) -> Result<Vec<f64>, V3Error> {
+    let Some(first) = values.first() else {
+        return Ok(vec![]);
+    };
+    values
+        .iter()
+        .map(|v| {
+            (*v >> *first)
+                .map(|c| c.val)
+                .map_err(|_| V3Error::IncompatibleUnits(format!("{first} and {v}")))
+        })
+        .collect()
+}
+
+/// Sorts a slice of [`Value`]s with compatible units in ascending order
+///
+/// The sort is stable, orders `NaN`s last, and leaves every [`Value`] in its own units.
+/// Returns an error, leaving the slice untouched, if any units are not compatible.
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::ordering::sort_values;
+/// let mut v:Vec<Value> = vec![
+///     Value::new(1.0, "m").unwrap(),
+///     Value::new(1.0, "ft").unwrap(),
+///     Value::new(14.0, "in").unwrap(),
+/// ];
+/// sort_values(&mut v).unwrap();
+/// assert_eq!(v[0].to_string(), "1 ft");
+/// assert_eq!(v[1].to_string(), "14 in");
+/// assert_eq!(v[2].to_string(), "1 m");
+/// ```
+pub fn sort_values(values: &mut [Value]) -> Result<(), V3Error> {
+    let mut keyed: Vec<(f64, Value)> = _common_numerics(values)?
+        .into_iter()
+        .zip(values.iter().copied())
+        .collect();
+    keyed.sort_by(|a, b| _total_cmp_f64(a.0, b.0));
+    for (v, (_, k)) in values.iter_mut().zip(keyed) {
+        *v = k;
+    }
+    Ok(())
+}
+
+/// Returns the smallest of a slice of [`Value`]s with compatible units
+///
+/// The first of equal [`Value`]s is returned, and `NaN`s are only returned if every numeric
+/// is `NaN`. Returns `None` for an empty slice and an error if any units are not compatible.
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::ordering::min_value;
+/// let v:Vec<Value> = vec![Value::new(1.0, "m").unwrap(), Value::new(1.0, "ft").unwrap()];
+/// assert_eq!(min_value(&v).unwrap().unwrap().to_string(), "1 ft");
+/// assert!(min_value(&[]).unwrap().is_none());
+/// ```
+pub fn min_value(values: &[Value]) -> Result<Option<Value>, V3Error> {
+    Ok(_common_numerics(values)?
+        .into_iter()
+        .zip(values.iter().copied())
+        .reduce(|a, b| {
+            if _total_cmp_f64(b.0, a.0) == Ordering::Less {
+                b
+            } else {
+                a
+            }
+        })
+        .map(|(_, v)| v))
+}
+
+/// Returns the largest of a slice of [`Value`]s with compatible units
+///
+/// The last of equal [`Value`]s is returned, and `NaN`s are returned if any numeric is
+/// `NaN`. Returns `None` for an empty slice and an error if any units are not compatible.
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::ordering::max_value;
+/// let v:Vec<Value> = vec![Value::new(1.0, "m").unwrap(), Value::new(1.0, "ft").unwrap()];
+/// assert_eq!(max_value(&v).unwrap().unwrap().to_string(), "1 m");
+/// ```
+pub fn max_value(values: &[Value]) -> Result<Option<Value>, V3Error> {
+    Ok(_common_numerics(values)?
+        .into_iter()
+        .zip(values.iter().copied())
+        .reduce(|a, b| {
+            if _total_cmp_f64(b.0, a.0) == Ordering::Less {
+                a
+            } else {
+                b
+            }
+        })
+        .map(|(_, v)| v))
+}
+
+/// A [`Value`] with a total order, usable as a [`BTreeMap`](std::collections::BTreeMap) key
+///
+/// [`Value`]s are ordered by their default [`CanonicalValue`], so [`Value`]s with the same
+/// dimensions are ordered by their numerics in SI base units. [`Value`]s that round to the
+/// same canonical numeric are equal. The wrapped [`Value`] keeps its units.
+///
+/// [`Value`]s with different dimensions are ordered by comparing their SI base unit types
+/// and exponents before their numerics, so that a mixed map stays consistent. That order
+/// has no physical meaning and may change between versions, so it should not be relied on.
+///
+/// # Example
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use bxvl::value::Value;
+/// use bxvl::value::ordering::TotalOrd;
+/// let mut m:BTreeMap<TotalOrd, &str> = BTreeMap::new();
+/// m.insert(TotalOrd::new(Value::new(3.0, "ft").unwrap()).unwrap(), "b");
+/// m.insert(TotalOrd::new(Value::new(20.0, "in").unwrap()).unwrap(), "a");
+/// m.insert(TotalOrd::new(Value::new(1.0, "m").unwrap()).unwrap(), "c");
+///
+/// let v:Vec<&str> = m.values().copied().collect();
+/// assert_eq!(v, vec!["a", "b", "c"]);
+/// assert_eq!(m.keys().next().unwrap().value().to_string(), "20 in");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TotalOrd {
+    /// The wrapped value
+    value: Value,
+    /// The key used to order the wrapped value
+    key: CanonicalValue,
+}
+
+impl TotalOrd {
+    /// Wraps a [`Value`] in a [`TotalOrd`]
+    ///
+    /// Returns an error if the [`Value`] cannot be reduced into SI base units.
+    pub fn new(value: Value) -> Result<TotalOrd, V3Error> {
+        Ok(TotalOrd {
+            value,
+            key: value.canonical_key()?,
+        })
+    }
+
+    /// Returns the wrapped [`Value`]
+    pub fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl TryFrom<Value> for TotalOrd {
+    type Error = V3Error;
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        TotalOrd::new(value)
+    }
+}
+
+impl From<TotalOrd> for Value {
+    fn from(t: TotalOrd) -> Self {
+        t.value
+    }
+}
+
+impl PartialEq for TotalOrd {
+    fn eq(&self, other: &TotalOrd) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for TotalOrd {}
+
+impl Hash for TotalOrd {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl PartialOrd for TotalOrd {
+    fn partial_cmp(&self, other: &TotalOrd) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalOrd {
+    fn cmp(&self, other: &TotalOrd) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl Display for TotalOrd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod ordering_testing {
+    use std::cmp::Ordering;
+    use std::collections::BTreeSet;
+
+    use crate::units::{Metric, UnitLength, UnitTemperature, UnitTime};
+    use crate::value::{
+        Value,
+        ordering::{TotalOrd, max_value, min_value, sort_values},
+    };
+
+    #[test]
+    fn total_cmp() {
+        let a = 1.0 * UnitLength::Foot;
+        let b = 12.0 * UnitLength::Inch;
+        let c = 1.0 * UnitLength::Meter(Metric::None);
+        let z = 0.0 * UnitLength::Foot;
+        let nz = -0.0 * UnitLength::Meter(Metric::None);
+        let n = f64::NAN * UnitLength::Inch;
+
+        assert_eq!(a.total_cmp(&b).unwrap(), Ordering::Equal);
+        assert_eq!(a.total_cmp(&c).unwrap(), Ordering::Less);
+        assert_eq!(c.total_cmp(&a).unwrap(), Ordering::Greater);
+        assert_eq!(z.total_cmp(&nz).unwrap(), Ordering::Equal);
+        assert_eq!(n.total_cmp(&a).unwrap(), Ordering::Greater);
+        assert_eq!(a.total_cmp(&n).unwrap(), Ordering::Less);
+        assert_eq!(n.total_cmp(&(n * -1.0)).unwrap(), Ordering::Equal);
+        assert!(
+            a.total_cmp(&(1.0 * UnitTime::Second(Metric::None)))
+                .is_err()
+        );
+
+        let t = 0.0 * UnitTemperature::Celsius(Metric::None);
+        let f = 33.0 * UnitTemperature::Fahrenheit;
+        assert_eq!(t.total_cmp(&f).unwrap(), Ordering::Less);
+
+        let v = Value::new(1.0, "l").unwrap();
+        let l = Value::new(10.0, "cm^3").unwrap();
+        assert_eq!(v.total_cmp(&l).unwrap(), Ordering::Greater);
+    }
+
+    #[test]
+    fn sort_and_extremes() {
+        let n = f64::NAN * UnitLength::Inch;
+        let mut v: Vec<Value> = vec![
+            1.0 * UnitLength::Meter(Metric::None),
+            n,
+            3.0 * UnitLength::Foot,
+            -2.0 * UnitLength::Inch,
+            37.0 * UnitLength::Inch,
+        ];
+        sort_values(&mut v).unwrap();
+        let s: Vec<String> = v.iter().map(|x| x.to_string()).collect();
+        assert_eq!(s, vec!["-2 in", "3 ft", "37 in", "1 m", "NaN in"]);
+
+        assert_eq!(min_value(&v).unwrap().unwrap().to_string(), "-2 in");
+        assert_eq!(max_value(&v).unwrap().unwrap().to_string(), "NaN in");
+        assert_eq!(max_value(&v[..4]).unwrap().unwrap().to_string(), "1 m");
+        assert_eq!(min_value(&[n]).unwrap().unwrap().to_string(), "NaN in");
+        assert!(max_value(&[]).unwrap().is_none());
+
+        let mut w: Vec<Value> = vec![
+            1.0 * UnitLength::Meter(Metric::None),
+            1.0 * UnitTime::Second(Metric::None),
+        ];
+        assert!(sort_values(&mut w).is_err());
+        assert!(min_value(&w).is_err());
+        assert!(max_value(&w).is_err());
+        assert_eq!(w[0].to_string(), "1 m");
+    }
+
+    #[test]
+    fn total_ord_wrapper() {
+        let s: BTreeSet<TotalOrd> = [
+            3.0 * UnitLength::Foot,
+            1.0 * UnitTime::Second(Metric::None),
+            36.0 * UnitLength::Inch,
+            1.0 * UnitLength::Meter(Metric::None),
+            0.5 * UnitTime::Second(Metric::None),
+        ]
+        .into_iter()
+        .map(|v| TotalOrd::try_from(v).unwrap())
+        .collect();
+
+        // 3 ft and 36 in are the same length
+        assert_eq!(s.len(), 4);
+        let a = TotalOrd::new(1.0 * UnitLength::Foot).unwrap();
+        let b = TotalOrd::new(12.0 * UnitLength::Inch).unwrap();
+        let c = TotalOrd::new(1.0 * UnitLength::Meter(Metric::None)).unwrap();
+        assert_eq!(a, b);
+        assert!(a < c);
+        assert_eq!(Value::from(b).to_string(), "12 in");
+    }
+}
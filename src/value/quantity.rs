@@ -0,0 +1,476 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Shr, Sub},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::V3Error, value::Value};
+
+/// Multiplies quantity wrappers, returning an error instead of panicking
+pub trait CheckedMul<Rhs> {
+    /// The wrapper of the product
+    type Output;
+    /// Returns the product, or an error if it cannot be expressed in its dimension
+    fn checked_mul(self, rhs: Rhs) -> Result<Self::Output, V3Error>;
+}
+
+/// Divides quantity wrappers, returning an error instead of panicking
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::quantity::{CheckedDiv, Length, Time, Velocity};
+///
+/// let v:Velocity = Length::new(3.0, "m").unwrap().checked_div(Time::new(2.0, "s").unwrap()).unwrap();
+/// assert_eq!(v.to_string(), "1.5 m/s");
+/// ```
+pub trait CheckedDiv<Rhs> {
+    /// The wrapper, or numeric, of the quotient
+    type Output;
+    /// Returns the quotient, or an error if it cannot be expressed in its dimension
+    fn checked_div(self, rhs: Rhs) -> Result<Self::Output, V3Error>;
+}
+
+/// Defines a wrapper holding a [`Value`] that satisfies a dimension predicate
+macro_rules! quantity_defs {
+    ($($(#[$doc:meta])* $name:ident => $pred:ident, $what:literal, $si:literal;)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+            #[serde(try_from = "Value", into = "Value")]
+            #[repr(transparent)]
+            pub struct $name(Value);
+
+            impl $name {
+                #[doc = concat!("Creates ", $what, " from a numeric and its units")]
+                ///
+                /// Returns an error if the units cannot be parsed or have the wrong dimension.
+                pub fn new(val: f64, units: &str) -> Result<$name, V3Error> {
+                    $name::try_from(Value::new(val, units)?)
+                }
+
+                /// Returns a reference to the inner [`Value`]
+                pub fn as_value(&self) -> &Value {
+                    &self.0
+                }
+
+                /// Wraps the result of an operation, converting it into SI units if its
+                /// units do not show the dimension, such as `l/m` for an area
+                fn _reduced(mut value: Value) -> Result<$name, V3Error> {
+                    if !value.$pred() && value.convert_dimensional($si).is_err() {
+                        return Err(V3Error::IncompatibleUnits(format!(
+                            "{value} is not {}",
+                            $what
+                        )));
+                    }
+                    Ok($name(value))
+                }
+            }
+
+            impl TryFrom<Value> for $name {
+                type Error = V3Error;
+                fn try_from(value: Value) -> Result<$name, V3Error> {
+                    if !value.$pred() {
+                        return Err(V3Error::IncompatibleUnits(format!(
+                            "{value} is not {}",
+                            $what
+                        )));
+                    }
+                    Ok($name(value))
+                }
+            }
+
+            impl From<$name> for Value {
+                fn from(q: $name) -> Value {
+                    q.0
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl Shr<&str> for $name {
+                type Output = Result<$name, V3Error>;
+                fn shr(self, other: &str) -> Self::Output {
+                    let mut n: Value = self.0;
+                    n.convert_dimensional(other)?;
+                    $name::try_from(n)
+                }
+            }
+
+            impl Add<$name> for $name {
+                type Output = $name;
+                /// # Panics
+                ///
+                /// Panics if the units of the operands cannot be converted into each other.
+                fn add(self, other: $name) -> $name {
+                    match self.0.try_add_dimensional(&other.0) {
+                        Ok(n) => $name(n),
+                        Err(_) => panic!("Cannot Add values {self} and {other}"),
+                    }
+                }
+            }
+
+            impl Sub<$name> for $name {
+                type Output = $name;
+                /// # Panics
+                ///
+                /// Panics if the units of the operands cannot be converted into each other.
+                fn sub(self, other: $name) -> $name {
+                    match self.0.try_sub_dimensional(&other.0) {
+                        Ok(n) => $name(n),
+                        Err(_) => panic!("Cannot Sub values {self} and {other}"),
+                    }
+                }
+            }
+
+            impl Neg for $name {
+                type Output = $name;
+                fn neg(self) -> $name {
+                    $name(-self.0)
+                }
+            }
+
+            impl Mul<f64> for $name {
+                type Output = $name;
+                fn mul(self, other: f64) -> $name {
+                    $name(self.0 * other)
+                }
+            }
+
+            impl Mul<$name> for f64 {
+                type Output = $name;
+                fn mul(self, other: $name) -> $name {
+                    $name(other.0 * self)
+                }
+            }
+
+            impl Div<f64> for $name {
+                type Output = $name;
+                fn div(self, other: f64) -> $name {
+                    $name(self.0 / other)
+                }
+            }
+
+            impl CheckedDiv<$name> for $name {
+                type Output = f64;
+                fn checked_div(self, other: $name) -> Result<f64, V3Error> {
+                    let mut n: Value = other.0;
+                    n._convert_dimensional(&self.0)?;
+                    Ok(self.0.val / n.val)
+                }
+            }
+
+            impl Div<$name> for $name {
+                type Output = f64;
+                /// # Panics
+                ///
+                /// Panics if the units of the operands cannot be converted into each other.
+                /// See [`CheckedDiv::checked_div`] for a non-panicking version.
+                fn div(self, other: $name) -> f64 {
+                    match self.checked_div(other) {
+                        Ok(n) => n,
+                        Err(_) => panic!("Cannot Div values {self} and {other}"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Defines the product of two wrappers
+macro_rules! quantity_mul {
+    ($($a:ident * $b:ident = $c:ident;)*) => {
+        $(
+            impl CheckedMul<$b> for $a {
+                type Output = $c;
+                fn checked_mul(self, other: $b) -> Result<$c, V3Error> {
+                    $c::_reduced(self.0 * other.0)
+                }
+            }
+
+            impl Mul<$b> for $a {
+                type Output = $c;
+                /// # Panics
+                ///
+                /// Panics if the product cannot be converted into the units of its dimension.
+                /// See [`CheckedMul::checked_mul`] for a non-panicking version.
+                fn mul(self, other: $b) -> $c {
+                    match self.checked_mul(other) {
+                        Ok(n) => n,
+                        Err(_) => panic!("Cannot Mul values {self} and {other}"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Defines the quotient of two wrappers
+macro_rules! quantity_div {
+    ($($a:ident / $b:ident = $c:ident;)*) => {
+        $(
+            impl CheckedDiv<$b> for $a {
+                type Output = $c;
+                fn checked_div(self, other: $b) -> Result<$c, V3Error> {
+                    $c::_reduced(self.0 / other.0)
+                }
+            }
+
+            impl Div<$b> for $a {
+                type Output = $c;
+                /// # Panics
+                ///
+                /// Panics if the quotient cannot be converted into the units of its dimension.
+                /// See [`CheckedDiv::checked_div`] for a non-panicking version.
+                fn div(self, other: $b) -> $c {
+                    match self.checked_div(other) {
+                        Ok(n) => n,
+                        Err(_) => panic!("Cannot Div values {self} and {other}"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+quantity_defs! {
+    /// A [`Value`] that is a length
+    Length => is_length, "a length", "m";
+    /// A [`Value`] that is an area
+    Area => is_area, "an area", "m^2";
+    /// A [`Value`] that is a volume
+    Volume => is_volume, "a volume", "m^3";
+    /// A [`Value`] that is a time
+    Time => is_time, "a time", "s";
+    /// A [`Value`] that is a mass
+    Mass => is_mass, "a mass", "kg";
+    /// A [`Value`] that is a velocity
+    Velocity => is_velocity, "a velocity", "m/s";
+    /// A [`Value`] that is an acceleration
+    Acceleration => is_acceleration, "an acceleration", "m/s^2";
+    /// A [`Value`] that is a force
+    Force => is_force, "a force", "N";
+    /// A [`Value`] that is a momentum
+    Momentum => is_momentum, "a momentum", "kg*m/s";
+    /// A [`Value`] that is an energy
+    Energy => is_energy, "an energy", "J";
+    /// A [`Value`] that is a power
+    Power => is_power, "a power", "W";
+    /// A [`Value`] that is a pressure
+    ///
+    /// Like every wrapper of this module, the dimension is checked once when it is created,
+    /// and multiplying or dividing wrappers produces the wrapper of the resulting dimension.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::quantity::{Area, Force, Length, Pressure};
+    ///
+    /// fn stress(f: Force, a: Area) -> Pressure {
+    ///     f / a
+    /// }
+    ///
+    /// let side:Length = Length::new(2.0, "m").unwrap();
+    /// let p:Pressure = stress(Force::new(8.0, "kN").unwrap(), side * side);
+    /// assert_eq!((p >> "kPa").unwrap().to_string(), "2 kPa");
+    ///
+    /// let v:Value = Value::new(3.0, "s").unwrap();
+    /// assert!(Length::try_from(v).is_err());
+    /// ```
+    Pressure => is_pressure, "a pressure", "Pa";
+}
+
+quantity_mul! {
+    Length * Length = Area;
+    Length * Area = Volume;
+    Area * Length = Volume;
+    Velocity * Time = Length;
+    Time * Velocity = Length;
+    Acceleration * Time = Velocity;
+    Time * Acceleration = Velocity;
+    Mass * Velocity = Momentum;
+    Velocity * Mass = Momentum;
+    Mass * Acceleration = Force;
+    Acceleration * Mass = Force;
+    Force * Time = Momentum;
+    Time * Force = Momentum;
+    Force * Length = Energy;
+    Length * Force = Energy;
+    Power * Time = Energy;
+    Time * Power = Energy;
+    Pressure * Area = Force;
+    Area * Pressure = Force;
+}
+
+quantity_div! {
+    Area / Length = Length;
+    Volume / Length = Area;
+    Volume / Area = Length;
+    Length / Time = Velocity;
+    Length / Velocity = Time;
+    Velocity / Time = Acceleration;
+    Velocity / Acceleration = Time;
+    Momentum / Mass = Velocity;
+    Momentum / Velocity = Mass;
+    Momentum / Time = Force;
+    Force / Mass = Acceleration;
+    Force / Acceleration = Mass;
+    Energy / Length = Force;
+    Energy / Force = Length;
+    Energy / Time = Power;
+    Energy / Power = Time;
+    Force / Area = Pressure;
+    Force / Pressure = Area;
+}
+
+#[cfg(test)]
+mod quantity_testing {
+    use crate::value::{
+        Value,
+        quantity::{
+            Acceleration, Area, CheckedDiv, CheckedMul, Energy, Force, Length, Mass, Momentum,
+            Power, Pressure, Time, Velocity, Volume,
+        },
+    };
+
+    #[test]
+    fn quantity_construction() {
+        let l: Length = Length::new(3.0, "ft").unwrap();
+        assert_eq!(l.to_string(), "3 ft");
+        assert_eq!(*l.as_value(), Value::new(3.0, "ft").unwrap());
+        assert_eq!(Value::from(l), Value::new(3.0, "ft").unwrap());
+        let v: Value = l.into();
+        assert!(v.is_length());
+
+        assert!(Length::new(3.0, "s").is_err());
+        assert!(Length::new(3.0, "m^2").is_err());
+        assert!(Length::new(3.0, "nope").is_err());
+        assert!(Force::new(1.0, "kg*m/s^2").is_ok());
+        assert!(Energy::new(1.0, "kW*hr").is_ok());
+        assert!(Pressure::try_from(Value::new(1.0, "J").unwrap()).is_err());
+        assert!(Volume::new(1.0, "l").is_ok());
+    }
+
+    #[test]
+    fn quantity_ops() {
+        let a: Length = Length::new(1.0, "ft").unwrap();
+        let b: Length = Length::new(12.0, "in").unwrap();
+        assert_eq!(a + b, Length::new(2.0, "ft").unwrap());
+        assert_eq!(a - b, Length::new(0.0, "ft").unwrap());
+        assert_eq!(-a, Length::new(-1.0, "ft").unwrap());
+        assert_eq!(a * 3.0, Length::new(3.0, "ft").unwrap());
+        assert_eq!(3.0 * a, Length::new(3.0, "ft").unwrap());
+        assert_eq!(a / 2.0, Length::new(0.5, "ft").unwrap());
+        assert!((b / a - 1.0).abs() < 1e-12);
+        assert_eq!((b >> "ft").unwrap(), a);
+        assert!((a >> "s").is_err());
+        assert!(a < Length::new(1.0, "m").unwrap());
+
+        let f: Force = Force::new(1.0, "N").unwrap();
+        let g: Force = Force::new(1.0, "kg*m/s^2").unwrap();
+        assert_eq!((f + g).to_string(), "2 N");
+        assert!((g / f - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quantity_relations() {
+        let l: Length = Length::new(100.0, "m").unwrap();
+        let t: Time = Time::new(20.0, "s").unwrap();
+        let m: Mass = Mass::new(2.0, "kg").unwrap();
+
+        let v: Velocity = l / t;
+        assert_eq!(v.to_string(), "5 m/s");
+        assert_eq!(v * t, l);
+        assert_eq!(l / v, t);
+        let a: Acceleration = v / t;
+        assert_eq!(a.to_string(), "0.25 m/s^2");
+        assert_eq!(a * t, v);
+        let p: Momentum = m * v;
+        assert_eq!(p / m, v);
+        let f: Force = m * a;
+        assert_eq!(f.to_string(), "0.5 m*kg/s^2");
+        assert_eq!(f / m, a);
+        assert_eq!(p / t, f);
+
+        let e: Energy = f * l;
+        assert_eq!(e.to_string(), "50 m^2*kg/s^2");
+        let w: Power = e / t;
+        assert_eq!(w * t, e);
+        assert_eq!(e / w, t);
+        assert_eq!(e / f, l);
+
+        let area: Area = l * Length::new(2.0, "m").unwrap();
+        let vol: Volume = area * Length::new(3.0, "m").unwrap();
+        assert_eq!(vol.to_string(), "600 m^3");
+        assert_eq!(vol / area, Length::new(3.0, "m").unwrap());
+        let pr: Pressure = Force::new(400.0, "N").unwrap() / area;
+        assert_eq!((pr >> "Pa").unwrap().to_string(), "2 Pa");
+        assert_eq!(Force::new(400.0, "N").unwrap() / pr, area);
+
+        let mixed: Velocity = Length::new(3.6, "km").unwrap() / Time::new(1.0, "hr").unwrap();
+        assert_eq!((mixed >> "m/s").unwrap().as_value().val.round(), 1.0);
+    }
+
+    #[test]
+    fn quantity_mixed_units() {
+        let a: Area = Volume::new(1.0, "l").unwrap() / Length::new(1.0, "m").unwrap();
+        assert!(a.as_value().is_area());
+        assert_eq!(a.to_string(), "0.001 m^2");
+
+        let t: Time = Energy::new(1.0, "kJ").unwrap() / Power::new(1.0, "W").unwrap();
+        assert!(t.as_value().is_time());
+        assert_eq!(t.to_string(), "1000 s");
+
+        let f: Force = Energy::new(1.0, "J").unwrap() / Length::new(1.0, "m").unwrap();
+        assert!(f.as_value().is_force());
+        assert_eq!(f.to_string(), "1 N");
+
+        let l: Length = Volume::new(1.0, "l").unwrap() / Area::new(1.0, "cm^2").unwrap();
+        assert!((l.as_value().val - 10.0).abs() < 1e-12);
+        assert_eq!(l.as_value().unit_string(), "m");
+
+        let e: Energy = Power::new(2.0, "kW").unwrap() * Time::new(1.0, "hr").unwrap();
+        assert!(e.as_value().is_energy());
+        assert!(((e >> "MJ").unwrap().as_value().val - 7.2).abs() < 1e-12);
+
+        let p: Pressure = Force::new(1.0, "lbfr").unwrap() / Area::new(1.0, "in^2").unwrap();
+        assert!(p.as_value().is_pressure());
+        assert!(((p >> "kPa").unwrap().as_value().val - 6.894757).abs() < 1e-6);
+
+        let v: Velocity = Length::new(1.0, "miles").unwrap() / Time::new(1.0, "hr").unwrap();
+        assert_eq!(v.as_value().unit_string(), "miles/hr");
+    }
+
+    #[test]
+    fn quantity_checked() {
+        let l: Length = Length::new(2.0, "m").unwrap();
+        let t: Time = Time::new(4.0, "s").unwrap();
+        let v: Velocity = l.checked_div(t).unwrap();
+        assert_eq!(v, l / t);
+        assert_eq!(v.checked_mul(t).unwrap(), l);
+        let a: Area = Volume::new(1.0, "l").unwrap().checked_div(l).unwrap();
+        assert_eq!(a.to_string(), "0.0005 m^2");
+        assert!((l.checked_div(Length::new(50.0, "cm").unwrap()).unwrap() - 4.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,510 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{fmt::Display, ops::Shr, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::V3Error, value::Value};
+
+/// A range of [`Value`]s between two bounds sharing the same units
+///
+/// Each bound is either inclusive or exclusive. The end bound is always expressed in the units
+/// of the start bound, and [`Value`]s in any compatible units can be tested against the range.
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::range::ValueRange;
+/// let r:ValueRange = "60..70 °F".parse().unwrap();
+/// assert!(r.contains(&Value::new(20.0, "°C").unwrap()));
+/// assert!(!r.contains(&Value::new(25.0, "°C").unwrap()));
+/// assert_eq!(r.to_string(), "60 °f..70 °f");
+///
+/// let t:ValueRange = "10 mm ± 0.5 mm".parse().unwrap();
+/// assert_eq!(t.to_string(), "9.5 mm..10.5 mm");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ValueRange {
+    /// The lower bound
+    start: Value,
+    /// The upper bound, in the units of `start`
+    end: Value,
+    /// `true` if the lower bound is part of the range
+    start_inclusive: bool,
+    /// `true` if the upper bound is part of the range
+    end_inclusive: bool,
+}
+
+impl ValueRange {
+    /// Creates a [`ValueRange`] with the given bounds
+    ///
+    /// `end` is converted into the units of `start`. Returns an error if the units are not
+    /// compatible or the range would be empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::range::ValueRange;
+    /// let a:Value = Value::new(1.0, "m").unwrap();
+    /// let b:Value = Value::new(200.0, "cm").unwrap();
+    /// let r:ValueRange = ValueRange::new(a, true, b, false).unwrap();
+    /// assert_eq!(r.to_string(), "[1 m..2 m)");
+    /// assert!(!r.contains(&b));
+    /// assert!(ValueRange::new(b, true, a, true).is_err());
+    /// ```
+    pub fn new(
+        start: Value,
+        start_inclusive: bool,
+        end: Value,
+        end_inclusive: bool,
+    ) -> Result<ValueRange, V3Error> {
+        let end: Value =
+            (end >> start).map_err(|_| V3Error::IncompatibleUnits(format!("{start} and {end}")))?;
+        if !(start.val < end.val || (start.val == end.val && start_inclusive && end_inclusive)) {
+            return Err(V3Error::ValueConversionError("[ValueRange] Empty range"));
+        }
+        Ok(ValueRange {
+            start,
+            end,
+            start_inclusive,
+            end_inclusive,
+        })
+    }
+
+    /// Creates a [`ValueRange`] that includes both bounds
+    pub fn inclusive(start: Value, end: Value) -> Result<ValueRange, V3Error> {
+        ValueRange::new(start, true, end, true)
+    }
+
+    /// Creates a [`ValueRange`] that excludes both bounds
+    pub fn exclusive(start: Value, end: Value) -> Result<ValueRange, V3Error> {
+        ValueRange::new(start, false, end, false)
+    }
+
+    /// Returns the lower bound
+    pub fn start(&self) -> Value {
+        self.start
+    }
+
+    /// Returns the upper bound
+    pub fn end(&self) -> Value {
+        self.end
+    }
+
+    /// Returns `true` if the lower bound is part of the range
+    pub fn is_start_inclusive(&self) -> bool {
+        self.start_inclusive
+    }
+
+    /// Returns `true` if the upper bound is part of the range
+    pub fn is_end_inclusive(&self) -> bool {
+        self.end_inclusive
+    }
+
+    /// Returns the numeric of `other` in the units of the range
+    fn _numeric_of(&self, other: &Value) -> Result<f64, V3Error> {
+        match *other >> self.start {
+            Ok(v) => Ok(v.val),
+            Err(_) => Err(V3Error::IncompatibleUnits(format!("{self} and {other}"))),
+        }
+    }
+
+    /// Returns `true` if a [`Value`] lies within the range
+    ///
+    /// `other` is converted into the units of the range first, and `false` is returned if the
+    /// units are not compatible.
+    pub fn contains(&self, other: &Value) -> bool {
+        let Ok(v) = self._numeric_of(other) else {
+            return false;
+        };
+        let above: bool = if self.start_inclusive {
+            v >= self.start.val
+        } else {
+            v > self.start.val
+        };
+        let below: bool = if self.end_inclusive {
+            v <= self.end.val
+        } else {
+            v < self.end.val
+        };
+        above && below
+    }
+
+    /// Returns `other` with its bounds in the units of `self`
+    fn _in_self_units(&self, other: &ValueRange) -> Result<ValueRange, V3Error> {
+        Ok(ValueRange {
+            start: Value {
+                val: self._numeric_of(&other.start)?,
+                ..self.start
+            },
+            end: Value {
+                val: self._numeric_of(&other.end)?,
+                ..self.start
+            },
+            ..*other
+        })
+    }
+
+    /// Returns the range of [`Value`]s within both ranges
+    ///
+    /// The result is in the units of `self`, and is `None` if the ranges do not overlap.
+    /// Returns an error if the units are not compatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::range::ValueRange;
+    /// let a:ValueRange = "10..20 mm".parse().unwrap();
+    /// let b:ValueRange = "1.5..3 cm".parse().unwrap();
+    /// assert_eq!(a.intersection(&b).unwrap().unwrap().to_string(), "15 mm..20 mm");
+    /// ```
+    pub fn intersection(&self, other: &ValueRange) -> Result<Option<ValueRange>, V3Error> {
+        let o: ValueRange = self._in_self_units(other)?;
+        let (start, start_inclusive) = _pick(
+            (self.start, self.start_inclusive),
+            (o.start, o.start_inclusive),
+            true,
+            true,
+        );
+        let (end, end_inclusive) = _pick(
+            (self.end, self.end_inclusive),
+            (o.end, o.end_inclusive),
+            false,
+            true,
+        );
+        Ok(ValueRange::new(start, start_inclusive, end, end_inclusive).ok())
+    }
+
+    /// Returns the range of [`Value`]s within either range
+    ///
+    /// The result is in the units of `self`, and is `None` if the ranges neither overlap nor
+    /// touch, as the union would not be a single range.
+    /// Returns an error if the units are not compatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::range::ValueRange;
+    /// let a:ValueRange = "10..20 mm".parse().unwrap();
+    /// let b:ValueRange = "2..3 cm".parse().unwrap();
+    /// assert_eq!(a.union(&b).unwrap().unwrap().to_string(), "10 mm..30 mm");
+    /// ```
+    pub fn union(&self, other: &ValueRange) -> Result<Option<ValueRange>, V3Error> {
+        let o: ValueRange = self._in_self_units(other)?;
+        let touching = |a: &ValueRange, b: &ValueRange| {
+            a.end.val > b.start.val
+                || (a.end.val == b.start.val && (a.end_inclusive || b.start_inclusive))
+        };
+        if !(touching(self, &o) && touching(&o, self)) {
+            return Ok(None);
+        }
+        let (start, start_inclusive) = _pick(
+            (self.start, self.start_inclusive),
+            (o.start, o.start_inclusive),
+            false,
+            false,
+        );
+        let (end, end_inclusive) = _pick(
+            (self.end, self.end_inclusive),
+            (o.end, o.end_inclusive),
+            true,
+            false,
+        );
+        Ok(Some(ValueRange {
+            start,
+            end,
+            start_inclusive,
+            end_inclusive,
+        }))
+    }
+
+    /// Widens both bounds of the range by a tolerance
+    ///
+    /// The tolerance is a difference, so absolute temperatures are treated as temperature
+    /// intervals. A negative tolerance narrows the range.
+    /// Returns an error if the units are not compatible or the range would become empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::range::ValueRange;
+    /// let r:ValueRange = "20..25 °C".parse().unwrap();
+    /// let w:ValueRange = r.widen(&Value::new(9.0, "°F").unwrap()).unwrap();
+    /// assert_eq!(w.to_string(), "15 °c..30 °c");
+    /// ```
+    pub fn widen(&self, tol: &Value) -> Result<ValueRange, V3Error> {
+        let mut t: Value = *tol;
+        if t.is_temperature() && !t.is_temperature_interval() {
+            t.v_temperature = Some(t.v_temperature.unwrap().interval());
+        }
+        ValueRange::new(
+            self.start.try_sub(&t)?,
+            self.start_inclusive,
+            self.end.try_add(&t)?,
+            self.end_inclusive,
+        )
+    }
+
+    /// Converts the bounds of a [`ValueRange`] into other units
+    pub fn convert(&mut self, other: &str) -> Result<(), V3Error> {
+        *self = (*self >> other)?;
+        Ok(())
+    }
+}
+
+/// Returns the larger or smaller of two bounds
+///
+/// Equal bounds are only inclusive if both are when `both` is set, and if either is otherwise.
+fn _pick(a: (Value, bool), b: (Value, bool), larger: bool, both: bool) -> (Value, bool) {
+    if a.0.val == b.0.val {
+        return (a.0, if both { a.1 && b.1 } else { a.1 || b.1 });
+    }
+    if (a.0.val > b.0.val) == larger { a } else { b }
+}
+
+impl Display for ValueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start_inclusive && self.end_inclusive {
+            write!(f, "{}..{}", self.start, self.end)
+        } else {
+            write!(
+                f,
+                "{}{}..{}{}",
+                if self.start_inclusive { '[' } else { '(' },
+                self.start,
+                self.end,
+                if self.end_inclusive { ']' } else { ')' }
+            )
+        }
+    }
+}
+
+impl Shr<Value> for ValueRange {
+    type Output = Result<ValueRange, V3Error>;
+    fn shr(self, other: Value) -> Self::Output {
+        Ok(ValueRange {
+            start: (self.start >> other)?,
+            end: (self.end >> other)?,
+            ..self
+        })
+    }
+}
+
+impl Shr<&str> for ValueRange {
+    type Output = Result<ValueRange, V3Error>;
+    fn shr(self, other: &str) -> Self::Output {
+        self >> Value::new(1.0, other)?
+    }
+}
+
+/// Parses a bound, using `units` when the bound has none
+pub(in crate::value) fn _parse_bound(s: &str, units: &str) -> Result<Value, V3Error> {
+    let s: &str = s.trim();
+    let (num, u) = match s.split_once(char::is_whitespace) {
+        Some((n, u)) => (n, u.trim()),
+        None => (s, units),
+    };
+    match num.parse::<f64>() {
+        Ok(n) => Value::new(n, u),
+        Err(_) => Err(V3Error::ParsingError(format!(
+            "[ValueRange] float conversion of {num}"
+        ))),
+    }
+}
+
+/// Returns the unit portion of a bound
+pub(in crate::value) fn _bound_units(s: &str) -> &str {
+    match s.trim().split_once(char::is_whitespace) {
+        Some((_, u)) => u.trim(),
+        None => "",
+    }
+}
+
+impl FromStr for ValueRange {
+    type Err = V3Error;
+    /// Parses a range such as `10..12 mm`, `10 mm..12 mm`, `[10..12) mm` or `10 mm ± 0.5 mm`
+    ///
+    /// A bound without units takes the units of the other bound.
+    fn from_str(s: &str) -> Result<ValueRange, V3Error> {
+        let s: &str = s.trim();
+        for sep in ["±", "+/-"] {
+            if let Some((center, tol)) = s.split_once(sep) {
+                let tol: Value = _parse_bound(tol, "")?;
+                let center: Value = _parse_bound(center, &tol.unit_string())?;
+                return ValueRange::inclusive(center, center)?.widen(&tol);
+            }
+        }
+
+        let (start_inclusive, s) = match s.strip_prefix('(') {
+            Some(r) => (false, r),
+            None => (true, s.strip_prefix('[').unwrap_or(s)),
+        };
+        let Some((start, end)) = s.split_once("..") else {
+            return Err(V3Error::ParsingError(format!(
+                "[ValueRange] Missing '..' in {s}"
+            )));
+        };
+        let end: &str = end.trim();
+        let (end_inclusive, end) = if let Some(i) = end.find(')') {
+            (false, format!("{}{}", &end[..i], &end[i + 1..]))
+        } else if let Some(i) = end.find(']') {
+            (true, format!("{}{}", &end[..i], &end[i + 1..]))
+        } else {
+            (true, end.to_string())
+        };
+        let end: Value = _parse_bound(&end, _bound_units(start))?;
+        let start: Value = _parse_bound(start, &end.unit_string())?;
+        ValueRange::new(start, start_inclusive, end, end_inclusive)
+    }
+}
+
+#[cfg(test)]
+mod range_testing {
+    use crate::units::{Metric, UnitLength, UnitTemperature, UnitTime};
+    use crate::value::{Value, range::ValueRange};
+
+    fn mm(x: f64) -> Value {
+        x * UnitLength::Meter(Metric::Milli)
+    }
+
+    #[test]
+    fn range_construction() {
+        let r = ValueRange::inclusive(mm(10.0), 1.2 * UnitLength::Meter(Metric::Centi)).unwrap();
+        assert_eq!(r.start(), mm(10.0));
+        assert_eq!(r.end(), mm(12.0));
+        assert!(r.is_start_inclusive() && r.is_end_inclusive());
+        assert_eq!(r.to_string(), "10 mm..12 mm");
+
+        let e = ValueRange::exclusive(mm(10.0), mm(12.0)).unwrap();
+        assert_eq!(e.to_string(), "(10 mm..12 mm)");
+        assert!(ValueRange::inclusive(mm(10.0), mm(10.0)).is_ok());
+        assert!(ValueRange::new(mm(10.0), true, mm(10.0), false).is_err());
+        assert!(ValueRange::inclusive(mm(12.0), mm(10.0)).is_err());
+        assert!(ValueRange::inclusive(mm(1.0), 1.0 * UnitTime::Second(Metric::None)).is_err());
+    }
+
+    #[test]
+    fn range_contains() {
+        let r = ValueRange::new(mm(10.0), true, mm(12.0), false).unwrap();
+        assert!(r.contains(&mm(10.0)));
+        assert!(r.contains(&(1.1 * UnitLength::Meter(Metric::Centi))));
+        assert!(!r.contains(&mm(12.0)));
+        assert!(!r.contains(&mm(9.0)));
+        assert!(!r.contains(&(11.0 * UnitTime::Second(Metric::None))));
+        assert!(
+            !ValueRange::exclusive(mm(10.0), mm(12.0))
+                .unwrap()
+                .contains(&mm(10.0))
+        );
+
+        let t = ValueRange::inclusive(
+            60.0 * UnitTemperature::Fahrenheit,
+            70.0 * UnitTemperature::Fahrenheit,
+        )
+        .unwrap();
+        assert!(t.contains(&(20.0 * UnitTemperature::Celsius(Metric::None))));
+        assert!(t.contains(&(293.15 * UnitTemperature::Kelvin(Metric::None))));
+        assert!(!t.contains(&(15.0 * UnitTemperature::Celsius(Metric::None))));
+    }
+
+    #[test]
+    fn range_set_operations() {
+        let a = ValueRange::inclusive(mm(10.0), mm(20.0)).unwrap();
+        let b = ValueRange::exclusive(mm(15.0), mm(25.0)).unwrap();
+        let c: ValueRange = "2..3 cm".parse().unwrap();
+        let d: ValueRange = "(20..30] mm".parse().unwrap();
+
+        assert_eq!(
+            a.intersection(&b).unwrap().unwrap().to_string(),
+            "(15 mm..20 mm]"
+        );
+        assert_eq!(a.union(&b).unwrap().unwrap().to_string(), "[10 mm..25 mm)");
+        assert_eq!(
+            a.intersection(&c).unwrap().unwrap().to_string(),
+            "20 mm..20 mm"
+        );
+        assert!(a.intersection(&d).unwrap().is_none());
+        assert_eq!(a.union(&d).unwrap().unwrap().to_string(), "10 mm..30 mm");
+
+        let f = ValueRange::new(mm(5.0), true, mm(20.0), false).unwrap();
+        assert_eq!(
+            a.intersection(&f).unwrap().unwrap().to_string(),
+            "[10 mm..20 mm)"
+        );
+        assert_eq!(a.union(&f).unwrap().unwrap().to_string(), "5 mm..20 mm");
+
+        let e = ValueRange::inclusive(mm(30.0), mm(40.0)).unwrap();
+        assert!(a.intersection(&e).unwrap().is_none());
+        assert!(a.union(&e).unwrap().is_none());
+
+        let s = ValueRange::inclusive(
+            1.0 * UnitTime::Second(Metric::None),
+            2.0 * UnitTime::Second(Metric::None),
+        )
+        .unwrap();
+        assert!(a.intersection(&s).is_err());
+        assert!(a.union(&s).is_err());
+    }
+
+    #[test]
+    fn range_widen_and_convert() {
+        let a = ValueRange::inclusive(mm(10.0), mm(20.0)).unwrap();
+        assert_eq!(a.widen(&mm(1.0)).unwrap().to_string(), "9 mm..21 mm");
+        assert_eq!(a.widen(&mm(-5.0)).unwrap().to_string(), "15 mm..15 mm");
+        assert!(a.widen(&mm(-6.0)).is_err());
+        assert!(a.widen(&(1.0 * UnitTime::Second(Metric::None))).is_err());
+
+        let t = ValueRange::inclusive(
+            20.0 * UnitTemperature::Celsius(Metric::None),
+            25.0 * UnitTemperature::Celsius(Metric::None),
+        )
+        .unwrap();
+        let w = t
+            .widen(&(1.0 * UnitTemperature::DeltaKelvin(Metric::None)))
+            .unwrap();
+        assert_eq!(w.to_string(), "19 °c..26 °c");
+
+        let mut c = a;
+        c.convert("cm").unwrap();
+        assert_eq!(c.to_string(), "1 cm..2 cm");
+        assert!(c.convert("s").is_err());
+    }
+
+    #[test]
+    fn range_parse() {
+        let a: ValueRange = "10..12 mm".parse().unwrap();
+        assert_eq!(a, ValueRange::inclusive(mm(10.0), mm(12.0)).unwrap());
+        let b: ValueRange = "10 mm..1.2 cm".parse().unwrap();
+        assert_eq!(b, a);
+        let c: ValueRange = "10 mm ± 0.5 mm".parse().unwrap();
+        assert_eq!(c.to_string(), "9.5 mm..10.5 mm");
+        let d: ValueRange = "10 ± 0.5 mm".parse().unwrap();
+        assert_eq!(d, c);
+        let e: ValueRange = "10 mm +/- 0.05 cm".parse().unwrap();
+        assert_eq!(e, c);
+        let f: ValueRange = "20 °C ± 0.5 °C".parse().unwrap();
+        assert_eq!(f.to_string(), "19.5 °c..20.5 °c");
+        let g: ValueRange = "[-5..5) s".parse().unwrap();
+        assert_eq!(g.to_string(), "[-5 s..5 s)");
+        let h: ValueRange = "(10 mm..12 mm]".parse().unwrap();
+        assert_eq!(h.to_string().parse::<ValueRange>().unwrap(), h);
+
+        assert!("10 mm".parse::<ValueRange>().is_err());
+        assert!("a..b mm".parse::<ValueRange>().is_err());
+        assert!("12..10 mm".parse::<ValueRange>().is_err());
+        assert!("10 s..12 mm".parse::<ValueRange>().is_err());
+    }
+}
@@ -0,0 +1,250 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{errors::V3Error, value::Value};
+
+impl Value {
+    /// Splits a [`Value`] across descending units of the same dimension
+    ///
+    /// Every unit but the last holds a whole number, and the last holds the remainder.
+    /// Remainders within floating point noise of a whole unit are carried into it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let h:Value = Value::new(1.6002, "m").unwrap();
+    /// let parts:Vec<Value> = h.to_compound(&["ft", "in"]).unwrap();
+    /// assert_eq!(parts[0].to_string(), "5 ft");
+    /// assert!((parts[1].val - 3.0).abs() < 1e-9);
+    ///
+    /// assert!(h.to_compound(&["in", "ft"]).is_err());
+    /// assert!(h.to_compound(&["ft", "s"]).is_err());
+    /// ```
+    pub fn to_compound(&self, units: &[&str]) -> Result<Vec<Value>, V3Error> {
+        self._compound("to_compound", units, None)
+    }
+
+    /// Formats a [`Value`] across descending units, rounding the last to a number of decimals
+    ///
+    /// At most 15 decimals are used. The string can be parsed back into a [`Value`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let t:Value = Value::new(4984.4, "s").unwrap();
+    /// assert_eq!(t.to_compound_string(&["hr", "min", "s"], 0).unwrap(), "1 hr 23 min 4 s");
+    ///
+    /// let w:Value = Value::new(-1.59, "lb").unwrap();
+    /// assert_eq!(w.to_compound_string(&["lb", "oz"], 1).unwrap(), "-1 lb 9.4 oz");
+    /// ```
+    pub fn to_compound_string(&self, units: &[&str], decimals: usize) -> Result<String, V3Error> {
+        let decimals: usize = decimals.min(15);
+        let parts: Vec<Value> = self._compound("to_compound_string", units, Some(decimals))?;
+        let last: usize = parts.len() - 1;
+        let body: Vec<String> = parts
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                if i == last {
+                    format!("{:.decimals$} {}", p.val.abs(), p.unit_string())
+                } else {
+                    format!("{} {}", p.val.abs(), p.unit_string())
+                }
+            })
+            .collect();
+        let negative: bool = parts.iter().any(|p| p.val < 0.0);
+        Ok(format!(
+            "{}{}",
+            if negative { "-" } else { "" },
+            body.join(" ")
+        ))
+    }
+
+    /// Splits a [`Value`] across units, optionally rounding the remainder first
+    fn _compound(
+        &self,
+        op: &str,
+        units: &[&str],
+        decimals: Option<usize>,
+    ) -> Result<Vec<Value>, V3Error> {
+        let Some(last) = units.last() else {
+            return Err(V3Error::UnsupportedUnit(format!("[{op}] No units given")));
+        };
+        let mut factors: Vec<f64> = Vec::with_capacity(units.len());
+        for u in units {
+            let f: f64 = (Value::new(1.0, u)? >> *last)?.val;
+            if factors.last().is_some_and(|p| *p <= f) {
+                return Err(V3Error::UnsupportedUnit(format!(
+                    "[{op}] Units must be in descending order: {}",
+                    units.join(", ")
+                )));
+            }
+            factors.push(f);
+        }
+
+        let total: f64 = (*self >> *last)?.val;
+        if !total.is_finite() {
+            return Err(V3Error::ValueConversionError(
+                "[to_compound] Value is not finite",
+            ));
+        }
+        let mut rest: f64 = total.abs();
+        if let Some(d) = decimals {
+            let scale: f64 = 10_f64.powi(d as i32);
+            rest = (rest * scale).round() / scale;
+        }
+        let sign = |n: f64| if total < 0.0 && n != 0.0 { -n } else { n };
+
+        let mut parts: Vec<Value> = Vec::with_capacity(units.len());
+        for (u, f) in units.iter().zip(&factors).take(units.len() - 1) {
+            let q: f64 = rest / f;
+            let n: f64 = if (q - q.round()).abs() <= 1e-9 * q.round().max(1.0) {
+                q.round()
+            } else {
+                q.floor()
+            };
+            rest = (rest - n * f).max(0.0);
+            parts.push(Value::new(sign(n), u)?);
+        }
+        parts.push(Value::new(sign(rest), last)?);
+        Ok(parts)
+    }
+
+    /// Sums space separated numerics and units, such as `5 ft 3 in`, into the last unit
+    ///
+    /// The sign of the first numeric applies to the whole sum.
+    pub(in crate::value) fn _from_compound(tokens: &[&str]) -> Result<Value, V3Error> {
+        let s: String = tokens.join(" ");
+        let Some(last) = tokens.last() else {
+            return Err(V3Error::ParsingError(
+                "[from_str] Empty compound value".into(),
+            ));
+        };
+        let mut total: Option<Value> = None;
+        let mut negative: bool = false;
+        for (i, pair) in tokens.chunks(2).enumerate() {
+            let n: f64 = pair[0]
+                .parse::<f64>()
+                .map_err(|_| V3Error::ParsingError("[from_str] float conversion".into()))?;
+            if i == 0 {
+                negative = pair[0].starts_with('-');
+            } else if pair[0].starts_with(['-', '+']) || n.is_nan() {
+                return Err(V3Error::ParsingError(format!(
+                    "[from_str] Only the first part of a compound value may be signed: {s}"
+                )));
+            }
+            let part: Value = Value::new(n.abs(), pair[1])?;
+            total = Some(match total {
+                Some(t) => t.try_add(&part)?,
+                None => part,
+            });
+        }
+        let Some(total) = total else {
+            return Err(V3Error::ParsingError(
+                "[from_str] Empty compound value".into(),
+            ));
+        };
+        let mut total: Value = (total >> *last)?;
+        if negative {
+            total.val = -total.val;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod compound_testing {
+    use crate::value::Value;
+
+    #[test]
+    fn split() {
+        let h: Value = Value::new(63.0, "in").unwrap();
+        let parts: Vec<Value> = h.to_compound(&["ft", "in"]).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Value::new(5.0, "ft").unwrap(),
+                Value::new(3.0, "in").unwrap()
+            ]
+        );
+
+        let six: Value = Value::new(1.8288, "m").unwrap();
+        let parts: Vec<Value> = six.to_compound(&["ft", "in"]).unwrap();
+        assert_eq!(parts[0].val, 6.0);
+        assert!(parts[1].val.abs() < 1e-9);
+
+        let neg: Value = Value::new(-90.5, "min").unwrap();
+        let parts: Vec<Value> = neg.to_compound(&["hr", "min"]).unwrap();
+        assert_eq!(parts[0].val, -1.0);
+        assert_eq!(parts[1].val, -30.5);
+
+        let one: Vec<Value> = h.to_compound(&["cm"]).unwrap();
+        assert!((one[0].val - 160.02).abs() < 1e-9);
+
+        assert!(h.to_compound(&[]).is_err());
+        assert!(h.to_compound(&["in", "in"]).is_err());
+        assert!(h.to_compound(&["ft", "kg"]).is_err());
+        assert!(
+            Value::new(f64::INFINITY, "in")
+                .unwrap()
+                .to_compound(&["ft", "in"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn format() {
+        let h: Value = Value::new(71.99, "in").unwrap();
+        assert_eq!(h.to_compound_string(&["ft", "in"], 0).unwrap(), "6 ft 0 in");
+        assert_eq!(
+            h.to_compound_string(&["ft", "in"], 2).unwrap(),
+            "5 ft 11.99 in"
+        );
+        let z: Value = Value::new(-0.001, "s").unwrap();
+        assert_eq!(z.to_compound_string(&["min", "s"], 0).unwrap(), "0 min 0 s");
+        assert_eq!(
+            Value::new(90.0, "s")
+                .unwrap()
+                .to_compound_string(&["min", "s"], 1_000_000)
+                .unwrap(),
+            "1 min 30.000000000000000 s"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let h: Value = "5 ft 3 in".parse().unwrap();
+        assert_eq!(h, Value::new(63.0, "in").unwrap());
+        assert_eq!(h.unit_string(), "in");
+
+        let t: Value = "1 h 23 min 4 s".parse().unwrap();
+        assert_eq!(t, Value::new(4984.0, "s").unwrap());
+        let n: Value = "-2 hr  30 min".parse().unwrap();
+        assert_eq!(n, Value::new(-150.0, "min").unwrap());
+
+        let w: Value = Value::new(-1.59, "lb").unwrap();
+        let s: String = w.to_compound_string(&["lb", "oz"], 3).unwrap();
+        let back: Value = s.parse().unwrap();
+        assert!((back.val - (w >> "oz").unwrap().val).abs() < 1e-3);
+
+        assert!("5 ft 3 kg".parse::<Value>().is_err());
+        let e: String = "2 s 5 ft".parse::<Value>().unwrap_err().to_string();
+        assert!(e.contains("2 s and 5 ft"), "{e}");
+        assert!("5 ft -3 in".parse::<Value>().is_err());
+        assert!("5 ft 3 parsecs_".parse::<Value>().is_err());
+    }
+}
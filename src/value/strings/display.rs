@@ -19,8 +19,23 @@ use crate::consts::*;
 use std::fmt::Display;
 
 use crate::value::Value;
+use crate::value::exponent::Exponent;
+use crate::value::numeric::Numeric;
 
-impl Display for Value {
+impl Value {
+    /// Formats a single unit raised to a positive exponent, such as `m^2` or `Hz^(1/2)`
+    pub(crate) fn _unit_power(u: String, exp: Exponent) -> String {
+        if exp == 1 {
+            u
+        } else if exp.is_integer() {
+            format!("{u}^{exp}")
+        } else {
+            format!("{u}^({exp})")
+        }
+    }
+}
+
+impl<T: Numeric> Display for Value<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut nums: Vec<String> = vec![];
         let mut denoms: Vec<String> = vec![];
@@ -67,16 +82,13 @@ impl Display for Value {
                 }
             };
 
-            if self.exp[i] < -1 {
-                denoms.push(format!("{}^{}", u, -self.exp[i]))
-            } else if self.exp[i] > 1 {
-                nums.push(format!("{}^{}", u, self.exp[i]))
-            } else if self.exp[i] == 1 {
-                nums.push(u);
-            } else if self.exp[i] == -1 {
-                denoms.push(u);
+            if self.exp[i] < 0 {
+                denoms.push(Value::_unit_power(u, -self.exp[i]));
+            } else if self.exp[i] > 0 {
+                nums.push(Value::_unit_power(u, self.exp[i]));
             }
         }
+        self._custom_unit_strings(&mut nums, &mut denoms);
 
         let final_num = nums.join("*");
         let final_denom = denoms.join("*");
@@ -89,6 +101,8 @@ impl Display for Value {
             final_str = final_num;
         } else if final_num.is_empty() && !final_denom.is_empty() {
             final_str = format!("1/{final_denom}");
+        } else if let Some(r) = self.v_ratio {
+            final_str = r.to_string();
         } else {
             final_str = String::from("");
         }
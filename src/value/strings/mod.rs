@@ -23,3 +23,6 @@ pub(crate) mod parse;
 
 /// This module is responsible for implementing the functions [`.into()`] or [`.from()`] to turn a [`&str`] into a [`Value`]
 pub(crate) mod value_from_str;
+
+/// This module is responsible for splitting a [`Value`] across several units and summing them back
+pub(crate) mod compound;
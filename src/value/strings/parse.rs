@@ -15,6 +15,7 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::value::exponent::Exponent;
 use crate::{
     consts::{
         ABSORBED_DOSE_INDEX, ABSORBED_DOSE_MAP, ANGLE_INDEX, ANGLE_MAP, CAPACITANCE_INDEX,
@@ -34,13 +35,13 @@ use crate::{
     },
     errors::V3Error,
     units::{
-        Metric, UnitAbsorbedDose, UnitAngle, UnitCatalyticActivity, UnitElectricCapacitance,
-        UnitElectricCharge, UnitElectricConductance, UnitElectricCurrent, UnitElectricInductance,
-        UnitElectricPotential, UnitElectricResistance, UnitEnergy, UnitForce, UnitFrequency,
-        UnitIlluminance, UnitInformation, UnitLength, UnitLuminousFlux, UnitLuminousIntensity,
-        UnitMagneticFlux, UnitMagneticFluxDensity, UnitMass, UnitPower, UnitPressure,
-        UnitRadioactivity, UnitRadioactivityExposure, UnitSolidAngle, UnitSound, UnitSubstance,
-        UnitTemperature, UnitTime, UnitVolume,
+        Metric, UnitAbsorbedDose, UnitAngle, UnitCatalyticActivity, UnitCustom,
+        UnitElectricCapacitance, UnitElectricCharge, UnitElectricConductance, UnitElectricCurrent,
+        UnitElectricInductance, UnitElectricPotential, UnitElectricResistance, UnitEnergy,
+        UnitForce, UnitFrequency, UnitIlluminance, UnitInformation, UnitLength, UnitLuminousFlux,
+        UnitLuminousIntensity, UnitMagneticFlux, UnitMagneticFluxDensity, UnitMass, UnitNone,
+        UnitPower, UnitPressure, UnitRadioactivity, UnitRadioactivityExposure, UnitSolidAngle,
+        UnitSound, UnitSubstance, UnitTemperature, UnitTime, UnitVolume,
     },
     value::Value,
 };
@@ -52,38 +53,57 @@ impl Value {
 
         // do the numors first
         for t in tokens.0 {
-            let mut expon: i32 = 1;
+            let mut expon: Exponent = Exponent::ONE;
             let temp_split: Vec<&str> = t.split('^').collect();
             if temp_split.len() > 1 {
-                expon = match temp_split[1].parse::<i32>() {
+                expon = match temp_split[1].parse::<Exponent>() {
                     Ok(t) => t,
                     Err(_) => {
                         return Err(V3Error::ParsingError(
-                            "[_create_unit_1] Cannot parse int".into(),
+                            "[_create_unit_1] Cannot parse exponent".into(),
                         ));
                     }
                 };
             }
-            self._parse_units(String::from(temp_split[0]), expon)?;
+            self._parse_token(temp_split[0], expon)?;
         }
 
         // now the denoms
         for t in tokens.1 {
-            let mut expon: i32 = -1;
+            let mut expon: Exponent = -Exponent::ONE;
             let temp_split: Vec<&str> = t.split('^').collect();
             if temp_split.len() > 1 {
-                expon *= match temp_split[1].parse::<i32>() {
+                expon *= match temp_split[1].parse::<Exponent>() {
                     Ok(t) => t,
                     Err(_) => {
                         return Err(V3Error::ParsingError(
-                            "[_create_unit_2] Cannot parse int".into(),
+                            "[_create_unit_2] Cannot parse exponent".into(),
                         ));
                     }
                 };
             }
-            self._parse_units(String::from(temp_split[0]), expon)?;
+            self._parse_token(temp_split[0], expon)?;
         }
 
+        if self.v_ratio.is_some() && self.unit_map != 0 {
+            return Err(V3Error::ParsingError(
+                "[_create_unit] Ratios cannot be combined with other units".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Parses a unit into a [`Value`], combining it with a unit of the same dimension
+    ///
+    /// e.g. `mg/kg` is parsed as a plain number rather than `1/kg`
+    fn _parse_token(&mut self, unit: &str, exp: Exponent) -> Result<(), V3Error> {
+        let mut n: Value = Value::_unitless(1.0);
+        n._parse_units(unit.into(), exp)?;
+        if n.unit_map & self.unit_map == 0 {
+            return self._parse_units(unit.into(), exp);
+        }
+        *self = self.try_mul(&n)?;
         Ok(())
     }
 
@@ -101,16 +121,49 @@ impl Value {
         let mut start_index: usize = 0;
         let mut end_index: usize;
         let mut found_divisor: bool = do_denom;
+        let mut in_exponent: bool = false;
+        let mut in_superscript: bool = false;
         let mut constructor: String = String::new();
-        for index in 0..block.chars().count() {
-            let c: char = match block.chars().nth(index) {
-                Some(t) => t,
-                None => return Err(V3Error::ParsingError("[_get_tokens] Index error".into())),
+        for (index, c) in block.char_indices() {
+            // rational exponents such as `^(1/2)` stay attached to their unit
+            if in_exponent {
+                if !c.is_whitespace() {
+                    constructor.push(c);
+                }
+                if c == ')' {
+                    in_exponent = false;
+                }
+                continue;
+            } else if c == '(' && left_count == 0 && constructor.ends_with('^') {
+                in_exponent = true;
+                constructor.push(c);
+                continue;
+            }
+
+            // superscripts such as `m⁻²` are read as `m^-2`
+            if let Some(d) = Self::_superscript(c) {
+                if left_count == 0 {
+                    if !in_superscript && !constructor.ends_with('^') {
+                        constructor.push('^');
+                    }
+                    constructor.push(d);
+                }
+                in_superscript = true;
+                continue;
+            }
+            in_superscript = false;
+
+            // unicode operators behave like their ASCII forms
+            let c: char = match c {
+                '·' | '⋅' | '×' => '*',
+                '∕' | '÷' => '/',
+                _ => c,
             };
+
             match c {
                 '(' => {
                     if left_count == 0 {
-                        start_index = index + 1;
+                        start_index = index + c.len_utf8();
                     }
                     left_count += 1;
                 }
@@ -161,6 +214,12 @@ impl Value {
             };
         }
 
+        if in_exponent {
+            return Err(V3Error::ParsingError(
+                "[_get_tokens] Unclosed exponent parenthesis".into(),
+            ));
+        }
+
         if !constructor.is_empty() {
             if !do_denom && !found_divisor {
                 numor.push(constructor.clone());
@@ -172,8 +231,85 @@ impl Value {
         Ok((numor, denom))
     }
 
+    /// Rewrites separated factors with signed exponents, such as `kg m-3`, into `kg*m^-3`
+    pub(crate) fn _scientific_units(units: &str) -> String {
+        let units: String = units.replace("**", "^");
+        let chars: Vec<char> = units.trim().chars().collect();
+        let mut ret: String = String::new();
+        let mut factor: String = String::new();
+        let mut separated: bool = false;
+        for (i, c) in chars.iter().enumerate() {
+            // a `.` between digits belongs to a decimal exponent
+            let decimal: bool = *c == '.'
+                && i > 0
+                && chars[i - 1].is_ascii_digit()
+                && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit());
+            // rational exponents such as `^(1/2)` stay attached to their unit
+            if factor.contains("^(") && !factor.ends_with(')') {
+                if !c.is_whitespace() {
+                    factor.push(*c);
+                }
+            } else if c.is_whitespace() || (*c == '.' && !decimal) {
+                Self::_push_factor(&mut ret, &mut factor, separated);
+                separated = !ret.is_empty();
+            } else if matches!(c, '*' | '/' | '(' | ')') && !factor.ends_with('^') {
+                Self::_push_factor(&mut ret, &mut factor, separated);
+                if *c == '(' && separated && !ret.ends_with(['*', '/', '(']) {
+                    ret.push('*');
+                }
+                ret.push(*c);
+                separated = false;
+            } else {
+                factor.push(*c);
+            }
+        }
+        Self::_push_factor(&mut ret, &mut factor, separated);
+        ret
+    }
+
+    /// Appends a factor to rewritten units, joining it to a separated previous factor
+    fn _push_factor(ret: &mut String, factor: &mut String, separated: bool) {
+        if factor.is_empty() {
+            return;
+        }
+        if separated && !ret.ends_with(['*', '/', '(', '^']) {
+            ret.push('*');
+        }
+        // trailing signed integers are exponents, as in `m-3` or `s2`
+        let digits: usize =
+            factor.len() - factor.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        let body: &str = &factor[..factor.len() - digits];
+        let body: &str = body.strip_suffix(['-', '+']).unwrap_or(body);
+        if digits > 0 && !body.is_empty() && !factor.contains('^') {
+            ret.push_str(body);
+            ret.push('^');
+            ret.push_str(&factor[body.len()..]);
+        } else {
+            ret.push_str(factor);
+        }
+        factor.clear();
+    }
+
+    /// Returns the ASCII form of a superscript digit or minus sign
+    fn _superscript(c: char) -> Option<char> {
+        match c {
+            '⁰' => Some('0'),
+            '¹' => Some('1'),
+            '²' => Some('2'),
+            '³' => Some('3'),
+            '⁴' => Some('4'),
+            '⁵' => Some('5'),
+            '⁶' => Some('6'),
+            '⁷' => Some('7'),
+            '⁸' => Some('8'),
+            '⁹' => Some('9'),
+            '⁻' => Some('-'),
+            _ => None,
+        }
+    }
+
     /// Searches and assigns a unit type to a [`Value`] during string parsing and construction
-    fn _get_single_letter(&mut self, unit: char, exp: i32, m: Metric) -> Result<(), V3Error> {
+    fn _get_single_letter(&mut self, unit: char, exp: Exponent, m: Metric) -> Result<(), V3Error> {
         match unit {
             '1' => {
                 // This handles the case of 1/m as a given string to parse
@@ -293,7 +429,12 @@ impl Value {
     }
 
     /// Searches and assigns a unit type to a [`Value`] during string parsing and construction
-    fn _get_double_letter(&mut self, unit: &String, exp: i32, m: Metric) -> Result<(), V3Error> {
+    fn _get_double_letter(
+        &mut self,
+        unit: &String,
+        exp: Exponent,
+        m: Metric,
+    ) -> Result<(), V3Error> {
         match unit.as_str() {
             "Hz" => {
                 self.v_frequency = Some(UnitFrequency::Hertz(m));
@@ -407,7 +548,12 @@ impl Value {
     }
 
     /// Searches and assigns a unit type to a [`Value`] during string parsing and construction
-    fn _get_triple_letter(&mut self, unit: &String, exp: i32, m: Metric) -> Result<(), V3Error> {
+    fn _get_triple_letter(
+        &mut self,
+        unit: &String,
+        exp: Exponent,
+        m: Metric,
+    ) -> Result<(), V3Error> {
         if let Some(cel_deg) = unit.strip_prefix('°') {
             if m != Metric::None {
                 return Err(V3Error::ParsingError(
@@ -511,7 +657,12 @@ impl Value {
     }
 
     /// Searches and assigns a unit type to a [`Value`] during string parsing and construction
-    fn _get_quadruple_letter(&mut self, unit: &String, exp: i32, m: Metric) -> Result<(), V3Error> {
+    fn _get_quadruple_letter(
+        &mut self,
+        unit: &String,
+        exp: Exponent,
+        m: Metric,
+    ) -> Result<(), V3Error> {
         if let Some(cel_deg) = unit.strip_prefix("°da") {
             if m != Metric::None {
                 return Err(V3Error::ParsingError(
@@ -582,7 +733,12 @@ impl Value {
     }
 
     /// Searches and assigns a unit type to a [`Value`] during string parsing and construction
-    fn _get_pentuple_letter(&mut self, unit: &str, exp: i32, m: Metric) -> Result<(), V3Error> {
+    fn _get_pentuple_letter(
+        &mut self,
+        unit: &str,
+        exp: Exponent,
+        m: Metric,
+    ) -> Result<(), V3Error> {
         if let Some(da) = unit.strip_prefix("da") {
             if m != Metric::None {
                 return Err(V3Error::ParsingError(
@@ -615,7 +771,7 @@ impl Value {
     }
 
     /// Searches and assigns a unit type to a [`Value`] during string parsing and construction
-    fn _get_sextuple_letter(&mut self, unit: &str, exp: i32) -> Result<(), V3Error> {
+    fn _get_sextuple_letter(&mut self, unit: &str, exp: Exponent) -> Result<(), V3Error> {
         if let Some(da) = unit.strip_prefix("da") {
             return self._get_quadruple_letter(&da.to_string(), exp, Metric::Deca);
         }
@@ -670,12 +826,52 @@ impl Value {
     }
 
     /// Searches through the given string for a new [`Value`] to parse for units
-    pub(crate) fn _parse_units(&mut self, unit: String, exp: i32) -> Result<(), V3Error> {
+    pub(crate) fn _parse_units(&mut self, unit: String, exp: Exponent) -> Result<(), V3Error> {
         let l: usize = unit.chars().count();
         if l == 0 {
             return Ok(());
         }
 
+        // temperature intervals are written with a leading `Δ`
+        if let Some(abs) = unit.strip_prefix('Δ') {
+            let mut t: Value = Value::_unitless(0.0);
+            t._parse_units(abs.into(), exp)?;
+            if t.unit_map != TEMPERATURE_MAP {
+                return Err(V3Error::UnsupportedUnit(format!(
+                    "[_parse_units] Unsupported temperature interval: {unit}"
+                )));
+            }
+            self.v_temperature = Some(t.v_temperature.unwrap().interval());
+            self.exp[TEMPERATURE_INDEX] = exp;
+            self.unit_map |= TEMPERATURE_MAP;
+            return Ok(());
+        }
+
+        // dimensionless ratios must stand alone
+        let ratio: Option<UnitNone> = match unit.as_str() {
+            "%" => Some(UnitNone::Percentage),
+            "‰" => Some(UnitNone::PerMille),
+            "ppm" => Some(UnitNone::PartsPerMillion),
+            "ppb" => Some(UnitNone::PartsPerBillion),
+            "ppt" => Some(UnitNone::PartsPerTrillion),
+            _ => None,
+        };
+        if let Some(r) = ratio {
+            if exp != 1 || self.v_ratio.is_some() {
+                return Err(V3Error::ParsingError(format!(
+                    "[_parse_units] Ratios cannot be compounded: {unit}"
+                )));
+            }
+            self.v_ratio = Some(r);
+            return Ok(());
+        }
+
+        // units of user-defined dimensions
+        if let Some(u) = UnitCustom::find(&unit) {
+            self._set_custom(u, exp);
+            return Ok(());
+        }
+
         // first match it against known unique strings
         match unit.as_str() {
             "mph" => {
@@ -796,6 +992,48 @@ impl Value {
                 self.unit_map |= ENERGY_MAP;
                 return Ok(());
             }
+            "Np" | "neper" | "nepers" => {
+                self.v_sound = Some(UnitSound::Neper);
+                self.exp[SOUND_INDEX] = exp;
+                self.unit_map |= SOUND_MAP;
+                return Ok(());
+            }
+            "dBm" => {
+                self.v_sound = Some(UnitSound::DecibelMilliwatt);
+                self.exp[SOUND_INDEX] = exp;
+                self.unit_map |= SOUND_MAP;
+                return Ok(());
+            }
+            "dBW" => {
+                self.v_sound = Some(UnitSound::DecibelWatt);
+                self.exp[SOUND_INDEX] = exp;
+                self.unit_map |= SOUND_MAP;
+                return Ok(());
+            }
+            "dBV" => {
+                self.v_sound = Some(UnitSound::DecibelVolt);
+                self.exp[SOUND_INDEX] = exp;
+                self.unit_map |= SOUND_MAP;
+                return Ok(());
+            }
+            "dBμV" | "dBµV" | "dBuV" => {
+                self.v_sound = Some(UnitSound::DecibelMicrovolt);
+                self.exp[SOUND_INDEX] = exp;
+                self.unit_map |= SOUND_MAP;
+                return Ok(());
+            }
+            "dBSPL" | "dBspl" => {
+                self.v_sound = Some(UnitSound::DecibelSoundPressure);
+                self.exp[SOUND_INDEX] = exp;
+                self.unit_map |= SOUND_MAP;
+                return Ok(());
+            }
+            "BTU" | "Btu" | "btu" => {
+                self.v_energy = Some(UnitEnergy::BritishThermalUnit);
+                self.exp[ENERGY_INDEX] = exp;
+                self.unit_map |= ENERGY_MAP;
+                return Ok(());
+            }
             "poundforce" | "poundsforce" | "lbfr" | "lbsfr" => {
                 self.v_force = Some(UnitForce::PoundForce);
                 self.exp[FORCE_INDEX] = exp;
@@ -908,6 +1146,7 @@ impl Value {
 
 #[cfg(test)]
 mod parse_testing {
+    use crate::value::exponent::Exponent;
     use crate::{
         consts::{
             ABSORBED_DOSE_INDEX, ABSORBED_DOSE_MAP, ANGLE_INDEX, ANGLE_MAP, CAPACITANCE_INDEX,
@@ -928,7 +1167,8 @@ mod parse_testing {
         },
         units::{
             Metric, UnitAngle, UnitElectricCapacitance, UnitEnergy, UnitForce, UnitFrequency,
-            UnitInformation, UnitLength, UnitMass, UnitPressure, UnitTemperature, UnitTime,
+            UnitInformation, UnitLength, UnitMass, UnitPressure, UnitSound, UnitTemperature,
+            UnitTime, custom::MAX_CUSTOM_DIMENSIONS,
         },
         value::Value,
     };
@@ -1005,6 +1245,54 @@ mod parse_testing {
         assert!(Value::new(1.5, "/m").is_ok());
     }
 
+    #[test]
+    fn scientific_units() {
+        let pairs: [(&str, &str); 10] = [
+            ("kg m-3", "kg/m^3"),
+            ("m s^-1", "m/s"),
+            ("J.kg-1.K-1", "J/(kg*K)"),
+            ("m**2", "m^2"),
+            ("W m-2 K-1", "W/(m^2*K)"),
+            ("kg  m2 s-2", "kg*m^2/s^2"),
+            ("m s**-2", "m/s^2"),
+            ("mol / (m3 s)", "mol/(m^3*s)"),
+            ("K m^0.5", "K*m^(1/2)"),
+            ("m s", "m*s"),
+        ];
+        for (scientific, slash) in pairs {
+            let s: Value = Value::new_scientific(1.5, scientific).unwrap();
+            let v: Value = Value::new(1.5, slash).unwrap();
+            assert_eq!(s, v, "{scientific}");
+            assert_eq!(s.dimension(), v.dimension(), "{scientific}");
+        }
+        assert_eq!(Value::_scientific_units("kg m-3"), "kg*m^-3");
+        assert_eq!(Value::_scientific_units("m+2 s^( 1 / 2 )"), "m^+2*s^(1/2)");
+        assert_eq!(Value::new_scientific(2.0, "").unwrap(), 2.0);
+        assert!(Value::new_scientific(1.0, "m-").is_err());
+        assert!(Value::new_scientific(1.0, "kg m-x").is_err());
+    }
+
+    #[test]
+    fn unicode_operators() {
+        let pairs: [(&str, &str); 7] = [
+            ("m²", "m^2"),
+            ("m·s⁻¹", "m*s^-1"),
+            ("kg⋅m/s²", "kg*m/s^2"),
+            ("W·m⁻²·K⁻¹", "W*m^-2*K^-1"),
+            ("N×m", "N*m"),
+            ("km∕hr", "km/hr"),
+            ("J÷(kg·K)", "J/(kg*K)"),
+        ];
+        for (unicode, ascii) in pairs {
+            let u: Value = Value::new(1.5, unicode).unwrap();
+            let a: Value = Value::new(1.5, ascii).unwrap();
+            assert_eq!(u.unit_string(), a.unit_string(), "{unicode}");
+            assert_eq!(u.exp, a.exp, "{unicode}");
+        }
+        assert_eq!(Value::new(1.0, "s⁻¹⁰").unwrap().exp[TIME_INDEX], -10);
+        assert!(Value::new(1.0, "m⁻").is_err());
+    }
+
     #[test]
     fn mul_special_case() {
         assert!(Value::new(1.5, "m*s").is_ok());
@@ -1071,6 +1359,7 @@ mod parse_testing {
     }
 
     #[test]
+    #[allow(clippy::nonminimal_bool)]
     fn letters_2_len() {
         let metric_units = [
             ("Hz", FREQUENCY_MAP, FREQUENCY_INDEX),
@@ -2208,7 +2497,7 @@ mod parse_testing {
         let mut k = Value {
             val: 1.5,
             unit_map: FREQUENCY_MAP | TIME_MAP,
-            exp: [0; 31],
+            exp: [Exponent::ZERO; 31],
             v_ab_dose: None,
             v_angle: None,
             v_capacitance: None,
@@ -2240,10 +2529,12 @@ mod parse_testing {
             v_magnetic_flux: None,
             v_magnetic_flux_density: None,
             v_solid_angle: None,
+            v_ratio: None,
+            v_custom: [None; MAX_CUSTOM_DIMENSIONS],
         };
 
-        k.exp[TIME_INDEX] = 1;
-        k.exp[FREQUENCY_INDEX] = 1;
+        k.exp[TIME_INDEX] = Exponent::from(1);
+        k.exp[FREQUENCY_INDEX] = Exponent::from(1);
 
         assert_eq!(t, k);
 
@@ -2252,7 +2543,7 @@ mod parse_testing {
         let mut k = Value {
             val: 1.5,
             unit_map: FREQUENCY_MAP | TIME_MAP,
-            exp: [0; 31],
+            exp: [Exponent::ZERO; 31],
             v_ab_dose: None,
             v_angle: None,
             v_capacitance: None,
@@ -2284,10 +2575,12 @@ mod parse_testing {
             v_magnetic_flux: None,
             v_magnetic_flux_density: None,
             v_solid_angle: None,
+            v_ratio: None,
+            v_custom: [None; MAX_CUSTOM_DIMENSIONS],
         };
 
-        k.exp[TIME_INDEX] = -1;
-        k.exp[FREQUENCY_INDEX] = 1;
+        k.exp[TIME_INDEX] = Exponent::from(-1);
+        k.exp[FREQUENCY_INDEX] = Exponent::from(1);
 
         assert_eq!(t, k);
 
@@ -2296,7 +2589,7 @@ mod parse_testing {
         let mut k = Value {
             val: 1.5,
             unit_map: FREQUENCY_MAP | TIME_MAP,
-            exp: [0; 31],
+            exp: [Exponent::ZERO; 31],
             v_ab_dose: None,
             v_angle: None,
             v_capacitance: None,
@@ -2328,10 +2621,12 @@ mod parse_testing {
             v_magnetic_flux: None,
             v_magnetic_flux_density: None,
             v_solid_angle: None,
+            v_ratio: None,
+            v_custom: [None; MAX_CUSTOM_DIMENSIONS],
         };
 
-        k.exp[TIME_INDEX] = -1;
-        k.exp[FREQUENCY_INDEX] = 1;
+        k.exp[TIME_INDEX] = Exponent::from(-1);
+        k.exp[FREQUENCY_INDEX] = Exponent::from(1);
 
         assert_eq!(t, k);
     }
@@ -2341,4 +2636,90 @@ mod parse_testing {
     fn parentheses_testing() {
         let _ = "2.0 ))((kg".parse::<Value>().unwrap();
     }
+
+    #[test]
+    fn rational_exponents() {
+        let t = "3 Hz^(1/2)".parse::<Value>().unwrap();
+        assert_eq!(t.exp[FREQUENCY_INDEX], Exponent::new(1, 2).unwrap());
+        assert_eq!(t, "3 Hz^0.5".parse::<Value>().unwrap());
+        assert_eq!(t, Value::new(3.0, "Hz^( 2 / 4 )").unwrap());
+        assert_eq!(t.to_string(), "3 Hz^(1/2)");
+
+        let t = "2 V/Hz^(1/2)".parse::<Value>().unwrap();
+        assert_eq!(t.exp[FREQUENCY_INDEX], Exponent::new(-1, 2).unwrap());
+        assert_eq!(t.to_string(), "2 V/Hz^(1/2)");
+        assert_eq!(t, "2 V*Hz^(-1/2)".parse::<Value>().unwrap());
+
+        let t = "5 MPa*m^(1/2)".parse::<Value>().unwrap();
+        assert_eq!(t.exp[LENGTH_INDEX], Exponent::new(1, 2).unwrap());
+        assert_eq!(t.to_string().parse::<Value>().unwrap(), t);
+
+        let t = "1 (kg*m^(3/2))/s^2".parse::<Value>().unwrap();
+        assert_eq!(t.exp[LENGTH_INDEX], Exponent::new(3, 2).unwrap());
+        assert_eq!(t.exp[TIME_INDEX], -2);
+
+        assert!("3 Hz^(1/2".parse::<Value>().is_err());
+        assert!("3 Hz^(1/0)".parse::<Value>().is_err());
+        assert!("3 Hz^(a/b)".parse::<Value>().is_err());
+    }
+
+    #[test]
+    fn temperature_intervals() {
+        let v = Value::new(1.5, "ΔK").unwrap();
+        assert_eq!(v.unit_map, TEMPERATURE_MAP);
+        assert_eq!(
+            v.v_temperature,
+            Some(UnitTemperature::DeltaKelvin(Metric::None))
+        );
+        assert_eq!(v.to_string(), "1.5 ΔK");
+
+        let v = Value::new(1.5, "Δ°C").unwrap();
+        assert_eq!(
+            v.v_temperature,
+            Some(UnitTemperature::DeltaCelsius(Metric::None))
+        );
+        assert_eq!(v.to_string().parse::<Value>().unwrap(), v);
+
+        let v = Value::new(1.5, "J/(kg*Δ°F)").unwrap();
+        assert_eq!(v.v_temperature, Some(UnitTemperature::DeltaFahrenheit));
+        assert_eq!(v.exp[TEMPERATURE_INDEX], -1);
+
+        let v = Value::new(1.5, "ΔmK^2").unwrap();
+        assert_eq!(
+            v.v_temperature,
+            Some(UnitTemperature::DeltaKelvin(Metric::Milli))
+        );
+        assert_eq!(v.exp[TEMPERATURE_INDEX], 2);
+
+        assert!(Value::new(1.5, "Δm").is_err());
+        assert!(Value::new(1.5, "Δ").is_err());
+    }
+
+    #[test]
+    fn levels() {
+        for (s, u) in [
+            ("Np", UnitSound::Neper),
+            ("neper", UnitSound::Neper),
+            ("dBm", UnitSound::DecibelMilliwatt),
+            ("dBW", UnitSound::DecibelWatt),
+            ("dBV", UnitSound::DecibelVolt),
+            ("dBμV", UnitSound::DecibelMicrovolt),
+            ("dBµV", UnitSound::DecibelMicrovolt),
+            ("dBuV", UnitSound::DecibelMicrovolt),
+            ("dBSPL", UnitSound::DecibelSoundPressure),
+        ] {
+            let v = Value::new(-3.0, s).unwrap();
+            assert_eq!(v.unit_map, SOUND_MAP);
+            assert_eq!(v.v_sound, Some(u));
+            assert_eq!(v.to_string().parse::<Value>().unwrap(), v);
+        }
+
+        let v = Value::new(-174.0, "dBm/Hz").unwrap();
+        assert_eq!(v.v_sound, Some(UnitSound::DecibelMilliwatt));
+        assert_eq!(v.exp[FREQUENCY_INDEX], -1);
+        assert_eq!(
+            Value::new(3.0, "dB").unwrap().v_sound,
+            Some(UnitSound::Bel(Metric::Deci))
+        );
+    }
 }
@@ -23,6 +23,13 @@ impl FromStr for Value {
     type Err = V3Error;
     fn from_str(s: &str) -> Result<Value, V3Error> {
         if !s.contains(char::is_whitespace) {
+            // ratios may be written directly after the numeric, such as `5%`
+            if let Some(n) = s.strip_suffix('%').or_else(|| s.strip_suffix('‰')) {
+                return match n.parse::<f64>() {
+                    Ok(t) => Value::new(t, &s[n.len()..]),
+                    Err(_) => Err(V3Error::ParsingError("[from_str] float conversion".into())),
+                };
+            }
             let val: Value = match s.parse::<f64>() {
                 Ok(t) => Value::new(t, "").unwrap(),
                 Err(_) => {
@@ -31,14 +38,23 @@ impl FromStr for Value {
             };
             return Ok(val);
         }
-        let temp: Vec<&str> = s.split(' ').collect();
-        let v: f64 = match temp[0].parse::<f64>() {
-            Ok(t) => t,
-            Err(_) => {
+        // compound values alternate numerics and units, such as `5 ft 3 in`
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        if tokens.len() > 2 {
+            if !tokens.len().is_multiple_of(2) {
+                return Err(V3Error::ParsingError(format!(
+                    "[from_str] Every numeric needs a unit: {s}"
+                )));
+            }
+            return Value::_from_compound(&tokens);
+        }
+        let v: f64 = match tokens.first().map(|t| t.parse::<f64>()) {
+            Some(Ok(t)) => t,
+            _ => {
                 return Err(V3Error::ParsingError("[from_str] float conversion".into()));
             }
         };
-        Value::new(v, temp[1])
+        Value::new(v, tokens.get(1).unwrap_or(&""))
     }
 }
 
@@ -95,4 +111,16 @@ mod from_str_testing {
     fn from_str_test_04() {
         let _: Value = Value::from_str("3.4.5 in/s").unwrap();
     }
+
+    #[test]
+    fn unconsumed_tokens() {
+        assert!(Value::from_str("5 ft 3").is_err());
+        assert!(Value::from_str("5 ft 3 in x").is_err());
+        assert!(Value::from_str("5 ft in").is_err());
+        assert!(Value::from_str(" ").is_err());
+        assert_eq!(
+            Value::from_str(" 5  ft ").unwrap(),
+            Value::new(5.0, "ft").unwrap()
+        );
+    }
 }
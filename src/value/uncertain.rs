@@ -0,0 +1,512 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Shr, Sub},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts::SOUND_MAP,
+    errors::V3Error,
+    value::{
+        Value,
+        range::{_bound_units, _parse_bound},
+    },
+};
+
+/// A [`Value`] with a standard uncertainty
+///
+/// Uncertainties are propagated to first order, assuming the uncertainties of the operands are
+/// uncorrelated, as described by the GUM. The uncertainty of an absolute temperature is a
+/// temperature interval. Levels are not supported as they do not convert linearly.
+///
+/// Every operand of an operation is treated as an independent measurement, even when it is the
+/// same [`UncertainValue`]. `a * a` therefore underestimates the uncertainty of a square, which
+/// should be computed with [`UncertainValue::powi`] instead.
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::uncertain::UncertainValue;
+/// let g:UncertainValue = "9.81 ± 0.02 m/s^2".parse().unwrap();
+/// let t:UncertainValue = "2 ± 0.01 s".parse().unwrap();
+///
+/// let v:UncertainValue = g * t;
+/// assert_eq!(v.value(), Value::new(19.62, "m/s").unwrap());
+/// assert!((v.uncertainty().val - 0.1059).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UncertainValue {
+    /// The measured value
+    value: Value,
+    /// The standard uncertainty, in the units of `value`
+    u: f64,
+}
+
+impl UncertainValue {
+    /// Creates an [`UncertainValue`] from a [`Value`] and its standard uncertainty
+    ///
+    /// The uncertainty is converted into the units of `value` as a difference, so absolute
+    /// temperatures are treated as temperature intervals. Returns an error if the units are
+    /// not compatible or `value` is a level.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::value::uncertain::UncertainValue;
+    /// let v:Value = Value::new(1.0, "m").unwrap();
+    /// let u:Value = Value::new(2.0, "mm").unwrap();
+    /// let x:UncertainValue = UncertainValue::new(v, u).unwrap();
+    /// assert_eq!(x.to_string(), "1 ± 0.002 m");
+    /// ```
+    pub fn new(value: Value, uncertainty: Value) -> Result<UncertainValue, V3Error> {
+        if value.unit_map & SOUND_MAP != 0 {
+            return Err(V3Error::IncompatibleUnits(format!(
+                "[UncertainValue] {value} cannot carry an uncertainty"
+            )));
+        }
+        match value._difference_in_self_units(&uncertainty) {
+            Some(u) => Ok(UncertainValue { value, u: u.abs() }),
+            None => Err(V3Error::IncompatibleUnits(format!(
+                "{value} and {uncertainty}"
+            ))),
+        }
+    }
+
+    /// Returns the measured [`Value`]
+    pub fn value(&self) -> Value {
+        self.value
+    }
+
+    /// Returns the standard uncertainty as a [`Value`]
+    ///
+    /// The uncertainty of an absolute temperature is a temperature interval.
+    pub fn uncertainty(&self) -> Value {
+        let mut u: Value = self.value;
+        u.val = self.u;
+        if u.is_temperature() && !u.is_temperature_interval() {
+            u.v_temperature = Some(u.v_temperature.unwrap().interval());
+        }
+        u
+    }
+
+    /// Returns the standard uncertainty relative to the magnitude of the measured [`Value`]
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.u / self.value.val.abs()
+    }
+
+    /// Returns the uncertainty once the measured [`Value`] is converted into the units of `other`
+    fn _u_in(&self, other: &Value) -> Result<f64, V3Error> {
+        let hi: Value = ((self.value + self.u) >> *other)?;
+        let lo: Value = ((self.value - self.u) >> *other)?;
+        Ok((hi.val - lo.val).abs() / 2.0)
+    }
+
+    /// Returns the numeric that the unit bookkeeping of an operation applies to the result
+    fn _unit_factor(
+        &self,
+        other: &UncertainValue,
+        op: fn(&Value, &Value) -> Result<Value, V3Error>,
+    ) -> Result<f64, V3Error> {
+        let (mut a, mut b): (Value, Value) = (self.value, other.value);
+        a.val = 1.0;
+        b.val = 1.0;
+        Ok(op(&a, &b)?.val)
+    }
+
+    /// Adds two [`UncertainValue`]s without panicking
+    ///
+    /// See [`Value::<f64>::try_add`].
+    pub fn try_add(&self, other: &UncertainValue) -> Result<UncertainValue, V3Error> {
+        let value: Value = self.value.try_add(&other.value)?;
+        let u: f64 = self.u.hypot(other._u_in(&self.value)?);
+        Ok(UncertainValue { value, u })
+    }
+
+    /// Subtracts two [`UncertainValue`]s without panicking
+    ///
+    /// See [`Value::<f64>::try_sub`].
+    pub fn try_sub(&self, other: &UncertainValue) -> Result<UncertainValue, V3Error> {
+        let value: Value = self.value.try_sub(&other.value)?;
+        let u: f64 = self.u.hypot(other._u_in(&self.value)?);
+        Ok(UncertainValue { value, u })
+    }
+
+    /// Multiplies two [`UncertainValue`]s without panicking
+    ///
+    /// See [`Value::<f64>::try_mul`].
+    pub fn try_mul(&self, other: &UncertainValue) -> Result<UncertainValue, V3Error> {
+        let value: Value = self.value.try_mul(&other.value)?;
+        let k: f64 = self._unit_factor(other, Value::<f64>::try_mul)?;
+        let u: f64 = k.abs() * (other.value.val * self.u).hypot(self.value.val * other.u);
+        Ok(UncertainValue { value, u })
+    }
+
+    /// Divides two [`UncertainValue`]s without panicking
+    ///
+    /// See [`Value::<f64>::try_div`].
+    pub fn try_div(&self, other: &UncertainValue) -> Result<UncertainValue, V3Error> {
+        let value: Value = self.value.try_div(&other.value)?;
+        let k: f64 = self._unit_factor(other, Value::<f64>::try_div)?;
+        let b: f64 = other.value.val;
+        let u: f64 = k.abs() * (self.u / b).hypot(self.value.val * other.u / (b * b));
+        Ok(UncertainValue { value, u })
+    }
+
+    /// Raises an [`UncertainValue`] to an integer power
+    ///
+    /// See [`Value::powv`].
+    pub fn powv(&self, p: i32) -> UncertainValue {
+        let value: Value = self.value.powv(p);
+        let u: f64 = (p as f64 * self.value.val.powi(p - 1)).abs() * self.u;
+        UncertainValue { value, u }
+    }
+
+    /// Multiplies an [`UncertainValue`] by itself an integer number of times
+    ///
+    /// Unlike repeated multiplication, the factors are fully correlated, so the relative
+    /// uncertainty is scaled by `p`. This is the same as [`UncertainValue::powv`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::uncertain::UncertainValue;
+    /// let a:UncertainValue = "2 ± 0.1 m".parse().unwrap();
+    ///
+    /// assert!(((a * a).uncertainty().val - 0.2828).abs() < 1e-4);
+    /// assert!((a.powi(2).uncertainty().val - 0.4).abs() < 1e-12);
+    /// ```
+    pub fn powi(&self, p: i32) -> UncertainValue {
+        self.powv(p)
+    }
+
+    /// Takes the square root of an [`UncertainValue`]
+    ///
+    /// See [`Value::checked_sqrt`].
+    pub fn sqrt(&self) -> Result<UncertainValue, V3Error> {
+        let value: Value = self.value.checked_sqrt()?;
+        let u: f64 = self.u / (2.0 * value.val);
+        Ok(UncertainValue { value, u })
+    }
+
+    /// Applies a trigonometric function given the derivative of its numeric at an angle
+    fn _trig(
+        &self,
+        op: fn(&Value) -> Result<Value, V3Error>,
+        derivative: fn(f64) -> f64,
+    ) -> Result<UncertainValue, V3Error> {
+        let value: Value = op(&self.value)?;
+        let x: f64 = self.value._angle_in_radians("trig")?;
+        let u: f64 = self.uncertainty()._angle_in_radians("trig")?;
+        Ok(UncertainValue {
+            value,
+            u: derivative(x).abs() * u,
+        })
+    }
+
+    /// Returns the sine of an angle [`UncertainValue`]
+    pub fn sin(&self) -> Result<UncertainValue, V3Error> {
+        self._trig(Value::checked_sin, f64::cos)
+    }
+
+    /// Returns the cosine of an angle [`UncertainValue`]
+    pub fn cos(&self) -> Result<UncertainValue, V3Error> {
+        self._trig(Value::checked_cos, f64::sin)
+    }
+
+    /// Returns the tangent of an angle [`UncertainValue`]
+    pub fn tan(&self) -> Result<UncertainValue, V3Error> {
+        self._trig(Value::checked_tan, |x| 1.0 / (x.cos() * x.cos()))
+    }
+
+    /// Returns the arcsine, as an angle in radians, of a unitless [`UncertainValue`]
+    pub fn asin(&self) -> Result<UncertainValue, V3Error> {
+        self._trig(Value::checked_asin, |x| 1.0 / (1.0 - x * x).sqrt())
+    }
+
+    /// Returns the arccosine, as an angle in radians, of a unitless [`UncertainValue`]
+    pub fn acos(&self) -> Result<UncertainValue, V3Error> {
+        self._trig(Value::checked_acos, |x| 1.0 / (1.0 - x * x).sqrt())
+    }
+
+    /// Returns the arctangent, as an angle in radians, of a unitless [`UncertainValue`]
+    pub fn atan(&self) -> Result<UncertainValue, V3Error> {
+        self._trig(Value::checked_atan, |x| 1.0 / (1.0 + x * x))
+    }
+
+    /// Converts an [`UncertainValue`] into other units
+    pub fn convert(&mut self, other: &str) -> Result<(), V3Error> {
+        *self = (*self >> other)?;
+        Ok(())
+    }
+}
+
+impl From<Value> for UncertainValue {
+    /// Creates an exact [`UncertainValue`]
+    fn from(value: Value) -> Self {
+        UncertainValue { value, u: 0.0 }
+    }
+}
+
+impl Display for UncertainValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let units: String = self.value.unit_string();
+        if units.is_empty() {
+            write!(f, "{} ± {}", self.value.val, self.u)
+        } else {
+            write!(f, "{} ± {} {}", self.value.val, self.u, units)
+        }
+    }
+}
+
+impl FromStr for UncertainValue {
+    type Err = V3Error;
+    /// Parses an uncertain value such as `9.81 ± 0.02 m/s^2` or `9.81 m/s^2 +/- 2 cm/s^2`
+    fn from_str(s: &str) -> Result<UncertainValue, V3Error> {
+        let Some((value, u)) = s.split_once('±').or_else(|| s.split_once("+/-")) else {
+            return Err(V3Error::ParsingError(format!(
+                "[UncertainValue] Missing '±' in {s}"
+            )));
+        };
+        let u: Value = _parse_bound(u, _bound_units(value))?;
+        let value: Value = _parse_bound(value, &u.unit_string())?;
+        UncertainValue::new(value, u)
+    }
+}
+
+impl Shr<Value> for UncertainValue {
+    type Output = Result<UncertainValue, V3Error>;
+    fn shr(self, other: Value) -> Self::Output {
+        Ok(UncertainValue {
+            value: (self.value >> other)?,
+            u: self._u_in(&other)?,
+        })
+    }
+}
+
+impl Shr<&str> for UncertainValue {
+    type Output = Result<UncertainValue, V3Error>;
+    fn shr(self, other: &str) -> Self::Output {
+        self >> Value::new(1.0, other)?
+    }
+}
+
+impl Add<UncertainValue> for UncertainValue {
+    type Output = UncertainValue;
+    fn add(self, other: UncertainValue) -> Self::Output {
+        match self.try_add(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Add values {self} and {other}"),
+        }
+    }
+}
+
+impl Sub<UncertainValue> for UncertainValue {
+    type Output = UncertainValue;
+    fn sub(self, other: UncertainValue) -> Self::Output {
+        match self.try_sub(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Sub values {self} and {other}"),
+        }
+    }
+}
+
+impl Mul<UncertainValue> for UncertainValue {
+    type Output = UncertainValue;
+    fn mul(self, other: UncertainValue) -> Self::Output {
+        match self.try_mul(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Mul values {self} and {other}"),
+        }
+    }
+}
+
+impl Div<UncertainValue> for UncertainValue {
+    type Output = UncertainValue;
+    fn div(self, other: UncertainValue) -> Self::Output {
+        match self.try_div(&other) {
+            Ok(v) => v,
+            Err(_) => panic!("Cannot Div values {self} and {other}"),
+        }
+    }
+}
+
+impl Mul<Value> for UncertainValue {
+    type Output = UncertainValue;
+    fn mul(self, other: Value) -> Self::Output {
+        self * UncertainValue::from(other)
+    }
+}
+
+impl Div<Value> for UncertainValue {
+    type Output = UncertainValue;
+    fn div(self, other: Value) -> Self::Output {
+        self / UncertainValue::from(other)
+    }
+}
+
+#[cfg(test)]
+mod uncertain_testing {
+    use crate::units::{Metric, UnitAngle, UnitLength, UnitSound, UnitTemperature, UnitTime};
+    use crate::value::{Value, uncertain::UncertainValue};
+
+    fn apr(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn uncertain_construction() {
+        let a = UncertainValue::new(
+            1.0 * UnitLength::Meter(Metric::None),
+            5.0 * UnitLength::Meter(Metric::Milli),
+        )
+        .unwrap();
+        assert_eq!(a.value(), 1.0 * UnitLength::Meter(Metric::None));
+        assert_eq!(a.uncertainty(), 0.005 * UnitLength::Meter(Metric::None));
+        assert!(apr(a.relative_uncertainty(), 0.005));
+        assert!(
+            UncertainValue::new(
+                1.0 * UnitLength::Meter(Metric::None),
+                1.0 * UnitTime::Second(Metric::None)
+            )
+            .is_err()
+        );
+        assert!(
+            UncertainValue::new(
+                1.0 * UnitSound::Bel(Metric::None),
+                0.1 * UnitSound::Bel(Metric::None)
+            )
+            .is_err()
+        );
+
+        let t = UncertainValue::new(
+            20.0 * UnitTemperature::Celsius(Metric::None),
+            0.9 * UnitTemperature::Fahrenheit,
+        )
+        .unwrap();
+        assert!(apr(t.uncertainty().val, 0.5));
+        assert!(t.uncertainty().is_temperature_interval());
+    }
+
+    #[test]
+    fn uncertain_strings() {
+        let a: UncertainValue = "9.81 ± 0.02 m/s^2".parse().unwrap();
+        assert_eq!(a.value(), Value::new(9.81, "m/s^2").unwrap());
+        assert_eq!(a.to_string(), "9.81 ± 0.02 m/s^2");
+        let b: UncertainValue = "981 cm/s^2 +/- 0.02 m/s^2".parse().unwrap();
+        assert_eq!(b.to_string(), "981 ± 2 cm/s^2");
+        let c: UncertainValue = "0.5 ± 0.1".parse().unwrap();
+        assert_eq!(c.to_string(), "0.5 ± 0.1");
+        assert!("9.81 m/s^2".parse::<UncertainValue>().is_err());
+        assert!("9.81 ± x m".parse::<UncertainValue>().is_err());
+    }
+
+    #[test]
+    fn uncertain_conversion() {
+        let a: UncertainValue = "1 ± 0.01 ft".parse().unwrap();
+        let b = (a >> "in").unwrap();
+        assert!(apr(b.value().val, 12.0));
+        assert!(apr(b.uncertainty().val, 0.12));
+        assert!((a >> "s").is_err());
+
+        let mut t: UncertainValue = "20 ± 0.5 °C".parse().unwrap();
+        t.convert("°F").unwrap();
+        assert!(apr(t.value().val, 68.0));
+        assert!(apr(t.uncertainty().val, 0.9));
+    }
+
+    #[test]
+    fn uncertain_arithmetic() {
+        let a: UncertainValue = "10 ± 0.3 m".parse().unwrap();
+        let b: UncertainValue = "500 ± 40 cm".parse().unwrap();
+        let s = a + b;
+        assert!(apr(s.value().val, 15.0));
+        assert!(apr(s.uncertainty().val, 0.5));
+        let d = a - b;
+        assert!(apr(d.value().val, 5.0));
+        assert!(apr(d.uncertainty().val, 0.5));
+
+        let t: UncertainValue = "2 ± 0.1 s".parse().unwrap();
+        let v = a / t;
+        assert_eq!(v.value(), Value::new(5.0, "m/s").unwrap());
+        assert!(apr(v.uncertainty().val, (0.15_f64).hypot(0.25)));
+        let area = a * b;
+        assert_eq!(area.value(), Value::new(50.0, "m^2").unwrap());
+        assert!(apr(area.uncertainty().val, (1.5_f64).hypot(4.0)));
+
+        let e = a * (2.0 * UnitTime::Second(Metric::None));
+        assert!(apr(e.uncertainty().val, 0.6));
+        let f = a / (2.0 * UnitTime::Second(Metric::None));
+        assert!(apr(f.uncertainty().val, 0.15));
+        assert!(a.try_add(&t).is_err());
+        assert!(a.try_sub(&t).is_err());
+
+        let temps = UncertainValue::new(
+            30.0 * UnitTemperature::Celsius(Metric::None),
+            0.3 * UnitTemperature::DeltaCelsius(Metric::None),
+        )
+        .unwrap()
+        .try_sub(&"20 ± 0.4 °C".parse().unwrap())
+        .unwrap();
+        assert!(temps.value().is_temperature_interval());
+        assert!(apr(temps.uncertainty().val, 0.5));
+    }
+
+    #[test]
+    fn uncertain_functions() {
+        let a: UncertainValue = "3 ± 0.1 m".parse().unwrap();
+        let p = a.powv(2);
+        assert_eq!(p.value(), Value::new(9.0, "m^2").unwrap());
+        assert!(apr(p.uncertainty().val, 0.6));
+        assert_eq!(a.powi(2), p);
+        assert!(apr((a * a).uncertainty().val, 0.6 / 2.0_f64.sqrt()));
+        let c = a.powi(3);
+        assert_eq!(c.value(), Value::new(27.0, "m^3").unwrap());
+        assert!(apr(c.uncertainty().val, 2.7));
+        assert!(apr(a.powi(-1).uncertainty().val, 0.1 / 9.0));
+        let r = p.sqrt().unwrap();
+        assert_eq!(r.value(), a.value());
+        assert!(apr(r.uncertainty().val, 0.1));
+
+        let angle = UncertainValue::new(30.0 * UnitAngle::Degree, 1.0 * UnitAngle::Degree).unwrap();
+        let s = angle.sin().unwrap();
+        assert!(s.value().is_empty());
+        assert!(apr(
+            s.uncertainty().val,
+            30.0_f64.to_radians().cos() * 1.0_f64.to_radians()
+        ));
+        let c = angle.cos().unwrap();
+        assert!(apr(c.uncertainty().val, 0.5 * 1.0_f64.to_radians()));
+        let t = angle.tan().unwrap();
+        assert!(apr(t.uncertainty().val, 1.0_f64.to_radians() / 0.75));
+        assert!(a.sin().is_err());
+
+        let x: UncertainValue = "0.5 ± 0.01".parse().unwrap();
+        let y = x.asin().unwrap();
+        assert!(y.value().is_radians());
+        assert!(apr(y.uncertainty().val, 0.01 / 0.75_f64.sqrt()));
+        assert!(apr(
+            x.acos().unwrap().uncertainty().val,
+            0.01 / 0.75_f64.sqrt()
+        ));
+        assert!(apr(x.atan().unwrap().uncertainty().val, 0.01 / 1.25));
+        assert!(a.atan().is_err());
+    }
+}
@@ -17,10 +17,14 @@
 
 use crate::consts::*;
 use crate::errors::V3Error;
+use crate::units::BaseUnit;
 use crate::units::Convert;
 use crate::units::Metric;
 use crate::units::UnitAngle;
+use crate::units::custom::MAX_CUSTOM_DIMENSIONS;
 use crate::value::Value;
+use crate::value::exponent::{Exponent, PowRational};
+use crate::value::numeric::{_representable, _scale, Numeric};
 
 impl Default for Value {
     /// The default constructor for a [`Value`]
@@ -35,7 +39,7 @@ impl Default for Value {
         Value {
             val: 0.0,
             unit_map: 0,
-            exp: [0; 31],
+            exp: [Exponent::ZERO; 31],
             v_ab_dose: None,
             v_angle: None,
             v_capacitance: None,
@@ -67,6 +71,8 @@ impl Default for Value {
             v_magnetic_flux: None,
             v_magnetic_flux_density: None,
             v_solid_angle: None,
+            v_ratio: None,
+            v_custom: [None; MAX_CUSTOM_DIMENSIONS],
         }
     }
 }
@@ -88,7 +94,7 @@ impl Value {
         let mut ret: Value = Value {
             val,
             unit_map: 0,
-            exp: [0; 31],
+            exp: [Exponent::ZERO; 31],
             v_ab_dose: None,
             v_angle: None,
             v_capacitance: None,
@@ -120,18 +126,40 @@ impl Value {
             v_magnetic_flux: None,
             v_magnetic_flux_density: None,
             v_solid_angle: None,
+            v_ratio: None,
+            v_custom: [None; MAX_CUSTOM_DIMENSIONS],
         };
         ret._create_unit(units)?;
 
         Ok(ret)
     }
 
+    /// Creates a [`Value`] from units written as separated factors with signed exponents
+    ///
+    /// Factors may be separated by whitespace, `.` or `*`, and carry exponents written
+    /// directly after the unit, after `^` or after `**`, as in FITS, CF and UDUNITS strings.
+    /// The usual `/` and parentheses are also accepted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new_scientific(1.2, "kg m-3").unwrap();
+    /// assert_eq!(a, Value::new(1.2, "kg/m^3").unwrap());
+    ///
+    /// let c:Value = Value::new_scientific(4186.0, "J.kg-1.K-1").unwrap();
+    /// assert_eq!(c, Value::new(4186.0, "J/(kg*K)").unwrap());
+    /// assert_eq!(Value::new_scientific(2.0, "m**2").unwrap().to_string(), "2 m^2");
+    /// ```
+    pub fn new_scientific(val: f64, units: &str) -> Result<Value, V3Error> {
+        Value::new(val, &Value::_scientific_units(units))
+    }
+
     /// Creates a [`Value`] specifically in radians
     fn _radians(val: f64) -> Value {
         let mut ret: Value = Value {
             val,
             unit_map: ANGLE_MAP,
-            exp: [0; 31],
+            exp: [Exponent::ZERO; 31],
             v_ab_dose: None,
             v_angle: Some(UnitAngle::Radian(Metric::None)),
             v_capacitance: None,
@@ -163,11 +191,21 @@ impl Value {
             v_magnetic_flux: None,
             v_magnetic_flux_density: None,
             v_solid_angle: None,
+            v_ratio: None,
+            v_custom: [None; MAX_CUSTOM_DIMENSIONS],
         };
-        ret.exp[ANGLE_INDEX] = 1;
+        ret.exp[ANGLE_INDEX] = Exponent::from(1);
         ret
     }
 
+    /// Creates a [`Value`] with no units
+    pub(in crate::value) fn _unitless(val: f64) -> Value {
+        Value {
+            val,
+            ..Default::default()
+        }
+    }
+
     /// Inverses the [`Value`]
     ///
     /// # Example
@@ -183,10 +221,15 @@ impl Value {
         for i in 0..self.exp.len() {
             self.exp[i] *= -1;
         }
+        for (_, e) in self.v_custom.iter_mut().flatten() {
+            *e *= -1;
+        }
     }
 
     /// Converts an angle to radians
     ///
+    /// `to_radians` will panic if the [`Value`] is not an angle.
+    ///
     /// # Example
     /// ```rust
     /// use bxvl::value::Value;
@@ -195,17 +238,41 @@ impl Value {
     /// a.to_radians();
     /// ```
     pub fn to_radians(&mut self) {
-        if self.unit_map != ANGLE_MAP && self.exp[ANGLE_INDEX] != 1 {
+        if self.try_to_radians().is_err() {
             panic!("[to_radians] Cannot convert non angle to radians");
         }
+    }
+
+    /// Converts an angle to radians without panicking
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{UnitAngle, UnitLength};
+    /// let mut a:Value = 45.0 * UnitAngle::Degree;
+    /// assert!(a.try_to_radians().is_ok());
+    ///
+    /// let mut l:Value = 45.0 * UnitLength::Foot;
+    /// assert!(l.try_to_radians().is_err());
+    /// ```
+    pub fn try_to_radians(&mut self) -> Result<(), V3Error> {
+        if !self.is_angle() {
+            return Err(V3Error::IncompatibleUnits(format!(
+                "[try_to_radians] {self} is not an angle"
+            )));
+        }
         self.val *= self
             .v_angle
             .unwrap()
             .convert(&UnitAngle::Radian(Metric::None));
+        self.v_angle = Some(UnitAngle::Radian(Metric::None));
+        Ok(())
     }
 
     /// Converts an angle to degrees
     ///
+    /// `to_degrees` will panic if the [`Value`] is not an angle.
+    ///
     /// # Example
     /// ```rust
     /// use bxvl::value::Value;
@@ -214,10 +281,32 @@ impl Value {
     /// a.to_degrees();
     /// ```
     pub fn to_degrees(&mut self) {
-        if self.unit_map != ANGLE_MAP && self.exp[ANGLE_INDEX] != 1 {
+        if self.try_to_degrees().is_err() {
             panic!("[to_degrees] Cannot convert non angle to degrees");
         }
+    }
+
+    /// Converts an angle to degrees without panicking
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{UnitAngle, UnitTime, Metric};
+    /// let mut a:Value = std::f64::consts::PI * UnitAngle::Radian(Metric::None);
+    /// assert!(a.try_to_degrees().is_ok());
+    ///
+    /// let mut t:Value = 3.0 * UnitTime::Second(Metric::None);
+    /// assert!(t.try_to_degrees().is_err());
+    /// ```
+    pub fn try_to_degrees(&mut self) -> Result<(), V3Error> {
+        if !self.is_angle() {
+            return Err(V3Error::IncompatibleUnits(format!(
+                "[try_to_degrees] {self} is not an angle"
+            )));
+        }
         self.val *= self.v_angle.unwrap().convert(&UnitAngle::Degree);
+        self.v_angle = Some(UnitAngle::Degree);
+        Ok(())
     }
 
     /// Returns if the [`Value`] numeric is NAN
@@ -257,7 +346,8 @@ impl Value {
 
     /// Takes the square root of the Value
     ///
-    /// Note: That if the unit exponents are not evenly divisible by 2, the function will panic.
+    /// Unit exponents that are not evenly divisible by 2 become fractional.
+    /// See [`Value::checked_sqrt`] for a non-panicking version.
     ///
     /// # Example
     /// ```rust
@@ -268,15 +358,30 @@ impl Value {
     /// ```
     /// `x` will be equal to `4.0 ft`
     pub fn sqrt(&self) -> Value {
-        let mut n: Value = *self;
-        for i in 0..31_usize {
-            if n.exp[i] % 2 != 0 {
-                panic!("[sqrt] Cannot square root Value: {self}");
-            }
-            n.exp[i] /= 2;
+        match self.checked_sqrt() {
+            Ok(n) => n,
+            Err(_) => panic!("[sqrt] Cannot square root Value: {self}"),
         }
-        n.val = n.val.sqrt();
-        n
+    }
+
+    /// Takes the square root of the Value without panicking
+    ///
+    /// Returns an error if a unit exponent cannot be represented.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::UnitLength;
+    /// let v:Value = 16.0 * UnitLength::Foot * UnitLength::Foot;
+    /// assert_eq!(v.checked_sqrt().unwrap(), 4.0 * UnitLength::Foot);
+    ///
+    /// let w:Value = 16.0 * UnitLength::Foot;
+    /// assert_eq!(w.checked_sqrt().unwrap().to_string(), "4 ft^(1/2)");
+    /// ```
+    pub fn checked_sqrt(&self) -> Result<Value, V3Error> {
+        let mut n: Value = self.checked_powr(1, 2)?;
+        n.val = self._plain_ratio()?.val.sqrt();
+        Ok(n)
     }
 
     /// Returns a new value to some arbitrary power.
@@ -292,17 +397,105 @@ impl Value {
     /// assert!(String::from("16 ft^2") == format!("{}", x));
     /// ```
     pub fn powv(&self, p: i32) -> Value {
-        let mut n: Value = *self;
+        let mut n: Value = self._plain_ratio().unwrap();
         for i in 0..31_usize {
             n.exp[i] *= p;
         }
+        if n._pow_custom(Exponent::from(p)).is_err() {
+            panic!("[powv] Cannot raise Value {self} to the power of {p}");
+        }
         n.val = n.val.powf(p as f64);
         n
     }
 
+    /// Returns a new value to a rational power of `num/den`
+    ///
+    /// See [`Value::checked_powr`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::UnitLength;
+    /// let v:Value = 4.0 * UnitLength::Foot;
+    /// let x:Value = v.powr(3, 2);
+    /// assert_eq!(x.to_string(), "8 ft^(3/2)");
+    /// ```
+    pub fn powr(&self, num: i32, den: i32) -> Value {
+        match self.checked_powr(num, den) {
+            Ok(n) => n,
+            Err(_) => panic!("[powr] Cannot raise Value {self} to the power of {num}/{den}"),
+        }
+    }
+
+    /// Returns a new value to a rational power of `num/den` without panicking
+    ///
+    /// Returns an error if `den` is zero or a unit exponent cannot be represented.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitFrequency};
+    /// let v:Value = 4.0 * UnitFrequency::Hertz(Metric::None);
+    /// assert_eq!(v.checked_powr(-1, 2).unwrap().to_string(), "0.5 1/Hz^(1/2)");
+    /// assert!(v.checked_powr(1, 0).is_err());
+    /// ```
+    pub fn checked_powr(&self, num: i32, den: i32) -> Result<Value, V3Error> {
+        let p: Exponent = Exponent::new(num, den)?;
+        let mut n: Value = self._plain_ratio()?;
+        for i in 0..31_usize {
+            n.exp[i] = n.exp[i].checked_mul(p)?;
+        }
+        n._pow_custom(p)?;
+        n.val = n.val.powr(p);
+        Ok(n)
+    }
+
+    /// Returns a new value to a floating point power
+    ///
+    /// The power is converted to the closest rational [`Exponent`] with a small denominator.
+    /// See [`Value::checked_powf`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitFrequency};
+    /// let v:Value = 16.0 * UnitFrequency::Hertz(Metric::None);
+    /// assert_eq!(v.powf(0.5).to_string(), "4 Hz^(1/2)");
+    /// ```
+    pub fn powf(&self, p: f64) -> Value {
+        match self.checked_powf(p) {
+            Ok(n) => n,
+            Err(_) => panic!("[powf] Cannot raise Value {self} to the power of {p}"),
+        }
+    }
+
+    /// Returns a new value to a floating point power without panicking
+    ///
+    /// Unitless values may be raised to any power, otherwise an error is returned
+    /// if `p` cannot be represented as a rational [`Exponent`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::UnitLength;
+    /// let v:Value = 2.0 * UnitLength::Foot;
+    /// assert!(v.checked_powf(0.25).is_ok());
+    /// assert!(v.checked_powf(std::f64::consts::PI).is_err());
+    /// ```
+    pub fn checked_powf(&self, p: f64) -> Result<Value, V3Error> {
+        if self.is_empty() {
+            return Ok(Value::_unitless(self._plain_ratio()?.val.powf(p)));
+        }
+        let e: Exponent = Exponent::from_f64(p)?;
+        let mut n: Value = self.checked_powr(e.numer(), e.denom())?;
+        n.val = self._plain_ratio()?.val.powf(p);
+        Ok(n)
+    }
+
     /// Takes the cube root of the Value
     ///
-    /// Note: That if the unit exponents are not evenly divisible by 3, the function will panic.
+    /// Unit exponents that are not evenly divisible by 3 become fractional.
+    /// See [`Value::checked_cbrt`] for a non-panicking version.
     ///
     /// # Example
     /// ```rust
@@ -313,15 +506,30 @@ impl Value {
     /// ```
     /// `x` will be equal to `3.0 ft`
     pub fn cbrt(&self) -> Value {
-        let mut n: Value = *self;
-        for i in 0..31_usize {
-            if n.exp[i] % 3 != 0 {
-                panic!("[cbrt] Cannot cube root Value: {self}");
-            }
-            n.exp[i] /= 3;
+        match self.checked_cbrt() {
+            Ok(n) => n,
+            Err(_) => panic!("[cbrt] Cannot cube root Value: {self}"),
         }
-        n.val = n.val.cbrt();
-        n
+    }
+
+    /// Takes the cube root of the Value without panicking
+    ///
+    /// Returns an error if a unit exponent cannot be represented.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::UnitLength;
+    /// let v:Value = 27.0 * UnitLength::Foot * UnitLength::Foot * UnitLength::Foot;
+    /// assert_eq!(v.checked_cbrt().unwrap(), 3.0 * UnitLength::Foot);
+    ///
+    /// let w:Value = 27.0 * UnitLength::Foot * UnitLength::Foot;
+    /// assert_eq!(w.checked_cbrt().unwrap().to_string(), "3 ft^(2/3)");
+    /// ```
+    pub fn checked_cbrt(&self) -> Result<Value, V3Error> {
+        let mut n: Value = self.checked_powr(1, 3)?;
+        n.val = self._plain_ratio()?.val.cbrt();
+        Ok(n)
     }
 
     /// Returns the absolute value of a [`Value`]
@@ -332,44 +540,364 @@ impl Value {
         *self
     }
 
-    /// Returns the sine of a [`Value`] in radians
+    /// Returns the sine of a [`Value`]
+    ///
+    /// The [`Value`] is converted to radians first.
+    ///
+    /// `sin` will panic if the [`Value`] is not an angle or unitless.
+    /// See [`Value::checked_sin`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::UnitAngle;
+    /// let a:Value = 30.0 * UnitAngle::Degree;
+    /// let s:Value = a.sin();
+    /// assert!(s.is_empty());
+    /// assert!((s.val - 0.5).abs() < 1e-12);
+    /// ```
     pub fn sin(&self) -> Value {
-        Value::_radians(self.val.sin())
+        match self.checked_sin() {
+            Ok(n) => n,
+            Err(_) => panic!("[sin] Cannot take the sine of non angle: {self}"),
+        }
+    }
+
+    /// Returns the sine of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not an angle or unitless.
+    pub fn checked_sin(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._angle_in_radians("checked_sin")?;
+        Ok(Value::_unitless(r.sin()))
     }
 
-    /// Returns the cosine of a [`Value`] in radians
+    /// Returns the cosine of a [`Value`]
+    ///
+    /// The [`Value`] is converted to radians first.
+    ///
+    /// `cos` will panic if the [`Value`] is not an angle or unitless.
+    /// See [`Value::checked_cos`] for a non-panicking version.
     pub fn cos(&self) -> Value {
-        Value::_radians(self.val.cos())
+        match self.checked_cos() {
+            Ok(n) => n,
+            Err(_) => panic!("[cos] Cannot take the cosine of non angle: {self}"),
+        }
     }
 
-    /// Returns the tangent of a [`Value`] in radians
+    /// Returns the cosine of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not an angle or unitless.
+    pub fn checked_cos(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._angle_in_radians("checked_cos")?;
+        Ok(Value::_unitless(r.cos()))
+    }
+
+    /// Returns the tangent of a [`Value`]
+    ///
+    /// The [`Value`] is converted to radians first.
+    ///
+    /// `tan` will panic if the [`Value`] is not an angle or unitless.
+    /// See [`Value::checked_tan`] for a non-panicking version.
     pub fn tan(&self) -> Value {
-        Value::_radians(self.val.tan())
+        match self.checked_tan() {
+            Ok(n) => n,
+            Err(_) => panic!("[tan] Cannot take the tangent of non angle: {self}"),
+        }
     }
 
-    /// Returns the tangent-h of a [`Value`] in radians
-    pub fn tanh(&self) -> Value {
-        Value::_radians(self.val.tanh())
+    /// Returns the tangent of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not an angle or unitless.
+    pub fn checked_tan(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._angle_in_radians("checked_tan")?;
+        Ok(Value::_unitless(r.tan()))
     }
 
-    /// Returns the arcsine of a [`Value`] in radians
+    /// Returns the arcsine, as an angle in radians, of a [`Value`]
+    ///
+    /// `asin` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_asin`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::UnitNone;
+    /// let s:Value = 0.5 * UnitNone::None;
+    /// let a:Value = s.asin();
+    /// assert!(a.is_radians());
+    /// ```
     pub fn asin(&self) -> Value {
-        Value::_radians(self.val.asin())
+        match self.checked_asin() {
+            Ok(n) => n,
+            Err(_) => panic!("[asin] Cannot take the arcsine of Value with units: {self}"),
+        }
+    }
+
+    /// Returns the arcsine, as an angle in radians, of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_asin(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_asin")?;
+        Ok(Value::_radians(r.asin()))
     }
 
-    /// Returns the arccosine of a [`Value`] in radians
+    /// Returns the arccosine, as an angle in radians, of a [`Value`]
+    ///
+    /// `acos` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_acos`] for a non-panicking version.
     pub fn acos(&self) -> Value {
-        Value::_radians(self.val.acos())
+        match self.checked_acos() {
+            Ok(n) => n,
+            Err(_) => panic!("[acos] Cannot take the arccosine of Value with units: {self}"),
+        }
     }
 
-    /// Returns the arctangent of a [`Value`] in radians
+    /// Returns the arccosine, as an angle in radians, of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_acos(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_acos")?;
+        Ok(Value::_radians(r.acos()))
+    }
+
+    /// Returns the arctangent, as an angle in radians, of a [`Value`]
+    ///
+    /// `atan` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_atan`] for a non-panicking version.
     pub fn atan(&self) -> Value {
-        Value::_radians(self.val.atan())
+        match self.checked_atan() {
+            Ok(n) => n,
+            Err(_) => panic!("[atan] Cannot take the arctangent of Value with units: {self}"),
+        }
+    }
+
+    /// Returns the arctangent, as an angle in radians, of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_atan(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_atan")?;
+        Ok(Value::_radians(r.atan()))
+    }
+
+    /// Returns the hyperbolic sine of a [`Value`]
+    ///
+    /// `sinh` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_sinh`] for a non-panicking version.
+    pub fn sinh(&self) -> Value {
+        match self.checked_sinh() {
+            Ok(n) => n,
+            Err(_) => panic!("[sinh] Cannot take the hyperbolic sine of Value with units: {self}"),
+        }
+    }
+
+    /// Returns the hyperbolic sine of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_sinh(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_sinh")?;
+        Ok(Value::_unitless(r.sinh()))
+    }
+
+    /// Returns the hyperbolic cosine of a [`Value`]
+    ///
+    /// `cosh` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_cosh`] for a non-panicking version.
+    pub fn cosh(&self) -> Value {
+        match self.checked_cosh() {
+            Ok(n) => n,
+            Err(_) => {
+                panic!("[cosh] Cannot take the hyperbolic cosine of Value with units: {self}")
+            }
+        }
     }
 
-    /// Returns the full unit circle arctangent of a [`Value`] in radians
+    /// Returns the hyperbolic cosine of a [`Value`] without panicking
     ///
-    /// atan2 will panic if the the given [`Value`] is not an angle.
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_cosh(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_cosh")?;
+        Ok(Value::_unitless(r.cosh()))
+    }
+
+    /// Returns the hyperbolic tangent of a [`Value`]
+    ///
+    /// `tanh` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_tanh`] for a non-panicking version.
+    pub fn tanh(&self) -> Value {
+        match self.checked_tanh() {
+            Ok(n) => n,
+            Err(_) => {
+                panic!("[tanh] Cannot take the hyperbolic tangent of Value with units: {self}")
+            }
+        }
+    }
+
+    /// Returns the hyperbolic tangent of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_tanh(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_tanh")?;
+        Ok(Value::_unitless(r.tanh()))
+    }
+
+    /// Returns the inverse hyperbolic sine of a [`Value`]
+    ///
+    /// `asinh` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_asinh`] for a non-panicking version.
+    pub fn asinh(&self) -> Value {
+        match self.checked_asinh() {
+            Ok(n) => n,
+            Err(_) => panic!(
+                "[asinh] Cannot take the inverse hyperbolic sine of Value with units: {self}"
+            ),
+        }
+    }
+
+    /// Returns the inverse hyperbolic sine of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_asinh(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_asinh")?;
+        Ok(Value::_unitless(r.asinh()))
+    }
+
+    /// Returns the inverse hyperbolic cosine of a [`Value`]
+    ///
+    /// `acosh` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_acosh`] for a non-panicking version.
+    pub fn acosh(&self) -> Value {
+        match self.checked_acosh() {
+            Ok(n) => n,
+            Err(_) => panic!(
+                "[acosh] Cannot take the inverse hyperbolic cosine of Value with units: {self}"
+            ),
+        }
+    }
+
+    /// Returns the inverse hyperbolic cosine of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_acosh(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_acosh")?;
+        Ok(Value::_unitless(r.acosh()))
+    }
+
+    /// Returns the inverse hyperbolic tangent of a [`Value`]
+    ///
+    /// `atanh` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_atanh`] for a non-panicking version.
+    pub fn atanh(&self) -> Value {
+        match self.checked_atanh() {
+            Ok(n) => n,
+            Err(_) => panic!(
+                "[atanh] Cannot take the inverse hyperbolic tangent of Value with units: {self}"
+            ),
+        }
+    }
+
+    /// Returns the inverse hyperbolic tangent of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_atanh(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_atanh")?;
+        Ok(Value::_unitless(r.atanh()))
+    }
+
+    /// Returns the exponential, `e^x`, of a [`Value`]
+    ///
+    /// `exp` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_exp`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{Metric, UnitLength, UnitNone};
+    /// let x:Value = 1.0 * UnitNone::None;
+    /// assert_eq!(x.exp(), std::f64::consts::E);
+    ///
+    /// let l:Value = 1.0 * UnitLength::Meter(Metric::None);
+    /// assert!(l.checked_exp().is_err());
+    /// ```
+    pub fn exp(&self) -> Value {
+        match self.checked_exp() {
+            Ok(n) => n,
+            Err(_) => panic!("[exp] Cannot take the exponential of Value with units: {self}"),
+        }
+    }
+
+    /// Returns the exponential, `e^x`, of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_exp(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_exp")?;
+        Ok(Value::_unitless(r.exp()))
+    }
+
+    /// Returns the natural logarithm of a [`Value`]
+    ///
+    /// `ln` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_ln`] for a non-panicking version.
+    pub fn ln(&self) -> Value {
+        match self.checked_ln() {
+            Ok(n) => n,
+            Err(_) => panic!("[ln] Cannot take the logarithm of Value with units: {self}"),
+        }
+    }
+
+    /// Returns the natural logarithm of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_ln(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_ln")?;
+        Ok(Value::_unitless(r.ln()))
+    }
+
+    /// Returns the base 10 logarithm of a [`Value`]
+    ///
+    /// `log10` will panic if the [`Value`] is not unitless.
+    /// See [`Value::checked_log10`] for a non-panicking version.
+    pub fn log10(&self) -> Value {
+        match self.checked_log10() {
+            Ok(n) => n,
+            Err(_) => panic!("[log10] Cannot take the logarithm of Value with units: {self}"),
+        }
+    }
+
+    /// Returns the base 10 logarithm of a [`Value`] without panicking
+    ///
+    /// Returns an error if the [`Value`] is not unitless.
+    pub fn checked_log10(&self) -> Result<Value, V3Error> {
+        let r: f64 = self._unitless_numeric("checked_log10")?;
+        Ok(Value::_unitless(r.log10()))
+    }
+
+    /// Returns the full unit circle arctangent of `self / other` as an angle in radians
+    ///
+    /// Both [`Value`]s must have compatible units, `other` is converted into the units of `self`.
+    ///
+    /// `atan2` will panic if the units of the [`Value`]s are not compatible.
+    /// See [`Value::checked_atan2`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use bxvl::units::{UnitLength, Metric};
+    /// let y:Value = 1.0 * UnitLength::Foot;
+    /// let x:Value = 12.0 * UnitLength::Inch;
+    /// let a:Value = y.atan2(&x);
+    /// assert!(a.is_radians());
+    /// ```
+    /// `a` will be approximately equal to `0.7854 radians`
+    pub fn atan2(&self, other: &Value) -> Value {
+        match self.checked_atan2(other) {
+            Ok(n) => n,
+            Err(_) => panic!("[atan2] Cannot take the arctangent of {self} and {other}"),
+        }
+    }
+
+    /// Returns the full unit circle arctangent of `self / other` in radians without panicking
+    ///
+    /// Returns an error if the units of the [`Value`]s are not compatible.
     ///
     /// # Example
     /// ```rust
@@ -377,19 +905,103 @@ impl Value {
     /// use bxvl::units::{UnitTime, UnitAngle, Metric};
     /// let a:Value = 10.0 * UnitTime::Second(Metric::None);
     /// let b:Value = 0.3 * UnitAngle::Radian(Metric::None);
-    /// let x:Value = a.atan2(&b);
+    /// assert!(a.checked_atan2(&a).is_ok());
+    /// assert!(a.checked_atan2(&b).is_err());
     /// ```
-    /// `x` will be approximately equal to `1.5408 radians`
-    pub fn atan2(&self, other: &Value) -> Value {
-        if !other.is_angle() {
-            panic!("[atan2] atan2 requires an Value angle");
+    pub fn checked_atan2(&self, other: &Value) -> Result<Value, V3Error> {
+        let x: f64 = self._rhs_in_self_units(other)?;
+        Ok(Value::_radians(self.val.atan2(x)))
+    }
+
+    /// Returns the numeric of an angle or unitless [`Value`] in radians
+    pub(in crate::value) fn _angle_in_radians(&self, op: &str) -> Result<f64, V3Error> {
+        if self.is_empty() {
+            return Ok(self._plain_ratio()?.val);
+        } else if self.is_angle() {
+            return Ok(self.val
+                * self
+                    .v_angle
+                    .unwrap()
+                    .convert(&UnitAngle::Radian(Metric::None)));
+        }
+        Err(V3Error::IncompatibleUnits(format!(
+            "[{op}] {self} is not an angle"
+        )))
+    }
+
+    /// Returns the numeric of a unitless [`Value`]
+    fn _unitless_numeric(&self, op: &str) -> Result<f64, V3Error> {
+        if self.is_empty() {
+            return Ok(self._plain_ratio()?.val);
+        }
+        Err(V3Error::IncompatibleUnits(format!(
+            "[{op}] {self} is not unitless"
+        )))
+    }
+
+    /// Returns `true` if two [`Value`]s are equal once reduced to the same units
+    ///
+    /// Unlike `==`, the [`Value`]s only need to have the same dimensions,
+    /// see [`Value::cmp_dimensional`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// let a:Value = Value::new(1.0, "N").unwrap();
+    /// let b:Value = Value::new(1.0, "kg*m/s^2").unwrap();
+    /// assert!(a != b);
+    /// assert!(a.eq_dimensional(&b));
+    /// ```
+    pub fn eq_dimensional(&self, other: &Value) -> bool {
+        self.cmp_dimensional(other) == Some(std::cmp::Ordering::Equal)
+    }
+
+    /// Compares two [`Value`]s with the same dimensions
+    ///
+    /// `other` is converted into the units of `self` when both reduce to the same SI base units.
+    /// Returns `None` if the dimensions of the [`Value`]s differ.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bxvl::value::Value;
+    /// use std::cmp::Ordering;
+    /// let a:Value = Value::new(1.0, "kW*h").unwrap();
+    /// let b:Value = Value::new(1.0, "MJ").unwrap();
+    /// assert_eq!(a.partial_cmp(&b), None);
+    /// assert_eq!(a.cmp_dimensional(&b), Some(Ordering::Greater));
+    /// assert_eq!(a.cmp_dimensional(&Value::new(1.0, "kW").unwrap()), None);
+    /// ```
+    pub fn cmp_dimensional(&self, other: &Value) -> Option<std::cmp::Ordering> {
+        let mut n: Value = *other;
+        n._convert_dimensional(self).ok()?;
+        self.partial_cmp(&n)
+    }
+}
+
+impl<T: Numeric> Value<T> {
+    /// Returns the factor of the ratio units, such as `%` or `ppm`, of a [`Value`]
+    pub(in crate::value) fn _ratio_factor(&self) -> f64 {
+        self.v_ratio.map_or(1.0, |r| r.base())
+    }
+
+    /// Returns a [`Value`] with its ratio units removed
+    pub(in crate::value) fn _plain_ratio(&self) -> Result<Value<T>, V3Error> {
+        let mut n: Value<T> = *self;
+        n.val = Value::_rescale_ratio(self.val, self._ratio_factor(), 1.0)?;
+        n.v_ratio = None;
+        Ok(n)
+    }
+
+    /// Rescales a numeric between two ratio factors
+    ///
+    /// Ratio factors are powers of ten, so the numeric is scaled by an exact power of ten.
+    pub(in crate::value) fn _rescale_ratio(val: T, from: f64, to: f64) -> Result<T, V3Error> {
+        let shift: i32 = (from.log10() - to.log10()).round() as i32;
+        if shift >= 0 {
+            _scale(val, 10_f64.powi(shift))
+        } else {
+            _representable(val.scale_ratio(1.0, 10_f64.powi(-shift)))
         }
-        let new_v: f64 = other.val
-            * other
-                .v_angle
-                .unwrap()
-                .convert(&UnitAngle::Radian(Metric::None));
-        Value::_radians(self.val.atan2(new_v))
     }
 
     /// Returns `true` if a [`Value`] has no units
@@ -446,6 +1058,13 @@ impl Value {
         false
     }
 
+    /// Returns `true` if a [`Value`] is a temperature interval
+    ///
+    /// `Δtemperature`
+    pub fn is_temperature_interval(&self) -> bool {
+        self.is_temperature() && self.v_temperature.unwrap().is_interval()
+    }
+
     /// Returns `true` if a [`Value`] is a density
     ///
     /// `mass / volume`
@@ -1162,7 +1781,7 @@ impl Value {
     }
 
     /// Returns `true` if two [`Value`]s have comparable, not equal, unit types
-    pub(in crate::value) fn __equivalent(&self, other: &Value) -> bool {
+    pub(in crate::value) fn __equivalent<U: Numeric>(&self, other: &Value<U>) -> bool {
         if self.unit_map == VOLUME_MAP && other.unit_map == LENGTH_MAP {
             if self.exp[VOLUME_INDEX] == 1 && other.exp[LENGTH_INDEX] == 3 {
                 return true;
@@ -1197,12 +1816,15 @@ impl Value {
             }
         }
 
-        true
+        self._custom_exps() == other._custom_exps()
     }
 
     /// Checks if the [`Value`] unit types are the same
-    pub(in crate::value) fn __equal(&self, other: &Value) -> bool {
-        if self.unit_map != other.unit_map {
+    pub(in crate::value) fn __equal<U: Numeric>(&self, other: &Value<U>) -> bool {
+        if self.unit_map != other.unit_map
+            || self.v_ratio != other.v_ratio
+            || self.v_custom != other.v_custom
+        {
             return false;
         }
         for i in 0..31_usize {
@@ -1430,16 +2052,13 @@ impl Value {
                 SOLID_ANGLE_MAP => self.v_solid_angle.unwrap().to_string(),
                 _ => String::from(""),
             };
-            if self.exp[i] < -1 {
-                denoms.push(format!("{}^{}", u, -self.exp[i]))
-            } else if self.exp[i] > 1 {
-                nums.push(format!("{}^{}", u, self.exp[i]))
-            } else if self.exp[i] == 1 {
-                nums.push(u);
-            } else if self.exp[i] == -1 {
-                denoms.push(u);
+            if self.exp[i] < 0 {
+                denoms.push(Value::_unit_power(u, -self.exp[i]));
+            } else if self.exp[i] > 0 {
+                nums.push(Value::_unit_power(u, self.exp[i]));
             }
         }
+        self._custom_unit_strings(&mut nums, &mut denoms);
 
         let final_num = nums.join("*");
         let final_denom = denoms.join("*");
@@ -1452,6 +2071,8 @@ impl Value {
             final_str = final_num;
         } else if final_num.is_empty() && !final_denom.is_empty() {
             final_str = format!("1/{final_denom}");
+        } else if let Some(r) = self.v_ratio {
+            final_str = r.to_string();
         } else {
             final_str = String::from("");
         }
@@ -1569,10 +2190,60 @@ mod value_impl_testing {
         assert_eq!(k.to_string(), "2 in");
     }
 
+    #[test]
+    fn test_sqrt_fractional() {
+        let t = (4.0 * UnitLength::Inch).sqrt();
+        assert_eq!(t.val, 2.0);
+        assert_eq!(t.to_string(), "2 in^(1/2)");
+        assert_eq!(t * t, 4.0 * UnitLength::Inch);
+    }
+
+    #[test]
+    fn test_checked_roots() {
+        let t = 4.0 * UnitLength::Inch * UnitLength::Inch;
+        assert_eq!(t.checked_sqrt().unwrap().to_string(), "2 in");
+        assert!(t.checked_cbrt().unwrap().to_string().ends_with(" in^(2/3)"));
+
+        let t = 8.0 * UnitLength::Inch * UnitLength::Inch * UnitLength::Inch;
+        assert_eq!(t.checked_cbrt().unwrap().to_string(), "2 in");
+        assert!(t.checked_sqrt().unwrap().to_string().ends_with(" in^(3/2)"));
+    }
+
+    #[test]
+    fn test_rational_powers() {
+        let t = 16.0 * UnitFrequency::Hertz(Metric::None);
+        assert_eq!(t.powr(1, 2), t.powf(0.5));
+        assert_eq!(t.powr(-1, 2).to_string(), "0.25 1/Hz^(1/2)");
+        assert_eq!(t.powr(2, 4).powv(2), t);
+        assert!(t.checked_powr(1, 0).is_err());
+        assert!(t.checked_powf(f64::NAN).is_err());
+        assert_eq!((8.0 * UnitNone::None).powf(1.0 / 3.0).val, 2.0);
+
+        // noise density in V/sqrt(Hz)
+        let n = Value::new(3.0, "V/Hz^(1/2)").unwrap();
+        let v = n * t.sqrt();
+        assert_eq!(v, Value::new(12.0, "V").unwrap());
+    }
+
     #[test]
     #[should_panic]
-    fn test_sqrt_fail() {
-        let _ = (4.0 * UnitLength::Inch).sqrt();
+    fn test_powr_fail() {
+        let _ = (8.0 * UnitLength::Inch).powr(1, 0);
+    }
+
+    #[test]
+    fn checked_angles() {
+        let mut t = 180.0 * UnitAngle::Degree;
+        t.try_to_radians().unwrap();
+        assert!(t.is_radians());
+        assert_eq!(t, f64::consts::PI);
+        t.try_to_degrees().unwrap();
+        assert_eq!(t.to_string(), "180 °");
+
+        let mut t = 4.0 * UnitLength::Inch;
+        assert!(t.try_to_radians().is_err());
+        assert!(t.try_to_degrees().is_err());
+        assert!(t.checked_atan2(&(1.0 * UnitAngle::Degree)).is_err());
     }
 
     #[test]
@@ -1591,53 +2262,112 @@ mod value_impl_testing {
     }
 
     #[test]
-    #[should_panic]
-    fn test_cbrt_fail() {
-        let _ = (8.0 * UnitLength::Inch * UnitLength::Inch).cbrt();
+    fn test_cbrt_fractional() {
+        let t = (8.0 * UnitLength::Inch * UnitLength::Inch).cbrt();
+        assert_eq!(t.val, 2.0);
+        assert_eq!(t.to_string(), "2 in^(2/3)");
     }
 
     #[test]
     fn get_sin() {
-        let t = 4.0 * UnitLength::Meter(Metric::None);
+        let t = 4.0 * UnitAngle::Radian(Metric::None);
         let a = t.sin();
-        println!("{}", a.val);
+        assert!(a.is_empty());
         assert!(a.val >= -0.756803);
         assert!(a.val <= -0.756801);
+
+        let t = 30.0 * UnitAngle::Degree;
+        assert!((t.sin().val - 0.5).abs() < 1e-12);
+        assert!((4.0 * UnitNone::None).sin() == a);
     }
 
     #[test]
     fn get_cos() {
-        let t = 4.0 * UnitLength::Meter(Metric::None);
+        let t = 4.0 * UnitAngle::Radian(Metric::None);
         let a = t.cos();
-        println!("{}", a.val);
+        assert!(a.is_empty());
         assert!(a.val >= -0.653644);
         assert!(a.val <= -0.653643);
+
+        let t = 60.0 * UnitAngle::Degree;
+        assert!((t.cos().val - 0.5).abs() < 1e-12);
     }
 
     #[test]
     fn get_tan() {
-        let t = 4.0 * UnitLength::Meter(Metric::None);
+        let t = 4.0 * UnitAngle::Radian(Metric::None);
         let a = t.tan();
-        println!("{}", a.val);
+        assert!(a.is_empty());
         assert!(a.val >= 1.157821);
         assert!(a.val <= 1.157822);
+
+        let t = 45.0 * UnitAngle::Degree;
+        assert!((t.tan().val - 1.0).abs() < 1e-12);
     }
 
     #[test]
-    fn get_tanh() {
+    #[should_panic]
+    fn get_sin_fail() {
+        let _ = (4.0 * UnitLength::Meter(Metric::None)).sin();
+    }
+
+    #[test]
+    fn checked_trig_fail() {
         let t = 4.0 * UnitLength::Meter(Metric::None);
+        assert!(t.checked_sin().is_err());
+        assert!(t.checked_cos().is_err());
+        assert!(t.checked_tan().is_err());
+        assert!(t.checked_asin().is_err());
+        assert!(t.checked_acos().is_err());
+        assert!(t.checked_atan().is_err());
+        assert!(t.checked_sinh().is_err());
+        assert!(t.checked_cosh().is_err());
+        assert!(t.checked_tanh().is_err());
+        assert!(t.checked_asinh().is_err());
+        assert!(t.checked_acosh().is_err());
+        assert!(t.checked_atanh().is_err());
+        assert!(t.checked_exp().is_err());
+        assert!(t.checked_ln().is_err());
+        assert!(t.checked_log10().is_err());
+
+        let t = 4.0 * UnitAngle::Degree;
+        assert!(t.checked_sinh().is_err());
+        assert!(t.checked_asin().is_err());
+    }
+
+    #[test]
+    fn get_hyperbolic() {
+        let t = 4.0 * UnitNone::None;
         let a = t.tanh();
-        println!("{}", a.val);
+        assert!(a.is_empty());
         assert!(a.val >= 0.9993292);
         assert!(a.val <= 0.9993293);
+        assert_eq!(t.sinh(), 4.0_f64.sinh());
+        assert_eq!(t.cosh(), 4.0_f64.cosh());
+        assert_eq!(t.asinh(), 4.0_f64.asinh());
+        assert_eq!(t.acosh(), 4.0_f64.acosh());
+        assert_eq!((0.5 * UnitNone::None).atanh(), 0.5_f64.atanh());
+    }
+
+    #[test]
+    fn get_exp_log() {
+        let t = 2.0 * UnitNone::None;
+        assert!(t.exp().is_empty());
+        assert_eq!(t.exp(), 2.0_f64.exp());
+        assert_eq!(t.ln(), 2.0_f64.ln());
+        assert_eq!((1000.0 * UnitNone::None).log10(), 3.0);
+
+        let r = (1.0 * UnitLength::Meter(Metric::None)) / (1.0 * UnitLength::Foot);
+        assert!(r.is_empty());
+        assert!(r.checked_ln().is_ok());
     }
 
     #[allow(clippy::approx_constant)]
     #[test]
     fn get_asin() {
-        let t = 0.5 * UnitLength::Meter(Metric::None);
+        let t = 0.5 * UnitNone::None;
         let a = t.asin();
-        println!("{}", a.val);
+        assert!(a.is_radians());
         assert!(a.val >= 0.523598);
         assert!(a.val <= 0.523600);
     }
@@ -1645,18 +2375,18 @@ mod value_impl_testing {
     #[allow(clippy::approx_constant)]
     #[test]
     fn get_acos() {
-        let t = 0.5 * UnitLength::Meter(Metric::None);
+        let t = 0.5 * UnitNone::None;
         let a = t.acos();
-        println!("{}", a.val);
+        assert!(a.is_radians());
         assert!(a.val >= 1.047197);
         assert!(a.val <= 1.047199);
     }
 
     #[test]
     fn get_atan() {
-        let t = 0.5 * UnitLength::Meter(Metric::None);
+        let t = 0.5 * UnitNone::None;
         let a = t.atan();
-        println!("{}", a.val);
+        assert!(a.is_radians());
         assert!(a.val >= 0.463647);
         assert!(a.val <= 0.463649);
     }
@@ -1666,9 +2396,14 @@ mod value_impl_testing {
         let t = 5.0 * UnitAngle::Radian(Metric::None);
         let v = 3.0 * UnitAngle::Radian(Metric::None);
         let a = t.atan2(&v);
-        println!("{}", a.val);
+        assert!(a.is_radians());
         assert!(a.val >= 1.0303768);
         assert!(a.val <= 1.0303769);
+
+        let t = 1.0 * UnitLength::Foot;
+        let v = 12.0 * UnitLength::Inch;
+        let a = t.atan2(&v);
+        assert!((a.val - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
     }
 
     #[test]
@@ -2366,4 +3101,68 @@ mod value_impl_testing {
         assert_eq!(t1.abs(), t2.abs());
         assert_eq!(t1.abs(), 1.1);
     }
+
+    #[test]
+    fn ratios() {
+        let p: Value = "5 %".parse().unwrap();
+        assert_eq!(p, "5%".parse::<Value>().unwrap());
+        assert_eq!(p.to_string(), "5 %");
+        assert_eq!(p.unit_string(), "%");
+        assert!(p.is_empty());
+        assert_eq!(Value::new(3.0, "ppm").unwrap().to_string(), "3 ppm");
+        assert_eq!(Value::new(2.0, "‰").unwrap().to_string(), "2 ‰");
+
+        assert_eq!(
+            (Value::new(0.05, "").unwrap() >> "%").unwrap().to_string(),
+            "5 %"
+        );
+        assert!(((p >> "").unwrap().val - 0.05).abs() < 1e-12);
+        let ppm: Value = Value::new(2500.0, "ppm").unwrap();
+        assert!(((ppm >> "%").unwrap().val - 0.25).abs() < 1e-12);
+        assert!(((ppm >> "ppb").unwrap().val - 2.5e6).abs() < 1e-6);
+        assert_eq!(ppm, Value::new(0.25, "%").unwrap());
+        assert_eq!(p, Value::new(0.05, "").unwrap());
+        assert_ne!(p, Value::new(0.05, "m").unwrap());
+        assert!(
+            p.canonical_key().unwrap() == Value::new(0.05, "").unwrap().canonical_key().unwrap()
+        );
+        assert!(ppm < p);
+
+        let m: Value = Value::new(100.0, "m").unwrap();
+        assert!(((m * p).val - 5.0).abs() < 1e-12);
+        assert_eq!((m * p).unit_string(), "m");
+        assert_eq!((p * 2.0_f64).to_string(), "10 %");
+        assert!(((p * p).val - 0.0025).abs() < 1e-12);
+        assert!(((p + Value::new(0.01, "").unwrap()).val - 6.0).abs() < 1e-12);
+        assert!(((p / Value::new(1.0, "%").unwrap()).val - 5.0).abs() < 1e-12);
+        assert!((Value::new(25.0, "%").unwrap().sqrt().val - 0.5).abs() < 1e-12);
+        let half: Value = Value::new(50.0, "%").unwrap();
+        assert!((half.checked_powf(2.5).unwrap().val - 0.5_f64.powf(2.5)).abs() < 1e-12);
+        assert!((half.powf(0.5).val - 0.5_f64.sqrt()).abs() < 1e-12);
+        assert!((half.powr(3, 2).val - 0.5_f64.powf(1.5)).abs() < 1e-12);
+
+        let frac: Value = Value::new(3.0, "mg").unwrap() / Value::new(1.0, "kg").unwrap();
+        assert!(((frac >> "ppm").unwrap().val - 3.0).abs() < 1e-9);
+        let same: Value = Value::new(2.0, "mm").unwrap() / Value::new(4.0, "km").unwrap();
+        assert!(((same >> "ppm").unwrap().val - 0.5).abs() < 1e-9);
+
+        // the same dimension in the numerator and denominator cancels
+        let frac: Value = Value::new(3.0, "mg/kg").unwrap();
+        assert!(frac.is_empty());
+        assert!(((frac >> "ppm").unwrap().val - 3.0).abs() < 1e-9);
+        assert_eq!(
+            Value::new(3.0, "kg/kg").unwrap(),
+            Value::new(3.0, "").unwrap()
+        );
+        assert_eq!(Value::new(3.0, "m/m").unwrap().to_string(), "3");
+        assert_eq!(Value::new(3.0, "m^2/m").unwrap().to_string(), "3 m");
+        assert_eq!(Value::new(3.0, "kg*m/kg").unwrap().to_string(), "3 m");
+        assert!(((Value::new(1.5, "ml/l").unwrap() >> "‰").unwrap().val - 1.5).abs() < 1e-12);
+        assert_eq!(Value::new(1.0, "mph*hr").unwrap().to_string(), "1 miles");
+
+        assert!(Value::new(1.0, "%^2").is_err());
+        assert!(Value::new(1.0, "m*%").is_err());
+        assert!(Value::new(1.0, "%*ppm").is_err());
+        assert!((Value::new(1.0, "m").unwrap() >> "%").is_err());
+    }
 }
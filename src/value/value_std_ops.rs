@@ -15,10 +15,21 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::{consts::*, units::Convert, value::Value};
-
-impl PartialEq<Value> for Value {
-    fn eq(&self, other: &Value) -> bool {
+use crate::{
+    consts::*,
+    units::{Convert, UnitSound, UnitTemperature},
+    value::{Value, exponent::PowRational, numeric::Numeric},
+};
+
+impl<T: Numeric> PartialEq<Value<T>> for Value<T> {
+    fn eq(&self, other: &Value<T>) -> bool {
+        // ratios such as `%` are equal to the plain numerics they stand for
+        if self.unit_map == 0 && other.unit_map == 0 && self.v_ratio != other.v_ratio {
+            return match (self._plain_ratio(), other._plain_ratio()) {
+                (Ok(a), Ok(b)) => a.val == b.val,
+                _ => false,
+            };
+        }
         if !self.__equal(other) {
             return false;
         }
@@ -33,15 +44,23 @@ impl PartialOrd for Value {
             return None;
         }
 
-        // special case to check if temperature is already the correct unit
-        if self.unit_map & TEMPERATURE_MAP != 0
-            && self.unit_map != TEMPERATURE_MAP
-            && self.v_temperature != other.v_temperature
+        // temperatures cannot be ordered against temperature intervals
+        if self.is_temperature()
+            && self.is_temperature_interval() != other.is_temperature_interval()
         {
             return None;
         }
 
-        let mut cmp_val: f64 = other.val;
+        // levels can only be ordered against levels of the same kind
+        if self.is_sound() && !self.v_sound.unwrap().is_compatible(&other.v_sound.unwrap()) {
+            return None;
+        }
+
+        if self._custom_exps() != other._custom_exps() {
+            return None;
+        }
+
+        let mut cmp_val: f64 = other.val * other._custom_factor_to(self);
 
         for i in 0..31_usize {
             if self.exp[i] != other.exp[i] {
@@ -140,10 +159,14 @@ impl PartialOrd for Value {
                     }
                     TEMPERATURE_MAP => {
                         if self.v_temperature != other.v_temperature {
-                            cmp_val = other
-                                .v_temperature
-                                .unwrap()
-                                .convert(&self.v_temperature.unwrap(), cmp_val);
+                            let t: UnitTemperature = other.v_temperature.unwrap();
+                            if self.is_temperature() {
+                                cmp_val = t.convert(&self.v_temperature.unwrap(), cmp_val);
+                            } else {
+                                cmp_val *= t
+                                    .interval_factor(&self.v_temperature.unwrap())
+                                    .powr(self.exp[TEMPERATURE_INDEX]);
+                            }
                         }
                     }
                     SUBSTANCE_MAP => {
@@ -247,7 +270,14 @@ impl PartialOrd for Value {
                     }
                     SOUND_MAP => {
                         if self.v_sound != other.v_sound {
-                            cmp_val *= other.v_sound.unwrap().convert(&self.v_sound.unwrap());
+                            let l: UnitSound = other.v_sound.unwrap();
+                            if self.is_sound() {
+                                cmp_val = l.convert_level(&self.v_sound.unwrap(), cmp_val);
+                            } else if l.is_referenced() || self.v_sound.unwrap().is_referenced() {
+                                return None;
+                            } else {
+                                cmp_val *= l.convert(&self.v_sound.unwrap());
+                            }
                         }
                     }
                     INFORMATION_MAP => {
@@ -274,7 +304,9 @@ impl PartialOrd for Value {
             }
         }
 
-        self.val.partial_cmp(&cmp_val)
+        self.val.partial_cmp(
+            &Value::_rescale_ratio(cmp_val, other._ratio_factor(), self._ratio_factor()).ok()?,
+        )
     }
 }
 
@@ -361,10 +393,34 @@ mod std_ops_testing {
     //#[coverage(off)]
     #[should_panic]
     fn value_ords_bad_temp() {
+        let t1 = 5.4 * UnitTemperature::Celsius(Metric::None);
+        let t2 = 5.0 * UnitTemperature::DeltaCelsius(Metric::None);
+
+        assert!(t1 > t2);
+    }
+
+    #[test]
+    fn value_ords_levels() {
+        let t1 = 20.0 * UnitSound::DecibelMilliwatt;
+        let t2 = -20.0 * UnitSound::DecibelWatt;
+        let t3 = 1.0 * UnitSound::Neper;
+        let t4 = 8.0 * UnitSound::Bel(Metric::Deci);
+
+        assert!(t1 > t2);
+        assert!(t3 > t4);
+        assert_eq!(t1.partial_cmp(&t3), None);
+        assert_eq!(t4.partial_cmp(&t1), None);
+    }
+
+    #[test]
+    fn value_ords_compound_temp() {
         let t1 = 5.4 * UnitTemperature::Celsius(Metric::None) * UnitLength::Meter(Metric::None);
         let t2 = 5.0 * UnitTemperature::Kelvin(Metric::None) * UnitLength::Meter(Metric::None);
+        let t3 = 9.5 * UnitTemperature::Fahrenheit * UnitLength::Meter(Metric::None);
 
         assert!(t1 > t2);
+        assert!(t3 < t1);
+        assert!(t3 > t2);
     }
 
     #[test]
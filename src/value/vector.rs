@@ -0,0 +1,444 @@
+/*  BXVL
+ *  Copyright (C) 2026 Bax Bradley
+ *
+ *  This library is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU Lesser General Public License as published
+ *  by the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU Lesser General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Shr, Sub},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{consts::SOUND_MAP, errors::V3Error, units::UnitNone, value::Value};
+
+/// Converts every component into the units of the first
+///
+/// Absolute temperatures and levels do not convert linearly, so they cannot be components.
+fn _unify<const N: usize>(c: [Value; N]) -> Result<[Value; N], V3Error> {
+    if (c[0].is_temperature() && !c[0].is_temperature_interval()) || c[0].unit_map & SOUND_MAP != 0
+    {
+        return Err(V3Error::IncompatibleUnits(format!(
+            "[Vector] {} cannot be a vector component",
+            c[0].unit_string()
+        )));
+    }
+    let mut ret: [Value; N] = c;
+    for v in ret.iter_mut().skip(1) {
+        *v = match *v >> c[0] {
+            Ok(n) => n,
+            Err(_) => return Err(V3Error::IncompatibleUnits(format!("{} and {}", c[0], v))),
+        };
+    }
+    Ok(ret)
+}
+
+/// Applies a fallible operation to each pair of components
+fn _zip<const N: usize>(
+    a: &[Value; N],
+    b: &[Value; N],
+    op: fn(&Value, &Value) -> Result<Value, V3Error>,
+) -> Result<[Value; N], V3Error> {
+    let mut ret: [Value; N] = *a;
+    for i in 0..N {
+        ret[i] = op(&a[i], &b[i])?;
+    }
+    Ok(ret)
+}
+
+/// Applies a fallible operation between each component and a scalar
+fn _scale<const N: usize>(
+    a: &[Value; N],
+    s: &Value,
+    op: fn(&Value, &Value) -> Result<Value, V3Error>,
+) -> Result<[Value; N], V3Error> {
+    let mut ret: [Value; N] = *a;
+    for v in ret.iter_mut() {
+        *v = op(v, s)?;
+    }
+    Ok(ret)
+}
+
+/// The [`vector_defs`] macro defines a vector type of [`Value`]s sharing a single unit
+macro_rules! vector_defs {
+    ($name:ident, $n:literal, $($comp:ident : $idx:literal),+) => {
+        impl $name<Value> {
+            /// Creates a vector from its components
+            ///
+            /// Every component is converted into the units of the first.
+            /// Returns an error if the components do not have compatible units, or if they are
+            /// absolute temperatures or levels.
+            pub fn new($($comp: Value),+) -> Result<$name<Value>, V3Error> {
+                Ok($name { c: _unify([$($comp),+])? })
+            }
+
+            $(
+                /// Returns a component of the vector
+                pub fn $comp(&self) -> Value {
+                    self.c[$idx]
+                }
+            )+
+
+            /// Returns the components of the vector
+            pub fn components(&self) -> [Value; $n] {
+                self.c
+            }
+
+            /// Returns only the unit portion of the vector as a string
+            pub fn unit_string(&self) -> String {
+                self.c[0].unit_string()
+            }
+
+            /// Adds two vectors without panicking
+            ///
+            /// `other` is converted into the units of `self` before being added.
+            pub fn try_add(&self, other: &$name<Value>) -> Result<$name<Value>, V3Error> {
+                Ok($name { c: _zip(&self.c, &other.c, Value::<f64>::try_add)? })
+            }
+
+            /// Subtracts two vectors without panicking
+            ///
+            /// `other` is converted into the units of `self` before being subtracted.
+            pub fn try_sub(&self, other: &$name<Value>) -> Result<$name<Value>, V3Error> {
+                Ok($name { c: _zip(&self.c, &other.c, Value::<f64>::try_sub)? })
+            }
+
+            /// Multiplies a vector by a scalar [`Value`] without panicking
+            pub fn try_mul(&self, other: &Value) -> Result<$name<Value>, V3Error> {
+                Ok($name { c: _scale(&self.c, other, Value::<f64>::try_mul)? })
+            }
+
+            /// Divides a vector by a scalar [`Value`] without panicking
+            pub fn try_div(&self, other: &Value) -> Result<$name<Value>, V3Error> {
+                Ok($name { c: _scale(&self.c, other, Value::<f64>::try_div)? })
+            }
+
+            /// Returns the dot product of two vectors
+            ///
+            /// The unit exponents of the vectors are added, as they are by [`Value::<f64>::try_mul`].
+            pub fn dot(&self, other: &$name<Value>) -> Result<Value, V3Error> {
+                Value::try_sum(_zip(&self.c, &other.c, Value::<f64>::try_mul)?)
+            }
+
+            /// Returns the Euclidean norm of the vector in the units of its components
+            pub fn norm(&self) -> Value {
+                let mut n: Value = self.c[0];
+                n.val = self.c.iter().map(|v| v.val * v.val).sum::<f64>().sqrt();
+                n
+            }
+
+            /// Returns the unitless vector in the same direction
+            ///
+            /// Returns an error if the vector has zero length.
+            pub fn normalize(&self) -> Result<$name<Value>, V3Error> {
+                let n: f64 = self.norm().val;
+                if n == 0.0 {
+                    return Err(V3Error::ValueConversionError(
+                        "[normalize] Zero length vector",
+                    ));
+                }
+                Ok($name { c: self.c.map(|v| (v.val / n) * UnitNone::None) })
+            }
+        }
+
+        impl<T: Display> Display for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "(")?;
+                for (i, v) in self.c.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{v}")?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl PartialEq for $name<Value> {
+            fn eq(&self, other: &$name<Value>) -> bool {
+                self.c == other.c
+            }
+        }
+
+        impl Serialize for $name<Value> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.c.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name<Value> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let c: [Value; $n] = <[Value; $n]>::deserialize(deserializer)?;
+                Ok($name { c: _unify(c).map_err(serde::de::Error::custom)? })
+            }
+        }
+
+        impl Shr<Value> for $name<Value> {
+            type Output = Result<$name<Value>, V3Error>;
+            fn shr(self, other: Value) -> Self::Output {
+                let mut c: [Value; $n] = self.c;
+                for v in c.iter_mut() {
+                    *v = (*v >> other)?;
+                }
+                Ok($name { c: _unify(c)? })
+            }
+        }
+
+        impl Shr<&str> for $name<Value> {
+            type Output = Result<$name<Value>, V3Error>;
+            fn shr(self, other: &str) -> Self::Output {
+                self >> Value::new(1.0, other)?
+            }
+        }
+
+        impl Neg for $name<Value> {
+            type Output = $name<Value>;
+            fn neg(self) -> Self::Output {
+                $name { c: self.c.map(|v| -v) }
+            }
+        }
+
+        impl Add<$name<Value>> for $name<Value> {
+            type Output = $name<Value>;
+            fn add(self, other: $name<Value>) -> Self::Output {
+                match self.try_add(&other) {
+                    Ok(v) => v,
+                    Err(_) => panic!("Cannot Add values {self} and {other}"),
+                }
+            }
+        }
+
+        impl Sub<$name<Value>> for $name<Value> {
+            type Output = $name<Value>;
+            fn sub(self, other: $name<Value>) -> Self::Output {
+                match self.try_sub(&other) {
+                    Ok(v) => v,
+                    Err(_) => panic!("Cannot Sub values {self} and {other}"),
+                }
+            }
+        }
+
+        impl Mul<Value> for $name<Value> {
+            type Output = $name<Value>;
+            fn mul(self, other: Value) -> Self::Output {
+                match self.try_mul(&other) {
+                    Ok(v) => v,
+                    Err(_) => panic!("Cannot Mul values {self} and {other}"),
+                }
+            }
+        }
+
+        impl Div<Value> for $name<Value> {
+            type Output = $name<Value>;
+            fn div(self, other: Value) -> Self::Output {
+                match self.try_div(&other) {
+                    Ok(v) => v,
+                    Err(_) => panic!("Cannot Div values {self} and {other}"),
+                }
+            }
+        }
+
+        impl Mul<f64> for $name<Value> {
+            type Output = $name<Value>;
+            fn mul(self, other: f64) -> Self::Output {
+                $name { c: self.c.map(|v| v * other) }
+            }
+        }
+
+        impl Div<f64> for $name<Value> {
+            type Output = $name<Value>;
+            fn div(self, other: f64) -> Self::Output {
+                $name { c: self.c.map(|v| v / other) }
+            }
+        }
+    };
+}
+
+/// A two dimensional vector whose components share a single unit
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::vector::Vector2;
+/// let a:Vector2<Value> = Vector2::new(
+///     Value::new(3.0, "m").unwrap(),
+///     Value::new(400.0, "cm").unwrap(),
+/// ).unwrap();
+/// assert_eq!(a.to_string(), "(3 m, 4 m)");
+/// assert_eq!(a.norm(), Value::new(5.0, "m").unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Vector2<T = Value> {
+    /// The components of the vector
+    c: [T; 2],
+}
+
+/// A three dimensional vector whose components share a single unit
+///
+/// # Example
+/// ```rust
+/// use bxvl::value::Value;
+/// use bxvl::value::vector::Vector3;
+/// let r:Vector3<Value> = Vector3::new(
+///     Value::new(0.0, "m").unwrap(),
+///     Value::new(2.0, "m").unwrap(),
+///     Value::new(0.0, "m").unwrap(),
+/// ).unwrap();
+/// let f:Vector3<Value> = Vector3::new(
+///     Value::new(3.0, "N").unwrap(),
+///     Value::new(0.0, "N").unwrap(),
+///     Value::new(0.0, "N").unwrap(),
+/// ).unwrap();
+///
+/// let t:Vector3<Value> = r.cross(&f).unwrap();
+/// assert_eq!(t.z(), Value::new(-6.0, "m*N").unwrap());
+/// assert_eq!(r.dot(&f).unwrap(), Value::new(0.0, "m*N").unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Vector3<T = Value> {
+    /// The components of the vector
+    c: [T; 3],
+}
+
+vector_defs!(Vector2, 2, x: 0, y: 1);
+vector_defs!(Vector3, 3, x: 0, y: 1, z: 2);
+
+impl Vector3<Value> {
+    /// Returns the cross product of two vectors
+    ///
+    /// The unit exponents of the vectors are added, as they are by [`Value::<f64>::try_mul`].
+    pub fn cross(&self, other: &Vector3<Value>) -> Result<Vector3<Value>, V3Error> {
+        let (a, b) = (&self.c, &other.c);
+        Ok(Vector3 {
+            c: [
+                a[1].try_mul(&b[2])?.try_sub(&a[2].try_mul(&b[1])?)?,
+                a[2].try_mul(&b[0])?.try_sub(&a[0].try_mul(&b[2])?)?,
+                a[0].try_mul(&b[1])?.try_sub(&a[1].try_mul(&b[0])?)?,
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod vector_testing {
+    use crate::units::{Metric, UnitForce, UnitLength, UnitSound, UnitTemperature, UnitTime};
+    use crate::value::{
+        Value,
+        vector::{Vector2, Vector3},
+    };
+
+    fn m(x: f64) -> Value {
+        x * UnitLength::Meter(Metric::None)
+    }
+
+    #[test]
+    fn vector_construction() {
+        let a = Vector3::new(m(1.0), 200.0 * UnitLength::Meter(Metric::Centi), m(3.0)).unwrap();
+        assert_eq!(a.y(), m(2.0));
+        assert_eq!(a.unit_string(), "m");
+        assert_eq!(a.components(), [m(1.0), m(2.0), m(3.0)]);
+        assert_eq!(a.to_string(), "(1 m, 2 m, 3 m)");
+        assert!(Vector3::new(m(1.0), m(1.0), 1.0 * UnitTime::Second(Metric::None)).is_err());
+
+        let b = Vector2::new(1.0 * UnitLength::Foot, 12.0 * UnitLength::Inch).unwrap();
+        assert_eq!(b.x(), b.y());
+        assert_eq!(b.to_string(), "(1 ft, 1 ft)");
+
+        let c = 20.0 * UnitTemperature::Celsius(Metric::None);
+        let f = 30.0 * UnitTemperature::Fahrenheit;
+        assert!(Vector2::new(c, f).is_err());
+        assert!(
+            Vector2::new(
+                1.0 * UnitSound::Bel(Metric::Deci),
+                2.0 * UnitSound::DecibelWatt
+            )
+            .is_err()
+        );
+        let dk = 5.0 * UnitTemperature::DeltaKelvin(Metric::None);
+        let d = Vector2::new(dk, dk).unwrap();
+        assert!((d >> "°C").is_err());
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vector3::new(m(1.0), m(2.0), m(3.0)).unwrap();
+        let b = Vector3::new(
+            100.0 * UnitLength::Meter(Metric::Centi),
+            100.0 * UnitLength::Meter(Metric::Centi),
+            100.0 * UnitLength::Meter(Metric::Centi),
+        )
+        .unwrap();
+        assert_eq!(a + b, Vector3::new(m(2.0), m(3.0), m(4.0)).unwrap());
+        assert_eq!(a - b, Vector3::new(m(0.0), m(1.0), m(2.0)).unwrap());
+        assert_eq!(-a, a * -1.0);
+        assert_eq!(a / 2.0, Vector3::new(m(0.5), m(1.0), m(1.5)).unwrap());
+
+        let t = 2.0 * UnitTime::Second(Metric::None);
+        let v = a / t;
+        assert_eq!(v.unit_string(), "m/s");
+        assert_eq!(v.x(), Value::new(0.5, "m/s").unwrap());
+        assert_eq!((v * t), a);
+
+        let s = Vector3::new(t, t, t).unwrap();
+        assert!(a.try_add(&s).is_err());
+        assert!(a.try_sub(&s).is_err());
+    }
+
+    #[test]
+    fn vector_products() {
+        let a = Vector3::new(m(1.0), m(2.0), m(3.0)).unwrap();
+        let f = Vector3::new(
+            4.0 * UnitForce::Newton(Metric::None),
+            5.0 * UnitForce::Newton(Metric::None),
+            6.0 * UnitForce::Newton(Metric::None),
+        )
+        .unwrap();
+        assert_eq!(a.dot(&f).unwrap(), Value::new(32.0, "m*N").unwrap());
+        assert_eq!(a.dot(&a).unwrap(), Value::new(14.0, "m^2").unwrap());
+        assert_eq!(
+            a.cross(&f).unwrap(),
+            Vector3::new(
+                Value::new(-3.0, "m*N").unwrap(),
+                Value::new(6.0, "m*N").unwrap(),
+                Value::new(-3.0, "m*N").unwrap()
+            )
+            .unwrap()
+        );
+        assert_eq!(a.cross(&a).unwrap(), a.cross(&a).unwrap() * 0.0);
+
+        let b = Vector2::new(m(3.0), m(-4.0)).unwrap();
+        assert_eq!(b.norm(), m(5.0));
+        let n = b.normalize().unwrap();
+        assert!(n.x().is_empty());
+        assert_eq!(n.x().val, 0.6);
+        assert_eq!(n.y().val, -0.8);
+        assert!(Vector2::new(m(0.0), m(0.0)).unwrap().normalize().is_err());
+        assert!(
+            Vector3::new(m(0.0), m(-0.0), m(0.0))
+                .unwrap()
+                .normalize()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn vector_conversion() {
+        let a = Vector2::new(1.0 * UnitLength::Yard, 2.0 * UnitLength::Yard).unwrap();
+        let b = (a >> "ft").unwrap();
+        assert_eq!(b.to_string(), "(3 ft, 6 ft)");
+        assert_eq!((a >> (1.0 * UnitLength::Yard)).unwrap().unit_string(), "yd");
+        assert!((a >> "s").is_err());
+    }
+}